@@ -1,6 +1,15 @@
 //! Commands made to easily integrate into your own `bitbelay` tools.
 
 pub mod avalanche;
+pub mod balance;
 pub mod chi_squared;
+pub mod collision;
 pub mod correlation;
+pub mod extension;
+pub mod flooding;
+pub mod hashbrown;
 pub mod performance;
+pub mod plan;
+pub mod probing;
+pub mod sanity;
+pub mod seed;
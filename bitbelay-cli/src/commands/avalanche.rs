@@ -6,9 +6,13 @@ use std::num::NonZeroUsize;
 use anyhow::Context;
 use anyhow::anyhow;
 use anyhow::bail;
-use bitbelay_providers::Provider;
+use bitbelay_providers::AvailableProviders;
 use bitbelay_report::Config;
+use bitbelay_statistics::correction::Procedure;
 use bitbelay_suites::r#trait::Suite;
+use bitbelay_tests::seeded::Seeder;
+use bitbelay_tests::tolerance::Tolerance;
+use clap::ArgAction;
 
 /// Arguments for the avalanche command.
 #[derive(clap::Args, Debug)]
@@ -18,6 +22,18 @@ pub struct Args {
     #[arg(short, long, default_value_t = 1 << 12)]
     experiments: usize,
 
+    /// Sizes the Strict Avalanche Criterion test automatically such that it
+    /// detects a bit with a bias of at least `--effect-size` with this
+    /// probability (instead of using `--experiments`).
+    #[arg(long, conflicts_with = "experiments")]
+    power: Option<f64>,
+
+    /// The smallest bias (deviance from a 50% flip probability) that the
+    /// Strict Avalanche Criterion test should detect when sized with
+    /// `--power`.
+    #[arg(long, default_value_t = 0.001)]
+    effect_size: f64,
+
     /// The number of iterations per experiment for the Strict Avalanche
     /// Criterion test.
     #[arg(short, long, default_value_t = 1 << 12)]
@@ -27,41 +43,115 @@ pub struct Args {
     /// the test to be considered successful.
     #[arg(short, long, default_value_t = 0.01)]
     max_deviance: f64,
+
+    /// Derives the bias tolerances of the Strict Avalanche Criterion and seed
+    /// avalanche tests from the number of trials and `--significance` instead
+    /// of using `--max-deviance`.
+    #[clap(long, action = ArgAction::SetTrue)]
+    sample_size_tolerance: bool,
+
+    /// The threshold of statistical significance for the family-wise verdict
+    /// of the Strict Avalanche Criterion test (and for the bias tolerances
+    /// when `--sample-size-tolerance` is set).
+    #[arg(long, default_value_t = 0.05)]
+    significance: f64,
+
+    /// The procedure used to correct for the number of output bits tested
+    /// within the Strict Avalanche Criterion test.
+    #[arg(long, default_value_t)]
+    correction: Procedure,
+
+    /// The number of experiments to perform for the seed avalanche test (only
+    /// run for hashers that can be explicitly seeded).
+    #[arg(long, default_value_t = 1 << 10)]
+    seed_experiments: usize,
 }
 
 /// The main function for the avalanche command.
 pub fn main<H: BuildHasher>(
     args: Args,
     build_hasher: H,
-    provider: Box<dyn Provider>,
+    seeder: Option<Seeder<H>>,
+    provider: AvailableProviders,
 ) -> anyhow::Result<()> {
     tracing::info!("Starting avalanche test suite.");
 
-    let experiments = NonZeroUsize::try_from(args.experiments)
-        .map_err(|_| anyhow!("--experiments must be non-zero!"))?;
-
     let iterations_per_experiment = NonZeroUsize::try_from(args.iterations_per_experiment)
         .map_err(|_| anyhow!("--iterations per experiment must be non-zero!"))?;
 
+    let seed_experiments = NonZeroUsize::try_from(args.seed_experiments)
+        .map_err(|_| anyhow!("--seed-experiments must be non-zero!"))?;
+
     let max_deviance = if (0.0..=1.0).contains(&args.max_deviance) {
         args.max_deviance
     } else {
         bail!("--max-deviance must be in the range of [0, 1]!")
     };
 
+    if args.significance <= 0.0 || args.significance >= 1.0 {
+        bail!("--significance must be between 0.0 and 1.0!");
+    }
+
+    let tolerance = if args.sample_size_tolerance {
+        Tolerance::SampleSize(args.significance)
+    } else {
+        Tolerance::Fixed(max_deviance)
+    };
+
     let mut suite = bitbelay_suites::avalanche::suite::Builder::<H, 64>::default()
         .build_hasher(&build_hasher)?
         .try_build()?;
 
+    let experiments = match args.power {
+        Some(power) => {
+            if power <= 0.0 || power >= 1.0 {
+                bail!("--power must be between 0.0 and 1.0!");
+            }
+
+            let experiments = suite
+                .required_experiments(
+                    iterations_per_experiment,
+                    args.effect_size,
+                    args.significance,
+                    power,
+                )
+                .ok_or_else(|| {
+                    anyhow!(
+                        "unable to size the Strict Avalanche Criterion test (check --effect-size)!"
+                    )
+                })?;
+
+            tracing::info!(
+                "Sized the Strict Avalanche Criterion test to {} experiments.",
+                experiments
+            );
+
+            experiments
+        }
+        None => NonZeroUsize::try_from(args.experiments)
+            .map_err(|_| anyhow!("--experiments must be non-zero!"))?,
+    };
+
     suite
         .run_strict_avalanche_criterion_test(
-            provider,
+            provider.clone().into(),
             experiments,
             iterations_per_experiment,
-            max_deviance,
+            tolerance,
+            args.significance,
+            args.correction,
         )
         .with_context(|| "running strict avalanche criterion test")?;
 
+    match seeder {
+        Some(seeder) => {
+            suite
+                .run_seed_test(seeder, provider.into(), seed_experiments, tolerance)
+                .with_context(|| "running seed avalanche test")?;
+        }
+        None => tracing::info!("Skipping the seed avalanche test: the hasher cannot be seeded."),
+    }
+
     suite
         .report()
         .write_to(&mut std::io::stderr(), &Config::default())?;
@@ -0,0 +1,56 @@
+//! A command for running the balance test suite.
+
+use std::hash::BuildHasher;
+use std::num::NonZeroUsize;
+
+use anyhow::anyhow;
+use anyhow::bail;
+use bitbelay_providers::Provider;
+use bitbelay_report::Config;
+use bitbelay_statistics::correction::Procedure;
+use bitbelay_suites::r#trait::Suite;
+
+/// Arguments for the balance command.
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    /// The number of keys to hash for the bit balance test.
+    #[arg(short, long, default_value_t = 1 << 16)]
+    keys: usize,
+
+    /// The threshold of statistical significance (applied to the p-values
+    /// after correcting for the number of output bits).
+    #[arg(long, default_value_t = 0.05)]
+    threshold: f64,
+
+    /// The procedure used to correct for the number of output bits tested.
+    #[arg(long, default_value_t)]
+    correction: Procedure,
+}
+
+/// The main function for the balance command.
+pub fn main<H: BuildHasher>(
+    args: Args,
+    build_hasher: H,
+    provider: Box<dyn Provider>,
+) -> anyhow::Result<()> {
+    tracing::info!("Starting balance test suite.");
+
+    let keys =
+        NonZeroUsize::try_from(args.keys).map_err(|_| anyhow!("--keys must be non-zero!"))?;
+
+    if !(0.0..=1.0).contains(&args.threshold) {
+        bail!("--threshold must be between 0.0 and 1.0!");
+    }
+
+    let mut suite = bitbelay_suites::balance::suite::Builder::default()
+        .build_hasher(&build_hasher)?
+        .try_build()?;
+
+    suite.run_bit_test(provider, keys, args.threshold, args.correction);
+
+    suite
+        .report()
+        .write_to(&mut std::io::stderr(), &Config::default())?;
+
+    Ok(())
+}
@@ -5,10 +5,13 @@ use std::num::NonZeroUsize;
 
 use anyhow::anyhow;
 use anyhow::bail;
+use bitbelay_providers::AvailableProviders;
 use bitbelay_providers::Provider;
 use bitbelay_report::Config;
 use bitbelay_suites::chi_squared::suite::Builder;
 use bitbelay_suites::r#trait::Suite as _;
+use bitbelay_tests::bucketing::Bucketing;
+use bitbelay_tests::chi_squared::independence;
 use tracing::Level;
 
 /// The default number of iterations per bucket.
@@ -16,20 +19,112 @@ use tracing::Level;
 /// NOTE: if this changes, update the argument documentation for `iterations`.
 const DEFAULT_ITERATIONS_PER_BUCKET: usize = 1000;
 
+/// The default number of iterations per cell of the contingency table within
+/// the sub-field independence test.
+///
+/// NOTE: if this changes, update the argument documentation for
+/// `independence_iterations`.
+const DEFAULT_INDEPENDENCE_ITERATIONS_PER_CELL: usize = 10;
+
+/// The default number of iterations per bucket (of the largest number of
+/// buckets) when sweeping across table sizes.
+///
+/// NOTE: if this changes, update the argument documentation for `iterations`.
+const DEFAULT_SWEEP_ITERATIONS_PER_BUCKET: usize = 10;
+
+/// The default primes to include when sweeping across table sizes (the largest
+/// prime less than each of 2^6, 2^8, ..., 2^20).
+const DEFAULT_SWEEP_PRIMES: &str = "61,251,1021,4093,16381,65521,262139,1048573";
+
 /// Arguments for the chi-squared command.
 #[derive(clap::Args, Debug)]
 pub struct Args {
     /// The number of buckets.
-    #[arg(short, long, default_value_t = 64)]
+    #[arg(short, long, default_value_t = 64, conflicts_with = "sweep")]
     buckets: usize,
 
+    /// The strategy used to map each hash to a bucket.
+    ///
+    /// Note that `low-bit-mask` and `high-bit-shift` require the number of
+    /// buckets to be a power of two.
+    #[arg(long, default_value_t)]
+    bucketing: Bucketing,
+
     /// The number of iterations to test.
     ///
     /// If no number is given, then the number will be 100 * the number of
-    /// buckets to ensure enough samples are taken.
+    /// buckets to ensure enough samples are taken. When sweeping, the number
+    /// will be 10 * the largest number of buckets in the sweep.
     #[arg(short, long)]
     iterations: Option<usize>,
 
+    /// Sizes the goodness of fit test automatically such that it detects a
+    /// deviation from a uniform distribution with an effect size of at least
+    /// `--effect-size` with this probability (instead of using
+    /// `--iterations`).
+    #[arg(long, conflicts_with_all = ["iterations", "sweep"])]
+    power: Option<f64>,
+
+    /// The smallest deviation from a uniform distribution (as Cohen's _w_)
+    /// that the goodness of fit test should detect when sized with `--power`.
+    #[arg(long, default_value_t = 0.05)]
+    effect_size: f64,
+
+    /// Additionally repeats the goodness of fit test this many times with fresh
+    /// data and checks that the resulting p-values are uniformly distributed
+    /// (a second-level test).
+    #[arg(long, conflicts_with = "sweep")]
+    repetitions: Option<usize>,
+
+    /// Sweeps the goodness of fit test across many table sizes (reusing the
+    /// same hashes for each) instead of testing a single number of buckets.
+    ///
+    /// The sweep honors `--bucketing`, `--iterations`, and `--threshold`, but
+    /// none of the other tests are run alongside it.
+    #[arg(long)]
+    sweep: bool,
+
+    /// The smallest power of two to include when sweeping.
+    #[arg(long, default_value_t = 4)]
+    sweep_min_power: u32,
+
+    /// The largest power of two to include when sweeping.
+    #[arg(long, default_value_t = 20)]
+    sweep_max_power: u32,
+
+    /// Additional (generally, prime) table sizes to include when sweeping.
+    ///
+    /// These are skipped when the bucketing strategy requires a power of two.
+    #[arg(long, value_delimiter = ',', default_value = DEFAULT_SWEEP_PRIMES)]
+    sweep_primes: Vec<usize>,
+
+    /// Additionally runs the Hamming weight (popcount) test with the same
+    /// number of iterations as the goodness of fit test.
+    #[arg(long, conflicts_with = "sweep")]
+    popcount: bool,
+
+    /// Additionally runs the Kolmogorov–Smirnov and Anderson–Darling
+    /// uniformity test with the same number of iterations as the goodness of
+    /// fit test.
+    #[arg(long, conflicts_with = "sweep")]
+    uniformity: bool,
+
+    /// Additionally runs the sub-field independence test.
+    #[arg(long, conflicts_with = "sweep")]
+    independence: bool,
+
+    /// The width (in bits) of the low and high sub-fields compared within the
+    /// sub-field independence test.
+    #[arg(long, default_value_t = 8, conflicts_with = "sweep")]
+    field_bits: u32,
+
+    /// The number of iterations to run for the sub-field independence test.
+    ///
+    /// If no number is given, then the number will be 10 * the number of cells
+    /// in the contingency table (i.e., 10 * 4^field-bits).
+    #[arg(long, conflicts_with = "sweep")]
+    independence_iterations: Option<usize>,
+
     /// The threshold of statistical significance.
     #[arg(long, default_value_t = 0.05)]
     threshold: f64,
@@ -39,38 +134,72 @@ pub struct Args {
 pub fn main<H: BuildHasher>(
     args: Args,
     build_hasher: H,
-    provider: Box<dyn Provider>,
+    provider: AvailableProviders,
 ) -> anyhow::Result<()> {
     tracing::info!("Starting chi-squared test suite.");
 
-    let buckets =
-        NonZeroUsize::try_from(args.buckets).map_err(|_| anyhow!("--buckets must be non-zero!"))?;
-
-    let iterations = NonZeroUsize::try_from(
-        args.iterations
-            .unwrap_or(args.buckets * DEFAULT_ITERATIONS_PER_BUCKET),
-    )
-    .map_err(|_| anyhow!("--iterations must be non-zero!"))?;
-
     if !(0.0..=1.0).contains(&args.threshold) {
         bail!("--threshold must be between 0.0 and 1.0!");
     }
 
-    tracing::info!(
-        "Running chi-squared test with {} buckets for {} iterations.",
-        args.buckets,
-        iterations
-    );
+    if args.field_bits == 0 || args.field_bits > independence::MAX_FIELD_BITS {
+        bail!(
+            "--field-bits must be between 1 and {}!",
+            independence::MAX_FIELD_BITS
+        );
+    }
+
+    if args.sweep {
+        return sweep(args, build_hasher, provider.into());
+    }
+
+    let buckets =
+        NonZeroUsize::try_from(args.buckets).map_err(|_| anyhow!("--buckets must be non-zero!"))?;
+
+    if !args.bucketing.supports(buckets) {
+        bail!(
+            "--bucketing {} requires --buckets to be a power of two!",
+            args.bucketing
+        );
+    }
 
     let mut suite = Builder::default()
         .buckets(buckets)
         .unwrap()
+        .bucketing(args.bucketing)
+        .unwrap()
         .build_hasher(&build_hasher)
         .unwrap()
         .try_build()
         .unwrap();
 
-    suite.run_goodness_of_fit(provider, iterations, args.threshold);
+    let iterations = match args.power {
+        Some(power) => {
+            if power <= 0.0 || power >= 1.0 {
+                bail!("--power must be between 0.0 and 1.0!");
+            }
+
+            suite
+                .required_iterations(args.effect_size, args.threshold, power)
+                .ok_or_else(|| {
+                    anyhow!("unable to size the goodness of fit test (check --effect-size)!")
+                })?
+        }
+        None => NonZeroUsize::try_from(
+            args.iterations
+                .unwrap_or(args.buckets * DEFAULT_ITERATIONS_PER_BUCKET),
+        )
+        .map_err(|_| anyhow!("--iterations must be non-zero!"))?,
+    };
+
+    tracing::info!(
+        "Running chi-squared test with {} buckets ({} bucketing) for {} iterations.",
+        args.buckets,
+        args.bucketing,
+        iterations
+    );
+
+    suite.run_goodness_of_fit(provider.clone().into(), iterations, args.threshold)?;
 
     if tracing::enabled!(Level::TRACE) {
         // SAFETY: we know there must be one test because we just ran it above!
@@ -87,6 +216,131 @@ pub fn main<H: BuildHasher>(
         }
     }
 
+    if let Some(repetitions) = args.repetitions {
+        let repetitions = NonZeroUsize::try_from(repetitions)
+            .map_err(|_| anyhow!("--repetitions must be non-zero!"))?;
+
+        tracing::info!(
+            "Running second-level goodness of fit test with {} repetitions of {} iterations.",
+            repetitions,
+            iterations
+        );
+
+        suite.run_goodness_of_fit_meta_test(
+            provider.clone().into(),
+            repetitions,
+            iterations,
+            args.threshold,
+        )?;
+    }
+
+    if args.popcount {
+        tracing::info!("Running Hamming weight test for {} iterations.", iterations);
+
+        suite.run_popcount_test(provider.clone().into(), iterations, args.threshold);
+    }
+
+    if args.uniformity {
+        tracing::info!("Running uniformity test for {} iterations.", iterations);
+        suite.run_uniformity_test(provider.clone().into(), iterations, args.threshold);
+    }
+
+    if args.independence {
+        let iterations = NonZeroUsize::try_from(
+            args.independence_iterations
+                .unwrap_or(DEFAULT_INDEPENDENCE_ITERATIONS_PER_CELL << (2 * args.field_bits)),
+        )
+        .map_err(|_| anyhow!("--independence-iterations must be non-zero!"))?;
+
+        tracing::info!(
+            "Running sub-field independence test with {}-bit fields for {} iterations.",
+            args.field_bits,
+            iterations
+        );
+
+        suite.run_independence_test(
+            provider.into(),
+            iterations,
+            args.field_bits,
+            args.threshold,
+        )?;
+    }
+
+    suite
+        .report()
+        .write_to(&mut std::io::stderr(), &Config::default())?;
+
+    Ok(())
+}
+
+/// Runs the goodness of fit sweep across table sizes.
+fn sweep<H: BuildHasher>(
+    args: Args,
+    build_hasher: H,
+    provider: Box<dyn Provider>,
+) -> anyhow::Result<()> {
+    if args.sweep_min_power > args.sweep_max_power || args.sweep_max_power >= usize::BITS {
+        bail!(
+            "--sweep-min-power must be less than or equal to --sweep-max-power, which must be \
+             less than {}!",
+            usize::BITS
+        );
+    }
+
+    let mut bucket_counts = (args.sweep_min_power..=args.sweep_max_power)
+        .map(|power| 1usize << power)
+        .collect::<Vec<_>>();
+
+    for prime in args.sweep_primes {
+        let buckets = NonZeroUsize::try_from(prime)
+            .map_err(|_| anyhow!("--sweep-primes must be non-zero!"))?;
+
+        if args.bucketing.supports(buckets) {
+            bucket_counts.push(prime);
+        } else {
+            tracing::warn!(
+                "Skipping {} buckets, as it is not supported by {} bucketing.",
+                prime,
+                args.bucketing
+            );
+        }
+    }
+
+    bucket_counts.sort();
+    bucket_counts.dedup();
+
+    // SAFETY: every power of two and every prime was checked to be non-zero above.
+    let bucket_counts = bucket_counts
+        .into_iter()
+        .map(|buckets| NonZeroUsize::try_from(buckets).unwrap())
+        .collect::<Vec<_>>();
+
+    // SAFETY: there is always at least one power of two in the sweep.
+    let largest = bucket_counts.last().unwrap().get();
+
+    let iterations = NonZeroUsize::try_from(
+        args.iterations
+            .unwrap_or(largest * DEFAULT_SWEEP_ITERATIONS_PER_BUCKET),
+    )
+    .map_err(|_| anyhow!("--iterations must be non-zero!"))?;
+
+    tracing::info!(
+        "Running chi-squared sweep across {} table sizes ({} bucketing) for {} iterations.",
+        bucket_counts.len(),
+        args.bucketing,
+        iterations
+    );
+
+    let mut suite = Builder::default()
+        .bucketing(args.bucketing)
+        .unwrap()
+        .build_hasher(&build_hasher)
+        .unwrap()
+        .try_build()
+        .unwrap();
+
+    suite.run_goodness_of_fit_sweep(provider, iterations, bucket_counts, args.threshold)?;
+
     suite
         .report()
         .write_to(&mut std::io::stderr(), &Config::default())?;
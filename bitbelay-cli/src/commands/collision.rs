@@ -0,0 +1,121 @@
+//! A command for running the collision test suite.
+
+use std::hash::BuildHasher;
+use std::num::NonZeroUsize;
+
+use anyhow::Context;
+use anyhow::anyhow;
+use anyhow::bail;
+use bitbelay_providers::Provider;
+use bitbelay_report::Config;
+use bitbelay_suites::r#trait::Suite;
+use bitbelay_tests::collision::differential;
+use bitbelay_tests::collision::differential::MAX_WEIGHT;
+
+/// Arguments for the collision command.
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    /// The number of keys to hash for the differential test.
+    #[arg(short, long, default_value_t = 1 << 8)]
+    keys: usize,
+
+    /// Sizes the differential test automatically such that it detects a hash
+    /// function producing at least `--effect-size` times as many collisions as
+    /// a random function with this probability (instead of using `--keys`).
+    #[arg(long, conflicts_with = "keys")]
+    power: Option<f64>,
+
+    /// The smallest ratio of observed to expected collisions that the
+    /// differential test should detect when sized with `--power`.
+    #[arg(long, default_value_t = 2.0)]
+    effect_size: f64,
+
+    /// The number of leading key bits that may be flipped for the differential
+    /// test.
+    #[arg(long, default_value_t = 64)]
+    key_bits: usize,
+
+    /// The maximum number of key bits flipped within a modified copy for the
+    /// differential test.
+    #[arg(short = 'w', long, default_value_t = 3)]
+    max_weight: usize,
+
+    /// The number of low output bits compared when checking for a collision.
+    #[arg(short, long, default_value_t = 32)]
+    bits: u32,
+
+    /// The threshold of statistical significance.
+    #[arg(long, default_value_t = 0.05)]
+    threshold: f64,
+}
+
+/// The main function for the collision command.
+pub fn main<H: BuildHasher>(
+    args: Args,
+    build_hasher: H,
+    provider: Box<dyn Provider>,
+) -> anyhow::Result<()> {
+    tracing::info!("Starting collision test suite.");
+
+    let key_bits = NonZeroUsize::try_from(args.key_bits)
+        .map_err(|_| anyhow!("--key-bits must be non-zero!"))?;
+
+    if !(1..=MAX_WEIGHT).contains(&args.max_weight) {
+        bail!("--max-weight must be between 1 and {}!", MAX_WEIGHT);
+    }
+
+    if !(1..=64).contains(&args.bits) {
+        bail!("--bits must be between 1 and 64!");
+    }
+
+    if !(0.0..=1.0).contains(&args.threshold) {
+        bail!("--threshold must be between 0.0 and 1.0!");
+    }
+
+    let keys = match args.power {
+        Some(power) => {
+            if power <= 0.0 || power >= 1.0 {
+                bail!("--power must be between 0.0 and 1.0!");
+            }
+
+            let keys = differential::required_keys(
+                key_bits,
+                args.max_weight,
+                args.bits,
+                args.effect_size,
+                args.threshold,
+                power,
+            )
+            .ok_or_else(|| {
+                anyhow!("unable to size the differential test (check --effect-size)!")
+            })?;
+
+            tracing::info!("Sized the differential test to {} keys.", keys);
+            keys
+        }
+        None => {
+            NonZeroUsize::try_from(args.keys).map_err(|_| anyhow!("--keys must be non-zero!"))?
+        }
+    };
+
+    let mut suite = bitbelay_suites::collision::suite::Builder::default()
+        .build_hasher(&build_hasher)?
+        .try_build()?;
+
+    suite
+        .run_differential_test(
+            provider,
+            keys,
+            key_bits,
+            args.max_weight,
+            args.bits,
+            args.threshold,
+        )
+        .with_context(|| "running differential test")?;
+
+    suite
+        .report()
+        .write_to(&mut std::io::stderr(), &Config::default())?;
+
+    Ok(())
+}
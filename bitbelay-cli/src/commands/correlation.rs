@@ -6,10 +6,12 @@ use std::num::NonZeroUsize;
 use anyhow::Context;
 use anyhow::anyhow;
 use anyhow::bail;
-use bitbelay_providers::Provider;
+use bitbelay_providers::AvailableProviders;
 use bitbelay_report::Config;
+use bitbelay_statistics::correction::Procedure;
+use bitbelay_statistics::correlation::matrix::CorrelationMatrix;
 use bitbelay_suites::r#trait::Suite;
-use bitbelay_tests::correlation::bitwise;
+use bitbelay_tests::tolerance::Tolerance;
 use clap::ArgAction;
 use colored::Colorize as _;
 
@@ -25,15 +27,56 @@ const CORRELATION_MATRIX_STOP_THREE: f64 = 0.50;
 /// Arguments for the correlation command.
 #[derive(clap::Args, Debug)]
 pub struct Args {
-    /// The number of iterations to carry out for the Bitwise test.
+    /// The number of iterations to carry out for each test.
     #[arg(short, long, default_value_t = 1 << 16)]
     iterations: usize,
 
+    /// Sizes the bitwise test automatically such that it detects a pair of
+    /// output bits with a correlation of at least `--effect-size` with this
+    /// probability (instead of using `--iterations`).
+    #[arg(long)]
+    power: Option<f64>,
+
+    /// The smallest correlation between a pair of output bits that the bitwise
+    /// test should detect when sized with `--power`.
+    #[arg(long, default_value_t = 0.01)]
+    effect_size: f64,
+
     /// The threshold of correlation at which any non-diagonal value causes the
     /// test to fail.
     #[arg(long, default_value_t = 0.05)]
     threshold: f64,
 
+    /// Derives the correlation threshold of the bitwise test from the number
+    /// of iterations and `--significance` instead of using `--threshold`.
+    #[clap(long, action = ArgAction::SetTrue)]
+    sample_size_tolerance: bool,
+
+    /// The maximum number of leading input bits that are correlated with the
+    /// output bits within the input-output correlation test.
+    #[arg(long, default_value_t = 256)]
+    max_input_bits: usize,
+
+    /// The largest lag for which the autocorrelation of the hash stream is
+    /// computed within the serial correlation test.
+    #[arg(long, default_value_t = 16)]
+    max_lag: usize,
+
+    /// The threshold of statistical significance for the bitwise, serial
+    /// correlation, and mutual information tests.
+    #[arg(long, default_value_t = 0.05)]
+    significance: f64,
+
+    /// The procedure used to correct for the number of comparisons made
+    /// within the bitwise, serial correlation, and mutual information tests.
+    #[arg(long, default_value_t)]
+    correction: Procedure,
+
+    /// The number of iterations to carry out for the mutual information test
+    /// (at least 327,680 are needed for the test to be conclusive).
+    #[arg(long, default_value_t = 1 << 20)]
+    mutual_information_iterations: usize,
+
     /// Prints the full correlation matrix to the terminal.
     #[clap(long, action = ArgAction::SetTrue)]
     correlation_matrix: bool,
@@ -47,7 +90,7 @@ pub struct Args {
 pub fn main<H: BuildHasher, const N: usize>(
     args: Args,
     build_hasher: H,
-    provider: Box<dyn Provider>,
+    provider: AvailableProviders,
 ) -> anyhow::Result<()> {
     tracing::info!("Starting correlation test suite.");
 
@@ -60,6 +103,19 @@ pub fn main<H: BuildHasher, const N: usize>(
         bail!("--threshold must be between 0.0 and 1.0!");
     };
 
+    let max_input_bits = NonZeroUsize::try_from(args.max_input_bits)
+        .map_err(|_| anyhow!("--max-input-bits must be non-zero!"))?;
+
+    let max_lag =
+        NonZeroUsize::try_from(args.max_lag).map_err(|_| anyhow!("--max-lag must be non-zero!"))?;
+
+    if args.significance <= 0.0 || args.significance >= 1.0 {
+        bail!("--significance must be between 0.0 and 1.0!");
+    }
+
+    let mutual_information_iterations = NonZeroUsize::try_from(args.mutual_information_iterations)
+        .map_err(|_| anyhow!("--mutual-information-iterations must be non-zero!"))?;
+
     let correlation_matrix_cell_width = NonZeroUsize::try_from(args.correlation_matrix_cell_width)
         .map_err(|_| anyhow!("--correlation-matrix-cell-width must be non-zero!"))
         .and_then(|cell_size| {
@@ -72,14 +128,74 @@ pub fn main<H: BuildHasher, const N: usize>(
             }
         })?;
 
+    let tolerance = if args.sample_size_tolerance {
+        Tolerance::SampleSize(args.significance)
+    } else {
+        Tolerance::Fixed(threshold)
+    };
+
     let mut suite = bitbelay_suites::correlation::suite::Builder::<H>::default()
         .build_hasher(&build_hasher)?
         .try_build::<N>()?;
 
+    let bitwise_iterations = match args.power {
+        Some(power) => {
+            if power <= 0.0 || power >= 1.0 {
+                bail!("--power must be between 0.0 and 1.0!");
+            }
+
+            let iterations = suite
+                .required_bitwise_iterations(args.effect_size, args.significance, power)
+                .ok_or_else(|| anyhow!("unable to size the bitwise test (check --effect-size)!"))?;
+
+            tracing::info!("Sized the bitwise test to {} iterations.", iterations);
+            iterations
+        }
+        None => iterations,
+    };
+
     suite
-        .run_bitwise_test(provider, iterations, threshold)
+        .run_bitwise_test(
+            provider.clone().into(),
+            bitwise_iterations,
+            tolerance,
+            args.significance,
+            args.correction,
+        )
         .with_context(|| "running bitwise test")?;
 
+    suite
+        .run_spearman_test(provider.clone().into(), iterations, threshold)
+        .with_context(|| "running spearman test")?;
+
+    suite
+        .run_input_output_test(
+            provider.clone().into(),
+            iterations,
+            max_input_bits,
+            threshold,
+        )
+        .with_context(|| "running input-output correlation test")?;
+
+    suite
+        .run_serial_test(
+            provider.clone().into(),
+            iterations,
+            max_lag,
+            args.significance,
+            args.correction,
+        )
+        .with_context(|| "running serial correlation test")?;
+
+    suite
+        .run_mutual_information_test(
+            provider.into(),
+            mutual_information_iterations,
+            args.significance,
+            args.correction,
+        )
+        .with_context(|| "running mutual information test")?;
+
     suite
         .report()
         .write_to(&mut std::io::stderr(), &Config::default())?;
@@ -97,14 +213,14 @@ pub fn main<H: BuildHasher, const N: usize>(
                 "there should be at least one bitwise test! This is an issue and should be looked \
                  at by the developers (please report this issue!)"
             ),
-            1 => print_correlation_table::<N>(
+            1 => print_correlation_table(
                 correlation_matrix_cell_width.get(),
                 // SAFETY: for the first unwrap, we just checked to ensure there is exactly one
                 // bitwise test, so this will always unwrap.
                 //
                 // SAFETY: for the second unwrap, this command _requires_ that at least
                 // one test iteration is run. As such, this will always unwrap.
-                bitwise_tests.pop().unwrap().results().unwrap(),
+                &bitwise_tests.pop().unwrap().results().unwrap(),
             ),
             v => bail!(
                 "there are {} bitwise tests, and it's not clear what correlation matrix to print \
@@ -117,7 +233,7 @@ pub fn main<H: BuildHasher, const N: usize>(
 }
 
 /// Prints a correlation table to stdout.
-fn print_correlation_table<const N: usize>(width: usize, correlations: bitwise::Results) {
+fn print_correlation_table(width: usize, correlations: &CorrelationMatrix) {
     if width == 0 {
         panic!("width of correlation table entries cannot be 0!");
     }
@@ -152,7 +268,7 @@ fn print_correlation_table<const N: usize>(width: usize, correlations: bitwise::
     println!();
 
     // Print the header.
-    for i in 1..=N {
+    for i in 1..=correlations.size() {
         if i < 10 || i % 10 == 0 {
             print!("{:^width$}", i.to_string().bold().underline())
         } else {
@@ -163,12 +279,8 @@ fn print_correlation_table<const N: usize>(width: usize, correlations: bitwise::
     println!();
 
     // Print each correlation value.
-    for i in 0..N {
-        for j in 0..N {
-            // SAFETY: for the first unwrap, due to the construction of this [`HashMap`]
-            // always containing correlations of NxN size, this will always
-            // unwrap.
-            let value = *correlations.get(&(i, j)).unwrap();
+    for i in 0..correlations.size() {
+        for value in correlations.row(i) {
             let cell = " ".repeat(width);
 
             if let Some(value) = value {
@@ -0,0 +1,62 @@
+//! A command for running the extension test suite.
+
+use std::hash::BuildHasher;
+use std::num::NonZeroUsize;
+
+use anyhow::Context;
+use anyhow::anyhow;
+use anyhow::bail;
+use bitbelay_providers::Provider;
+use bitbelay_report::Config;
+use bitbelay_suites::r#trait::Suite;
+
+/// Arguments for the extension command.
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    /// The number of keys to hash for the length extension test.
+    #[arg(short, long, default_value_t = 1 << 14)]
+    keys: usize,
+
+    /// The maximum number of zero bytes appended or prepended to (and the
+    /// maximum number of chunks written for) each key.
+    #[arg(short, long, default_value_t = 8)]
+    max_extension: usize,
+
+    /// The threshold of statistical significance.
+    #[arg(long, default_value_t = 0.05)]
+    threshold: f64,
+}
+
+/// The main function for the extension command.
+pub fn main<H: BuildHasher>(
+    args: Args,
+    build_hasher: H,
+    provider: Box<dyn Provider>,
+) -> anyhow::Result<()> {
+    tracing::info!("Starting extension test suite.");
+
+    let keys =
+        NonZeroUsize::try_from(args.keys).map_err(|_| anyhow!("--keys must be non-zero!"))?;
+
+    if args.max_extension < 2 {
+        bail!("--max-extension must be at least two!");
+    }
+
+    if !(0.0..=1.0).contains(&args.threshold) {
+        bail!("--threshold must be between 0.0 and 1.0!");
+    }
+
+    let mut suite = bitbelay_suites::extension::suite::Builder::default()
+        .build_hasher(&build_hasher)?
+        .try_build()?;
+
+    suite
+        .run_length_test(provider, keys, args.max_extension, args.threshold)
+        .with_context(|| "running length extension test")?;
+
+    suite
+        .report()
+        .write_to(&mut std::io::stderr(), &Config::default())?;
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+//! A command for running the hash flooding test suite.
+
+use std::hash::BuildHasher;
+use std::num::NonZeroUsize;
+
+use anyhow::Context;
+use anyhow::anyhow;
+use anyhow::bail;
+use bitbelay_providers::Provider;
+use bitbelay_report::Config;
+use bitbelay_suites::r#trait::Suite;
+use bitbelay_tests::flooding::persistent::MAX_WEIGHT;
+use bitbelay_tests::seeded::Seeder;
+
+/// Arguments for the hash flooding command.
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    /// The number of keys to search for the persistent collision test.
+    #[arg(short, long, default_value_t = 1 << 8)]
+    keys: usize,
+
+    /// The number of low output bits compared when checking for a collision.
+    #[arg(short, long, default_value_t = 16)]
+    bits: u32,
+
+    /// The maximum number of input bits that differ within a searched pair.
+    #[arg(short = 'w', long, default_value_t = 2)]
+    max_weight: usize,
+
+    /// The number of fresh seeds under which each candidate pair is
+    /// re-checked.
+    #[arg(short, long, default_value_t = 8)]
+    reseeds: usize,
+
+    /// The threshold of statistical significance.
+    #[arg(long, default_value_t = 0.05)]
+    threshold: f64,
+}
+
+/// The main function for the hash flooding command.
+pub fn main<H: BuildHasher>(
+    args: Args,
+    seeder: Option<Seeder<H>>,
+    provider: Box<dyn Provider>,
+) -> anyhow::Result<()> {
+    tracing::info!("Starting hash flooding test suite.");
+
+    let seeder = match seeder {
+        Some(seeder) => seeder,
+        None => bail!(
+            "the flooding command requires a hasher that can be explicitly seeded (use \
+             `wrapper_seeded()` rather than `wrapper()`)!"
+        ),
+    };
+
+    let keys =
+        NonZeroUsize::try_from(args.keys).map_err(|_| anyhow!("--keys must be non-zero!"))?;
+
+    if !(1..=64).contains(&args.bits) {
+        bail!("--bits must be between 1 and 64!");
+    }
+
+    if !(1..=MAX_WEIGHT).contains(&args.max_weight) {
+        bail!("--max-weight must be between 1 and {}!", MAX_WEIGHT);
+    }
+
+    let reseeds =
+        NonZeroUsize::try_from(args.reseeds).map_err(|_| anyhow!("--reseeds must be non-zero!"))?;
+
+    if !(0.0..=1.0).contains(&args.threshold) {
+        bail!("--threshold must be between 0.0 and 1.0!");
+    }
+
+    let mut suite = bitbelay_suites::flooding::suite::Builder::default()
+        .seeder(seeder)?
+        .try_build()?;
+
+    suite
+        .run_persistent_test(
+            provider,
+            keys,
+            args.bits,
+            args.max_weight,
+            reseeds,
+            args.threshold,
+        )
+        .with_context(|| "running persistent collision test")?;
+
+    suite
+        .report()
+        .write_to(&mut std::io::stderr(), &Config::default())?;
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+//! A command for running the `hashbrown` test suite.
+
+use std::hash::BuildHasher;
+use std::num::NonZeroUsize;
+
+use anyhow::Context;
+use anyhow::anyhow;
+use anyhow::bail;
+use bitbelay_providers::Provider;
+use bitbelay_report::Config;
+use bitbelay_suites::r#trait::Suite;
+
+/// Arguments for the `hashbrown` command.
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    /// The number of buckets within the simulated table for the H1/H2 split
+    /// test (must be a power of two of at least 16).
+    #[arg(short, long, default_value_t = 1 << 14)]
+    buckets: usize,
+
+    /// The number of iterations to carry out for the H1/H2 split test.
+    ///
+    /// Iterations beyond those needed to fill the simulated table are used as
+    /// lookups when measuring the false match rate.
+    #[arg(short, long, default_value_t = 1 << 20)]
+    iterations: usize,
+
+    /// The threshold of statistical significance.
+    #[arg(long, default_value_t = 0.05)]
+    threshold: f64,
+}
+
+/// The main function for the `hashbrown` command.
+pub fn main<H: BuildHasher>(
+    args: Args,
+    build_hasher: H,
+    provider: Box<dyn Provider>,
+) -> anyhow::Result<()> {
+    tracing::info!("Starting hashbrown test suite.");
+
+    let buckets =
+        NonZeroUsize::try_from(args.buckets).map_err(|_| anyhow!("--buckets must be non-zero!"))?;
+
+    let iterations = NonZeroUsize::try_from(args.iterations)
+        .map_err(|_| anyhow!("--iterations must be non-zero!"))?;
+
+    if !(0.0..=1.0).contains(&args.threshold) {
+        bail!("--threshold must be between 0.0 and 1.0!");
+    }
+
+    let mut suite = bitbelay_suites::hashbrown::suite::Builder::default()
+        .build_hasher(&build_hasher)?
+        .try_build()?;
+
+    suite
+        .run_split_test(provider, iterations, buckets, args.threshold)
+        .with_context(|| "running H1/H2 split test")?;
+
+    suite
+        .report()
+        .write_to(&mut std::io::stderr(), &Config::default())?;
+
+    Ok(())
+}
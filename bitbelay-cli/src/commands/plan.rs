@@ -0,0 +1,180 @@
+//! A command for planning the number of samples needed by each test.
+
+use std::num::NonZeroUsize;
+
+use anyhow::anyhow;
+use anyhow::bail;
+use bitbelay_tests::avalanche::sac;
+use bitbelay_tests::chi_squared::goodness_of_fit;
+use bitbelay_tests::collision::differential;
+use bitbelay_tests::collision::differential::MAX_WEIGHT;
+use bitbelay_tests::correlation::bitwise;
+use colored::Colorize as _;
+
+/// The number of output bits assumed for the avalanche and correlation tests.
+const OUTPUT_BITS: usize = 64;
+
+/// Arguments for the plan command.
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    /// The threshold of statistical significance.
+    #[arg(long, default_value_t = 0.05)]
+    significance: f64,
+
+    /// The desired probability of detecting an effect of the given size (the
+    /// statistical power).
+    #[arg(long, default_value_t = 0.8)]
+    power: f64,
+
+    /// The number of buckets for the goodness of fit test.
+    #[arg(short, long, default_value_t = 64)]
+    buckets: usize,
+
+    /// The smallest deviation from a uniform distribution (as Cohen's _w_) to
+    /// detect within the goodness of fit test.
+    #[arg(long, default_value_t = 0.05)]
+    goodness_of_fit_effect_size: f64,
+
+    /// The smallest bias (deviance from a 50% flip probability) to detect
+    /// within the Strict Avalanche Criterion test.
+    #[arg(long, default_value_t = 0.001)]
+    bias: f64,
+
+    /// The number of iterations per experiment for the Strict Avalanche
+    /// Criterion test.
+    #[arg(short, long, default_value_t = 1 << 12)]
+    iterations_per_experiment: usize,
+
+    /// The smallest correlation between a pair of output bits to detect within
+    /// the bitwise correlation test.
+    #[arg(long, default_value_t = 0.01)]
+    correlation: f64,
+
+    /// The smallest ratio of observed to expected collisions to detect within
+    /// the differential collision test.
+    #[arg(long, default_value_t = 2.0)]
+    collision_ratio: f64,
+
+    /// The number of leading key bits that may be flipped for the differential
+    /// collision test.
+    #[arg(long, default_value_t = 64)]
+    key_bits: usize,
+
+    /// The maximum number of key bits flipped within a modified copy for the
+    /// differential collision test.
+    #[arg(short = 'w', long, default_value_t = 3)]
+    max_weight: usize,
+
+    /// The number of low output bits compared when checking for a collision
+    /// within the differential collision test.
+    #[arg(long, default_value_t = 32)]
+    bits: u32,
+}
+
+/// The main function for the plan command.
+pub fn main(args: Args) -> anyhow::Result<()> {
+    if args.significance <= 0.0 || args.significance >= 1.0 {
+        bail!("--significance must be between 0.0 and 1.0!");
+    }
+
+    if args.power <= 0.0 || args.power >= 1.0 {
+        bail!("--power must be between 0.0 and 1.0!");
+    }
+
+    let buckets =
+        NonZeroUsize::try_from(args.buckets).map_err(|_| anyhow!("--buckets must be non-zero!"))?;
+
+    let iterations_per_experiment = NonZeroUsize::try_from(args.iterations_per_experiment)
+        .map_err(|_| anyhow!("--iterations-per-experiment must be non-zero!"))?;
+
+    let key_bits = NonZeroUsize::try_from(args.key_bits)
+        .map_err(|_| anyhow!("--key-bits must be non-zero!"))?;
+
+    if !(1..=MAX_WEIGHT).contains(&args.max_weight) {
+        bail!("--max-weight must be between 1 and {}!", MAX_WEIGHT);
+    }
+
+    if !(1..=64).contains(&args.bits) {
+        bail!("--bits must be between 1 and 64!");
+    }
+
+    let goodness_of_fit = goodness_of_fit::required_iterations(
+        buckets,
+        args.goodness_of_fit_effect_size,
+        args.significance,
+        args.power,
+    )
+    .ok_or_else(|| anyhow!("--goodness-of-fit-effect-size must be positive!"))?;
+
+    let sac = sac::required_iterations(OUTPUT_BITS, args.bias, args.significance, args.power)
+        .ok_or_else(|| anyhow!("--bias must be non-zero and less than 0.5!"))?;
+
+    let bitwise =
+        bitwise::required_iterations(OUTPUT_BITS, args.correlation, args.significance, args.power)
+            .ok_or_else(|| anyhow!("--correlation must be non-zero and between -1.0 and 1.0!"))?;
+
+    let differential = differential::required_keys(
+        key_bits,
+        args.max_weight,
+        args.bits,
+        args.collision_ratio,
+        args.significance,
+        args.power,
+    )
+    .ok_or_else(|| anyhow!("--collision-ratio must be greater than 1.0!"))?;
+
+    println!(
+        "{}\n\nSamples needed to detect each effect with a probability of {} at a significance of \
+         {}.\n",
+        "Plan".bold().underline(),
+        args.power,
+        args.significance
+    );
+
+    println!(
+        "* {}: {} iterations to detect a deviation of w = {} across {} buckets (`bitbelay \
+         chi-squared --buckets {} --iterations {}`).",
+        "Goodness of fit".bold(),
+        goodness_of_fit,
+        args.goodness_of_fit_effect_size,
+        buckets,
+        buckets,
+        goodness_of_fit
+    );
+
+    println!(
+        "* {}: {} iterations to detect a bit bias of {} across {} output bits (`bitbelay \
+         avalanche --iterations-per-experiment {} --experiments {}`).",
+        "Strict Avalanche Criterion".bold(),
+        sac,
+        args.bias,
+        OUTPUT_BITS,
+        iterations_per_experiment,
+        sac.get().div_ceil(iterations_per_experiment.get())
+    );
+
+    println!(
+        "* {}: {} iterations to detect a correlation of {} across {} pairs of output bits \
+         (`bitbelay correlation --iterations {}`).",
+        "Bitwise correlation".bold(),
+        bitwise,
+        args.correlation,
+        OUTPUT_BITS * (OUTPUT_BITS - 1) / 2,
+        bitwise
+    );
+
+    println!(
+        "* {}: {} keys to detect {}x as many collisions as expected in the low {} output bits \
+         (`bitbelay collision --key-bits {} --max-weight {} --bits {} --keys {}`).",
+        "Differential collisions".bold(),
+        differential,
+        args.collision_ratio,
+        args.bits,
+        key_bits,
+        args.max_weight,
+        args.bits,
+        differential
+    );
+
+    Ok(())
+}
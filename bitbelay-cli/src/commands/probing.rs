@@ -0,0 +1,129 @@
+//! A command for running the probing test suite.
+
+use std::hash::BuildHasher;
+use std::num::NonZeroUsize;
+
+use anyhow::Context;
+use anyhow::anyhow;
+use anyhow::bail;
+use bitbelay_providers::AvailableProviders;
+use bitbelay_report::Config;
+use bitbelay_suites::r#trait::Suite;
+use bitbelay_tests::bucketing::Bucketing;
+use bitbelay_tests::probing::length::Scheme;
+
+/// Arguments for the probing command.
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    /// The collision resolution schemes to simulate.
+    #[arg(
+        short,
+        long,
+        value_enum,
+        value_delimiter = ',',
+        default_value = "linear,quadratic,robin-hood"
+    )]
+    schemes: Vec<Scheme>,
+
+    /// The load factors to which each simulated table is filled.
+    #[arg(short, long, value_delimiter = ',', default_value = "0.5,0.75,0.875")]
+    load_factors: Vec<f64>,
+
+    /// The number of buckets within each simulated table (must be a power of
+    /// two).
+    #[arg(short, long, default_value_t = 1 << 16)]
+    buckets: usize,
+
+    /// The strategy used to map each hash to its home slot.
+    #[arg(long, default_value_t)]
+    bucketing: Bucketing,
+
+    /// The number of tables to fill for each scheme and load factor.
+    #[arg(long, default_value_t = 16)]
+    tables: usize,
+
+    /// The maximum relative deviance from an ideal, random hash for the test
+    /// to be considered successful.
+    #[arg(short, long, default_value_t = 0.05)]
+    max_deviance: f64,
+
+    /// The threshold of statistical significance used to determine whether a
+    /// value that exceeds the maximum deviance is a failure.
+    #[arg(long, default_value_t = 0.01)]
+    significance: f64,
+}
+
+/// The main function for the probing command.
+///
+/// Unlike most commands, this command runs many tests (one per scheme and load
+/// factor), so it receives the selected [`AvailableProviders`] rather than a
+/// single [`Provider`](bitbelay_providers::Provider).
+pub fn main<H: BuildHasher>(
+    args: Args,
+    build_hasher: H,
+    provider: AvailableProviders,
+) -> anyhow::Result<()> {
+    tracing::info!("Starting probing test suite.");
+
+    let buckets =
+        NonZeroUsize::try_from(args.buckets).map_err(|_| anyhow!("--buckets must be non-zero!"))?;
+
+    if !buckets.is_power_of_two() {
+        bail!("--buckets must be a power of two!");
+    }
+
+    let tables =
+        NonZeroUsize::try_from(args.tables).map_err(|_| anyhow!("--tables must be non-zero!"))?;
+
+    if let Some(load_factor) = args
+        .load_factors
+        .iter()
+        .find(|load_factor| !(**load_factor > 0.0 && **load_factor < 1.0))
+    {
+        bail!(
+            "--load-factors must be within the range (0, 1), but {} was provided!",
+            load_factor
+        );
+    }
+
+    if args.max_deviance < 0.0 {
+        bail!("--max-deviance must be non-negative!");
+    }
+
+    if args.significance <= 0.0 || args.significance >= 1.0 {
+        bail!("--significance must be between 0.0 and 1.0!");
+    }
+
+    let mut suite = bitbelay_suites::probing::suite::Builder::default()
+        .bucketing(args.bucketing)?
+        .build_hasher(&build_hasher)?
+        .try_build()?;
+
+    for scheme in &args.schemes {
+        for load_factor in &args.load_factors {
+            tracing::info!(
+                "Running {} probing at a load factor of {}.",
+                scheme,
+                load_factor
+            );
+
+            suite
+                .run_probe_length_test(
+                    provider.clone().into(),
+                    *scheme,
+                    buckets,
+                    *load_factor,
+                    tables,
+                    args.max_deviance,
+                    args.significance,
+                )
+                .with_context(|| "running probe sequence length test")?;
+        }
+    }
+
+    suite
+        .report()
+        .write_to(&mut std::io::stderr(), &Config::default())?;
+
+    Ok(())
+}
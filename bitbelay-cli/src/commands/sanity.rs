@@ -0,0 +1,41 @@
+//! A command for running the sanity test suite.
+
+use std::hash::BuildHasher;
+use std::num::NonZeroUsize;
+
+use anyhow::anyhow;
+use bitbelay_providers::Provider;
+use bitbelay_report::Config;
+use bitbelay_suites::r#trait::Suite;
+
+/// Arguments for the sanity command.
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    /// The number of keys to check for the correctness test.
+    #[arg(short, long, default_value_t = 1 << 10)]
+    keys: usize,
+}
+
+/// The main function for the sanity command.
+pub fn main<H: BuildHasher>(
+    args: Args,
+    build_hasher: H,
+    provider: Box<dyn Provider>,
+) -> anyhow::Result<()> {
+    tracing::info!("Starting sanity test suite.");
+
+    let keys =
+        NonZeroUsize::try_from(args.keys).map_err(|_| anyhow!("--keys must be non-zero!"))?;
+
+    let mut suite = bitbelay_suites::sanity::suite::Builder::default()
+        .build_hasher(&build_hasher)?
+        .try_build()?;
+
+    suite.run_correctness_test(provider, keys);
+
+    suite
+        .report()
+        .write_to(&mut std::io::stderr(), &Config::default())?;
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+//! A command for running the seed test suite.
+
+use std::hash::BuildHasher;
+use std::num::NonZeroUsize;
+
+use anyhow::Context;
+use anyhow::anyhow;
+use anyhow::bail;
+use bitbelay_providers::Provider;
+use bitbelay_report::Config;
+use bitbelay_statistics::correction::Procedure;
+use bitbelay_suites::r#trait::Suite;
+
+/// Arguments for the seed command.
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    /// The number of hasher instances to create for the seed independence
+    /// test.
+    #[arg(short, long, default_value_t = 32)]
+    instances: usize,
+
+    /// The number of keys to hash with every instance for the seed
+    /// independence test.
+    #[arg(short, long, default_value_t = 1 << 12)]
+    keys: usize,
+
+    /// The threshold of statistical significance.
+    #[arg(long, default_value_t = 0.05)]
+    threshold: f64,
+
+    /// The procedure used to correct for the number of pairs of instances
+    /// tested.
+    #[arg(long, default_value_t)]
+    correction: Procedure,
+}
+
+/// The main function for the seed command.
+pub fn main<H: BuildHasher>(
+    args: Args,
+    factory: Option<&dyn Fn() -> H>,
+    provider: Box<dyn Provider>,
+) -> anyhow::Result<()> {
+    tracing::info!("Starting seed test suite.");
+
+    let factory = match factory {
+        Some(factory) => factory,
+        None => bail!(
+            "the seed command requires a hasher factory (use `wrapper_with_factory()` rather than \
+             `wrapper()`)!"
+        ),
+    };
+
+    let instances = NonZeroUsize::try_from(args.instances)
+        .map_err(|_| anyhow!("--instances must be non-zero!"))?;
+
+    let keys =
+        NonZeroUsize::try_from(args.keys).map_err(|_| anyhow!("--keys must be non-zero!"))?;
+
+    if !(0.0..=1.0).contains(&args.threshold) {
+        bail!("--threshold must be between 0.0 and 1.0!");
+    }
+
+    let mut suite = bitbelay_suites::seed::suite::Builder::default()
+        .factory(factory)?
+        .try_build()?;
+
+    suite
+        .run_independence_test(provider, instances, keys, args.threshold, args.correction)
+        .with_context(|| "running seed independence test")?;
+
+    suite
+        .report()
+        .write_to(&mut std::io::stderr(), &Config::default())?;
+
+    Ok(())
+}
@@ -5,13 +5,24 @@ pub mod commands;
 use std::hash::BuildHasher;
 
 use bitbelay_providers::AvailableProviders;
+pub use bitbelay_tests::seeded::SeededBuildHasher;
+use bitbelay_tests::seeded::Seeder;
 use clap::Parser;
 use clap::Subcommand;
 
 use crate::commands::avalanche;
+use crate::commands::balance;
 use crate::commands::chi_squared;
+use crate::commands::collision;
 use crate::commands::correlation;
+use crate::commands::extension;
+use crate::commands::flooding;
+use crate::commands::hashbrown;
 use crate::commands::performance;
+use crate::commands::plan;
+use crate::commands::probing;
+use crate::commands::sanity;
+use crate::commands::seed;
 
 /// A performance evaluation harness for hashing functions.
 #[derive(Debug, Parser)]
@@ -40,18 +51,53 @@ pub enum Commands {
     /// Runs the avalanche test suite.
     Avalanche(commands::avalanche::Args),
 
+    /// Runs the balance test suite.
+    Balance(commands::balance::Args),
+
     /// Runs the chi-squared test suite.
     ChiSquared(commands::chi_squared::Args),
 
+    /// Runs the collision test suite.
+    Collision(commands::collision::Args),
+
     /// Runs the correlation test suite.
     Correlation(commands::correlation::Args),
 
+    /// Runs the extension test suite.
+    Extension(commands::extension::Args),
+
+    /// Runs the hash flooding test suite (requires a seeded hasher).
+    Flooding(commands::flooding::Args),
+
+    /// Runs the `hashbrown` test suite.
+    Hashbrown(commands::hashbrown::Args),
+
     /// Runs the speed test suite.
     Performance(commands::performance::Args),
+
+    /// Plans the number of samples each test needs to detect a given effect.
+    Plan(commands::plan::Args),
+
+    /// Runs the probing test suite.
+    Probing(commands::probing::Args),
+
+    /// Runs the sanity test suite.
+    Sanity(commands::sanity::Args),
+
+    /// Runs the seed test suite (requires a hasher factory).
+    Seed(commands::seed::Args),
 }
 
 /// The main function for the wrapper.
-fn main<H: BuildHasher>(build_hasher: H) -> anyhow::Result<()> {
+///
+/// Commands that evaluate many instances of a hasher (e.g., the seed command)
+/// require a `factory` to create those instances, and tests that change the
+/// seed of a hasher (e.g., the seed avalanche test) require a `seeder`.
+fn main<H: BuildHasher>(
+    build_hasher: H,
+    factory: Option<&dyn Fn() -> H>,
+    seeder: Option<Seeder<H>>,
+) -> anyhow::Result<()> {
     let global_args = Args::parse();
 
     let log_level = if global_args.trace {
@@ -68,13 +114,22 @@ fn main<H: BuildHasher>(build_hasher: H) -> anyhow::Result<()> {
 
     match global_args.command {
         Commands::Avalanche(args) => {
-            avalanche::main(args, build_hasher, global_args.provider.into())
+            avalanche::main(args, build_hasher, seeder, global_args.provider)
         }
-        Commands::ChiSquared(args) => {
-            chi_squared::main(args, build_hasher, global_args.provider.into())
+        Commands::Balance(args) => balance::main(args, build_hasher, global_args.provider.into()),
+        Commands::ChiSquared(args) => chi_squared::main(args, build_hasher, global_args.provider),
+        Commands::Collision(args) => {
+            collision::main(args, build_hasher, global_args.provider.into())
         }
         Commands::Correlation(args) => {
-            correlation::main::<H, 64>(args, build_hasher, global_args.provider.into())
+            correlation::main::<H, 64>(args, build_hasher, global_args.provider)
+        }
+        Commands::Extension(args) => {
+            extension::main(args, build_hasher, global_args.provider.into())
+        }
+        Commands::Flooding(args) => flooding::main(args, seeder, global_args.provider.into()),
+        Commands::Hashbrown(args) => {
+            hashbrown::main(args, build_hasher, global_args.provider.into())
         }
         Commands::Performance(args) => {
             if global_args.trace || global_args.verbose {
@@ -92,10 +147,40 @@ fn main<H: BuildHasher>(build_hasher: H) -> anyhow::Result<()> {
 
             performance::main(args, build_hasher, global_args.provider.into())
         }
+        Commands::Plan(args) => plan::main(args),
+        Commands::Probing(args) => probing::main(args, build_hasher, global_args.provider),
+        Commands::Sanity(args) => sanity::main(args, build_hasher, global_args.provider.into()),
+        Commands::Seed(args) => seed::main(args, factory, global_args.provider.into()),
     }
 }
 
 /// A wrapper for an out-of-the-box command line tool for `bitbelay`.
+///
+/// Commands that require many instances of a hasher (e.g., the seed command)
+/// are not available through this wrapper—use [`wrapper_with_factory()`]
+/// instead.
 pub fn wrapper<H: BuildHasher>(build_hasher: H) -> anyhow::Result<()> {
-    main(build_hasher)
+    main(build_hasher, None, None)
+}
+
+/// A wrapper for an out-of-the-box command line tool for `bitbelay` that
+/// creates hashers using a factory.
+///
+/// A single instance created by the factory is used for commands that evaluate
+/// a single hasher, while commands that evaluate many instances of a hasher
+/// (e.g., the seed command) call the factory for each instance.
+pub fn wrapper_with_factory<H: BuildHasher, F: Fn() -> H>(factory: F) -> anyhow::Result<()> {
+    main(factory(), Some(&factory), None)
+}
+
+/// A wrapper for an out-of-the-box command line tool for `bitbelay` that
+/// evaluates a [`SeededBuildHasher`].
+///
+/// Every command is available through this wrapper: hashers are created from
+/// random seeds, and tests that change the seed of a hasher (e.g., the seed
+/// avalanche test) are run in addition to the usual tests.
+pub fn wrapper_seeded<H: SeededBuildHasher>() -> anyhow::Result<()> {
+    let seeder = Seeder::<H>::new();
+    let factory = || seeder.random();
+    main(factory(), Some(&factory), Some(seeder))
 }
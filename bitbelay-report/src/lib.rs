@@ -352,13 +352,27 @@ fn write_section_wrapped_lines<W: Write>(
 //===================//
 
 /// Writes a section title.
+///
+/// Titles that are too long to fit on a single line are wrapped, and each
+/// continuation line is indented to align with the start of the title.
 fn write_section_title<W: Write>(
     writer: &mut W,
     title: &str,
     config: &Config,
 ) -> std::io::Result<()> {
-    let title = format!("{} {}", "#".bold(), title.underline().bold());
-    write_section_line(writer, &title, config)
+    // Two spaces, two description block chars, and the leading `# `.
+    let max_line_length = config.width() - 6;
+
+    for (i, line) in textwrap::wrap(title, Options::new(max_line_length))
+        .into_iter()
+        .enumerate()
+    {
+        let prefix = if i == 0 { "#" } else { " " };
+        let line = format!("{} {}", prefix.bold(), line.underline().bold());
+        write_section_line(writer, &line, config)?;
+    }
+
+    Ok(())
 }
 
 /// Writes a horizontal rule within a section.
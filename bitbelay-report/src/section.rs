@@ -12,8 +12,8 @@ pub enum Section {
 }
 
 impl Section {
-    /// Returns a refernece to a [`Some(TestResult)`] if the [`Section`] is of
-    /// type [`Section::TestResult`]. Else, returns [`None`].
+    /// Returns a reference to a [`Test`] wrapped in [`Some`] if the [`Section`]
+    /// is of type [`Section::TestResult`]. Else, returns [`None`].
     ///
     /// # Examples
     ///
@@ -0,0 +1,89 @@
+//! Binomial statistical tests.
+//!
+//! # Supported Tests
+//!
+//! At present, only the following binomial tests are supported:
+//!
+//! * An exact, two-sided [binomial test] via [`two_sided_p_value()`].
+//!
+//! # Sources
+//!
+//! * The main page for the binomial test ([link][binomial test]).
+//!
+//! [binomial test]: https://en.wikipedia.org/wiki/Binomial_test
+
+use statrs::distribution::Binomial;
+use statrs::distribution::DiscreteCDF as _;
+
+/// Computes the exact, two-sided p-value of observing `successes` within
+/// `trials` independent trials that each succeed with `probability`.
+///
+/// The two-sided p-value is computed by doubling the smaller of the two tails
+/// (and capping the result at `1.0`), which is exact when `probability` is
+/// `0.5`.
+///
+/// If there are no trials, if `successes` exceeds `trials`, or if
+/// `probability` is not within `(0.0, 1.0)`, then no result is returned.
+///
+/// # Examples
+///
+/// ```
+/// use bitbelay_statistics::binomial::two_sided_p_value;
+///
+/// // A fair coin landing heads 50 times out of 100 is entirely unremarkable.
+/// let p = two_sided_p_value(50, 100, 0.5).unwrap();
+/// assert!(p > 0.05);
+///
+/// // A fair coin landing heads 80 times out of 100 is not.
+/// let p = two_sided_p_value(80, 100, 0.5).unwrap();
+/// assert!(p < 0.05);
+///
+/// assert!(two_sided_p_value(0, 0, 0.5).is_none());
+/// ```
+pub fn two_sided_p_value(successes: u64, trials: u64, probability: f64) -> Option<f64> {
+    if trials == 0 || successes > trials || probability <= 0.0 || probability >= 1.0 {
+        return None;
+    }
+
+    // SAFETY: we checked above that the probability is within `(0.0, 1.0)`, so
+    // this will always unwrap.
+    let distribution = Binomial::new(probability, trials).unwrap();
+
+    let lower = distribution.cdf(successes);
+    let upper = match successes {
+        0 => 1.0,
+        successes => distribution.sf(successes - 1),
+    };
+
+    Some((2.0 * lower.min(upper)).min(1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn matches_known_values() {
+        // P(X <= 2) for X ~ Binomial(10, 0.5) is 56 / 1024.
+        assert_relative_eq!(
+            two_sided_p_value(2, 10, 0.5).unwrap(),
+            2.0 * 56.0 / 1024.0,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            two_sided_p_value(8, 10, 0.5).unwrap(),
+            2.0 * 56.0 / 1024.0,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(two_sided_p_value(5, 10, 0.5).unwrap(), 1.0);
+    }
+
+    #[test]
+    fn invalid_inputs() {
+        assert!(two_sided_p_value(11, 10, 0.5).is_none());
+        assert!(two_sided_p_value(5, 10, 0.0).is_none());
+        assert!(two_sided_p_value(5, 10, 1.0).is_none());
+    }
+}
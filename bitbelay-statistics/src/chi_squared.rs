@@ -7,6 +7,12 @@
 //! * A [Pearson goodness of fit](pearson-chi-squared-test) against a
 //!   theoretrical random, uniform distribution via
 //!   [`UniformPearsonTest::goodness_of_fit()`].
+//! * A multinomial goodness of fit against arbitrary expected frequencies (or
+//!   probabilities) using either the Pearson or the [G][g-test] statistic via
+//!   [`MultinomialTest`].
+//! * A [test of independence][independence] between the two variables of a
+//!   contingency table using either the Pearson or the G statistic via
+//!   [`ContingencyTest::independence()`].
 //!
 //! Other tests may be added in the future as needed.
 //!
@@ -28,8 +34,9 @@
 //! [p-value-explanation]: https://en.wikipedia.org/wiki/Chi-squared_distribution#Computational_methods
 //! [example]:
 //!     https://en.wikipedia.org/wiki/Pearson's_chi-squared_test#Chi-squared_goodness_of_fit_test
-
-use std::f64::NAN;
+//! [g-test]: https://en.wikipedia.org/wiki/G-test
+//! [independence]:
+//!     https://en.wikipedia.org/wiki/Pearson%27s_chi-squared_test#Testing_for_statistical_independence
 
 use statrs::distribution::ChiSquared;
 use statrs::distribution::ContinuousCDF as _;
@@ -41,38 +48,156 @@ use statrs::distribution::ContinuousCDF as _;
 ///
 /// * If the number of expected items per bucket under the random, uniform
 ///   distribution is not at least 5, then no result is returned.
-/// * To simplify the code, the theoretical distribution is hardcoded to model a
-///   uniform distribution. This means that the expected values are determined
-///   by taking the total number of observations and spreading them equally
-///   amongst the possible slots.
-/// * As such, this function cannot be used to calculate chi-squared for
-///   arbitrary multinomial distributions (though it may be extended to do so in
-///   the future).
+/// * The expected values are determined by taking the total number of
+///   observations and spreading them equally amongst the possible slots. For
+///   arbitrary multinomial distributions, see [`MultinomialTest`].
 pub(crate) fn chi_squared_uniform(observations: &[usize]) -> Option<f64> {
     let expected = observations.iter().sum::<usize>() as f64 / observations.len() as f64;
+    chi_squared(observations, &vec![expected; observations.len()])
+}
+
+/// The minimum expected frequency within each bin for the chi-squared
+/// approximation to be considered valid.
+const MIN_EXPECTED: f64 = 5.0;
+
+/// The tolerance within which a set of probabilities must sum to one.
+const PROBABILITY_TOLERANCE: f64 = 1e-6;
+
+/// Pools adjacent bins (in order) until every bin has an expected frequency of
+/// at least [`MIN_EXPECTED`].
+///
+/// Any remaining bins at the end that do not reach [`MIN_EXPECTED`] are pooled
+/// into the last bin. This is appropriate for distributions whose tails have
+/// small expected frequencies, such as the binomial distribution.
+pub(crate) fn pool(observations: &[usize], expected: &[f64]) -> (Vec<usize>, Vec<f64>) {
+    let mut pooled_observations = Vec::new();
+    let mut pooled_expected = Vec::new();
+
+    let mut current_observations = 0;
+    let mut current_expected = 0.0;
+
+    for (observed, expected) in observations.iter().zip(expected.iter()) {
+        current_observations += observed;
+        current_expected += expected;
+
+        if current_expected >= MIN_EXPECTED {
+            pooled_observations.push(current_observations);
+            pooled_expected.push(current_expected);
+
+            current_observations = 0;
+            current_expected = 0.0;
+        }
+    }
+
+    if current_expected > 0.0 || current_observations > 0 {
+        match (pooled_observations.last_mut(), pooled_expected.last_mut()) {
+            (Some(observations), Some(expected)) => {
+                *observations += current_observations;
+                *expected += current_expected;
+            }
+            _ => {
+                pooled_observations.push(current_observations);
+                pooled_expected.push(current_expected);
+            }
+        }
+    }
+
+    (pooled_observations, pooled_expected)
+}
+
+/// Generates the chi-squared (X^2) test statistic for a given observed
+/// distribution against arbitrary expected frequencies.
+///
+/// # Notes
+///
+/// * If the number of observations does not match the number of expected
+///   frequencies, or if any expected frequency is not at least 5, then no
+///   result is returned.
+pub(crate) fn chi_squared(observations: &[usize], expected: &[f64]) -> Option<f64> {
+    if observations.len() != expected.len() || expected.iter().any(|e| *e < MIN_EXPECTED) {
+        return None;
+    }
+
+    Some(pearson_statistic(observations, expected))
+}
+
+/// Computes `sum((O - E)^2 / E)` without checking the expected frequencies.
+fn pearson_statistic(observations: &[usize], expected: &[f64]) -> f64 {
+    observations
+        .iter()
+        .zip(expected.iter())
+        .fold(0.0, |acc, (&observed, &expected)| {
+            acc + (observed as f64 - expected).powi(2) / expected
+        })
+}
 
-    if expected < 5.0 {
+/// Generates the G (log-likelihood ratio) test statistic for a given observed
+/// distribution against arbitrary expected frequencies.
+///
+/// # Notes
+///
+/// * If the number of observations does not match the number of expected
+///   frequencies, or if any expected frequency is not at least 5, then no
+///   result is returned.
+/// * Bins with no observations contribute nothing to the statistic.
+pub(crate) fn g(observations: &[usize], expected: &[f64]) -> Option<f64> {
+    if observations.len() != expected.len() || expected.iter().any(|e| *e < MIN_EXPECTED) {
         return None;
     }
 
-    let chi_squared = observations.iter().fold(0.0, |acc, &count| {
-        let difference = count as isize - expected as isize;
-        acc + (difference.pow(2) as f64) / expected
-    });
+    Some(g_statistic(observations, expected))
+}
+
+/// Computes `2 * sum(O * ln(O / E))` without checking the expected
+/// frequencies.
+fn g_statistic(observations: &[usize], expected: &[f64]) -> f64 {
+    let g = observations
+        .iter()
+        .zip(expected.iter())
+        .filter(|(observed, _)| **observed > 0)
+        .fold(0.0, |acc, (&observed, &expected)| {
+            let observed = observed as f64;
+            acc + observed * (observed / expected).ln()
+        });
+
+    // NOTE: this avoids returning tiny negative values caused by floating
+    // point error when the observations match the expectations exactly.
+    (2.0 * g).max(0.0)
+}
+
+/// Computes the p-value for a chi-squared statistic with the given degrees of
+/// freedom.
+pub(crate) fn p_value(chi_squared_statistic: f64, degrees_of_freedom: f64) -> Option<f64> {
+    let percentile = ChiSquared::new(degrees_of_freedom)
+        .unwrap_or_else(|_| {
+            // SAFETY: this would be highly irregular to fail with the inputs that
+            // are supported. As such, any failure to instantiate this should panic
+            // and be investigated further.
+            panic!(
+                "could not create chi-squared distribution with {} degrees of freedom",
+                degrees_of_freedom
+            )
+        })
+        .cdf(chi_squared_statistic);
 
-    Some(chi_squared)
+    if percentile.is_nan() {
+        return None;
+    }
+
+    Some(1.0 - percentile)
 }
 
 /// Pearson chi-squared tests for a theoretical uniform distribution.
 ///
 /// # Notes
 ///
-/// * To simplify the code, all tests assume a theoretical uniform distrbution
-///   rather than arbitrary multinomial distributions (though the code may be
-///   extended to support that in the future).
+/// * These tests assume a theoretical uniform distrbution and return no result
+///   when the expected frequency of each bin is too small. For arbitrary
+///   multinomial distributions (or to pool small bins), see
+///   [`MultinomialTest`].
 /// * Though there are Pearson tests for goodness of fit, homogeneity, and
-///   independence, only goodness of fit is implemented at present. This may
-///   change in the future if other tests are needed.
+///   independence, only goodness of fit is implemented here. Independence is
+///   tested via [`ContingencyTest`].
 ///
 /// # Sources
 ///
@@ -147,23 +272,350 @@ impl UniformPearsonTest {
         let chi_squared_statistic = chi_squared_uniform(observations)?;
         let degrees_of_freedom = observations.len() as f64 - 1.0;
 
-        let percentile = ChiSquared::new(degrees_of_freedom)
-            .unwrap_or_else(|_| {
-                // SAFETY: this would be highly irregular to fail with the inputs that
-                // are supported. As such, any failure to instantiate this should panic
-                // and be investigated further.
-                panic!(
-                    "could not create chi-squared distribution with {} degrees of freedom",
-                    degrees_of_freedom
-                )
-            })
-            .cdf(chi_squared_statistic);
-
-        if percentile == NAN {
+        p_value(chi_squared_statistic, degrees_of_freedom)
+    }
+}
+
+/// The test statistic used within a [`MultinomialTest`] or a
+/// [`ContingencyTest`].
+///
+/// Both statistics are compared against the same chi-squared distribution and
+/// generally agree for large samples. The G statistic is often preferred when
+/// the observations deviate substantially from the expected frequencies.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Statistic {
+    /// Pearson's chi-squared statistic (`sum((O - E)^2 / E)`).
+    #[default]
+    Pearson,
+
+    /// The G (log-likelihood ratio) statistic (`2 * sum(O * ln(O / E))`).
+    G,
+}
+
+impl Statistic {
+    /// Computes the statistic for a set of observations against a set of
+    /// expected frequencies.
+    ///
+    /// If the number of observations does not match the number of expected
+    /// frequencies, or if any expected frequency is not at least 5, then no
+    /// result is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_statistics::chi_squared::Statistic;
+    ///
+    /// let observations = &[10, 30];
+    /// let expected = &[20.0, 20.0];
+    ///
+    /// assert_eq!(
+    ///     Statistic::Pearson.compute(observations, expected),
+    ///     Some(10.0)
+    /// );
+    /// assert!(Statistic::G.compute(observations, expected).unwrap() > 10.0);
+    ///
+    /// assert!(Statistic::Pearson.compute(&[1, 2], &[1.5, 1.5]).is_none());
+    /// ```
+    pub fn compute(&self, observations: &[usize], expected: &[f64]) -> Option<f64> {
+        match self {
+            Statistic::Pearson => chi_squared(observations, expected),
+            Statistic::G => g(observations, expected),
+        }
+    }
+}
+
+impl std::fmt::Display for Statistic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Statistic::Pearson => write!(f, "Pearson"),
+            Statistic::G => write!(f, "G"),
+        }
+    }
+}
+
+/// Goodness of fit tests for arbitrary multinomial distributions.
+///
+/// # Notes
+///
+/// * Adjacent bins (in order) are pooled until every bin has an expected
+///   frequency of at least 5, with any remainder pooled into the last bin. Bins
+///   should therefore be ordered such that neighbouring bins are related (e.g.,
+///   probe lengths or Hamming weights in increasing order).
+/// * The statistic is compared against a chi-squared distribution with one less
+///   degree of freedom than the number of pooled bins.
+///
+/// # Sources
+///
+/// * [Wikipedia] explains the multinomial goodness of fit test.
+///
+/// [Wikipedia]: https://en.wikipedia.org/wiki/Multinomial_test
+#[allow(missing_debug_implementations)]
+pub struct MultinomialTest;
+
+impl MultinomialTest {
+    /// Performs a goodness of fit test for an observed distribution against
+    /// the expected frequencies (_not_ probabilities) of a theoretical
+    /// distribution using the given [`Statistic`].
+    ///
+    /// The p-value is interpreted in the same way as
+    /// [`UniformPearsonTest::goodness_of_fit()`]: a value less than the chosen
+    /// significance level indicates that the observed data did not arise
+    /// from the theoretical distribution.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_statistics::chi_squared::MultinomialTest;
+    /// use bitbelay_statistics::chi_squared::Statistic;
+    ///
+    /// // The number of heads when flipping two fair coins 400 times.
+    /// let expected = &[100.0, 200.0, 100.0];
+    ///
+    /// let p = MultinomialTest::goodness_of_fit(&[98, 203, 99], expected, Statistic::G).unwrap();
+    /// assert!(p >= 0.05);
+    ///
+    /// let p = MultinomialTest::goodness_of_fit(&[150, 150, 100], expected, Statistic::G).unwrap();
+    /// assert!(p < 0.05);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The `expected` frequencies should sum to the total number of
+    ///   observations.
+    /// * If the number of observations does not match the number of expected
+    ///   frequencies, or if fewer than two bins remain after pooling, then no
+    ///   result is returned.
+    pub fn goodness_of_fit(
+        observations: &[usize],
+        expected: &[f64],
+        statistic: Statistic,
+    ) -> Option<f64> {
+        if observations.len() != expected.len() {
             return None;
         }
 
-        Some(1.0 - percentile)
+        let (observations, expected) = pool(observations, expected);
+
+        if observations.len() < 2 {
+            return None;
+        }
+
+        let test_statistic = statistic.compute(&observations, &expected)?;
+        let degrees_of_freedom = observations.len() as f64 - 1.0;
+
+        p_value(test_statistic, degrees_of_freedom)
+    }
+
+    /// Performs a goodness of fit test for an observed distribution against
+    /// the probabilities of a theoretical distribution using the given
+    /// [`Statistic`].
+    ///
+    /// The expected frequencies are computed by multiplying each probability
+    /// by the total number of observations before calling
+    /// [`MultinomialTest::goodness_of_fit()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_statistics::chi_squared::MultinomialTest;
+    /// use bitbelay_statistics::chi_squared::Statistic;
+    ///
+    /// let probabilities = &[0.25, 0.5, 0.25];
+    ///
+    /// let p = MultinomialTest::goodness_of_fit_probabilities(
+    ///     &[98, 203, 99],
+    ///     probabilities,
+    ///     Statistic::Pearson,
+    /// )
+    /// .unwrap();
+    /// assert!(p >= 0.05);
+    ///
+    /// // Probabilities that do not sum to one are rejected.
+    /// assert!(
+    ///     MultinomialTest::goodness_of_fit_probabilities(&[1, 2], &[0.5, 0.6], Statistic::G)
+    ///         .is_none()
+    /// );
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * If any probability is negative, or if the probabilities do not sum to
+    ///   one (within a small tolerance), then no result is returned.
+    pub fn goodness_of_fit_probabilities(
+        observations: &[usize],
+        probabilities: &[f64],
+        statistic: Statistic,
+    ) -> Option<f64> {
+        if probabilities.iter().any(|p| *p < 0.0)
+            || (probabilities.iter().sum::<f64>() - 1.0).abs() > PROBABILITY_TOLERANCE
+        {
+            return None;
+        }
+
+        let total = observations.iter().sum::<usize>() as f64;
+        let expected = probabilities.iter().map(|p| p * total).collect::<Vec<_>>();
+
+        Self::goodness_of_fit(observations, &expected, statistic)
+    }
+
+    /// Performs a goodness of fit test for an observed distribution against a
+    /// theoretical uniform distribution using the given [`Statistic`].
+    ///
+    /// Unlike [`UniformPearsonTest::goodness_of_fit()`], adjacent bins are
+    /// pooled when the expected frequency of each bin is less than 5 (rather
+    /// than no result being returned).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_statistics::chi_squared::MultinomialTest;
+    /// use bitbelay_statistics::chi_squared::Statistic;
+    ///
+    /// let p = MultinomialTest::uniform(&[10, 10, 10, 10, 10], Statistic::G).unwrap();
+    /// assert!(p >= 0.05);
+    ///
+    /// let p = MultinomialTest::uniform(&[500, 10, 10, 10, 10], Statistic::G).unwrap();
+    /// assert!(p < 0.05);
+    /// ```
+    pub fn uniform(observations: &[usize], statistic: Statistic) -> Option<f64> {
+        let expected = observations.iter().sum::<usize>() as f64 / observations.len() as f64;
+        Self::goodness_of_fit(observations, &vec![expected; observations.len()], statistic)
+    }
+}
+
+/// Tests of independence for contingency tables.
+///
+/// # Sources
+///
+/// * [Wikipedia][pearson] explains the Pearson chi-squared test of
+///   independence.
+/// * [Wikipedia][g-test] explains the G-test of independence (including its
+///   relation to mutual information and Williams' correction).
+///
+/// [pearson]: https://en.wikipedia.org/wiki/Pearson%27s_chi-squared_test#Testing_for_statistical_independence
+/// [g-test]: https://en.wikipedia.org/wiki/G-test
+#[allow(missing_debug_implementations)]
+pub struct ContingencyTest;
+
+impl ContingencyTest {
+    /// Performs a test of independence between the two variables of a
+    /// contingency table using the given [`Statistic`].
+    ///
+    /// The contingency table is provided as a set of rows, where
+    /// `table[i][j]` is the number of times the first variable took on value
+    /// `i` while the second variable took on value `j`.
+    ///
+    /// As with [`UniformPearsonTest::goodness_of_fit()`], a p-value less than
+    /// the chosen significance level indicates that the null hypothesis **can
+    /// be rejected** (i.e., the variables are dependent).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_statistics::chi_squared::ContingencyTest;
+    /// use bitbelay_statistics::chi_squared::Statistic;
+    ///
+    /// for statistic in [Statistic::Pearson, Statistic::G] {
+    ///     let table = vec![vec![52, 48], vec![49, 51]];
+    ///     let p = ContingencyTest::independence(&table, statistic).unwrap();
+    ///     assert!(p >= 0.05);
+    ///
+    ///     let table = vec![vec![80, 20], vec![20, 80]];
+    ///     let p = ContingencyTest::independence(&table, statistic).unwrap();
+    ///     assert!(p < 0.05);
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The expected frequency of each cell is `row total * column total /
+    ///   total` (i.e., the frequency expected if the variables are
+    ///   independent).
+    /// * Rows and columns that were never observed are excluded from the test,
+    ///   and the statistic is compared against a chi-squared distribution with
+    ///   `(r - 1)(c - 1)` degrees of freedom, where `r` and `c` are the number
+    ///   of observed rows and columns.
+    /// * The G statistic is divided by [Williams' correction], which removes
+    ///   most of the upward bias of the statistic when the table has many cells
+    ///   relative to the number of observations (without it, the test rejects
+    ///   independent variables far too often for large tables).
+    /// * If the table is empty or not rectangular, if fewer than two rows or
+    ///   columns were observed, or if the mean expected frequency of the
+    ///   (observed) cells is less than 5, then no result is returned. Unlike
+    ///   goodness of fit tests, individual cells are not required to reach an
+    ///   expected frequency of 5, as the approximation holds for tables with
+    ///   many cells so long as the mean expected frequency does.
+    ///
+    /// [Williams' correction]: https://en.wikipedia.org/wiki/G-test#Distribution_and_use
+    pub fn independence(table: &[Vec<usize>], statistic: Statistic) -> Option<f64> {
+        let columns = table.first()?.len();
+
+        if table.iter().any(|row| row.len() != columns) {
+            return None;
+        }
+
+        let row_totals = table
+            .iter()
+            .map(|row| row.iter().sum::<usize>())
+            .collect::<Vec<_>>();
+        let column_totals = (0..columns)
+            .map(|j| table.iter().map(|row| row[j]).sum::<usize>())
+            .collect::<Vec<_>>();
+        let total = row_totals.iter().sum::<usize>() as f64;
+
+        let observed_rows = row_totals.iter().filter(|t| **t > 0).count();
+        let observed_columns = column_totals.iter().filter(|t| **t > 0).count();
+
+        if observed_rows < 2 || observed_columns < 2 {
+            return None;
+        }
+
+        // NOTE: the expected frequencies of the observed cells sum to the
+        // total, so this is their mean.
+        if total / ((observed_rows * observed_columns) as f64) < MIN_EXPECTED {
+            return None;
+        }
+
+        let mut observations = Vec::with_capacity(observed_rows * observed_columns);
+        let mut expected = Vec::with_capacity(observed_rows * observed_columns);
+
+        for (row, row_total) in table.iter().zip(row_totals.iter()) {
+            if *row_total == 0 {
+                continue;
+            }
+
+            for (observed, column_total) in row.iter().zip(column_totals.iter()) {
+                if *column_total == 0 {
+                    continue;
+                }
+
+                observations.push(*observed);
+                expected.push(*row_total as f64 * *column_total as f64 / total);
+            }
+        }
+
+        let degrees_of_freedom = ((observed_rows - 1) * (observed_columns - 1)) as f64;
+
+        let statistic = match statistic {
+            Statistic::Pearson => pearson_statistic(&observations, &expected),
+            Statistic::G => {
+                let reciprocal_sum = |totals: &[usize]| {
+                    totals
+                        .iter()
+                        .filter(|t| **t > 0)
+                        .map(|t| total / *t as f64)
+                        .sum::<f64>()
+                };
+
+                let williams = 1.0
+                    + (reciprocal_sum(&row_totals) - 1.0) * (reciprocal_sum(&column_totals) - 1.0)
+                        / (6.0 * total * degrees_of_freedom);
+
+                g_statistic(&observations, &expected) / williams
+            }
+        };
+
+        p_value(statistic, degrees_of_freedom)
     }
 }
 
@@ -213,4 +665,112 @@ mod tests {
         let p = UniformPearsonTest::goodness_of_fit(&observations);
         assert_relative_eq!(p.unwrap(), 0.0, epsilon = TOLERANCE);
     }
+
+    #[test]
+    fn test_pooling() {
+        let (observations, expected) = pool(
+            &[1, 2, 10, 20, 10, 3, 1],
+            &[1.0, 3.0, 9.0, 20.0, 9.0, 3.0, 1.0],
+        );
+
+        assert_eq!(observations, vec![13, 20, 14]);
+        assert_eq!(expected, vec![13.0, 20.0, 13.0]);
+    }
+
+    #[test]
+    fn test_multinomial_pearson_matches_uniform() {
+        let observations = vec![50, 60, 40, 47, 53];
+        let expected = vec![50.0; 5];
+
+        let p = MultinomialTest::goodness_of_fit(&observations, &expected, Statistic::Pearson);
+        assert_relative_eq!(p.unwrap(), 0.359472, epsilon = TOLERANCE);
+    }
+
+    #[test]
+    fn test_multinomial_invalid_inputs() {
+        for statistic in [Statistic::Pearson, Statistic::G] {
+            assert!(MultinomialTest::goodness_of_fit(&[1, 2], &[1.0], statistic).is_none());
+            assert!(MultinomialTest::goodness_of_fit(&[1, 2], &[1.0, 2.0], statistic).is_none());
+        }
+    }
+
+    #[test]
+    fn test_contingency_independence() {
+        // chi^2 = 4.0 for this table with 2 degrees of freedom, so p = e^(-2).
+        let table = vec![vec![20, 30, 50], vec![30, 20, 50]];
+
+        let p = ContingencyTest::independence(&table, Statistic::Pearson);
+        assert_relative_eq!(p.unwrap(), (-2.0f64).exp(), epsilon = TOLERANCE);
+    }
+
+    #[test]
+    fn test_contingency_g_applies_williams_correction() {
+        // G = 2 * sum(O ln(O / E)) = 77.0979 for this table with 1 degree of
+        // freedom, and Williams' correction is 1 + (4 - 1)(4 - 1) / (6 * 200).
+        let table = vec![vec![80, 20], vec![20, 80]];
+        let williams = 1.0 + 9.0 / 1200.0;
+
+        let p = ContingencyTest::independence(&table, Statistic::G);
+        let expected = p_value(77.0979 / williams, 1.0).unwrap();
+        assert_relative_eq!(p.unwrap(), expected, max_relative = TOLERANCE);
+    }
+
+    #[test]
+    fn test_contingency_allows_sparse_cells() {
+        // Most of the cells have an expected frequency below 5, but the mean
+        // expected frequency is 8.
+        let table = vec![vec![2, 2, 20], vec![2, 2, 20]];
+
+        for statistic in [Statistic::Pearson, Statistic::G] {
+            let p = ContingencyTest::independence(&table, statistic);
+            assert_relative_eq!(p.unwrap(), 1.0, epsilon = TOLERANCE);
+        }
+    }
+
+    #[test]
+    fn test_contingency_invalid_inputs() {
+        for statistic in [Statistic::Pearson, Statistic::G] {
+            assert!(ContingencyTest::independence(&[], statistic).is_none());
+            assert!(ContingencyTest::independence(&[vec![10, 10], vec![10]], statistic).is_none());
+            assert!(
+                ContingencyTest::independence(&[vec![10, 10], vec![0, 0]], statistic).is_none()
+            );
+            assert!(ContingencyTest::independence(&[vec![1, 2], vec![2, 1]], statistic).is_none());
+        }
+    }
+
+    #[test]
+    fn test_g_statistic() {
+        // G = 2 * (10 ln(10 / 20) + 30 ln(30 / 20)) = 10.4650.
+        let g = g(&[10, 30], &[20.0, 20.0]);
+        assert_relative_eq!(g.unwrap(), 10.4650, epsilon = TOLERANCE);
+
+        let g = super::g(&[20, 20], &[20.0, 20.0]);
+        assert_relative_eq!(g.unwrap(), 0.0, epsilon = TOLERANCE);
+    }
+
+    #[test]
+    fn test_multinomial_pools_small_bins() {
+        // Without pooling, the outer bins would have expected frequencies that
+        // are too small for the test to be valid.
+        let observations = vec![1, 10, 48, 90, 48, 10, 1];
+        let probabilities = vec![0.01, 0.05, 0.24, 0.4, 0.24, 0.05, 0.01];
+
+        for statistic in [Statistic::Pearson, Statistic::G] {
+            let p = MultinomialTest::goodness_of_fit_probabilities(
+                &observations,
+                &probabilities,
+                statistic,
+            );
+            assert!(p.unwrap() >= 0.05);
+        }
+    }
+
+    #[test]
+    fn test_multinomial_uniform_matches_uniform_pearson() {
+        let observations = vec![50, 60, 40, 47, 53];
+
+        let p = MultinomialTest::uniform(&observations, Statistic::Pearson);
+        assert_relative_eq!(p.unwrap(), 0.359472, epsilon = TOLERANCE);
+    }
 }
@@ -0,0 +1,265 @@
+//! Corrections for multiple comparisons.
+//!
+//! When many hypotheses are tested at once (e.g., the correlation between
+//! every pair of output bits), some of them will appear significant purely by
+//! chance. The procedures within this module adjust the p-values of a family
+//! of tests such that the family as a whole can be compared against a single
+//! significance level.
+//!
+//! # Supported Procedures
+//!
+//! * The [Bonferroni correction][bonferroni], which controls the family-wise
+//!   error rate.
+//! * The [Holm–Bonferroni method][holm], which also controls the family-wise
+//!   error rate but is uniformly more powerful than the Bonferroni correction.
+//! * The [Benjamini–Hochberg procedure][bh], which controls the false discovery
+//!   rate (and is more powerful still when many hypotheses are false).
+//!
+//! [bonferroni]: https://en.wikipedia.org/wiki/Bonferroni_correction
+//! [holm]: https://en.wikipedia.org/wiki/Holm%E2%80%93Bonferroni_method
+//! [bh]: https://en.wikipedia.org/wiki/False_discovery_rate#Benjamini%E2%80%93Hochberg_procedure
+
+/// A procedure for correcting the p-values of a family of tests.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum Procedure {
+    /// The Bonferroni correction.
+    #[cfg_attr(feature = "clap", clap(name = "bonferroni"))]
+    Bonferroni,
+
+    /// The Holm–Bonferroni method.
+    #[cfg_attr(feature = "clap", clap(name = "holm"))]
+    #[default]
+    Holm,
+
+    /// The Benjamini–Hochberg procedure.
+    #[cfg_attr(feature = "clap", clap(name = "benjamini-hochberg"))]
+    BenjaminiHochberg,
+}
+
+impl Procedure {
+    /// Gets the full name of this [`Procedure`] (for use within reports).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_statistics::correction::Procedure;
+    ///
+    /// assert_eq!(Procedure::Bonferroni.name(), "Bonferroni correction");
+    /// ```
+    pub fn name(&self) -> &'static str {
+        match self {
+            Procedure::Bonferroni => "Bonferroni correction",
+            Procedure::Holm => "Holm–Bonferroni method",
+            Procedure::BenjaminiHochberg => "Benjamini–Hochberg procedure",
+        }
+    }
+
+    /// Gets the URL of a reference describing this [`Procedure`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_statistics::correction::Procedure;
+    ///
+    /// assert_eq!(
+    ///     Procedure::Bonferroni.source(),
+    ///     "https://en.wikipedia.org/wiki/Bonferroni_correction"
+    /// );
+    /// ```
+    pub fn source(&self) -> &'static str {
+        match self {
+            Procedure::Bonferroni => "https://en.wikipedia.org/wiki/Bonferroni_correction",
+            Procedure::Holm => "https://en.wikipedia.org/wiki/Holm%E2%80%93Bonferroni_method",
+            Procedure::BenjaminiHochberg => {
+                "https://en.wikipedia.org/wiki/False_discovery_rate#Benjamini%E2%80%93Hochberg_procedure"
+            }
+        }
+    }
+
+    /// Gets a description of the error rate that is controlled by this
+    /// [`Procedure`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_statistics::correction::Procedure;
+    ///
+    /// assert_eq!(Procedure::Holm.controls(), "family-wise error rate");
+    /// ```
+    pub fn controls(&self) -> &'static str {
+        match self {
+            Procedure::Bonferroni | Procedure::Holm => "family-wise error rate",
+            Procedure::BenjaminiHochberg => "false discovery rate",
+        }
+    }
+
+    /// Adjusts a family of p-values.
+    ///
+    /// The adjusted p-values are returned in the same order as the provided
+    /// p-values, and each adjusted p-value can be compared directly against
+    /// the desired significance level of the family.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_statistics::correction::Procedure;
+    ///
+    /// let p_values = &[0.01, 0.04, 0.03, 0.5];
+    ///
+    /// assert_eq!(
+    ///     Procedure::Bonferroni.adjust(p_values),
+    ///     vec![0.04, 0.16, 0.12, 1.0]
+    /// );
+    /// assert_eq!(
+    ///     Procedure::Holm.adjust(p_values),
+    ///     vec![0.04, 0.09, 0.09, 0.5]
+    /// );
+    /// assert_eq!(
+    ///     Procedure::BenjaminiHochberg.adjust(p_values),
+    ///     vec![0.04, 0.16 / 3.0, 0.16 / 3.0, 0.5]
+    /// );
+    /// ```
+    pub fn adjust(&self, p_values: &[f64]) -> Vec<f64> {
+        let tests = p_values.len() as f64;
+
+        // NOTE: the indices of the p-values in ascending order of p-value.
+        let mut order = (0..p_values.len()).collect::<Vec<_>>();
+        order.sort_by(|a, b| p_values[*a].total_cmp(&p_values[*b]));
+
+        let mut adjusted = vec![0.0; p_values.len()];
+
+        match self {
+            Procedure::Bonferroni => {
+                for (adjusted, p_value) in adjusted.iter_mut().zip(p_values) {
+                    *adjusted = (p_value * tests).min(1.0);
+                }
+            }
+            Procedure::Holm => {
+                // The `k`th smallest p-value is multiplied by `m - k + 1`, and
+                // the adjusted p-values are made non-decreasing.
+                let mut running = 0.0f64;
+
+                for (rank, index) in order.iter().enumerate() {
+                    let value = (p_values[*index] * (tests - rank as f64)).min(1.0);
+                    running = running.max(value);
+                    adjusted[*index] = running;
+                }
+            }
+            Procedure::BenjaminiHochberg => {
+                // The `k`th smallest p-value is multiplied by `m / k`, and the
+                // adjusted p-values are made non-increasing from the largest
+                // p-value downwards.
+                let mut running = 1.0f64;
+
+                for (rank, index) in order.iter().enumerate().rev() {
+                    let value = (p_values[*index] * tests / (rank + 1) as f64).min(1.0);
+                    running = running.min(value);
+                    adjusted[*index] = running;
+                }
+            }
+        }
+
+        adjusted
+    }
+
+    /// Determines which hypotheses within a family are rejected at the
+    /// significance level `alpha` (returned in the same order as the provided
+    /// p-values).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_statistics::correction::Procedure;
+    ///
+    /// let p_values = &[0.01, 0.04, 0.03, 0.5];
+    ///
+    /// assert_eq!(
+    ///     Procedure::Bonferroni.reject(p_values, 0.05),
+    ///     vec![true, false, false, false]
+    /// );
+    /// assert_eq!(
+    ///     Procedure::BenjaminiHochberg.reject(p_values, 0.06),
+    ///     vec![true, true, true, false]
+    /// );
+    /// ```
+    pub fn reject(&self, p_values: &[f64], alpha: f64) -> Vec<bool> {
+        self.adjust(p_values)
+            .into_iter()
+            .map(|p_value| p_value <= alpha)
+            .collect()
+    }
+}
+
+impl std::fmt::Display for Procedure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Procedure::Bonferroni => write!(f, "bonferroni"),
+            Procedure::Holm => write!(f, "holm"),
+            Procedure::BenjaminiHochberg => write!(f, "benjamini-hochberg"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn empty_family() {
+        for procedure in [
+            Procedure::Bonferroni,
+            Procedure::Holm,
+            Procedure::BenjaminiHochberg,
+        ] {
+            assert!(procedure.adjust(&[]).is_empty());
+        }
+    }
+
+    #[test]
+    fn adjusted_p_values_are_ordered_like_the_originals() {
+        let p_values = [0.02, 0.001, 0.3, 0.04, 0.01];
+
+        for procedure in [
+            Procedure::Bonferroni,
+            Procedure::Holm,
+            Procedure::BenjaminiHochberg,
+        ] {
+            let adjusted = procedure.adjust(&p_values);
+
+            for i in 0..p_values.len() {
+                assert!(adjusted[i] >= p_values[i]);
+
+                for j in 0..p_values.len() {
+                    if p_values[i] < p_values[j] {
+                        assert!(adjusted[i] <= adjusted[j]);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn holm_matches_reference() {
+        // Expected values follow directly from the definition of the method.
+        let adjusted = Procedure::Holm.adjust(&[0.02, 0.001, 0.3, 0.04, 0.01]);
+        let expected = [0.06, 0.005, 0.3, 0.08, 0.04];
+
+        for (actual, expected) in adjusted.iter().zip(expected) {
+            assert_relative_eq!(*actual, expected, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn benjamini_hochberg_matches_reference() {
+        // Expected values follow directly from the definition of the procedure.
+        let adjusted = Procedure::BenjaminiHochberg.adjust(&[0.02, 0.001, 0.3, 0.04, 0.01]);
+        let expected = [0.1 / 3.0, 0.005, 0.3, 0.05, 0.025];
+
+        for (actual, expected) in adjusted.iter().zip(expected) {
+            assert_relative_eq!(*actual, expected, epsilon = 1e-12);
+        }
+    }
+}
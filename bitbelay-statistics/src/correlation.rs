@@ -1,4 +1,5 @@
 //! Correlation statistical tests.
 
+pub mod matrix;
 pub mod pearson;
 pub mod spearman;
@@ -0,0 +1,327 @@
+//! Dense, symmetric matrices of correlation coefficients.
+//!
+//! A [`SymmetricMatrix`] holds a value for every pair of a fixed number of
+//! variables where the value for `(i, j)` is the same as the value for `(j,
+//! i)`. As such, only the upper triangle (including the diagonal) is stored.
+//!
+//! A [`CorrelationMatrix`] is a [`SymmetricMatrix`] of correlation
+//! coefficients. Each entry is an [`Option<f64>`], as a correlation coefficient
+//! is undefined when either variable is constant (see, for example,
+//! [`pearson::correlation()`](super::pearson::correlation())).
+//!
+//! When the `serde` feature is enabled, a [`SymmetricMatrix`] can be
+//! serialized and deserialized as its size and the row-major upper triangle of
+//! entries.
+
+/// A dense, symmetric matrix.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        try_from = "Serialized<T>",
+        into = "Serialized<T>",
+        bound(
+            serialize = "T: Clone + serde::Serialize",
+            deserialize = "T: serde::Deserialize<'de>"
+        )
+    )
+)]
+pub struct SymmetricMatrix<T> {
+    /// The number of variables (i.e., the number of rows and columns).
+    size: usize,
+
+    /// The row-major upper triangle of entries, including the diagonal.
+    upper: Vec<T>,
+}
+
+/// A dense, symmetric matrix of correlation coefficients.
+///
+/// If a correlation could not be computed, its entry is [`None`].
+pub type CorrelationMatrix = SymmetricMatrix<Option<f64>>;
+
+impl<T> SymmetricMatrix<T> {
+    /// Creates a new [`SymmetricMatrix`] across `size` variables by calling
+    /// `f` with each `(i, j)` where `i <= j`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_statistics::correlation::matrix::CorrelationMatrix;
+    ///
+    /// let matrix = CorrelationMatrix::from_fn(3, |i, j| if i == j { Some(1.0) } else { Some(0.5) });
+    ///
+    /// assert_eq!(matrix.size(), 3);
+    /// assert_eq!(matrix.get(0, 0), Some(1.0));
+    /// assert_eq!(matrix.get(2, 1), Some(0.5));
+    /// ```
+    pub fn from_fn(size: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let mut upper = Vec::with_capacity(size * (size + 1) / 2);
+
+        for i in 0..size {
+            for j in i..size {
+                upper.push(f(i, j));
+            }
+        }
+
+        Self { size, upper }
+    }
+
+    /// Gets the number of variables (i.e., the number of rows and columns).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_statistics::correlation::matrix::CorrelationMatrix;
+    ///
+    /// let matrix = CorrelationMatrix::from_fn(4, |_, _| None);
+    /// assert_eq!(matrix.size(), 4);
+    /// ```
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Gets the index of entry `(i, j)` within the upper triangle.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either `i` or `j` is not less than the size of the matrix.
+    fn index(&self, i: usize, j: usize) -> usize {
+        assert!(
+            i < self.size && j < self.size,
+            "index ({}, {}) is out of bounds for a symmetric matrix of size {}",
+            i,
+            j,
+            self.size
+        );
+
+        let (i, j) = if i <= j { (i, j) } else { (j, i) };
+
+        // The rows before row `i` hold `size + (size - 1) + ... + (size - i + 1)`
+        // entries, and row `i` starts at column `i`.
+        i * self.size - i * (i.saturating_sub(1)) / 2 + (j - i)
+    }
+}
+
+impl<T: Copy> SymmetricMatrix<T> {
+    /// Gets the entry for variables `i` and `j`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either `i` or `j` is not less than the size of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_statistics::correlation::matrix::CorrelationMatrix;
+    ///
+    /// let matrix = CorrelationMatrix::from_fn(3, |i, j| Some((i * 10 + j) as f64));
+    ///
+    /// assert_eq!(matrix.get(0, 2), Some(2.0));
+    /// assert_eq!(matrix.get(2, 0), Some(2.0));
+    /// assert_eq!(matrix.get(1, 1), Some(11.0));
+    /// ```
+    pub fn get(&self, i: usize, j: usize) -> T {
+        self.upper[self.index(i, j)]
+    }
+
+    /// Gets an iterator over the entries of row `i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is not less than the size of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_statistics::correlation::matrix::CorrelationMatrix;
+    ///
+    /// let matrix = CorrelationMatrix::from_fn(3, |i, j| Some((i * 10 + j) as f64));
+    ///
+    /// assert_eq!(
+    ///     matrix.row(1).collect::<Vec<_>>(),
+    ///     vec![Some(1.0), Some(11.0), Some(12.0)]
+    /// );
+    /// ```
+    pub fn row(&self, i: usize) -> impl Iterator<Item = T> + '_ {
+        assert!(
+            i < self.size,
+            "row {} is out of bounds for a symmetric matrix of size {}",
+            i,
+            self.size
+        );
+
+        (0..self.size).map(move |j| self.get(i, j))
+    }
+
+    /// Gets an iterator over the entries of column `j`.
+    ///
+    /// Because the matrix is symmetric, this is the same as [row
+    /// `j`](SymmetricMatrix::row()).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `j` is not less than the size of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_statistics::correlation::matrix::CorrelationMatrix;
+    ///
+    /// let matrix = CorrelationMatrix::from_fn(3, |i, j| Some((i * 10 + j) as f64));
+    ///
+    /// assert!(matrix.column(2).eq(matrix.row(2)));
+    /// ```
+    pub fn column(&self, j: usize) -> impl Iterator<Item = T> + '_ {
+        self.row(j)
+    }
+
+    /// Gets an iterator over each entry `((i, j), value)` strictly above the
+    /// diagonal (i.e., where `i < j`) in row-major order.
+    ///
+    /// This visits every unique pair of distinct variables exactly once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_statistics::correlation::matrix::CorrelationMatrix;
+    ///
+    /// let matrix = CorrelationMatrix::from_fn(3, |i, j| Some((i * 10 + j) as f64));
+    ///
+    /// assert_eq!(
+    ///     matrix.upper_triangle().collect::<Vec<_>>(),
+    ///     vec![
+    ///         ((0, 1), Some(1.0)),
+    ///         ((0, 2), Some(2.0)),
+    ///         ((1, 2), Some(12.0))
+    ///     ]
+    /// );
+    /// ```
+    pub fn upper_triangle(&self) -> impl Iterator<Item = ((usize, usize), T)> + '_ {
+        (0..self.size)
+            .flat_map(move |i| ((i + 1)..self.size).map(move |j| (i, j)))
+            .map(|(i, j)| ((i, j), self.get(i, j)))
+    }
+}
+
+impl CorrelationMatrix {
+    /// Gets the off-diagonal entry with the largest absolute correlation as
+    /// `((i, j), correlation)` (where `i < j`).
+    ///
+    /// Entries that could not be computed are skipped. If there are no
+    /// computed off-diagonal entries, [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_statistics::correlation::matrix::CorrelationMatrix;
+    ///
+    /// let matrix = CorrelationMatrix::from_fn(3, |i, j| match (i, j) {
+    ///     (0, 2) => Some(-0.75),
+    ///     (1, 2) => None,
+    ///     (i, j) if i == j => Some(1.0),
+    ///     _ => Some(0.25),
+    /// });
+    ///
+    /// assert_eq!(matrix.max_off_diagonal(), Some(((0, 2), -0.75)));
+    ///
+    /// let matrix = CorrelationMatrix::from_fn(1, |_, _| Some(1.0));
+    /// assert_eq!(matrix.max_off_diagonal(), None);
+    /// ```
+    pub fn max_off_diagonal(&self) -> Option<((usize, usize), f64)> {
+        self.upper_triangle()
+            .filter_map(|(pair, correlation)| correlation.map(|correlation| (pair, correlation)))
+            .max_by(|(_, a), (_, b)| a.abs().total_cmp(&b.abs()))
+    }
+}
+
+/// The serialized form of a [`SymmetricMatrix`].
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Serialized<T> {
+    /// The number of variables.
+    size: usize,
+
+    /// The row-major upper triangle of entries, including the diagonal.
+    upper: Vec<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<T> From<SymmetricMatrix<T>> for Serialized<T> {
+    fn from(matrix: SymmetricMatrix<T>) -> Self {
+        Self {
+            size: matrix.size,
+            upper: matrix.upper,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> TryFrom<Serialized<T>> for SymmetricMatrix<T> {
+    type Error = String;
+
+    fn try_from(serialized: Serialized<T>) -> Result<Self, Self::Error> {
+        let expected = serialized.size * (serialized.size + 1) / 2;
+
+        if serialized.upper.len() != expected {
+            return Err(format!(
+                "a symmetric matrix of size {} must have {} entries in its upper triangle, but {} \
+                 were found",
+                serialized.size,
+                expected,
+                serialized.upper.len()
+            ));
+        }
+
+        Ok(Self {
+            size: serialized.size,
+            upper: serialized.upper,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_stores_every_entry_symmetrically() {
+        let size = 7;
+        let matrix = SymmetricMatrix::from_fn(size, |i, j| i * size + j);
+
+        for i in 0..size {
+            for j in 0..size {
+                let (low, high) = (i.min(j), i.max(j));
+                assert_eq!(matrix.get(i, j), low * size + high);
+            }
+        }
+    }
+
+    #[test]
+    fn upper_triangle_visits_each_pair_once() {
+        let matrix = CorrelationMatrix::from_fn(64, |_, _| Some(0.0));
+        assert_eq!(matrix.upper_triangle().count(), 64 * 63 / 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_out_of_bounds() {
+        CorrelationMatrix::from_fn(2, |_, _| None).get(0, 2);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn it_round_trips_through_serde() {
+        let matrix =
+            CorrelationMatrix::from_fn(3, |i, j| if i == 0 && j == 1 { None } else { Some(0.5) });
+
+        let json = serde_json::to_string(&matrix).unwrap();
+        assert_eq!(json, r#"{"size":3,"upper":[0.5,null,0.5,0.5,0.5,0.5]}"#);
+        assert_eq!(
+            serde_json::from_str::<CorrelationMatrix>(&json).unwrap(),
+            matrix
+        );
+
+        assert!(serde_json::from_str::<CorrelationMatrix>(r#"{"size":3,"upper":[1.0]}"#).is_err());
+    }
+}
@@ -27,6 +27,13 @@
 //!
 //! [Wikipedia]: https://en.wikipedia.org/wiki/Pearson_correlation_coefficient
 
+use statrs::distribution::ContinuousCDF as _;
+use statrs::distribution::Normal;
+
+/// The amount by which a correlation may fall outside of `[-1.0, 1.0]` (due
+/// to floating point error) and still be considered valid.
+const CORRELATION_TOLERANCE: f64 = 1e-9;
+
 /// Computes the Pearson correlation coefficient between the provided element
 /// slices.
 ///
@@ -90,6 +97,94 @@ pub fn correlation(a: &[f64], b: &[f64]) -> Option<f64> {
     Some(num / denom)
 }
 
+/// Computes the Pearson correlation coefficient between two binary variables
+/// from their counts (known as the [phi coefficient]).
+///
+/// * `total` is the number of observations.
+/// * `a` is the number of observations where the first variable is `1`.
+/// * `b` is the number of observations where the second variable is `1`.
+/// * `both` is the number of observations where both variables are `1`.
+///
+/// This is equivalent to calling [`correlation()`] on the two binary variables,
+/// but it does not require storing every observation.
+///
+/// # Results
+///
+/// If there are no observations, if the counts are inconsistent, or if either
+/// variable is constant, the result is undefined and, as such, [`None`] is
+/// returned.
+///
+/// # Examples
+///
+/// ```
+/// use bitbelay_statistics::correlation::pearson;
+///
+/// // The variables `[1, 1, 0, 0]` and `[1, 1, 0, 0]`.
+/// assert_eq!(pearson::phi(4, 2, 2, 2), Some(1.0));
+///
+/// // The variables `[1, 1, 0, 0]` and `[0, 0, 1, 1]`.
+/// assert_eq!(pearson::phi(4, 2, 2, 0), Some(-1.0));
+///
+/// // The variables `[1, 1, 0, 0]` and `[1, 0, 1, 0]`.
+/// assert_eq!(pearson::phi(4, 2, 2, 1), Some(0.0));
+/// ```
+///
+/// [phi coefficient]: https://en.wikipedia.org/wiki/Phi_coefficient
+pub fn phi(total: usize, a: usize, b: usize, both: usize) -> Option<f64> {
+    if total == 0 || a > total || b > total || both > a.min(b) {
+        return None;
+    }
+
+    let (n, a, b, both) = (total as f64, a as f64, b as f64, both as f64);
+
+    let num = n * both - a * b;
+    let denom = (a * (n - a) * b * (n - b)).sqrt();
+
+    if denom == 0.0 {
+        return None;
+    }
+
+    Some(num / denom)
+}
+
+/// Computes the two-sided p-value of observing a Pearson correlation at least
+/// as extreme as `correlation` over `pairs` pairs of values under the null
+/// hypothesis that the variables are uncorrelated.
+///
+/// Under the null hypothesis, `correlation * sqrt(pairs)` is approximately
+/// standard normal for a large number of pairs, which is the approximation
+/// used here.
+///
+/// # Results
+///
+/// If there are no pairs or the correlation is not within `[-1.0, 1.0]`
+/// (allowing for floating point error), [`None`] is returned.
+///
+/// # Examples
+///
+/// ```
+/// use bitbelay_statistics::correlation::pearson;
+///
+/// // A small correlation over a few pairs is entirely unremarkable.
+/// assert!(pearson::p_value(0.01, 100).unwrap() > 0.05);
+///
+/// // The same correlation over many pairs is not.
+/// assert!(pearson::p_value(0.01, 1_000_000).unwrap() < 0.05);
+/// ```
+pub fn p_value(correlation: f64, pairs: usize) -> Option<f64> {
+    if pairs == 0
+        || !(-1.0 - CORRELATION_TOLERANCE..=1.0 + CORRELATION_TOLERANCE).contains(&correlation)
+    {
+        return None;
+    }
+
+    let z = correlation * (pairs as f64).sqrt();
+
+    // SAFETY: a standard normal distribution is always valid.
+    let normal = Normal::new(0.0, 1.0).unwrap();
+    Some((2.0 * normal.sf(z.abs())).min(1.0))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -113,4 +208,20 @@ mod tests {
         let b = &[];
         assert_eq!(correlation(a, b), None);
     }
+
+    #[test]
+    fn phi_matches_correlation() {
+        let a = &[1.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0];
+        let b = &[1.0, 0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 1.0];
+
+        let expected = correlation(a, b).unwrap();
+        let actual = phi(8, 4, 5, 3).unwrap();
+        assert!((expected - actual).abs() < 1e-12);
+    }
+
+    #[test]
+    fn phi_constant() {
+        assert_eq!(phi(4, 4, 2, 2), None);
+        assert_eq!(phi(0, 0, 0, 0), None);
+    }
 }
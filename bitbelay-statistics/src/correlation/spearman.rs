@@ -2,8 +2,16 @@
 //!
 //! # Overview
 //!
-//! Computes the Spearman rank correlation coefficient as defined by the
-//! following formula.
+//! Computes the Spearman rank correlation coefficient, which is the Pearson
+//! correlation coefficient between the ranks of the elements.
+//!
+//! $$
+//! \rho = r(R(a), R(b))
+//! $$
+//!
+//! Tied elements are assigned the average of the ranks that they span. When
+//! there are no ties, this is equivalent to the following (more commonly
+//! cited) formula.
 //!
 //! $$
 //! \rho = 1 - \frac{6\sum\mathrm{d}_{i}^{2}}{n(n^2 - 1)} \text{ where } d_i
@@ -17,6 +25,7 @@
 //!
 //! [Wikipedia]: https://en.wikipedia.org/wiki/Spearman%27s_rank_correlation_coefficient
 
+use crate::correlation::pearson;
 use crate::rank;
 
 /// Computes the Spearman rank correlation coefficient between the provided
@@ -24,8 +33,9 @@ use crate::rank;
 ///
 /// # Results
 ///
-/// If the slices are not the same length or if they are empty, the result is
-/// undefined and, as such, [`None`] is returned. In all other cases, the
+/// If the slices are not the same length, if they are empty, or if either
+/// slice only contains a single distinct value, the result is undefined and,
+/// as such, [`None`] is returned. In all other cases, the
 /// Spearman rank correlation is returned as an [`f64`] in the range of `-1 <=
 /// rho <= 1` and may be interpretted as follows:
 ///
@@ -57,25 +67,14 @@ use crate::rank;
 /// .unwrap();
 /// assert_relative_eq!(rho, 0.095, epsilon = 1e-3);
 /// ```
-pub fn correlation<T: Clone + Ord>(a: &[T], b: &[T]) -> Option<f64> {
+pub fn correlation<T: Ord>(a: &[T], b: &[T]) -> Option<f64> {
     // If the slices are not the same length or empty, the result is undefined.
     // Therefore, we return [`None`].
     if a.is_empty() || a.len() != b.len() {
         return None;
     }
 
-    let a = rank(a);
-    let b = rank(b);
-    let n = a.len() as f64;
-
-    // Sum of the rank differences squared.
-    let differences: f64 = a
-        .into_iter()
-        .zip(b)
-        .map(|(a, b)| (a as f64 - b as f64).powi(2))
-        .sum();
-
-    Some(1.0 - (6.0 * differences) / (n * (n.powi(2) - 1.0)))
+    pearson::correlation(&rank(a), &rank(b))
 }
 
 #[cfg(test)]
@@ -95,4 +94,17 @@ mod tests {
         let b: &[usize] = &[];
         assert_eq!(correlation(a, b), None);
     }
+
+    #[test]
+    fn ties() {
+        // A monotonic relationship with many ties is still perfectly
+        // correlated.
+        let a = &[1, 1, 2, 2, 3, 3, 3, 4];
+        let b = &[5, 5, 7, 7, 9, 9, 9, 10];
+        assert!((correlation(a, b).unwrap() - 1.0).abs() < 1e-12);
+
+        // A constant slice has no defined correlation.
+        let c = &[1, 1, 1, 1, 1, 1, 1, 1];
+        assert_eq!(correlation(a, c), None);
+    }
 }
@@ -0,0 +1,188 @@
+//! Entropy and mutual information.
+//!
+//! The [G-test] of independence between two discrete variables is closely
+//! related to their [mutual information]: the G statistic is `2N ln(2) I`,
+//! where `N` is the number of observations and `I` is the mutual information
+//! in bits. The test itself is performed via
+//! [`ContingencyTest::independence()`](crate::chi_squared::ContingencyTest::independence())
+//! using [`Statistic::G`](crate::chi_squared::Statistic::G).
+//!
+//! # Sources
+//!
+//! * The main page for information entropy ([link][entropy]).
+//! * The main page for mutual information ([link][mutual information]).
+//! * The main page for the G-test, including its relation to mutual information
+//!   ([link][G-test]).
+//!
+//! [entropy]: https://en.wikipedia.org/wiki/Entropy_(information_theory)
+//! [mutual information]: https://en.wikipedia.org/wiki/Mutual_information
+//! [G-test]: https://en.wikipedia.org/wiki/G-test
+
+/// Computes the [Shannon entropy](https://en.wikipedia.org/wiki/Entropy_(information_theory))
+/// (in bits) of the empirical distribution described by a set of counts.
+///
+/// If there are no observations, then no result is returned.
+///
+/// # Examples
+///
+/// ```
+/// use bitbelay_statistics::entropy::entropy;
+///
+/// // A fair coin has exactly one bit of entropy.
+/// assert_eq!(entropy(&[50, 50]), Some(1.0));
+///
+/// // A constant has no entropy.
+/// assert_eq!(entropy(&[100, 0, 0, 0]), Some(0.0));
+///
+/// assert!(entropy(&[0, 0]).is_none());
+/// ```
+pub fn entropy(counts: &[usize]) -> Option<f64> {
+    let total = counts.iter().sum::<usize>();
+
+    if total == 0 {
+        return None;
+    }
+
+    let total = total as f64;
+
+    let entropy = counts
+        .iter()
+        .filter(|count| **count > 0)
+        .map(|count| {
+            let p = *count as f64 / total;
+            -p * p.log2()
+        })
+        .sum::<f64>();
+
+    // NOTE: this avoids returning `-0.0` for distributions with no entropy.
+    Some(entropy.max(0.0))
+}
+
+/// The marginal counts (rows, columns) and total of a joint histogram.
+type Marginals = (Vec<usize>, Vec<usize>, usize);
+
+/// Computes the row and column marginals of a joint histogram.
+///
+/// If the joint histogram is empty, is not rectangular, or contains no
+/// observations, then no result is returned.
+fn marginals(joint: &[Vec<usize>]) -> Option<Marginals> {
+    let columns = joint.first()?.len();
+
+    if columns == 0 || joint.iter().any(|row| row.len() != columns) {
+        return None;
+    }
+
+    let rows = joint.iter().map(|row| row.iter().sum()).collect::<Vec<_>>();
+    let columns = (0..columns)
+        .map(|j| joint.iter().map(|row| row[j]).sum())
+        .collect::<Vec<_>>();
+    let total = rows.iter().sum::<usize>();
+
+    if total == 0 {
+        return None;
+    }
+
+    Some((rows, columns, total))
+}
+
+/// Computes the [mutual information](https://en.wikipedia.org/wiki/Mutual_information)
+/// (in bits) between two discrete variables from their joint histogram.
+///
+/// The joint histogram is provided as a set of rows, where `joint[i][j]` is
+/// the number of times the first variable took on value `i` while the second
+/// variable took on value `j`.
+///
+/// If the joint histogram is empty, is not rectangular, or contains no
+/// observations, then no result is returned.
+///
+/// # Examples
+///
+/// ```
+/// use bitbelay_statistics::entropy::mutual_information;
+///
+/// // Two independent fair coins share no information.
+/// let joint = vec![vec![25, 25], vec![25, 25]];
+/// assert_eq!(mutual_information(&joint), Some(0.0));
+///
+/// // Two identical fair coins share one full bit of information.
+/// let joint = vec![vec![50, 0], vec![0, 50]];
+/// assert_eq!(mutual_information(&joint), Some(1.0));
+/// ```
+///
+/// # Notes
+///
+/// * The estimate is computed using the empirical (plug-in) probabilities,
+///   which is biased upwards for small samples. Under independence, the bias is
+///   approximately `(r - 1)(c - 1) / (2N ln 2)` bits, where `r` and `c` are the
+///   number of observed row and column values and `N` is the number of
+///   observations.
+pub fn mutual_information(joint: &[Vec<usize>]) -> Option<f64> {
+    let (rows, columns, total) = marginals(joint)?;
+    let total = total as f64;
+
+    let mut mutual_information = 0.0;
+
+    for (i, row) in joint.iter().enumerate() {
+        for (j, count) in row.iter().enumerate() {
+            if *count == 0 {
+                continue;
+            }
+
+            let count = *count as f64;
+            let expected = rows[i] as f64 * columns[j] as f64 / total;
+            mutual_information += (count / total) * (count / expected).log2();
+        }
+    }
+
+    // NOTE: this avoids returning tiny negative values caused by floating
+    // point error for variables that are perfectly independent.
+    Some(mutual_information.max(0.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::chi_squared::ContingencyTest;
+    use crate::chi_squared::Statistic;
+
+    const TOLERANCE: f64 = 1e-6;
+
+    #[test]
+    fn test_entropy() {
+        assert_relative_eq!(entropy(&[1, 1, 1, 1]).unwrap(), 2.0, epsilon = TOLERANCE);
+        assert_relative_eq!(entropy(&[3, 1]).unwrap(), 0.8112781, epsilon = TOLERANCE);
+    }
+
+    #[test]
+    fn test_mutual_information_partial_dependence() {
+        // I(X; Y) = H(X) + H(Y) - H(X, Y).
+        let joint = vec![vec![30, 10], vec![20, 40]];
+        let expected = entropy(&[40, 60]).unwrap() + entropy(&[50, 50]).unwrap()
+            - entropy(&[30, 10, 20, 40]).unwrap();
+
+        assert_relative_eq!(
+            mutual_information(&joint).unwrap(),
+            expected,
+            epsilon = TOLERANCE
+        );
+    }
+
+    #[test]
+    fn test_g_test_statistic() {
+        // The G statistic for this table is 2 * sum(O ln(O / E)) = 77.0979.
+        let joint = vec![vec![80, 20], vec![20, 80]];
+        let g = 2.0 * 200.0 * std::f64::consts::LN_2 * mutual_information(&joint).unwrap();
+
+        assert_relative_eq!(g, 77.0979, epsilon = 1e-3);
+        assert!(ContingencyTest::independence(&joint, Statistic::G).unwrap() < 1e-10);
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        assert!(mutual_information(&[]).is_none());
+        assert!(mutual_information(&[vec![1, 2], vec![3]]).is_none());
+        assert!(mutual_information(&[vec![0, 0], vec![0, 0]]).is_none());
+    }
+}
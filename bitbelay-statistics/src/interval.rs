@@ -0,0 +1,207 @@
+//! Intervals within which a statistic is expected to fall.
+//!
+//! # Supported Intervals
+//!
+//! At present, the following intervals are supported:
+//!
+//! * The acceptance region of the [score test] for a binomial proportion via
+//!   [`binomial()`]. Inverting this region yields the [Wilson score interval].
+//! * A confidence interval for a Pearson correlation coefficient using the
+//!   [Fisher transformation] via [`fisher_z()`].
+//!
+//! # Sources
+//!
+//! * The main page for binomial proportion confidence intervals ([link][score
+//!   test]).
+//! * The main page for the Fisher transformation ([link][Fisher
+//!   transformation]).
+//!
+//! [score test]: https://en.wikipedia.org/wiki/Binomial_proportion_confidence_interval
+//! [Wilson score interval]: https://en.wikipedia.org/wiki/Binomial_proportion_confidence_interval#Wilson_score_interval
+//! [Fisher transformation]: https://en.wikipedia.org/wiki/Fisher_transformation
+
+use statrs::distribution::ContinuousCDF as _;
+use statrs::distribution::Normal;
+
+/// A closed interval.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Interval {
+    /// The lower bound.
+    pub lower: f64,
+
+    /// The upper bound.
+    pub upper: f64,
+}
+
+impl Interval {
+    /// Gets whether the interval contains `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_statistics::interval::Interval;
+    ///
+    /// let interval = Interval {
+    ///     lower: -1.0,
+    ///     upper: 1.0,
+    /// };
+    ///
+    /// assert!(interval.contains(0.0));
+    /// assert!(interval.contains(1.0));
+    /// assert!(!interval.contains(1.5));
+    /// ```
+    pub fn contains(&self, value: f64) -> bool {
+        (self.lower..=self.upper).contains(&value)
+    }
+
+    /// Gets half of the width of the interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_statistics::interval::Interval;
+    ///
+    /// let interval = Interval {
+    ///     lower: 0.25,
+    ///     upper: 0.75,
+    /// };
+    ///
+    /// assert_eq!(interval.half_width(), 0.25);
+    /// ```
+    pub fn half_width(&self) -> f64 {
+        (self.upper - self.lower) / 2.0
+    }
+}
+
+/// Gets the critical value of a standard normal distribution for a two-sided
+/// test at `significance`.
+fn critical_value(significance: f64) -> Option<f64> {
+    if significance <= 0.0 || significance >= 1.0 {
+        return None;
+    }
+
+    // SAFETY: a standard normal distribution is always valid.
+    let normal = Normal::new(0.0, 1.0).unwrap();
+    Some(normal.inverse_cdf(1.0 - significance / 2.0))
+}
+
+/// Computes the interval within which the observed proportion of successes
+/// over `trials` independent trials that each succeed with `probability` is
+/// expected to fall at the given threshold of statistical `significance`.
+///
+/// This is the acceptance region of the two-sided [score
+/// test](https://en.wikipedia.org/wiki/Score_test): an observed proportion
+/// outside of the interval rejects the hypothesis that the true proportion is
+/// `probability`. The interval is clamped to `[0.0, 1.0]`.
+///
+/// If there are no trials, if `probability` is not within `(0.0, 1.0)`, or if
+/// `significance` is not within `(0.0, 1.0)`, then no result is returned.
+///
+/// # Examples
+///
+/// ```
+/// use bitbelay_statistics::interval;
+///
+/// // The proportion of heads for a fair coin tossed 10,000 times falls within
+/// // roughly 0.5 ± 0.0098 with 95% confidence.
+/// let interval = interval::binomial(10_000, 0.5, 0.05).unwrap();
+/// assert!((interval.half_width() - 0.0098).abs() < 1e-4);
+///
+/// // The interval narrows as the number of trials grows.
+/// let narrower = interval::binomial(1_000_000, 0.5, 0.05).unwrap();
+/// assert!(narrower.half_width() < interval.half_width());
+///
+/// assert!(interval::binomial(0, 0.5, 0.05).is_none());
+/// ```
+pub fn binomial(trials: u64, probability: f64, significance: f64) -> Option<Interval> {
+    if trials == 0 || probability <= 0.0 || probability >= 1.0 {
+        return None;
+    }
+
+    let z = critical_value(significance)?;
+    let margin = z * (probability * (1.0 - probability) / trials as f64).sqrt();
+
+    Some(Interval {
+        lower: (probability - margin).max(0.0),
+        upper: (probability + margin).min(1.0),
+    })
+}
+
+/// Computes the interval within which a Pearson correlation coefficient
+/// computed over `pairs` samples is expected to fall at the given threshold of
+/// statistical `significance` when the true correlation is `correlation`.
+///
+/// The interval is computed using the [Fisher
+/// transformation](https://en.wikipedia.org/wiki/Fisher_transformation), under
+/// which the transformed sample correlation is approximately normally
+/// distributed with a standard error of `1 / sqrt(pairs - 3)`.
+///
+/// If there are three or fewer pairs, if `correlation` is not within `(-1.0,
+/// 1.0)`, or if `significance` is not within `(0.0, 1.0)`, then no result is
+/// returned.
+///
+/// # Examples
+///
+/// ```
+/// use bitbelay_statistics::interval;
+///
+/// // Uncorrelated samples of 10,000 pairs produce a correlation coefficient
+/// // within roughly ±0.0196 with 95% confidence.
+/// let interval = interval::fisher_z(0.0, 10_000, 0.05).unwrap();
+/// assert!((interval.upper - 0.0196).abs() < 1e-4);
+/// assert!((interval.lower + 0.0196).abs() < 1e-4);
+///
+/// assert!(interval::fisher_z(0.0, 3, 0.05).is_none());
+/// ```
+pub fn fisher_z(correlation: f64, pairs: usize, significance: f64) -> Option<Interval> {
+    if pairs <= 3 || correlation <= -1.0 || correlation >= 1.0 {
+        return None;
+    }
+
+    let z = critical_value(significance)?;
+    let center = correlation.atanh();
+    let margin = z / ((pairs - 3) as f64).sqrt();
+
+    Some(Interval {
+        lower: (center - margin).tanh(),
+        upper: (center + margin).tanh(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn binomial_matches_known_values() {
+        let interval = binomial(100, 0.5, 0.05).unwrap();
+        assert_relative_eq!(interval.lower, 0.5 - 0.0979982, epsilon = 1e-6);
+        assert_relative_eq!(interval.upper, 0.5 + 0.0979982, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn binomial_is_clamped() {
+        let interval = binomial(1, 0.01, 0.05).unwrap();
+        assert_eq!(interval.lower, 0.0);
+        assert!(interval.upper < 1.0);
+    }
+
+    #[test]
+    fn fisher_z_is_asymmetric_away_from_zero() {
+        let interval = fisher_z(0.9, 100, 0.05).unwrap();
+        assert!(interval.contains(0.9));
+        assert!(interval.upper - 0.9 < 0.9 - interval.lower);
+        assert!(interval.upper < 1.0);
+    }
+
+    #[test]
+    fn invalid_inputs() {
+        assert!(binomial(100, 0.0, 0.05).is_none());
+        assert!(binomial(100, 0.5, 0.0).is_none());
+        assert!(binomial(100, 0.5, 1.0).is_none());
+        assert!(fisher_z(1.0, 100, 0.05).is_none());
+        assert!(fisher_z(0.0, 100, 1.5).is_none());
+    }
+}
@@ -0,0 +1,284 @@
+//! Kolmogorov–Smirnov and Anderson–Darling statistical tests.
+//!
+//! Both tests compare the empirical cumulative distribution function (CDF) of
+//! a set of samples against the CDF of a theoretical distribution. Unlike a
+//! chi-squared test, neither requires the samples to be binned.
+//!
+//! # Supported Tests
+//!
+//! At present, only the following tests are supported:
+//!
+//! * A [Kolmogorov–Smirnov test][ks-test] against a theoretical, continuous
+//!   uniform distribution on `[0, 1]` via
+//!   [`UniformKolmogorovSmirnovTest::goodness_of_fit()`].
+//! * An [Anderson–Darling test][ad-test] against a theoretical, continuous
+//!   uniform distribution on `[0, 1]` via
+//!   [`UniformAndersonDarlingTest::goodness_of_fit()`].
+//!
+//! # Sources
+//!
+//! * A general overview of the Kolmogorov–Smirnov test ([link][ks-test]).
+//! * A general overview of the Anderson–Darling test ([link][ad-test]).
+//! * Marsaglia and Marsaglia describe the approximation used for the asymptotic
+//!   distribution of the Anderson–Darling statistic ([link][marsaglia]).
+//!
+//! [ks-test]: https://en.wikipedia.org/wiki/Kolmogorov%E2%80%93Smirnov_test
+//! [ad-test]: https://en.wikipedia.org/wiki/Anderson%E2%80%93Darling_test
+//! [marsaglia]: https://doi.org/10.18637/jss.v009.i02
+
+/// The maximum number of terms summed when computing the Kolmogorov
+/// distribution.
+const MAX_TERMS: usize = 100;
+
+/// Sorts the samples and returns them if every sample is within `[0, 1]`.
+fn sorted(samples: &[f64]) -> Option<Vec<f64>> {
+    if samples.is_empty() || samples.iter().any(|s| !(0.0..=1.0).contains(s)) {
+        return None;
+    }
+
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    Some(sorted)
+}
+
+/// Computes the Kolmogorov–Smirnov statistic (`D`) for a set of samples
+/// against a continuous uniform distribution on `[0, 1]`.
+///
+/// `D` is the largest absolute difference between the empirical CDF of the
+/// samples and the CDF of the uniform distribution.
+///
+/// If there are no samples or any sample is outside of `[0, 1]`, then no
+/// result is returned.
+///
+/// # Examples
+///
+/// ```
+/// use bitbelay_statistics::kolmogorov_smirnov::uniform_statistic;
+///
+/// let d = uniform_statistic(&[0.125, 0.375, 0.625, 0.875]).unwrap();
+/// assert_eq!(d, 0.125);
+/// ```
+pub fn uniform_statistic(samples: &[f64]) -> Option<f64> {
+    let samples = sorted(samples)?;
+    let n = samples.len() as f64;
+
+    let statistic = samples
+        .iter()
+        .enumerate()
+        .fold(0.0f64, |acc, (i, &sample)| {
+            let above = (i + 1) as f64 / n - sample;
+            let below = sample - i as f64 / n;
+            acc.max(above).max(below)
+        });
+
+    Some(statistic)
+}
+
+/// Computes the probability that a Kolmogorov–Smirnov statistic of at least
+/// `statistic` is observed within `samples` samples under the null
+/// hypothesis.
+///
+/// This uses the asymptotic Kolmogorov distribution with Stephens' correction
+/// for finite sample sizes, which is accurate for all but the smallest sample
+/// sizes.
+fn kolmogorov_p_value(statistic: f64, samples: usize) -> f64 {
+    let root = (samples as f64).sqrt();
+    let lambda = (root + 0.12 + 0.11 / root) * statistic;
+
+    // NOTE: the series below converges very slowly (and the result is
+    // indistinguishable from one) for small values of lambda.
+    if lambda < 0.2 {
+        return 1.0;
+    }
+
+    let mut sum = 0.0;
+    let mut sign = 1.0;
+
+    for j in 1..=MAX_TERMS {
+        let term = (-2.0 * (j as f64).powi(2) * lambda.powi(2)).exp();
+        sum += sign * term;
+        sign = -sign;
+
+        if term < 1e-12 {
+            break;
+        }
+    }
+
+    (2.0 * sum).clamp(0.0, 1.0)
+}
+
+/// Kolmogorov–Smirnov tests for a theoretical, continuous uniform
+/// distribution on `[0, 1]`.
+#[allow(missing_debug_implementations)]
+pub struct UniformKolmogorovSmirnovTest;
+
+impl UniformKolmogorovSmirnovTest {
+    /// Performs a goodness of fit test for a set of samples against a
+    /// continuous uniform distribution on `[0, 1]` using the
+    /// Kolmogorov–Smirnov statistic.
+    ///
+    /// A p-value less than the chosen significance level indicates that the
+    /// samples did not arise from a continuous uniform distribution.
+    ///
+    /// If there are no samples or any sample is outside of `[0, 1]`, then no
+    /// result is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_statistics::kolmogorov_smirnov::UniformKolmogorovSmirnovTest;
+    ///
+    /// // A set of samples that are evenly spread across `[0, 1]`.
+    /// let samples = (0..1000)
+    ///     .map(|i| (i as f64 + 0.5) / 1000.0)
+    ///     .collect::<Vec<_>>();
+    /// let p = UniformKolmogorovSmirnovTest::goodness_of_fit(&samples).unwrap();
+    /// assert!(p >= 0.05);
+    ///
+    /// // A set of samples that are squeezed into `[0, 0.5]`.
+    /// let samples = (0..1000)
+    ///     .map(|i| (i as f64 + 0.5) / 2000.0)
+    ///     .collect::<Vec<_>>();
+    /// let p = UniformKolmogorovSmirnovTest::goodness_of_fit(&samples).unwrap();
+    /// assert!(p < 0.05);
+    /// ```
+    pub fn goodness_of_fit(samples: &[f64]) -> Option<f64> {
+        let statistic = uniform_statistic(samples)?;
+        Some(kolmogorov_p_value(statistic, samples.len()))
+    }
+}
+
+/// Computes the Anderson–Darling statistic (`A^2`) for a set of samples
+/// against a continuous uniform distribution on `[0, 1]`.
+///
+/// Compared to the Kolmogorov–Smirnov statistic, `A^2` gives more weight to
+/// the tails of the distribution.
+///
+/// If there are no samples or any sample is outside of `(0, 1)` (where the
+/// statistic is undefined), then no result is returned.
+///
+/// # Examples
+///
+/// ```
+/// use bitbelay_statistics::kolmogorov_smirnov::uniform_anderson_darling_statistic;
+///
+/// let a = uniform_anderson_darling_statistic(&[0.125, 0.375, 0.625, 0.875]).unwrap();
+/// assert!(a < 0.2);
+///
+/// assert!(uniform_anderson_darling_statistic(&[0.0, 0.5]).is_none());
+/// ```
+pub fn uniform_anderson_darling_statistic(samples: &[f64]) -> Option<f64> {
+    let samples = sorted(samples)?;
+
+    if samples.iter().any(|s| *s <= 0.0 || *s >= 1.0) {
+        return None;
+    }
+
+    let n = samples.len();
+    let sum = (0..n).fold(0.0, |acc, i| {
+        let weight = (2 * i + 1) as f64;
+        acc + weight * (samples[i].ln() + (1.0 - samples[n - 1 - i]).ln())
+    });
+
+    Some(-(n as f64) - sum / n as f64)
+}
+
+/// Computes the asymptotic CDF of the Anderson–Darling statistic using the
+/// approximation from Marsaglia and Marsaglia (2004).
+fn anderson_darling_cdf(statistic: f64) -> f64 {
+    let z = statistic;
+
+    if z <= 0.0 {
+        return 0.0;
+    }
+
+    if z < 2.0 {
+        (-1.2337141 / z).exp() / z.sqrt()
+            * (2.00012
+                + (0.247105 - (0.0649821 - (0.0347962 - (0.011672 - 0.00168691 * z) * z) * z) * z)
+                    * z)
+    } else {
+        (-(1.0776
+            - (2.30695 - (0.43424 - (0.082433 - (0.008056 - 0.0003146 * z) * z) * z) * z) * z)
+            .exp())
+        .exp()
+    }
+}
+
+/// Anderson–Darling tests for a theoretical, continuous uniform distribution
+/// on `[0, 1]`.
+#[allow(missing_debug_implementations)]
+pub struct UniformAndersonDarlingTest;
+
+impl UniformAndersonDarlingTest {
+    /// Performs a goodness of fit test for a set of samples against a
+    /// continuous uniform distribution on `[0, 1]` using the Anderson–Darling
+    /// statistic.
+    ///
+    /// A p-value less than the chosen significance level indicates that the
+    /// samples did not arise from a continuous uniform distribution.
+    ///
+    /// If there are no samples or any sample is outside of `(0, 1)`, then no
+    /// result is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_statistics::kolmogorov_smirnov::UniformAndersonDarlingTest;
+    ///
+    /// // A set of samples that are evenly spread across `(0, 1)`.
+    /// let samples = (0..1000)
+    ///     .map(|i| (i as f64 + 0.5) / 1000.0)
+    ///     .collect::<Vec<_>>();
+    /// let p = UniformAndersonDarlingTest::goodness_of_fit(&samples).unwrap();
+    /// assert!(p >= 0.05);
+    ///
+    /// // A set of samples that are squeezed into `(0, 0.5)`.
+    /// let samples = (0..1000)
+    ///     .map(|i| (i as f64 + 0.5) / 2000.0)
+    ///     .collect::<Vec<_>>();
+    /// let p = UniformAndersonDarlingTest::goodness_of_fit(&samples).unwrap();
+    /// assert!(p < 0.05);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * The p-value is computed from the asymptotic distribution of the
+    ///   statistic, which is accurate for more than a few dozen samples.
+    pub fn goodness_of_fit(samples: &[f64]) -> Option<f64> {
+        let statistic = uniform_anderson_darling_statistic(samples)?;
+        Some((1.0 - anderson_darling_cdf(statistic)).clamp(0.0, 1.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const TOLERANCE: f64 = 1e-3;
+
+    #[test]
+    fn kolmogorov_critical_values() {
+        // The asymptotic critical value of `sqrt(n) * D` at a significance
+        // level of 0.05 is 1.358.
+        let n = 1_000_000;
+        let statistic = 1.3581 / (n as f64).sqrt();
+        assert_relative_eq!(kolmogorov_p_value(statistic, n), 0.05, epsilon = TOLERANCE);
+    }
+
+    #[test]
+    fn anderson_darling_critical_values() {
+        // The asymptotic critical value of `A^2` at a significance level of
+        // 0.05 is 2.492.
+        assert_relative_eq!(1.0 - anderson_darling_cdf(2.492), 0.05, epsilon = TOLERANCE);
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert!(UniformKolmogorovSmirnovTest::goodness_of_fit(&[]).is_none());
+        assert!(UniformKolmogorovSmirnovTest::goodness_of_fit(&[1.5]).is_none());
+        assert!(UniformAndersonDarlingTest::goodness_of_fit(&[1.0]).is_none());
+    }
+}
@@ -2,43 +2,84 @@
 //!
 //! # Types of Tests
 //!
-//! * Tests related to the [Chi-squared distribution] are located in the
-//!   `chi_squared` module ([link](chi_squared)).
+//! * Tests related to the [binomial distribution] are located in the `binomial`
+//!   module ([link](binomial)).
+//! * Tests related to the [Chi-squared distribution], including the Pearson and
+//!   [G-test] of independence, are located in the `chi_squared` module
+//!   ([link](chi_squared)).
 //! * Tests related to the correlation, such as [Pearson] and [Spearman]
 //!   correlation, are located in the `correlation` module
-//!   ([link](correlation)).
+//!   ([link](correlation)), along with a dense, symmetric correlation matrix
+//!   type ([link](correlation::matrix::SymmetricMatrix)).
+//! * Procedures that correct for [multiple comparisons], such as the
+//!   Bonferroni, Holm, and Benjamini–Hochberg procedures, are located in the
+//!   `correction` module ([link](correction)).
+//! * Measures of [entropy] and [mutual information] are located in the
+//!   `entropy` module ([link](entropy)).
+//! * Intervals within which a statistic is expected to fall, such as the
+//!   acceptance region of a binomial proportion or a [Fisher transformation]
+//!   confidence interval for a correlation coefficient, are located in the
+//!   `interval` module ([link](interval)).
+//! * Planners that compute the number of samples needed for a test to detect an
+//!   effect of a given size with a given [statistical power] are located in the
+//!   `power` module ([link](power)).
+//! * Tests that compare an empirical distribution function against a
+//!   theoretical distribution, such as the [Kolmogorov–Smirnov] and
+//!   [Anderson–Darling] tests, are located in the `kolmogorov_smirnov` module
+//!   ([link](kolmogorov_smirnov)).
 //!
+//! [binomial distribution]: https://en.wikipedia.org/wiki/Binomial_distribution
 //! [Chi-squared distribution]: https://en.wikipedia.org/wiki/Chi-squared_distribution
+//! [multiple comparisons]: https://en.wikipedia.org/wiki/Multiple_comparisons_problem
+//! [entropy]: https://en.wikipedia.org/wiki/Entropy_(information_theory)
+//! [mutual information]: https://en.wikipedia.org/wiki/Mutual_information
+//! [G-test]: https://en.wikipedia.org/wiki/G-test
+//! [Fisher transformation]: https://en.wikipedia.org/wiki/Fisher_transformation
+//! [Kolmogorov–Smirnov]: https://en.wikipedia.org/wiki/Kolmogorov%E2%80%93Smirnov_test
+//! [Anderson–Darling]: https://en.wikipedia.org/wiki/Anderson%E2%80%93Darling_test
+//! [statistical power]: https://en.wikipedia.org/wiki/Power_of_a_test
 //! [Pearson]: https://en.wikipedia.org/wiki/Pearson_correlation_coefficient
 //! [Spearman]: https://en.wikipedia.org/wiki/Spearman%27s_rank_correlation_coefficient
 
-use std::collections::BTreeMap;
-
+pub mod binomial;
 pub mod chi_squared;
+pub mod correction;
 pub mod correlation;
+pub mod entropy;
+pub mod interval;
+pub mod kolmogorov_smirnov;
+pub mod power;
 
 /// Ranks the inputs according to their [sort order](std::cmp::Ord`).
-fn rank<T: Clone + Ord>(data: &[T]) -> Vec<usize> {
-    let mut sorted = data.to_vec();
-    sorted.sort();
-
-    let mut ranks = BTreeMap::new();
-    let mut current_rank = 1usize;
-
-    for value in sorted {
-        ranks.entry(value.clone()).or_insert_with(|| {
-            let rank = current_rank;
-            current_rank += 1;
-            rank
-        });
+///
+/// Ranks start at `1`, and tied values are each assigned the average of the
+/// ranks that they span (known as _fractional_ ranking).
+fn rank<T: Ord>(data: &[T]) -> Vec<f64> {
+    let mut order = (0..data.len()).collect::<Vec<_>>();
+    order.sort_by(|a, b| data[*a].cmp(&data[*b]));
+
+    let mut ranks = vec![0.0; data.len()];
+    let mut start = 0;
+
+    while start < order.len() {
+        let mut end = start + 1;
+
+        while end < order.len() && data[order[end]] == data[order[start]] {
+            end += 1;
+        }
+
+        // NOTE: the positions `start..end` correspond to the (one-based) ranks
+        // `start + 1..=end`, whose average is the midpoint below.
+        let average = (start + 1 + end) as f64 / 2.0;
+
+        for index in &order[start..end] {
+            ranks[*index] = average;
+        }
+
+        start = end;
     }
 
-    // SAFETY: we just went through every value in `data` above, so we know every
-    // element now exists and will be retrieved within `ranks`. Thus, this will
-    // always unwrap.
-    data.iter()
-        .map(|v| *ranks.get(v).unwrap())
-        .collect::<Vec<_>>()
+    ranks
 }
 
 #[cfg(test)]
@@ -48,9 +89,18 @@ mod tests {
     #[test]
     fn rank_works_correctly() {
         let input = &[1, 3, 5, 2, 4, 6];
-        assert_eq!(rank(input), input);
+        assert_eq!(rank(input), &[1.0, 3.0, 5.0, 2.0, 4.0, 6.0]);
 
         let input = &[20, 10, 40, 30];
-        assert_eq!(rank(input), &[2, 1, 4, 3]);
+        assert_eq!(rank(input), &[2.0, 1.0, 4.0, 3.0]);
+    }
+
+    #[test]
+    fn rank_averages_ties() {
+        let input = &[1, 2, 2, 3];
+        assert_eq!(rank(input), &[1.0, 2.5, 2.5, 4.0]);
+
+        let input = &[10, 20, 10, 30, 20, 10];
+        assert_eq!(rank(input), &[2.0, 4.5, 2.0, 6.0, 4.5, 2.0]);
     }
 }
@@ -0,0 +1,304 @@
+//! Statistical power and sample size planning.
+//!
+//! Each function within this module computes the number of samples needed for
+//! a test to detect an effect of at least the given size with probability
+//! `power` (the [statistical power]) when the test is performed at the given
+//! threshold of statistical `significance`. Fewer samples risk passing a
+//! flawed hash function simply because the flaw was too small to be seen,
+//! whereas many more samples only cost time.
+//!
+//! # Supported Tests
+//!
+//! At present, the following tests are supported:
+//!
+//! * A Chi-squared goodness of fit test via [`goodness_of_fit()`], where the
+//!   effect size is [Cohen's _w_].
+//! * A two-sided test of a binomial proportion via [`proportion()`], where the
+//!   effect size is the deviation of the true proportion from the expected
+//!   proportion.
+//! * A two-sided test of a Pearson correlation coefficient via
+//!   [`correlation()`], where the effect size is the true correlation.
+//! * A one-sided test for an excess of Poisson-distributed events (such as hash
+//!   collisions) via [`collisions()`], where the effect size is the ratio of
+//!   the true rate of events to the expected rate.
+//!
+//! # Sources
+//!
+//! * The main page for effect sizes, including Cohen's _w_ ([link][effect
+//!   size]).
+//! * The main page for the noncentral Chi-squared distribution, which includes
+//!   Patnaik's approximation ([link][noncentral]).
+//!
+//! [statistical power]: https://en.wikipedia.org/wiki/Power_of_a_test
+//! [Cohen's _w_]: https://en.wikipedia.org/wiki/Effect_size#Cohen's_w
+//! [effect size]: https://en.wikipedia.org/wiki/Effect_size
+//! [noncentral]: https://en.wikipedia.org/wiki/Noncentral_chi-squared_distribution
+
+use statrs::distribution::ChiSquared;
+use statrs::distribution::ContinuousCDF as _;
+use statrs::distribution::Normal;
+
+/// The number of bisection steps used when searching for the noncentrality
+/// parameter of a Chi-squared goodness of fit test.
+const BISECTION_STEPS: usize = 200;
+
+/// Gets whether `value` is strictly between `0.0` and `1.0`.
+fn is_probability(value: f64) -> bool {
+    value > 0.0 && value < 1.0
+}
+
+/// Gets the `p` quantile of a standard normal distribution.
+fn z(p: f64) -> f64 {
+    // SAFETY: a standard normal distribution is always valid.
+    Normal::new(0.0, 1.0).unwrap().inverse_cdf(p)
+}
+
+/// Computes the power of a Chi-squared test with `freedom` degrees of freedom
+/// and a noncentrality parameter of `lambda` at `critical`.
+///
+/// The noncentral Chi-squared distribution is approximated by a scaled,
+/// central Chi-squared distribution with matching mean and variance
+/// (Patnaik's approximation).
+fn chi_squared_power(freedom: f64, lambda: f64, critical: f64) -> f64 {
+    let scale = (freedom + 2.0 * lambda) / (freedom + lambda);
+    let shape = (freedom + lambda).powi(2) / (freedom + 2.0 * lambda);
+
+    // SAFETY: the shape is always positive, as both `freedom` and `lambda` are
+    // non-negative and `freedom` is positive.
+    ChiSquared::new(shape).unwrap().sf(critical / scale)
+}
+
+/// Computes the number of samples needed for a Chi-squared goodness of fit
+/// test across `categories` categories to detect a deviation from the
+/// expected distribution with an effect size of `effect_size`.
+///
+/// The effect size is [Cohen's _w_](https://en.wikipedia.org/wiki/Effect_size#Cohen's_w),
+/// `sqrt(sum((p1 - p0)^2 / p0))` across each category, where `p0` is the
+/// expected probability of a category and `p1` is its true probability.
+///
+/// If there are fewer than two categories, if `effect_size` is not positive,
+/// or if `significance` or `power` are not within `(0.0, 1.0)`, then no
+/// result is returned.
+///
+/// # Examples
+///
+/// ```
+/// use bitbelay_statistics::power;
+///
+/// // A "medium" effect across two categories (one degree of freedom) needs
+/// // roughly 88 samples (Cohen).
+/// let samples = power::goodness_of_fit(2, 0.3, 0.05, 0.8).unwrap();
+/// assert!((84..=90).contains(&samples));
+///
+/// // Smaller effects need many more samples.
+/// assert!(power::goodness_of_fit(2, 0.03, 0.05, 0.8).unwrap() > 8_000);
+/// ```
+pub fn goodness_of_fit(
+    categories: usize,
+    effect_size: f64,
+    significance: f64,
+    power: f64,
+) -> Option<u64> {
+    if categories < 2
+        || effect_size <= 0.0
+        || !is_probability(significance)
+        || !is_probability(power)
+    {
+        return None;
+    }
+
+    let freedom = (categories - 1) as f64;
+
+    // SAFETY: the degrees of freedom are always positive.
+    let critical = ChiSquared::new(freedom)
+        .unwrap()
+        .inverse_cdf(1.0 - significance);
+
+    // The power increases with the noncentrality parameter, so the smallest
+    // noncentrality parameter that achieves the desired power is found by
+    // first bracketing it and then bisecting.
+    let mut low = 0.0;
+    let mut high = 1.0;
+
+    while chi_squared_power(freedom, high, critical) < power {
+        low = high;
+        high *= 2.0;
+    }
+
+    for _ in 0..BISECTION_STEPS {
+        let middle = (low + high) / 2.0;
+
+        if chi_squared_power(freedom, middle, critical) < power {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    // The noncentrality parameter is the number of samples multiplied by the
+    // square of the effect size.
+    Some((high / effect_size.powi(2)).ceil() as u64)
+}
+
+/// Computes the number of trials needed for a two-sided test of a binomial
+/// proportion to detect a true proportion of `probability + deviance` when
+/// the expected proportion is `probability`.
+///
+/// If `probability` or `significance` or `power` are not within `(0.0, 1.0)`,
+/// or if `probability + deviance` is not within `(0.0, 1.0)` or `deviance` is
+/// zero, then no result is returned.
+///
+/// # Examples
+///
+/// ```
+/// use bitbelay_statistics::power;
+///
+/// // Detecting a coin that lands heads 60% of the time needs roughly 194
+/// // tosses.
+/// assert_eq!(power::proportion(0.5, 0.1, 0.05, 0.8), Some(194));
+///
+/// // Detecting a bit that flips 50.1% of the time needs millions of trials.
+/// assert!(power::proportion(0.5, 0.001, 0.05, 0.8).unwrap() > 1_000_000);
+/// ```
+pub fn proportion(probability: f64, deviance: f64, significance: f64, power: f64) -> Option<u64> {
+    let alternative = probability + deviance;
+
+    if deviance == 0.0
+        || !is_probability(probability)
+        || !is_probability(alternative)
+        || !is_probability(significance)
+        || !is_probability(power)
+    {
+        return None;
+    }
+
+    let null = z(1.0 - significance / 2.0) * (probability * (1.0 - probability)).sqrt();
+    let alternative = z(power) * (alternative * (1.0 - alternative)).sqrt();
+
+    Some(((null + alternative) / deviance).powi(2).ceil() as u64)
+}
+
+/// Computes the number of pairs needed for a two-sided test of a Pearson
+/// correlation coefficient to detect a true correlation of `correlation`
+/// between two variables.
+///
+/// The sample correlation is approximately normally distributed under the
+/// [Fisher transformation](https://en.wikipedia.org/wiki/Fisher_transformation)
+/// with a standard error of `1 / sqrt(pairs - 3)`.
+///
+/// If `correlation` is zero or not within `(-1.0, 1.0)`, or if `significance`
+/// or `power` are not within `(0.0, 1.0)`, then no result is returned.
+///
+/// # Examples
+///
+/// ```
+/// use bitbelay_statistics::power;
+///
+/// // A "medium" correlation needs roughly 85 pairs (Cohen).
+/// assert_eq!(power::correlation(0.3, 0.05, 0.8), Some(85));
+///
+/// // A correlation of 0.01 needs roughly 78,500 pairs.
+/// assert!(power::correlation(0.01, 0.05, 0.8).unwrap() > 75_000);
+/// ```
+pub fn correlation(correlation: f64, significance: f64, power: f64) -> Option<u64> {
+    if correlation == 0.0
+        || correlation.abs() >= 1.0
+        || !is_probability(significance)
+        || !is_probability(power)
+    {
+        return None;
+    }
+
+    let numerator = z(1.0 - significance / 2.0) + z(power);
+    Some(((numerator / correlation.atanh()).powi(2) + 3.0).ceil() as u64)
+}
+
+/// Computes the number of comparisons needed for a one-sided test for an
+/// excess of rare events to detect a true rate of events that is `ratio` times
+/// the expected rate, where each comparison produces an event with
+/// `probability`.
+///
+/// The number of events is assumed to follow a Poisson distribution, which is
+/// approximated by a normal distribution with matching mean and variance. For
+/// example, the number of collisions between the low bits of pairs of hashes
+/// follows a Poisson distribution where `probability` is `2^-bits`.
+///
+/// If `probability` or `significance` or `power` are not within `(0.0, 1.0)`,
+/// or if `ratio` is not greater than one, then no result is returned.
+///
+/// # Examples
+///
+/// ```
+/// use bitbelay_statistics::power;
+///
+/// // Detecting twice as many collisions as expected needs roughly eight
+/// // collisions to be expected.
+/// let comparisons = power::collisions(0.5f64.powi(32), 2.0, 0.05, 0.8).unwrap();
+/// let expected = comparisons as f64 * 0.5f64.powi(32);
+/// assert!((7.5..8.5).contains(&expected));
+/// ```
+pub fn collisions(probability: f64, ratio: f64, significance: f64, power: f64) -> Option<u64> {
+    if ratio <= 1.0
+        || !is_probability(probability)
+        || !is_probability(significance)
+        || !is_probability(power)
+    {
+        return None;
+    }
+
+    // The expected number of events, `mean`, must satisfy
+    //
+    //   mean + z(1 - significance) * sqrt(mean)
+    //     <= ratio * mean - z(power) * sqrt(ratio * mean)
+    //
+    // which, solved for `sqrt(mean)`, yields the following.
+    let root = (z(1.0 - significance) + z(power) * ratio.sqrt()) / (ratio - 1.0);
+    Some((root.powi(2) / probability).ceil() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn goodness_of_fit_grows_with_categories() {
+        let few = goodness_of_fit(2, 0.1, 0.05, 0.8).unwrap();
+        let many = goodness_of_fit(64, 0.1, 0.05, 0.8).unwrap();
+        assert!(few < many);
+    }
+
+    #[test]
+    fn goodness_of_fit_matches_known_values() {
+        // Cohen's tables list 1,194 samples for w = 0.1 with four degrees of
+        // freedom at a significance of 0.05 and a power of 0.8.
+        let samples = goodness_of_fit(5, 0.1, 0.05, 0.8).unwrap();
+        assert!((1_180..=1_210).contains(&samples), "{samples}");
+    }
+
+    #[test]
+    fn proportion_is_symmetric() {
+        assert_eq!(
+            proportion(0.5, 0.05, 0.05, 0.8),
+            proportion(0.5, -0.05, 0.05, 0.8)
+        );
+    }
+
+    #[test]
+    fn stricter_significance_needs_more_samples() {
+        assert!(correlation(0.1, 0.001, 0.8).unwrap() > correlation(0.1, 0.05, 0.8).unwrap());
+        assert!(
+            collisions(0.01, 1.5, 0.001, 0.8).unwrap() > collisions(0.01, 1.5, 0.05, 0.8).unwrap()
+        );
+    }
+
+    #[test]
+    fn invalid_inputs() {
+        assert!(goodness_of_fit(1, 0.1, 0.05, 0.8).is_none());
+        assert!(goodness_of_fit(2, 0.0, 0.05, 0.8).is_none());
+        assert!(proportion(0.5, 0.0, 0.05, 0.8).is_none());
+        assert!(proportion(0.5, 0.6, 0.05, 0.8).is_none());
+        assert!(correlation(1.0, 0.05, 0.8).is_none());
+        assert!(correlation(0.1, 0.05, 1.0).is_none());
+        assert!(collisions(0.01, 1.0, 0.05, 0.8).is_none());
+    }
+}
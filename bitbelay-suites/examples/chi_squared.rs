@@ -20,7 +20,7 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let provider = Box::new(AlphanumericProvider::new(10));
 
-    suite.run_goodness_of_fit(provider, NonZeroUsize::try_from(10_000).unwrap(), 0.05);
+    suite.run_goodness_of_fit(provider, NonZeroUsize::try_from(10_000).unwrap(), 0.05)?;
     suite
         .report()
         .write_to(&mut std::io::stderr(), &Config::default())?;
@@ -11,8 +11,12 @@ use std::num::NonZeroUsize;
 
 use bitbelay_providers::Provider;
 use bitbelay_report::Report;
+use bitbelay_statistics::correction::Procedure;
 use bitbelay_tests::avalanche::Test;
 use bitbelay_tests::avalanche::sac;
+use bitbelay_tests::avalanche::seed;
+use bitbelay_tests::seeded::Seeder;
+use bitbelay_tests::tolerance::Tolerance;
 
 pub mod suite;
 
@@ -21,12 +25,16 @@ pub mod suite;
 pub enum Error {
     /// An error with the SAC test.
     StrictAvalancheCriterion(sac::Error),
+
+    /// An error with the seed avalanche test.
+    Seed(seed::Error),
 }
 
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Error::StrictAvalancheCriterion(err) => write!(f, "sac error: {err}"),
+            Error::Seed(err) => write!(f, "seed error: {err}"),
         }
     }
 }
@@ -80,7 +88,9 @@ impl<'a, H: BuildHasher, const N: usize> Suite<'a, H, N> {
     /// use std::num::NonZeroUsize;
     ///
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
     /// use bitbelay_suites::avalanche::suite::Builder;
+    /// use bitbelay_tests::tolerance::Tolerance;
     ///
     /// let hasher = RandomState::new();
     /// let mut suite = Builder::<RandomState, 64>::default()
@@ -93,7 +103,9 @@ impl<'a, H: BuildHasher, const N: usize> Suite<'a, H, N> {
     ///     provider,
     ///     NonZeroUsize::try_from(10).unwrap(),
     ///     NonZeroUsize::try_from(5_000).unwrap(),
-    ///     0.01,
+    ///     Tolerance::SampleSize(0.05),
+    ///     0.05,
+    ///     Procedure::Holm,
     /// );
     ///
     /// assert_eq!(suite.tests().len(), 1);
@@ -113,7 +125,9 @@ impl<'a, H: BuildHasher, const N: usize> Suite<'a, H, N> {
     /// use std::num::NonZeroUsize;
     ///
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
     /// use bitbelay_suites::avalanche::suite::Builder;
+    /// use bitbelay_tests::tolerance::Tolerance;
     ///
     /// let hasher = RandomState::new();
     /// let mut suite = Builder::<RandomState, 64>::default()
@@ -126,7 +140,9 @@ impl<'a, H: BuildHasher, const N: usize> Suite<'a, H, N> {
     ///     provider,
     ///     NonZeroUsize::try_from(10).unwrap(),
     ///     NonZeroUsize::try_from(5_000).unwrap(),
-    ///     0.01,
+    ///     Tolerance::SampleSize(0.05),
+    ///     0.05,
+    ///     Procedure::Holm,
     /// );
     ///
     /// assert_eq!(suite.tests().len(), 1);
@@ -146,8 +162,46 @@ impl<'a, H: BuildHasher, const N: usize> Suite<'a, H, N> {
         self.tests
     }
 
+    /// Computes the number of experiments of `iterations_per_experiment`
+    /// iterations needed for a [Strict Avalanche Criterion test](sac::Test)
+    /// within this [`Suite`] to detect an output bit that flips with a
+    /// probability of `0.5 + bias` with probability `power` at the given
+    /// threshold of statistical `significance`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_suites::avalanche::suite::Builder;
+    ///
+    /// let hasher = RandomState::new();
+    /// let suite = Builder::<RandomState, 64>::default()
+    ///     .build_hasher(&hasher)?
+    ///     .try_build()?;
+    ///
+    /// let experiments = suite
+    ///     .required_experiments(NonZeroUsize::try_from(4096).unwrap(), 0.001, 0.05, 0.8)
+    ///     .unwrap();
+    /// assert!(experiments.get() > 100);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn required_experiments(
+        &self,
+        iterations_per_experiment: NonZeroUsize,
+        bias: f64,
+        significance: f64,
+        power: f64,
+    ) -> Option<NonZeroUsize> {
+        let iterations = sac::required_iterations(N, bias, significance, power)?;
+        NonZeroUsize::try_from(iterations.get().div_ceil(iterations_per_experiment.get())).ok()
+    }
+
     /// Runs a [Strict Avalanche Criterion test](sac::Test) within the
-    /// [`Suite`] for a given [`Provider`] and number of iterations.
+    /// [`Suite`] for a given [`Provider`], number of iterations, bias
+    /// tolerance, and multiple-comparison correction.
     ///
     /// # Examples
     ///
@@ -156,7 +210,9 @@ impl<'a, H: BuildHasher, const N: usize> Suite<'a, H, N> {
     /// use std::num::NonZeroUsize;
     ///
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
     /// use bitbelay_suites::avalanche::suite::Builder;
+    /// use bitbelay_tests::tolerance::Tolerance;
     ///
     /// let hasher = RandomState::new();
     /// let mut suite = Builder::<RandomState, 64>::default()
@@ -169,7 +225,9 @@ impl<'a, H: BuildHasher, const N: usize> Suite<'a, H, N> {
     ///     provider,
     ///     NonZeroUsize::try_from(10).unwrap(),
     ///     NonZeroUsize::try_from(5_000).unwrap(),
-    ///     0.01,
+    ///     Tolerance::SampleSize(0.05),
+    ///     0.05,
+    ///     Procedure::Holm,
     /// );
     ///
     /// assert_eq!(suite.tests().len(), 1);
@@ -181,13 +239,17 @@ impl<'a, H: BuildHasher, const N: usize> Suite<'a, H, N> {
         provider: Box<dyn Provider>,
         experiments: NonZeroUsize,
         iterations_per_experiment: NonZeroUsize,
-        max_deviance: f64,
+        tolerance: Tolerance,
+        significance: f64,
+        correction: Procedure,
     ) -> Result<()> {
         let mut test = sac::Test::try_new(
             self.build_hasher,
             provider,
             iterations_per_experiment,
-            max_deviance,
+            tolerance,
+            significance,
+            correction,
         )
         .map_err(Error::StrictAvalancheCriterion)?;
 
@@ -204,6 +266,66 @@ impl<'a, H: BuildHasher, const N: usize> Suite<'a, H, N> {
 
         Ok(())
     }
+
+    /// Runs a [seed avalanche test](seed::Test) within the [`Suite`] for a
+    /// given [`Seeder`], [`Provider`], and number of experiments.
+    ///
+    /// Note that the hashers under test are constructed by the [`Seeder`]
+    /// rather than taken from the [`Suite`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_suites::avalanche::suite::Builder;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::SeededBuildHasher as _;
+    /// use bitbelay_tests::seeded::Seeder;
+    /// use bitbelay_tests::tolerance::Tolerance;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let hasher = Hasher::from_seed(42);
+    /// let mut suite = Builder::<Hasher, 64>::default()
+    ///     .build_hasher(&hasher)?
+    ///     .try_build()?;
+    ///
+    /// suite.run_seed_test(
+    ///     Seeder::new(),
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(100).unwrap(),
+    ///     Tolerance::SampleSize(0.05),
+    /// )?;
+    ///
+    /// assert_eq!(suite.tests().len(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn run_seed_test(
+        &mut self,
+        seeder: Seeder<H>,
+        provider: Box<dyn Provider>,
+        experiments: NonZeroUsize,
+        tolerance: Tolerance,
+    ) -> Result<()> {
+        let mut test = seed::Test::try_new(seeder, provider, tolerance).map_err(Error::Seed)?;
+
+        for i in 1..=experiments.get() {
+            if i % 1_000 == 0 && i != 0 {
+                tracing::info!("Executed {} experiments.", i);
+            }
+
+            test.run_single_experiment();
+        }
+
+        self.tests.push(Test::Seed(test));
+
+        Ok(())
+    }
 }
 
 impl<'a, H: BuildHasher, const N: usize> crate::r#trait::Suite for Suite<'a, H, N> {
@@ -0,0 +1,187 @@
+//! Balance test suite.
+
+use std::hash::BuildHasher;
+use std::num::NonZeroUsize;
+
+use bitbelay_providers::Provider;
+use bitbelay_report::Report;
+use bitbelay_statistics::correction::Procedure;
+use bitbelay_tests::balance::Test;
+use bitbelay_tests::balance::bit;
+
+pub mod suite;
+
+/// A balance test suite.
+#[derive(Debug)]
+pub struct Suite<'a, H: BuildHasher> {
+    /// The hash function builder.
+    build_hasher: &'a H,
+
+    /// The tests that have been run within this suite.
+    tests: Vec<Test<'a, H>>,
+}
+
+impl<'a, H: BuildHasher> Suite<'a, H> {
+    /// Gets the [`BuildHasher`] for this [`Suite`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::BuildHasher as _;
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_suites::balance::suite::Builder;
+    ///
+    /// let hasher = RandomState::new();
+    /// let suite = Builder::default().build_hasher(&hasher)?.try_build()?;
+    ///
+    /// // Used as a surrogate to test that the [`BuildHasher`]s are the same.
+    /// assert_eq!(suite.build_hasher().hash_one("42"), hasher.hash_one("42"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn build_hasher(&self) -> &H {
+        self.build_hasher
+    }
+
+    /// Gets the [`Test`]s run within this [`Suite`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_suites::balance::suite::Builder;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut suite = Builder::default().build_hasher(&hasher)?.try_build()?;
+    ///
+    /// suite.run_bit_test(
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(100).unwrap(),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
+    ///
+    /// assert_eq!(suite.tests().len(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn tests(&self) -> &[Test<'a, H>] {
+        self.tests.as_ref()
+    }
+
+    /// Consumes `self` and returns the [`Test`]s run within this [`Suite`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_suites::balance::suite::Builder;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut suite = Builder::default().build_hasher(&hasher)?.try_build()?;
+    ///
+    /// suite.run_bit_test(
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(100).unwrap(),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
+    ///
+    /// assert!(matches!(
+    ///     suite
+    ///         .into_tests()
+    ///         .into_iter()
+    ///         .next()
+    ///         .unwrap()
+    ///         .into_bit_test(),
+    ///     Some(_)
+    /// ));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn into_tests(self) -> Vec<Test<'a, H>> {
+        self.tests
+    }
+
+    /// Runs a [bit balance test](bit::Test) within the [`Suite`] for a given
+    /// [`Provider`], number of keys, threshold of statistical significance, and
+    /// multiple-comparison correction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_suites::balance::suite::Builder;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut suite = Builder::default().build_hasher(&hasher)?.try_build()?;
+    ///
+    /// suite.run_bit_test(
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(100).unwrap(),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
+    ///
+    /// assert_eq!(suite.tests().len(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn run_bit_test(
+        &mut self,
+        provider: Box<dyn Provider>,
+        keys: NonZeroUsize,
+        threshold: f64,
+        correction: Procedure,
+    ) {
+        let mut test = bit::Test::new(self.build_hasher, provider, threshold, correction);
+
+        for i in 0..keys.get() {
+            if i % 1_000 == 0 && i != 0 {
+                tracing::info!("Hashed {} keys.", i);
+            }
+
+            test.single_iteration();
+        }
+
+        self.tests.push(Test::Bit(test));
+    }
+}
+
+impl<'a, H: BuildHasher> crate::r#trait::Suite for Suite<'a, H> {
+    fn title(&self) -> &'static str {
+        "Balance"
+    }
+
+    fn report(&self) -> Report {
+        let tests = self
+            .tests
+            .iter()
+            .map(|t| t.report_section())
+            .collect::<Vec<_>>();
+
+        let mut builder = bitbelay_report::Builder::default()
+            .title(self.title())
+            .unwrap();
+
+        for test in tests {
+            builder = builder.push_test_result(test);
+        }
+
+        // SAFETY: this is manually crafted to always unwrap.
+        builder.try_build().unwrap()
+    }
+}
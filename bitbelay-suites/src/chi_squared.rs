@@ -5,11 +5,47 @@ use std::num::NonZeroUsize;
 
 use bitbelay_providers::Provider;
 use bitbelay_report::Report;
+use bitbelay_tests::bucketing::Bucketing;
 use bitbelay_tests::chi_squared::Test;
 use bitbelay_tests::chi_squared::goodness_of_fit;
+use bitbelay_tests::chi_squared::independence;
+use bitbelay_tests::chi_squared::popcount;
+use bitbelay_tests::chi_squared::sweep;
+use bitbelay_tests::chi_squared::uniformity;
+use bitbelay_tests::r#trait::Test as _;
+
+use crate::meta;
 
 pub mod suite;
 
+/// An error related to a [`Suite`].
+#[derive(Debug)]
+pub enum Error {
+    /// An error with the goodness of fit test.
+    GoodnessOfFit(goodness_of_fit::Error),
+
+    /// An error with the goodness of fit sweep test.
+    Sweep(sweep::Error),
+
+    /// An error with the sub-field independence test.
+    Independence(independence::Error),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::GoodnessOfFit(err) => write!(f, "goodness of fit error: {err}"),
+            Error::Sweep(err) => write!(f, "sweep error: {err}"),
+            Error::Independence(err) => write!(f, "independence error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A [`Result`](std::result::Result) with an [`Error`].
+type Result<T> = std::result::Result<T, Error>;
+
 /// A chi-squared test suite.
 #[derive(Debug)]
 pub struct Suite<'a, H: BuildHasher> {
@@ -19,8 +55,14 @@ pub struct Suite<'a, H: BuildHasher> {
     /// The tests that have been run within this suite.
     tests: Vec<Test<'a, H>>,
 
+    /// The second-level tests that have been run within this suite.
+    meta_tests: Vec<meta::Test>,
+
     /// The number of buckets to use within each test.
     buckets: NonZeroUsize,
+
+    /// The strategy used to map each hash to a bucket within each test.
+    bucketing: Bucketing,
 }
 
 impl<'a, H: BuildHasher> Suite<'a, H> {
@@ -44,6 +86,27 @@ impl<'a, H: BuildHasher> Suite<'a, H> {
         self.buckets
     }
 
+    /// Gets the bucketing strategy for the tests run within this [`Suite`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_suites::chi_squared::suite::Builder;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    ///
+    /// let hasher = RandomState::new();
+    /// let suite = Builder::default().build_hasher(&hasher)?.try_build()?;
+    ///
+    /// assert_eq!(suite.bucketing(), Bucketing::Modulo);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn bucketing(&self) -> Bucketing {
+        self.bucketing
+    }
+
     /// Gets the [`BuildHasher`] for this [`Suite`] by reference.
     ///
     /// # Examples
@@ -82,7 +145,7 @@ impl<'a, H: BuildHasher> Suite<'a, H> {
     ///
     /// let provider = Box::new(AlphanumericProvider::new(10));
     ///
-    /// suite.run_goodness_of_fit(provider, NonZeroUsize::try_from(10).unwrap(), 0.05);
+    /// suite.run_goodness_of_fit(provider, NonZeroUsize::try_from(10).unwrap(), 0.05)?;
     ///
     /// assert_eq!(suite.tests().len(), 1);
     /// assert_eq!(
@@ -120,7 +183,7 @@ impl<'a, H: BuildHasher> Suite<'a, H> {
     ///
     /// let provider = Box::new(AlphanumericProvider::new(10));
     ///
-    /// suite.run_goodness_of_fit(provider, NonZeroUsize::try_from(10).unwrap(), 0.05);
+    /// suite.run_goodness_of_fit(provider, NonZeroUsize::try_from(10).unwrap(), 0.05)?;
     ///
     /// assert_eq!(suite.tests().len(), 1);
     /// assert!(matches!(
@@ -139,6 +202,138 @@ impl<'a, H: BuildHasher> Suite<'a, H> {
         self.tests
     }
 
+    /// Gets the second-level [`meta::Test`]s run within this [`Suite`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_suites::chi_squared::suite::Builder;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut suite = Builder::default().build_hasher(&hasher)?.try_build()?;
+    ///
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    ///
+    /// suite.run_goodness_of_fit_meta_test(
+    ///     provider,
+    ///     NonZeroUsize::try_from(10).unwrap(),
+    ///     NonZeroUsize::try_from(2560).unwrap(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert!(suite.tests().is_empty());
+    /// assert_eq!(suite.meta_tests().len(), 1);
+    /// assert_eq!(suite.meta_tests()[0].p_values().len(), 10);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn meta_tests(&self) -> &[meta::Test] {
+        self.meta_tests.as_ref()
+    }
+
+    /// Computes the number of iterations needed for a [goodness of fit
+    /// test](goodness_of_fit::Test) across the buckets of this [`Suite`] to
+    /// detect a deviation from a uniform distribution with an effect size
+    /// (Cohen's _w_) of at least `effect_size` with probability `power` at the
+    /// given `threshold` of statistical significance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_suites::chi_squared::suite::Builder;
+    ///
+    /// let hasher = RandomState::new();
+    /// let suite = Builder::default().build_hasher(&hasher)?.try_build()?;
+    ///
+    /// let iterations = suite.required_iterations(0.05, 0.05, 0.8).unwrap();
+    /// assert!(iterations.get() > 10_000);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn required_iterations(
+        &self,
+        effect_size: f64,
+        threshold: f64,
+        power: f64,
+    ) -> Option<NonZeroUsize> {
+        goodness_of_fit::required_iterations(self.buckets, effect_size, threshold, power)
+    }
+
+    /// Repeats a [goodness of fit test](goodness_of_fit::Test) of `iterations`
+    /// iterations `repetitions` times with fresh data from the [`Provider`]
+    /// and runs a second-level [`meta::Test`] on the resulting p-values
+    /// within the [`Suite`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_suites::chi_squared::suite::Builder;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut suite = Builder::default().build_hasher(&hasher)?.try_build()?;
+    ///
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    ///
+    /// suite.run_goodness_of_fit_meta_test(
+    ///     provider,
+    ///     NonZeroUsize::try_from(10).unwrap(),
+    ///     NonZeroUsize::try_from(2560).unwrap(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// let test = suite.meta_tests().first().unwrap();
+    /// assert!(test.p_values().iter().all(|p_value| p_value.is_some()));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn run_goodness_of_fit_meta_test(
+        &mut self,
+        provider: Box<dyn Provider>,
+        repetitions: NonZeroUsize,
+        iterations: NonZeroUsize,
+        threshold: f64,
+    ) -> Result<()> {
+        let name = format!(
+            "Goodness of Fit / {} / {} bucketing / {} iterations",
+            provider.name(),
+            self.bucketing,
+            iterations
+        );
+
+        let mut test = goodness_of_fit::Test::try_new(
+            self.build_hasher,
+            provider,
+            self.buckets,
+            self.bucketing,
+            threshold,
+        )
+        .map_err(Error::GoodnessOfFit)?;
+
+        let meta = meta::Test::run(name, repetitions, threshold, || {
+            test.reset();
+
+            for _ in 0..iterations.get() {
+                test.single_iteration();
+            }
+
+            test.p_value()
+        });
+
+        self.meta_tests.push(meta);
+
+        Ok(())
+    }
+
     /// Runs a [goodness of fit test](goodness_of_fit::Test) within the
     /// [`Suite`] for a given [`Provider`] and number of iterations.
     ///
@@ -156,7 +351,7 @@ impl<'a, H: BuildHasher> Suite<'a, H> {
     ///
     /// let provider = Box::new(AlphanumericProvider::new(10));
     ///
-    /// suite.run_goodness_of_fit(provider, NonZeroUsize::try_from(10).unwrap(), 0.05);
+    /// suite.run_goodness_of_fit(provider, NonZeroUsize::try_from(10).unwrap(), 0.05)?;
     ///
     /// assert_eq!(suite.tests().len(), 1);
     /// assert_eq!(
@@ -179,9 +374,229 @@ impl<'a, H: BuildHasher> Suite<'a, H> {
         provider: Box<dyn Provider>,
         iterations: NonZeroUsize,
         threshold: f64,
+    ) -> Result<()> {
+        let mut test = goodness_of_fit::Test::try_new(
+            self.build_hasher,
+            provider,
+            self.buckets,
+            self.bucketing,
+            threshold,
+        )
+        .map_err(Error::GoodnessOfFit)?;
+
+        for i in 0..iterations.get() {
+            if i % 1_000 == 0 && i != 0 {
+                tracing::info!("Executed {} iterations.", i);
+            }
+
+            test.single_iteration();
+        }
+
+        self.tests.push(Test::GoodnessOfFit(test));
+
+        Ok(())
+    }
+
+    /// Runs a [goodness of fit sweep](sweep::Test) within the [`Suite`] for a
+    /// given [`Provider`], number of iterations, and set of bucket counts.
+    ///
+    /// The same hashed values are reused for every number of buckets in
+    /// `bucket_counts`. Note that the number of buckets configured for the
+    /// [`Suite`] is not used by this test.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_suites::chi_squared::suite::Builder;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut suite = Builder::default().build_hasher(&hasher)?.try_build()?;
+    ///
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    ///
+    /// suite.run_goodness_of_fit_sweep(
+    ///     provider,
+    ///     NonZeroUsize::try_from(1000).unwrap(),
+    ///     (4..=6)
+    ///         .map(|power| NonZeroUsize::try_from(1 << power).unwrap())
+    ///         .collect(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// let test = suite.tests().first().unwrap().as_sweep_test().unwrap();
+    /// assert_eq!(test.hashes().len(), 1000);
+    /// assert_eq!(test.results().len(), 3);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn run_goodness_of_fit_sweep(
+        &mut self,
+        provider: Box<dyn Provider>,
+        iterations: NonZeroUsize,
+        bucket_counts: Vec<NonZeroUsize>,
+        threshold: f64,
+    ) -> Result<()> {
+        let mut test = sweep::Test::try_new(
+            self.build_hasher,
+            provider,
+            bucket_counts,
+            self.bucketing,
+            threshold,
+        )
+        .map_err(Error::Sweep)?;
+
+        for i in 0..iterations.get() {
+            if i % 1_000_000 == 0 && i != 0 {
+                tracing::info!("Executed {} iterations.", i);
+            }
+
+            test.single_iteration();
+        }
+
+        self.tests.push(Test::Sweep(test));
+
+        Ok(())
+    }
+
+    /// Runs a [Hamming weight test](popcount::Test) within the [`Suite`] for a
+    /// given [`Provider`] and number of iterations.
+    ///
+    /// Note that the number of buckets and the bucketing strategy configured
+    /// for the [`Suite`] are not used by this test.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_suites::chi_squared::suite::Builder;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut suite = Builder::default().build_hasher(&hasher)?.try_build()?;
+    ///
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    ///
+    /// suite.run_popcount_test(provider, NonZeroUsize::try_from(10).unwrap(), 0.05);
+    ///
+    /// let test = suite.tests().first().unwrap().as_popcount_test().unwrap();
+    /// assert_eq!(test.weights().iter().sum::<usize>(), 10);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn run_popcount_test(
+        &mut self,
+        provider: Box<dyn Provider>,
+        iterations: NonZeroUsize,
+        threshold: f64,
+    ) {
+        let mut test = popcount::Test::new(self.build_hasher, provider, threshold);
+
+        for i in 0..iterations.get() {
+            if i % 1_000 == 0 && i != 0 {
+                tracing::info!("Executed {} iterations.", i);
+            }
+
+            test.single_iteration();
+        }
+
+        self.tests.push(Test::Popcount(test));
+    }
+
+    /// Runs a [uniformity test](uniformity::Test) within the [`Suite`] for a
+    /// given [`Provider`] and number of iterations.
+    ///
+    /// Note that the number of buckets and the bucketing strategy configured
+    /// for the [`Suite`] are not used by this test.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_suites::chi_squared::suite::Builder;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut suite = Builder::default().build_hasher(&hasher)?.try_build()?;
+    ///
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    ///
+    /// suite.run_uniformity_test(provider, NonZeroUsize::try_from(10).unwrap(), 0.05);
+    ///
+    /// let test = suite.tests().first().unwrap().as_uniformity_test().unwrap();
+    /// assert_eq!(test.samples().len(), 10);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn run_uniformity_test(
+        &mut self,
+        provider: Box<dyn Provider>,
+        iterations: NonZeroUsize,
+        threshold: f64,
     ) {
+        let mut test = uniformity::Test::new(self.build_hasher, provider, threshold);
+
+        for i in 0..iterations.get() {
+            if i % 1_000 == 0 && i != 0 {
+                tracing::info!("Executed {} iterations.", i);
+            }
+
+            test.single_iteration();
+        }
+
+        self.tests.push(Test::Uniformity(test));
+    }
+
+    /// Runs a [sub-field independence test](independence::Test) within the
+    /// [`Suite`] for a given [`Provider`], number of iterations, and width (in
+    /// bits) of the low and high sub-fields.
+    ///
+    /// Note that the number of buckets and the bucketing strategy configured
+    /// for the [`Suite`] are not used by this test.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_suites::chi_squared::suite::Builder;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut suite = Builder::default().build_hasher(&hasher)?.try_build()?;
+    ///
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    ///
+    /// suite.run_independence_test(provider, NonZeroUsize::try_from(10).unwrap(), 8, 0.05)?;
+    ///
+    /// let test = suite
+    ///     .tests()
+    ///     .first()
+    ///     .unwrap()
+    ///     .as_independence_test()
+    ///     .unwrap();
+    /// assert_eq!(test.table().iter().flatten().sum::<usize>(), 10);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn run_independence_test(
+        &mut self,
+        provider: Box<dyn Provider>,
+        iterations: NonZeroUsize,
+        field_bits: u32,
+        threshold: f64,
+    ) -> Result<()> {
         let mut test =
-            goodness_of_fit::Test::new(self.build_hasher, provider, self.buckets, threshold);
+            independence::Test::try_new(self.build_hasher, provider, field_bits, threshold)
+                .map_err(Error::Independence)?;
 
         for i in 0..iterations.get() {
             if i % 1_000 == 0 && i != 0 {
@@ -191,7 +606,9 @@ impl<'a, H: BuildHasher> Suite<'a, H> {
             test.single_iteration();
         }
 
-        self.tests.push(Test::GoodnessOfFit(test));
+        self.tests.push(Test::Independence(test));
+
+        Ok(())
     }
 }
 
@@ -205,6 +622,7 @@ impl<'a, H: BuildHasher> crate::r#trait::Suite for Suite<'a, H> {
             .tests
             .iter()
             .map(|t| t.report_section())
+            .chain(self.meta_tests.iter().map(|t| t.report_section()))
             .collect::<Vec<_>>();
 
         let mut builder = bitbelay_report::Builder::default()
@@ -3,6 +3,8 @@
 use std::hash::BuildHasher;
 use std::num::NonZeroUsize;
 
+use bitbelay_tests::bucketing::Bucketing;
+
 use crate::chi_squared::Suite;
 
 /// The default number of buckets to use when none are provided.
@@ -33,6 +35,9 @@ pub enum MultipleError {
 
     /// Multiple buckets values were provided to the [`Builder`].
     Buckets,
+
+    /// Multiple bucketing values were provided to the [`Builder`].
+    Bucketing,
 }
 
 impl std::fmt::Display for MultipleError {
@@ -40,6 +45,7 @@ impl std::fmt::Display for MultipleError {
         match self {
             MultipleError::BuildHasher => write!(f, "build hasher"),
             MultipleError::Buckets => write!(f, "buckets"),
+            MultipleError::Bucketing => write!(f, "bucketing"),
         }
     }
 }
@@ -78,6 +84,9 @@ pub struct Builder<'a, H: BuildHasher> {
 
     /// The number of buckets to use within each test.
     buckets: Option<NonZeroUsize>,
+
+    /// The strategy used to map each hash to a bucket within each test.
+    bucketing: Option<Bucketing>,
 }
 
 impl<'a, H: BuildHasher> Default for Builder<'a, H> {
@@ -85,6 +94,7 @@ impl<'a, H: BuildHasher> Default for Builder<'a, H> {
         Self {
             build_hasher: Default::default(),
             buckets: Default::default(),
+            bucketing: Default::default(),
         }
     }
 }
@@ -119,6 +129,36 @@ impl<'a, H: BuildHasher> Builder<'a, H> {
         Ok(self)
     }
 
+    /// Sets the bucketing strategy to use for tests within this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_suites::chi_squared::suite::Builder;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    ///
+    /// let hasher = RandomState::new();
+    /// let suite = Builder::default()
+    ///     .bucketing(Bucketing::FastRange)?
+    ///     .build_hasher(&hasher)?
+    ///     .try_build()?;
+    ///
+    /// assert_eq!(suite.bucketing(), Bucketing::FastRange);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn bucketing(mut self, bucketing: Bucketing) -> Result<Self> {
+        if self.bucketing.is_some() {
+            return Err(Error::Multiple(MultipleError::Bucketing));
+        }
+
+        self.bucketing = Some(bucketing);
+        Ok(self)
+    }
+
     /// Sets the [`BuildHasher`] for this [`Builder`].
     ///
     /// # Examples
@@ -182,7 +222,9 @@ impl<'a, H: BuildHasher> Builder<'a, H> {
         Ok(Suite {
             build_hasher,
             tests: Vec::new(),
+            meta_tests: Vec::new(),
             buckets,
+            bucketing: self.bucketing.unwrap_or_default(),
         })
     }
 }
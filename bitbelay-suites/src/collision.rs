@@ -0,0 +1,222 @@
+//! Collision test suite.
+
+use std::hash::BuildHasher;
+use std::num::NonZeroUsize;
+
+use bitbelay_providers::Provider;
+use bitbelay_report::Report;
+use bitbelay_tests::collision::Test;
+use bitbelay_tests::collision::differential;
+
+pub mod suite;
+
+/// An error related to a [`Suite`].
+#[derive(Debug)]
+pub enum Error {
+    /// An error with the differential test.
+    Differential(differential::Error),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Differential(err) => write!(f, "differential error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A [`Result`](std::result::Result) with an [`Error`].
+type Result<T> = std::result::Result<T, Error>;
+
+/// A collision test suite.
+#[derive(Debug)]
+pub struct Suite<'a, H: BuildHasher> {
+    /// The hash function builder.
+    build_hasher: &'a H,
+
+    /// The tests that have been run within this suite.
+    tests: Vec<Test<'a, H>>,
+}
+
+impl<'a, H: BuildHasher> Suite<'a, H> {
+    /// Gets the [`BuildHasher`] for this [`Suite`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::BuildHasher as _;
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_suites::collision::suite::Builder;
+    ///
+    /// let hasher = RandomState::new();
+    /// let suite = Builder::default().build_hasher(&hasher)?.try_build()?;
+    ///
+    /// // Used as a surrogate to test that the [`BuildHasher`]s are the same.
+    /// assert_eq!(suite.build_hasher().hash_one("42"), hasher.hash_one("42"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn build_hasher(&self) -> &H {
+        self.build_hasher
+    }
+
+    /// Gets the [`Test`]s run within this [`Suite`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_suites::collision::suite::Builder;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut suite = Builder::default().build_hasher(&hasher)?.try_build()?;
+    ///
+    /// suite.run_differential_test(
+    ///     Box::new(AlphanumericProvider::new(8)),
+    ///     NonZeroUsize::try_from(10).unwrap(),
+    ///     NonZeroUsize::try_from(64).unwrap(),
+    ///     3,
+    ///     32,
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert_eq!(suite.tests().len(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn tests(&self) -> &[Test<'a, H>] {
+        self.tests.as_ref()
+    }
+
+    /// Consumes `self` and returns the [`Test`]s run within this [`Suite`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_suites::collision::suite::Builder;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut suite = Builder::default().build_hasher(&hasher)?.try_build()?;
+    ///
+    /// suite.run_differential_test(
+    ///     Box::new(AlphanumericProvider::new(8)),
+    ///     NonZeroUsize::try_from(10).unwrap(),
+    ///     NonZeroUsize::try_from(64).unwrap(),
+    ///     3,
+    ///     32,
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert!(matches!(
+    ///     suite
+    ///         .into_tests()
+    ///         .into_iter()
+    ///         .next()
+    ///         .unwrap()
+    ///         .into_differential_test(),
+    ///     Some(_)
+    /// ));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn into_tests(self) -> Vec<Test<'a, H>> {
+        self.tests
+    }
+
+    /// Runs a [differential test](differential::Test) within the [`Suite`] for
+    /// a given [`Provider`], number of keys, number of leading key bits that
+    /// may be flipped, maximum number of flipped bits, and number of compared
+    /// output bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_suites::collision::suite::Builder;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut suite = Builder::default().build_hasher(&hasher)?.try_build()?;
+    ///
+    /// suite.run_differential_test(
+    ///     Box::new(AlphanumericProvider::new(8)),
+    ///     NonZeroUsize::try_from(10).unwrap(),
+    ///     NonZeroUsize::try_from(64).unwrap(),
+    ///     3,
+    ///     32,
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert_eq!(suite.tests().len(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn run_differential_test(
+        &mut self,
+        provider: Box<dyn Provider>,
+        keys: NonZeroUsize,
+        key_bits: NonZeroUsize,
+        max_weight: usize,
+        bits: u32,
+        threshold: f64,
+    ) -> Result<()> {
+        let mut test = differential::Test::try_new(
+            self.build_hasher,
+            provider,
+            key_bits,
+            max_weight,
+            bits,
+            threshold,
+        )
+        .map_err(Error::Differential)?;
+
+        for i in 0..keys.get() {
+            if i % 1_000 == 0 && i != 0 {
+                tracing::info!("Hashed {} keys.", i);
+            }
+
+            test.single_iteration();
+        }
+
+        self.tests.push(Test::Differential(test));
+
+        Ok(())
+    }
+}
+
+impl<'a, H: BuildHasher> crate::r#trait::Suite for Suite<'a, H> {
+    fn title(&self) -> &'static str {
+        "Collision"
+    }
+
+    fn report(&self) -> Report {
+        let tests = self
+            .tests
+            .iter()
+            .map(|t| t.report_section())
+            .collect::<Vec<_>>();
+
+        let mut builder = bitbelay_report::Builder::default()
+            .title(self.title())
+            .unwrap();
+
+        for test in tests {
+            builder = builder.push_test_result(test);
+        }
+
+        // SAFETY: this is manually crafted to always unwrap.
+        builder.try_build().unwrap()
+    }
+}
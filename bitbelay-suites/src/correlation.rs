@@ -5,8 +5,14 @@ use std::num::NonZeroUsize;
 
 use bitbelay_providers::Provider;
 use bitbelay_report::Report;
+use bitbelay_statistics::correction::Procedure;
 use bitbelay_tests::correlation::Test;
 use bitbelay_tests::correlation::bitwise;
+use bitbelay_tests::correlation::input_output;
+use bitbelay_tests::correlation::mutual_information;
+use bitbelay_tests::correlation::serial;
+use bitbelay_tests::correlation::spearman;
+use bitbelay_tests::tolerance::Tolerance;
 
 pub mod suite;
 
@@ -54,7 +60,9 @@ impl<'a, H: BuildHasher, const N: usize> Suite<'a, H, N> {
     /// use std::num::NonZeroUsize;
     ///
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
     /// use bitbelay_suites::correlation::suite::Builder;
+    /// use bitbelay_tests::tolerance::Tolerance;
     ///
     /// let hasher = RandomState::new();
     /// let mut suite = Builder::default()
@@ -63,7 +71,13 @@ impl<'a, H: BuildHasher, const N: usize> Suite<'a, H, N> {
     ///
     /// let provider = Box::new(AlphanumericProvider::new(10));
     ///
-    /// suite.run_bitwise_test(provider, NonZeroUsize::try_from(10).unwrap(), 0.05);
+    /// suite.run_bitwise_test(
+    ///     provider,
+    ///     NonZeroUsize::try_from(10).unwrap(),
+    ///     Tolerance::Fixed(0.05),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
     ///
     /// assert_eq!(suite.tests().len(), 1);
     /// assert_eq!(
@@ -73,8 +87,7 @@ impl<'a, H: BuildHasher, const N: usize> Suite<'a, H, N> {
     ///         .unwrap()
     ///         .as_bitwise_test()
     ///         .unwrap()
-    ///         .bit_values()[0]
-    ///         .len(),
+    ///         .iterations(),
     ///     10
     /// );
     ///
@@ -93,7 +106,9 @@ impl<'a, H: BuildHasher, const N: usize> Suite<'a, H, N> {
     /// use std::num::NonZeroUsize;
     ///
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
     /// use bitbelay_suites::correlation::suite::Builder;
+    /// use bitbelay_tests::tolerance::Tolerance;
     ///
     /// let hasher = RandomState::new();
     /// let mut suite = Builder::default()
@@ -102,7 +117,13 @@ impl<'a, H: BuildHasher, const N: usize> Suite<'a, H, N> {
     ///
     /// let provider = Box::new(AlphanumericProvider::new(10));
     ///
-    /// suite.run_bitwise_test(provider, NonZeroUsize::try_from(10).unwrap(), 0.05);
+    /// suite.run_bitwise_test(
+    ///     provider,
+    ///     NonZeroUsize::try_from(10).unwrap(),
+    ///     Tolerance::Fixed(0.05),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
     ///
     /// assert_eq!(suite.tests().len(), 1);
     /// assert!(matches!(
@@ -121,6 +142,37 @@ impl<'a, H: BuildHasher, const N: usize> Suite<'a, H, N> {
         self.tests
     }
 
+    /// Computes the number of iterations needed for a [bitwise
+    /// test](bitwise::Test) within this [`Suite`] to detect a pair of output
+    /// bits with a correlation of at least `correlation` with probability
+    /// `power` at the given threshold of statistical `significance`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_suites::correlation::suite::Builder;
+    ///
+    /// let hasher = RandomState::new();
+    /// let suite = Builder::default()
+    ///     .build_hasher(&hasher)?
+    ///     .try_build::<64>()?;
+    ///
+    /// let iterations = suite.required_bitwise_iterations(0.01, 0.05, 0.8).unwrap();
+    /// assert!(iterations.get() > 100_000);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn required_bitwise_iterations(
+        &self,
+        correlation: f64,
+        significance: f64,
+        power: f64,
+    ) -> Option<NonZeroUsize> {
+        bitwise::required_iterations(N, correlation, significance, power)
+    }
+
     /// Runs a [bitwise test](bitwise::Test) within the [`Suite`] for a given
     /// [`Provider`] and number of iterations.
     ///
@@ -131,7 +183,9 @@ impl<'a, H: BuildHasher, const N: usize> Suite<'a, H, N> {
     /// use std::num::NonZeroUsize;
     ///
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
     /// use bitbelay_suites::correlation::suite::Builder;
+    /// use bitbelay_tests::tolerance::Tolerance;
     ///
     /// let hasher = RandomState::new();
     /// let mut suite = Builder::default()
@@ -140,7 +194,13 @@ impl<'a, H: BuildHasher, const N: usize> Suite<'a, H, N> {
     ///
     /// let provider = Box::new(AlphanumericProvider::new(10));
     ///
-    /// suite.run_bitwise_test(provider, NonZeroUsize::try_from(10).unwrap(), 0.05);
+    /// suite.run_bitwise_test(
+    ///     provider,
+    ///     NonZeroUsize::try_from(10).unwrap(),
+    ///     Tolerance::Fixed(0.05),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
     ///
     /// assert_eq!(suite.tests().len(), 1);
     /// assert_eq!(
@@ -150,8 +210,7 @@ impl<'a, H: BuildHasher, const N: usize> Suite<'a, H, N> {
     ///         .unwrap()
     ///         .as_bitwise_test()
     ///         .unwrap()
-    ///         .bit_values()[0]
-    ///         .len(),
+    ///         .iterations(),
     ///     10
     /// );
     ///
@@ -161,14 +220,235 @@ impl<'a, H: BuildHasher, const N: usize> Suite<'a, H, N> {
         &mut self,
         mut provider: Box<dyn Provider>,
         iterations: NonZeroUsize,
-        threshold: f64,
+        tolerance: Tolerance,
+        significance: f64,
+        correction: Procedure,
     ) -> anyhow::Result<()> {
-        let mut test = bitwise::Test::new(self.build_hasher, threshold);
+        let mut test = bitwise::Test::new(self.build_hasher, tolerance, significance, correction);
         test.run(&mut provider, iterations);
         self.tests.push(Test::Bitwise(test));
 
         Ok(())
     }
+
+    /// Runs a [Spearman sub-word correlation test](spearman::Test) within the
+    /// [`Suite`] for a given [`Provider`] and number of iterations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_suites::correlation::suite::Builder;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut suite = Builder::default()
+    ///     .build_hasher(&hasher)?
+    ///     .try_build::<64>()?;
+    ///
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    ///
+    /// suite.run_spearman_test(provider, NonZeroUsize::try_from(10).unwrap(), 0.05)?;
+    ///
+    /// let test = suite.tests().first().unwrap().as_spearman_test().unwrap();
+    /// assert_eq!(test.hashes().len(), 10);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn run_spearman_test(
+        &mut self,
+        mut provider: Box<dyn Provider>,
+        iterations: NonZeroUsize,
+        threshold: f64,
+    ) -> anyhow::Result<()> {
+        let mut test = spearman::Test::new(self.build_hasher, threshold);
+        test.run(&mut provider, iterations);
+        self.tests.push(Test::Spearman(test));
+
+        Ok(())
+    }
+
+    /// Runs an [input-output correlation test](input_output::Test) within the
+    /// [`Suite`] for a given [`Provider`], number of iterations, and maximum
+    /// number of input bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_suites::correlation::suite::Builder;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut suite = Builder::default()
+    ///     .build_hasher(&hasher)?
+    ///     .try_build::<64>()?;
+    ///
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    ///
+    /// suite.run_input_output_test(
+    ///     provider,
+    ///     NonZeroUsize::try_from(10).unwrap(),
+    ///     NonZeroUsize::try_from(256).unwrap(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// let test = suite
+    ///     .tests()
+    ///     .first()
+    ///     .unwrap()
+    ///     .as_input_output_test()
+    ///     .unwrap();
+    /// assert_eq!(test.keys(), 10);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn run_input_output_test(
+        &mut self,
+        provider: Box<dyn Provider>,
+        iterations: NonZeroUsize,
+        max_input_bits: NonZeroUsize,
+        threshold: f64,
+    ) -> anyhow::Result<()> {
+        let mut test =
+            input_output::Test::new(self.build_hasher, provider, max_input_bits, threshold);
+
+        for i in 0..iterations.get() {
+            if i % 1_000 == 0 && i != 0 {
+                tracing::info!("Hashed {} keys.", i);
+            }
+
+            test.single_iteration();
+        }
+
+        self.tests.push(Test::InputOutput(test));
+
+        Ok(())
+    }
+
+    /// Runs a [serial correlation test](serial::Test) within the [`Suite`] for
+    /// a given [`Provider`], number of iterations, and maximum lag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_suites::correlation::suite::Builder;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut suite = Builder::default()
+    ///     .build_hasher(&hasher)?
+    ///     .try_build::<64>()?;
+    ///
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    ///
+    /// suite.run_serial_test(
+    ///     provider,
+    ///     NonZeroUsize::try_from(10).unwrap(),
+    ///     NonZeroUsize::try_from(4).unwrap(),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// )?;
+    ///
+    /// let test = suite.tests().first().unwrap().as_serial_test().unwrap();
+    /// assert_eq!(test.hashes().len(), 10);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn run_serial_test(
+        &mut self,
+        provider: Box<dyn Provider>,
+        iterations: NonZeroUsize,
+        max_lag: NonZeroUsize,
+        threshold: f64,
+        correction: Procedure,
+    ) -> anyhow::Result<()> {
+        let mut test =
+            serial::Test::new(self.build_hasher, provider, max_lag, threshold, correction);
+
+        for i in 0..iterations.get() {
+            if i % 1_000 == 0 && i != 0 {
+                tracing::info!("Hashed {} keys.", i);
+            }
+
+            test.single_iteration();
+        }
+
+        self.tests.push(Test::Serial(test));
+
+        Ok(())
+    }
+
+    /// Runs a [mutual information test](mutual_information::Test) within the
+    /// [`Suite`] for a given [`Provider`] and number of iterations.
+    ///
+    /// Note that at least [`mutual_information::MIN_ITERATIONS`] iterations
+    /// are needed for the test to be conclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_suites::correlation::suite::Builder;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut suite = Builder::default()
+    ///     .build_hasher(&hasher)?
+    ///     .try_build::<64>()?;
+    ///
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    ///
+    /// suite.run_mutual_information_test(
+    ///     provider,
+    ///     NonZeroUsize::try_from(10).unwrap(),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// )?;
+    ///
+    /// let test = suite
+    ///     .tests()
+    ///     .first()
+    ///     .unwrap()
+    ///     .as_mutual_information_test()
+    ///     .unwrap();
+    /// assert_eq!(test.hashes(), 10);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn run_mutual_information_test(
+        &mut self,
+        provider: Box<dyn Provider>,
+        iterations: NonZeroUsize,
+        threshold: f64,
+        correction: Procedure,
+    ) -> anyhow::Result<()> {
+        let mut test =
+            mutual_information::Test::new(self.build_hasher, provider, threshold, correction);
+
+        for i in 0..iterations.get() {
+            if i % 1_000 == 0 && i != 0 {
+                tracing::info!("Hashed {} keys.", i);
+            }
+
+            test.single_iteration();
+        }
+
+        self.tests.push(Test::MutualInformation(test));
+
+        Ok(())
+    }
 }
 
 impl<'a, H: BuildHasher, const N: usize> crate::r#trait::Suite for Suite<'a, H, N> {
@@ -0,0 +1,238 @@
+//! Hash flooding test suite.
+
+use std::hash::BuildHasher;
+use std::num::NonZeroUsize;
+
+use bitbelay_providers::Provider;
+use bitbelay_report::Report;
+use bitbelay_tests::flooding::Test;
+use bitbelay_tests::flooding::persistent;
+use bitbelay_tests::seeded::Seeder;
+
+pub mod suite;
+
+/// An error related to a [`Suite`].
+#[derive(Debug)]
+pub enum Error {
+    /// An error with the persistent collision test.
+    Persistent(persistent::Error),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Persistent(err) => write!(f, "persistent error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A [`Result`](std::result::Result) with an [`Error`].
+type Result<T> = std::result::Result<T, Error>;
+
+/// A hash flooding test suite.
+#[derive(Debug)]
+pub struct Suite<H: BuildHasher> {
+    /// The seeder used to construct hashers.
+    seeder: Seeder<H>,
+
+    /// The tests that have been run within this suite.
+    tests: Vec<Test<H>>,
+}
+
+impl<H: BuildHasher> Suite<H> {
+    /// Gets the [`Seeder`] for this [`Suite`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// use bitbelay_suites::flooding::suite::Builder;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let suite = Builder::default()
+    ///     .seeder(Seeder::<Hasher>::new())?
+    ///     .try_build()?;
+    ///
+    /// assert_eq!(suite.seeder().seed_bytes(), 8);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn seeder(&self) -> Seeder<H> {
+        self.seeder
+    }
+
+    /// Gets the [`Test`]s run within this [`Suite`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_suites::flooding::suite::Builder;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let mut suite = Builder::default()
+    ///     .seeder(Seeder::<Hasher>::new())?
+    ///     .try_build()?;
+    ///
+    /// suite.run_persistent_test(
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(10).unwrap(),
+    ///     16,
+    ///     2,
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert_eq!(suite.tests().len(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn tests(&self) -> &[Test<H>] {
+        self.tests.as_ref()
+    }
+
+    /// Consumes `self` and returns the [`Test`]s run within this [`Suite`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_suites::flooding::suite::Builder;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let mut suite = Builder::default()
+    ///     .seeder(Seeder::<Hasher>::new())?
+    ///     .try_build()?;
+    ///
+    /// suite.run_persistent_test(
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(10).unwrap(),
+    ///     16,
+    ///     2,
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert!(matches!(
+    ///     suite
+    ///         .into_tests()
+    ///         .into_iter()
+    ///         .next()
+    ///         .unwrap()
+    ///         .into_persistent_test(),
+    ///     Some(_)
+    /// ));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn into_tests(self) -> Vec<Test<H>> {
+        self.tests
+    }
+
+    /// Runs a [persistent collision test](persistent::Test) within the
+    /// [`Suite`] for a given [`Provider`], number of keys, number of compared
+    /// output bits, maximum input difference weight, and number of fresh seeds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_suites::flooding::suite::Builder;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let mut suite = Builder::default()
+    ///     .seeder(Seeder::<Hasher>::new())?
+    ///     .try_build()?;
+    ///
+    /// suite.run_persistent_test(
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(10).unwrap(),
+    ///     16,
+    ///     2,
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert_eq!(suite.tests().len(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn run_persistent_test(
+        &mut self,
+        provider: Box<dyn Provider>,
+        keys: NonZeroUsize,
+        bits: u32,
+        max_weight: usize,
+        reseeds: NonZeroUsize,
+        threshold: f64,
+    ) -> Result<()> {
+        let mut test =
+            persistent::Test::try_new(self.seeder, provider, bits, max_weight, reseeds, threshold)
+                .map_err(Error::Persistent)?;
+
+        for i in 0..keys.get() {
+            if i % 1_000 == 0 && i != 0 {
+                tracing::info!("Searched {} keys.", i);
+            }
+
+            test.single_iteration();
+        }
+
+        self.tests.push(Test::Persistent(test));
+
+        Ok(())
+    }
+}
+
+impl<H: BuildHasher> crate::r#trait::Suite for Suite<H> {
+    fn title(&self) -> &'static str {
+        "Hash Flooding"
+    }
+
+    fn report(&self) -> Report {
+        let tests = self
+            .tests
+            .iter()
+            .map(|t| t.report_section())
+            .collect::<Vec<_>>();
+
+        let mut builder = bitbelay_report::Builder::default()
+            .title(self.title())
+            .unwrap();
+
+        for test in tests {
+            builder = builder.push_test_result(test);
+        }
+
+        // SAFETY: this is manually crafted to always unwrap.
+        builder.try_build().unwrap()
+    }
+}
@@ -0,0 +1,144 @@
+//! Builder for a [`Suite`].
+
+use std::hash::BuildHasher;
+
+use bitbelay_tests::seeded::Seeder;
+
+use crate::flooding::Suite;
+
+/// An error when a required field is missing.
+#[derive(Debug)]
+pub enum MissingError {
+    /// No seeder was provided to the [`Builder`].
+    Seeder,
+}
+
+impl std::fmt::Display for MissingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MissingError::Seeder => write!(f, "seeder"),
+        }
+    }
+}
+
+impl std::error::Error for MissingError {}
+
+/// An error when multiple values are provided for a singular field.
+#[derive(Debug)]
+pub enum MultipleError {
+    /// Multiple seeders were provided to the [`Builder`].
+    Seeder,
+}
+
+impl std::fmt::Display for MultipleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MultipleError::Seeder => write!(f, "seeder"),
+        }
+    }
+}
+
+impl std::error::Error for MultipleError {}
+
+/// An error related to a [`Builder`].
+#[derive(Debug)]
+pub enum Error {
+    /// A required field was missing from the [`Builder`].
+    Missing(MissingError),
+
+    /// Multiple values were provided for a singular field in the [`Builder`].
+    Multiple(MultipleError),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Missing(err) => write!(f, "missing error: {}", err),
+            Error::Multiple(err) => write!(f, "multiple error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A [`Result`](std::result::Result) with an [`Error`].
+type Result<T> = std::result::Result<T, Error>;
+
+/// A builder for a [`Suite`].
+#[derive(Debug)]
+pub struct Builder<H: BuildHasher> {
+    /// The seeder used to construct hashers.
+    seeder: Option<Seeder<H>>,
+}
+
+impl<H: BuildHasher> Default for Builder<H> {
+    fn default() -> Self {
+        Self {
+            seeder: Default::default(),
+        }
+    }
+}
+
+impl<H: BuildHasher> Builder<H> {
+    /// Sets the seeder used to construct hashers for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// use bitbelay_suites::flooding::suite::Builder;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let suite = Builder::default()
+    ///     .seeder(Seeder::<Hasher>::new())?
+    ///     .try_build()?;
+    ///
+    /// assert_eq!(suite.seeder().seed_bytes(), 8);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn seeder(mut self, seeder: Seeder<H>) -> Result<Self> {
+        if self.seeder.is_some() {
+            return Err(Error::Multiple(MultipleError::Seeder));
+        }
+
+        self.seeder = Some(seeder);
+        Ok(self)
+    }
+
+    /// Consumes `self` to attempt to build a [`Suite`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// use bitbelay_suites::flooding::suite::Builder;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let suite = Builder::default()
+    ///     .seeder(Seeder::<Hasher>::new())?
+    ///     .try_build()?;
+    ///
+    /// assert!(suite.tests().is_empty());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_build(self) -> Result<Suite<H>> {
+        let seeder = self.seeder.ok_or(Error::Missing(MissingError::Seeder))?;
+
+        Ok(Suite {
+            seeder,
+            tests: Vec::new(),
+        })
+    }
+}
@@ -12,6 +12,10 @@
 //!   multiple types of input data).
 //! * **Modules** are the discrete components of each test that are pass/fail.
 //!
+//! Any test that produces a p-value can also be repeated many times with fresh
+//! data within a second-level [**meta** test](meta), which checks that the
+//! resulting p-values are uniformly distributed.
+//!
 //! For example, the Chi-squared **suite** supports running a goodness of fit
 //! **test** for a particular input. Underneath the goodness of fit test, the
 //! failure to reject the null hypothesis is a **module** that can be passed or
@@ -22,9 +26,18 @@
 //! to implement.
 
 pub mod avalanche;
+pub mod balance;
 pub mod chi_squared;
+pub mod collision;
 pub mod correlation;
+pub mod extension;
+pub mod flooding;
+pub mod hashbrown;
+pub mod meta;
 pub mod performance;
+pub mod probing;
+pub mod sanity;
+pub mod seed;
 
 /// Traits for `bitbelay` test suites.
 pub mod r#trait {
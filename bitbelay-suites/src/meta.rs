@@ -0,0 +1,396 @@
+//! Second-level testing of p-values.
+//!
+//! When the null hypothesis holds, the p-value of a well-behaved test is
+//! uniformly distributed on `[0, 1]`. A single run of a test only checks
+//! whether one p-value is suspiciously small, but repeating the test many
+//! times with fresh data and checking that the resulting p-values are uniform
+//! also catches hash functions whose p-values are suspiciously _large_ (i.e.,
+//! hash functions that look "too good" to be random) as well as subtle flaws
+//! that only rarely push a single p-value below the threshold.
+//!
+//! A [`Test`] reports on both levels:
+//!
+//! * The **first level** counts how many repetitions rejected the null
+//!   hypothesis. Roughly `threshold * repetitions` rejections are expected, and
+//!   an exact, two-sided binomial test checks whether the count strays too far
+//!   from this.
+//! * The **second level** runs a Kolmogorov–Smirnov test of the collected
+//!   p-values against a continuous uniform distribution on `[0, 1]`.
+//!
+//! # Sources
+//!
+//! * [TestU01] popularized two-level testing for random number generators.
+//! * [SMHasher] applies the same idea to hash functions.
+//!
+//! [TestU01]: https://simul.iro.umontreal.ca/testu01/tu01.html
+//! [SMHasher]: https://github.com/aappleby/smhasher
+
+use std::num::NonZeroUsize;
+
+use bitbelay_report::section;
+use bitbelay_report::section::test::Builder;
+use bitbelay_report::section::test::Module;
+use bitbelay_report::section::test::module;
+use bitbelay_statistics::binomial;
+use bitbelay_statistics::kolmogorov_smirnov::UniformKolmogorovSmirnovTest;
+use colored::Colorize as _;
+
+/// The number of equal-width bins in the histogram of p-values within the
+/// report.
+const BINS: usize = 10;
+
+/// A second-level test of the p-values produced by repeating a test.
+#[derive(Debug)]
+pub struct Test {
+    /// The name of the repeated test.
+    name: String,
+
+    /// The threshold of statistical significance.
+    threshold: f64,
+
+    /// The p-value produced by each repetition (if one could be computed).
+    p_values: Vec<Option<f64>>,
+}
+
+impl Test {
+    /// Runs a new [`Test`] by calling `repetition` `repetitions` times, where
+    /// each call repeats the underlying test with fresh data and returns its
+    /// p-value (if one could be computed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_suites::meta::Test;
+    ///
+    /// let mut i = 0;
+    /// let test = Test::run(
+    ///     "Evenly Spread",
+    ///     NonZeroUsize::try_from(100).unwrap(),
+    ///     0.05,
+    ///     || {
+    ///         i += 1;
+    ///         Some((i as f64 - 0.5) / 100.0)
+    ///     },
+    /// );
+    ///
+    /// assert_eq!(test.p_values().len(), 100);
+    /// assert_eq!(test.rejections(), 5);
+    /// ```
+    pub fn run(
+        name: impl Into<String>,
+        repetitions: NonZeroUsize,
+        threshold: f64,
+        mut repetition: impl FnMut() -> Option<f64>,
+    ) -> Self {
+        let mut p_values = Vec::with_capacity(repetitions.get());
+
+        for i in 0..repetitions.get() {
+            if i % 1_000 == 0 && i != 0 {
+                tracing::info!("Executed {} repetitions.", i);
+            }
+
+            p_values.push(repetition());
+        }
+
+        Self {
+            name: name.into(),
+            threshold,
+            p_values,
+        }
+    }
+
+    /// Gets the name of the repeated test.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_suites::meta::Test;
+    ///
+    /// let test = Test::run("Foo", NonZeroUsize::try_from(1).unwrap(), 0.05, || None);
+    /// assert_eq!(test.name(), "Foo");
+    /// ```
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the threshold of statistical significance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_suites::meta::Test;
+    ///
+    /// let test = Test::run("Foo", NonZeroUsize::try_from(1).unwrap(), 0.05, || None);
+    /// assert_eq!(test.threshold(), 0.05);
+    /// ```
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Gets the p-value produced by each repetition (if one could be
+    /// computed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_suites::meta::Test;
+    ///
+    /// let test = Test::run("Foo", NonZeroUsize::try_from(3).unwrap(), 0.05, || {
+    ///     Some(0.5)
+    /// });
+    /// assert_eq!(test.p_values(), &[Some(0.5), Some(0.5), Some(0.5)]);
+    /// ```
+    pub fn p_values(&self) -> &[Option<f64>] {
+        &self.p_values
+    }
+
+    /// Gets the p-values of the repetitions where one could be computed.
+    fn computed(&self) -> Vec<f64> {
+        self.p_values.iter().flatten().copied().collect()
+    }
+
+    /// Gets the number of repetitions that rejected the null hypothesis at the
+    /// threshold of statistical significance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_suites::meta::Test;
+    ///
+    /// let test = Test::run("Foo", NonZeroUsize::try_from(3).unwrap(), 0.05, || {
+    ///     Some(0.01)
+    /// });
+    /// assert_eq!(test.rejections(), 3);
+    /// ```
+    pub fn rejections(&self) -> usize {
+        self.p_values
+            .iter()
+            .flatten()
+            .filter(|p_value| **p_value < self.threshold)
+            .count()
+    }
+
+    /// Computes the exact, two-sided binomial p-value of the number of
+    /// [rejections](Test::rejections()) under the null hypothesis that each
+    /// repetition rejects with a probability equal to the threshold of
+    /// statistical significance.
+    ///
+    /// If no p-values could be computed, [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_suites::meta::Test;
+    ///
+    /// // Every repetition rejecting is very unlikely.
+    /// let test = Test::run("Foo", NonZeroUsize::try_from(100).unwrap(), 0.05, || {
+    ///     Some(0.01)
+    /// });
+    /// assert!(test.first_level_p_value().unwrap() < 0.05);
+    /// ```
+    pub fn first_level_p_value(&self) -> Option<f64> {
+        let computed = self.p_values.iter().flatten().count();
+        binomial::two_sided_p_value(self.rejections() as u64, computed as u64, self.threshold)
+    }
+
+    /// Computes the Kolmogorov–Smirnov p-value of the collected p-values
+    /// against a continuous uniform distribution on `[0, 1]`.
+    ///
+    /// If no p-values could be computed, [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_suites::meta::Test;
+    ///
+    /// // P-values that are all "too good" are not uniform.
+    /// let test = Test::run("Foo", NonZeroUsize::try_from(100).unwrap(), 0.05, || {
+    ///     Some(0.99)
+    /// });
+    /// assert_eq!(test.rejections(), 0);
+    /// assert!(test.second_level_p_value().unwrap() < 0.05);
+    /// ```
+    pub fn second_level_p_value(&self) -> Option<f64> {
+        UniformKolmogorovSmirnovTest::goodness_of_fit(&self.computed())
+    }
+
+    /// Generates the [`Module`] for the first level of the [`Test`].
+    fn first_level_module(&self) -> Module {
+        let computed = self.p_values.iter().flatten().count();
+        let rejections = self.rejections();
+        let expected = self.threshold * computed as f64;
+
+        let (result, details) = match self.first_level_p_value() {
+            Some(p_value) if p_value >= self.threshold => (
+                module::Result::Pass,
+                format!(
+                    "{} of {} repetitions rejected the null hypothesis at a threshold of {:.2} \
+                     ({:.2} expected). The binomial p-value of {:.2e} was greater than or equal \
+                     to the threshold, so the number of rejections is consistent with a random \
+                     function.",
+                    rejections, computed, self.threshold, expected, p_value
+                ),
+            ),
+            Some(p_value) => (
+                module::Result::Fail,
+                format!(
+                    "{} of {} repetitions rejected the null hypothesis at a threshold of {:.2} \
+                     ({:.2} expected). The binomial p-value of {:.2e} was less than the \
+                     threshold, so the number of rejections is **not** consistent with a random \
+                     function.",
+                    rejections, computed, self.threshold, expected, p_value
+                ),
+            ),
+            None => (
+                module::Result::Inconclusive,
+                String::from(
+                    "No repetition produced a p-value (try increasing the number of iterations).",
+                ),
+            ),
+        };
+
+        Module::new(
+            result,
+            "First Level",
+            Some(format!("{}/{} rejected", rejections, computed)),
+            Some(details),
+        )
+    }
+
+    /// Generates the [`Module`] for the second level of the [`Test`].
+    fn second_level_module(&self) -> Module {
+        let (result, value, summary) = match self.second_level_p_value() {
+            Some(p_value) if p_value >= self.threshold => (
+                module::Result::Pass,
+                Some(format!("{:.2}", p_value)),
+                format!(
+                    "The Kolmogorov–Smirnov p-value of {:.2} was greater than or equal to the \
+                     threshold of {:.2}. As such, the null hypothesis that the p-values are \
+                     uniformly distributed **cannot** be rejected.",
+                    p_value, self.threshold
+                ),
+            ),
+            Some(p_value) => (
+                module::Result::Fail,
+                Some(format!("{:.2e}", p_value)),
+                format!(
+                    "The Kolmogorov–Smirnov p-value of {:.2e} was less than the threshold of \
+                     {:.2}. As such, the null hypothesis that the p-values are uniformly \
+                     distributed **is** rejected. See the histogram below to tell whether the \
+                     p-values were too small (a biased hash function) or too large (a hash \
+                     function that is \"too good\" to be random).",
+                    p_value, self.threshold
+                ),
+            ),
+            None => (
+                module::Result::Inconclusive,
+                None,
+                String::from(
+                    "No repetition produced a p-value (try increasing the number of iterations).",
+                ),
+            ),
+        };
+
+        let mut counts = [0usize; BINS];
+
+        for p_value in self.computed() {
+            counts[((p_value * BINS as f64) as usize).min(BINS - 1)] += 1;
+        }
+
+        let mut details = format!("{}\n\n{}\n", summary, "P-value Histogram".italic());
+
+        for (i, count) in counts.iter().enumerate() {
+            details.push_str(&format!(
+                "\n* [{:.1}, {:.1}{} => {}",
+                i as f64 / BINS as f64,
+                (i + 1) as f64 / BINS as f64,
+                if i == BINS - 1 { "]" } else { ")" },
+                count
+            ));
+        }
+
+        Module::new(result, "Second Level", value, Some(details))
+    }
+}
+
+impl bitbelay_tests::r#trait::Test for Test {
+    fn title(&self) -> &'static str {
+        "Second-Level Uniformity"
+    }
+
+    fn report_section(&self) -> section::Test {
+        // SAFETY: all of the pieces of this [`Builder`] are hand-crafted to not
+        // fail, so all of the below will unwrap.
+        get_report_base(&self.name, self.p_values.len())
+            .push_module(self.first_level_module())
+            .push_module(self.second_level_module())
+            .try_build()
+            .unwrap()
+    }
+}
+
+/// Populates the boilerplate report information within a
+/// [`Test`](section::Test).
+pub fn get_report_base(name: &str, repetitions: usize) -> Builder {
+    let overview = "The second-level uniformity test repeats another test many times with fresh \
+                    data and checks that the resulting p-values are uniformly distributed.";
+
+    let relation = "When a hash function behaves like a random function, the p-value of each \
+                    repetition is uniformly distributed on [0, 1]. A single run of a test only \
+                    catches p-values that are suspiciously small, whereas the distribution of \
+                    many p-values also catches hash functions that are \"too good\" to be random \
+                    (p-values clustered near 1) and flaws too subtle to reliably show up in a \
+                    single run.";
+
+    let algorithm = "(1) The underlying test is repeated for the specified number of repetitions, \
+                     each with fresh data from the data provider, and the p-value of each \
+                     repetition is collected.\n\n(2) First level: the number of repetitions that \
+                     rejected the null hypothesis at the threshold of statistical significance is \
+                     compared against the expected number using an exact, two-sided binomial \
+                     test.\n\n(3) Second level: the collected p-values are compared against a \
+                     continuous uniform distribution on [0, 1] using a Kolmogorov–Smirnov test.";
+
+    let interpretation = "* Both levels should pass for a hash function that behaves like a \
+                          random function.\n\n* A second-level failure with p-values piled up \
+                          near 0 indicates a biased hash function, even if few repetitions failed \
+                          individually.\n\n* A second-level failure with p-values piled up near 1 \
+                          indicates a hash function whose output is more regular than a random \
+                          function's.";
+
+    let sources = "* https://simul.iro.umontreal.ca/testu01/tu01.html\n* https://github.com/aappleby/smhasher\n* https://en.wikipedia.org/wiki/Kolmogorov%E2%80%93Smirnov_test";
+
+    Builder::default()
+        .title(format!(
+            "Second-Level Uniformity / {} / {} repetitions",
+            name, repetitions
+        ))
+        .unwrap()
+        .description(format!(
+            "{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}",
+            "Overview".italic(),
+            overview,
+            "Relation to Hashing".italic(),
+            relation,
+            "Algorithm".italic(),
+            algorithm,
+            "Interpretation".italic(),
+            interpretation,
+            "Sources".italic(),
+            sources
+        ))
+        .unwrap()
+}
@@ -0,0 +1,258 @@
+//! Probing test suite.
+
+use std::hash::BuildHasher;
+use std::num::NonZeroUsize;
+
+use bitbelay_providers::Provider;
+use bitbelay_report::Report;
+use bitbelay_tests::bucketing::Bucketing;
+use bitbelay_tests::probing::Test;
+use bitbelay_tests::probing::length;
+
+pub mod suite;
+
+/// An error related to a [`Suite`].
+#[derive(Debug)]
+pub enum Error {
+    /// An error with the probe sequence length test.
+    ProbeLength(length::Error),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::ProbeLength(err) => write!(f, "probe length error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A [`Result`](std::result::Result) with an [`Error`].
+type Result<T> = std::result::Result<T, Error>;
+
+/// A probing test suite.
+#[derive(Debug)]
+pub struct Suite<'a, H: BuildHasher> {
+    /// The hash function builder.
+    build_hasher: &'a H,
+
+    /// The tests that have been run within this suite.
+    tests: Vec<Test<'a, H>>,
+
+    /// The strategy used to map each hash to its home slot within each test.
+    bucketing: Bucketing,
+}
+
+impl<'a, H: BuildHasher> Suite<'a, H> {
+    /// Gets the [`BuildHasher`] for this [`Suite`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::BuildHasher as _;
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_suites::probing::suite::Builder;
+    ///
+    /// let hasher = RandomState::new();
+    /// let suite = Builder::default().build_hasher(&hasher)?.try_build()?;
+    ///
+    /// // Used as a surrogate to test that the [`BuildHasher`]s are the same.
+    /// assert_eq!(suite.build_hasher().hash_one("42"), hasher.hash_one("42"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn build_hasher(&self) -> &H {
+        self.build_hasher
+    }
+
+    /// Gets the bucketing strategy for the tests run within this [`Suite`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_suites::probing::suite::Builder;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    ///
+    /// let hasher = RandomState::new();
+    /// let suite = Builder::default()
+    ///     .bucketing(Bucketing::LowBitMask)?
+    ///     .build_hasher(&hasher)?
+    ///     .try_build()?;
+    ///
+    /// assert_eq!(suite.bucketing(), Bucketing::LowBitMask);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn bucketing(&self) -> Bucketing {
+        self.bucketing
+    }
+
+    /// Gets the [`Test`]s run within this [`Suite`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_suites::probing::suite::Builder;
+    /// use bitbelay_tests::probing::length::Scheme;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut suite = Builder::default().build_hasher(&hasher)?.try_build()?;
+    ///
+    /// suite.run_probe_length_test(
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Scheme::Linear,
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     0.75,
+    ///     NonZeroUsize::try_from(4).unwrap(),
+    ///     0.25,
+    ///     0.01,
+    /// )?;
+    ///
+    /// assert_eq!(suite.tests().len(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn tests(&self) -> &[Test<'a, H>] {
+        self.tests.as_ref()
+    }
+
+    /// Consumes `self` and returns the [`Test`]s run within this [`Suite`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_suites::probing::suite::Builder;
+    /// use bitbelay_tests::probing::length::Scheme;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut suite = Builder::default().build_hasher(&hasher)?.try_build()?;
+    ///
+    /// suite.run_probe_length_test(
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Scheme::Linear,
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     0.75,
+    ///     NonZeroUsize::try_from(4).unwrap(),
+    ///     0.25,
+    ///     0.01,
+    /// )?;
+    ///
+    /// assert!(matches!(
+    ///     suite
+    ///         .into_tests()
+    ///         .into_iter()
+    ///         .next()
+    ///         .unwrap()
+    ///         .into_probe_length_test(),
+    ///     Some(_)
+    /// ));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn into_tests(self) -> Vec<Test<'a, H>> {
+        self.tests
+    }
+
+    /// Runs a [probe sequence length test](length::Test) within the [`Suite`]
+    /// for a given [`Provider`], collision resolution
+    /// [`Scheme`](length::Scheme), number of buckets, load factor, number of
+    /// tables to fill, maximum deviance, and threshold of statistical
+    /// significance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_suites::probing::suite::Builder;
+    /// use bitbelay_tests::probing::length::Scheme;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut suite = Builder::default().build_hasher(&hasher)?.try_build()?;
+    ///
+    /// suite.run_probe_length_test(
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Scheme::Linear,
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     0.75,
+    ///     NonZeroUsize::try_from(4).unwrap(),
+    ///     0.25,
+    ///     0.01,
+    /// )?;
+    ///
+    /// assert_eq!(suite.tests().len(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn run_probe_length_test(
+        &mut self,
+        provider: Box<dyn Provider>,
+        scheme: length::Scheme,
+        buckets: NonZeroUsize,
+        load_factor: f64,
+        tables: NonZeroUsize,
+        max_deviance: f64,
+        significance: f64,
+    ) -> Result<()> {
+        let mut test = length::Test::try_new(
+            self.build_hasher,
+            provider,
+            scheme,
+            buckets,
+            self.bucketing,
+            load_factor,
+            max_deviance,
+            significance,
+        )
+        .map_err(Error::ProbeLength)?;
+
+        for i in 1..=tables.get() {
+            test.run_single_table();
+            tracing::info!("Filled {} of {} tables.", i, tables);
+        }
+
+        self.tests.push(Test::ProbeLength(test));
+
+        Ok(())
+    }
+}
+
+impl<'a, H: BuildHasher> crate::r#trait::Suite for Suite<'a, H> {
+    fn title(&self) -> &'static str {
+        "Probing"
+    }
+
+    fn report(&self) -> Report {
+        let tests = self
+            .tests
+            .iter()
+            .map(|t| t.report_section())
+            .collect::<Vec<_>>();
+
+        let mut builder = bitbelay_report::Builder::default()
+            .title(self.title())
+            .unwrap();
+
+        for test in tests {
+            builder = builder.push_test_result(test);
+        }
+
+        // SAFETY: this is manually crafted to always unwrap.
+        builder.try_build().unwrap()
+    }
+}
@@ -0,0 +1,230 @@
+//! Seed test suite.
+//!
+//! Unlike the other test suites, which evaluate a single [`BuildHasher`], this
+//! suite is constructed with a factory that creates a new (and, ideally,
+//! independently seeded) [`BuildHasher`] each time it is called.
+
+use std::hash::BuildHasher;
+use std::num::NonZeroUsize;
+
+use bitbelay_providers::Provider;
+use bitbelay_report::Report;
+use bitbelay_statistics::correction::Procedure;
+use bitbelay_tests::seed::Test;
+use bitbelay_tests::seed::independence;
+
+pub mod suite;
+
+/// An error related to a [`Suite`].
+#[derive(Debug)]
+pub enum Error {
+    /// An error with the seed independence test.
+    Independence(independence::Error),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Independence(err) => write!(f, "independence error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A [`Result`](std::result::Result) with an [`Error`].
+type Result<T> = std::result::Result<T, Error>;
+
+/// A seed test suite.
+pub struct Suite<'a, H: BuildHasher> {
+    /// The factory used to create hasher instances.
+    factory: &'a dyn Fn() -> H,
+
+    /// The tests that have been run within this suite.
+    tests: Vec<Test<H>>,
+}
+
+impl<'a, H: BuildHasher> std::fmt::Debug for Suite<'a, H> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Suite")
+            .field("factory", &"<factory>")
+            .field("tests", &self.tests.len())
+            .finish()
+    }
+}
+
+impl<'a, H: BuildHasher> Suite<'a, H> {
+    /// Gets the factory used to create hasher instances within this [`Suite`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::BuildHasher as _;
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_suites::seed::suite::Builder;
+    ///
+    /// let factory = RandomState::new;
+    /// let suite = Builder::default().factory(&factory)?.try_build()?;
+    ///
+    /// let hasher = (suite.factory())();
+    /// assert_eq!(hasher.hash_one("42"), hasher.hash_one("42"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn factory(&self) -> &dyn Fn() -> H {
+        self.factory
+    }
+
+    /// Gets the [`Test`]s run within this [`Suite`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_suites::seed::suite::Builder;
+    ///
+    /// let factory = RandomState::new;
+    /// let mut suite = Builder::default().factory(&factory)?.try_build()?;
+    ///
+    /// suite.run_independence_test(
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     NonZeroUsize::try_from(256).unwrap(),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// )?;
+    ///
+    /// assert_eq!(suite.tests().len(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn tests(&self) -> &[Test<H>] {
+        self.tests.as_ref()
+    }
+
+    /// Consumes `self` and returns the [`Test`]s run within this [`Suite`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_suites::seed::suite::Builder;
+    ///
+    /// let factory = RandomState::new;
+    /// let mut suite = Builder::default().factory(&factory)?.try_build()?;
+    ///
+    /// suite.run_independence_test(
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     NonZeroUsize::try_from(256).unwrap(),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// )?;
+    ///
+    /// assert!(matches!(
+    ///     suite
+    ///         .into_tests()
+    ///         .into_iter()
+    ///         .next()
+    ///         .unwrap()
+    ///         .into_independence_test(),
+    ///     Some(_)
+    /// ));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn into_tests(self) -> Vec<Test<H>> {
+        self.tests
+    }
+
+    /// Runs a [seed independence test](independence::Test) within the
+    /// [`Suite`] for a given [`Provider`], number of hasher instances, and
+    /// number of keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_suites::seed::suite::Builder;
+    ///
+    /// let factory = RandomState::new;
+    /// let mut suite = Builder::default().factory(&factory)?.try_build()?;
+    ///
+    /// suite.run_independence_test(
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     NonZeroUsize::try_from(256).unwrap(),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// )?;
+    ///
+    /// assert_eq!(suite.tests().len(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn run_independence_test(
+        &mut self,
+        provider: Box<dyn Provider>,
+        instances: NonZeroUsize,
+        keys: NonZeroUsize,
+        threshold: f64,
+        correction: Procedure,
+    ) -> Result<()> {
+        let instances = (0..instances.get())
+            .map(|_| (self.factory)())
+            .collect::<Vec<_>>();
+
+        let mut test = independence::Test::try_new(instances, provider, threshold, correction)
+            .map_err(Error::Independence)?;
+
+        for i in 0..keys.get() {
+            if i % 1_000 == 0 && i != 0 {
+                tracing::info!("Hashed {} keys.", i);
+            }
+
+            test.single_iteration();
+        }
+
+        self.tests.push(Test::Independence(test));
+
+        Ok(())
+    }
+}
+
+impl<'a, H: BuildHasher> crate::r#trait::Suite for Suite<'a, H> {
+    fn title(&self) -> &'static str {
+        "Seed"
+    }
+
+    fn report(&self) -> Report {
+        let tests = self
+            .tests
+            .iter()
+            .map(|t| t.report_section())
+            .collect::<Vec<_>>();
+
+        let mut builder = bitbelay_report::Builder::default()
+            .title(self.title())
+            .unwrap();
+
+        for test in tests {
+            builder = builder.push_test_result(test);
+        }
+
+        // SAFETY: this is manually crafted to always unwrap.
+        builder.try_build().unwrap()
+    }
+}
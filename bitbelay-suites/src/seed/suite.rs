@@ -0,0 +1,140 @@
+//! Builder for a [`Suite`].
+
+use std::hash::BuildHasher;
+
+use crate::seed::Suite;
+
+/// An error when a required field is missing.
+#[derive(Debug)]
+pub enum MissingError {
+    /// No hasher factory was provided to the [`Builder`].
+    Factory,
+}
+
+impl std::fmt::Display for MissingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MissingError::Factory => write!(f, "factory"),
+        }
+    }
+}
+
+impl std::error::Error for MissingError {}
+
+/// An error when multiple values are provided for a singular field.
+#[derive(Debug)]
+pub enum MultipleError {
+    /// Multiple hasher factories were provided to the [`Builder`].
+    Factory,
+}
+
+impl std::fmt::Display for MultipleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MultipleError::Factory => write!(f, "factory"),
+        }
+    }
+}
+
+impl std::error::Error for MultipleError {}
+
+/// An error related to a [`Builder`].
+#[derive(Debug)]
+pub enum Error {
+    /// A required field was missing from the [`Builder`].
+    Missing(MissingError),
+
+    /// Multiple values were provided for a singular field in the [`Builder`].
+    Multiple(MultipleError),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Missing(err) => write!(f, "missing error: {}", err),
+            Error::Multiple(err) => write!(f, "multiple error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A [`Result`](std::result::Result) with an [`Error`].
+type Result<T> = std::result::Result<T, Error>;
+
+/// A builder for a [`Suite`].
+pub struct Builder<'a, H: BuildHasher> {
+    /// The factory used to create hasher instances.
+    factory: Option<&'a dyn Fn() -> H>,
+}
+
+impl<'a, H: BuildHasher> std::fmt::Debug for Builder<'a, H> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Builder")
+            .field("factory", &self.factory.map(|_| "<factory>"))
+            .finish()
+    }
+}
+
+impl<'a, H: BuildHasher> Default for Builder<'a, H> {
+    fn default() -> Self {
+        Self {
+            factory: Default::default(),
+        }
+    }
+}
+
+impl<'a, H: BuildHasher> Builder<'a, H> {
+    /// Sets the factory used to create hasher instances for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::BuildHasher as _;
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_suites::seed::suite::Builder;
+    ///
+    /// let factory = RandomState::new;
+    /// let suite = Builder::default().factory(&factory)?.try_build()?;
+    ///
+    /// // Every call to the factory creates a new instance.
+    /// let hasher = (suite.factory())();
+    /// assert_eq!(hasher.hash_one("42"), hasher.hash_one("42"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn factory(mut self, factory: &'a dyn Fn() -> H) -> Result<Self> {
+        if self.factory.is_some() {
+            return Err(Error::Multiple(MultipleError::Factory));
+        }
+
+        self.factory = Some(factory);
+        Ok(self)
+    }
+
+    /// Consumes `self` to attempt to build a [`Suite`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_suites::seed::suite::Builder;
+    ///
+    /// let factory = RandomState::new;
+    /// let suite = Builder::default().factory(&factory)?.try_build()?;
+    ///
+    /// assert!(suite.tests().is_empty());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_build(self) -> Result<Suite<'a, H>> {
+        let factory = self.factory.ok_or(Error::Missing(MissingError::Factory))?;
+
+        Ok(Suite {
+            factory,
+            tests: Vec::new(),
+        })
+    }
+}
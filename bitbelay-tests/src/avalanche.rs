@@ -7,12 +7,16 @@ use bitbelay_report::section;
 use crate::r#trait::Test as _;
 
 pub mod sac;
+pub mod seed;
 
 /// A type of avalanche test.
 #[derive(Debug)]
 pub enum Test<'a, H: BuildHasher, const N: usize> {
     /// Strict Avalanche Criterion test.
     StrictAvalancheCriterion(sac::Test<'a, H, N>),
+
+    /// Seed avalanche test.
+    Seed(seed::Test<H, N>),
 }
 
 impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
@@ -27,8 +31,10 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
     /// use std::num::NonZeroUsize;
     ///
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
     /// use bitbelay_tests::avalanche::Test;
     /// use bitbelay_tests::avalanche::sac;
+    /// use bitbelay_tests::tolerance::Tolerance;
     ///
     /// let hasher = RandomState::new();
     /// let test = Test::StrictAvalancheCriterion(
@@ -36,7 +42,9 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
     ///         &hasher,
     ///         Box::new(AlphanumericProvider::new(10)),
     ///         NonZeroUsize::try_from(1000).unwrap(),
-    ///         0.01,
+    ///         Tolerance::Fixed(0.01),
+    ///         0.05,
+    ///         Procedure::Holm,
     ///     )
     ///     .unwrap(),
     /// );
@@ -48,6 +56,7 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
     pub fn as_strict_avalanche_criterion_test(&self) -> Option<&sac::Test<'a, H, N>> {
         match self {
             Test::StrictAvalancheCriterion(test) => Some(test),
+            _ => None,
         }
     }
 
@@ -62,8 +71,10 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
     /// use std::num::NonZeroUsize;
     ///
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
     /// use bitbelay_tests::avalanche::Test;
     /// use bitbelay_tests::avalanche::sac;
+    /// use bitbelay_tests::tolerance::Tolerance;
     ///
     /// let hasher = RandomState::new();
     /// let test = Test::StrictAvalancheCriterion(
@@ -71,7 +82,9 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
     ///         &hasher,
     ///         Box::new(AlphanumericProvider::new(10)),
     ///         NonZeroUsize::try_from(1000).unwrap(),
-    ///         0.01,
+    ///         Tolerance::Fixed(0.01),
+    ///         0.05,
+    ///         Procedure::Holm,
     ///     )
     ///     .unwrap(),
     /// );
@@ -86,6 +99,78 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
     pub fn into_strict_avalanche_criterion_test(self) -> Option<sac::Test<'a, H, N>> {
         match self {
             Test::StrictAvalancheCriterion(test) => Some(test),
+            _ => None,
+        }
+    }
+
+    /// Gets a reference to a [`seed::Test`] wrapped in [`Some`] if the
+    /// [`Test`] is a [`Test::Seed`]. Else, returns [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::avalanche::Test;
+    /// use bitbelay_tests::avalanche::seed;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    /// use bitbelay_tests::tolerance::Tolerance;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let test = Test::Seed(seed::Test::<Hasher, 64>::try_new(
+    ///     Seeder::new(),
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Tolerance::Fixed(0.01),
+    /// )?);
+    ///
+    /// assert!(matches!(test.as_seed_test(), Some(_)));
+    /// assert!(matches!(test.as_strict_avalanche_criterion_test(), None));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn as_seed_test(&self) -> Option<&seed::Test<H, N>> {
+        match self {
+            Test::Seed(test) => Some(test),
+            _ => None,
+        }
+    }
+
+    /// Consumes the [`Test`] and returns a [`seed::Test`] wrapped in [`Some`]
+    /// if the [`Test`] is a [`Test::Seed`]. Else, returns [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::avalanche::Test;
+    /// use bitbelay_tests::avalanche::seed;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    /// use bitbelay_tests::tolerance::Tolerance;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let test = Test::Seed(seed::Test::<Hasher, 64>::try_new(
+    ///     Seeder::new(),
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Tolerance::Fixed(0.01),
+    /// )?);
+    ///
+    /// assert!(matches!(test.into_seed_test(), Some(_)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn into_seed_test(self) -> Option<seed::Test<H, N>> {
+        match self {
+            Test::Seed(test) => Some(test),
+            _ => None,
         }
     }
 
@@ -98,8 +183,10 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
     /// use std::num::NonZeroUsize;
     ///
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
     /// use bitbelay_tests::avalanche::Test;
     /// use bitbelay_tests::avalanche::sac;
+    /// use bitbelay_tests::tolerance::Tolerance;
     ///
     /// let hasher = RandomState::new();
     /// let test = Test::StrictAvalancheCriterion(
@@ -107,7 +194,9 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
     ///         &hasher,
     ///         Box::new(AlphanumericProvider::new(10)),
     ///         NonZeroUsize::try_from(1000).unwrap(),
-    ///         0.01,
+    ///         Tolerance::Fixed(0.01),
+    ///         0.05,
+    ///         Procedure::Holm,
     ///     )
     ///     .unwrap(),
     /// );
@@ -118,6 +207,7 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
     pub fn report_section(&self) -> section::Test {
         match self {
             Test::StrictAvalancheCriterion(test) => test.report_section(),
+            Test::Seed(test) => test.report_section(),
         }
     }
 }
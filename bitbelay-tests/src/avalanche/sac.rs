@@ -16,11 +16,16 @@ use bitbelay_report::section;
 use bitbelay_report::section::test::Builder;
 use bitbelay_report::section::test::Module;
 use bitbelay_report::section::test::module;
+use bitbelay_statistics::binomial;
+use bitbelay_statistics::correction::Procedure;
+use bitbelay_statistics::power;
 use colored::Colorize;
 pub use experiment::Experiment;
 use lazy_static::lazy_static;
 use ordered_float::OrderedFloat;
 
+use crate::tolerance::Tolerance;
+
 lazy_static! {
     static ref ONE_PCT_CHAR: String = ".".green().to_string();
     static ref FIVE_PCT_CHAR: String = "?".yellow().to_string();
@@ -35,6 +40,10 @@ pub enum Error {
 
     /// An invalid value was passed for max deviance.
     InvalidMaxDeviance(f64),
+
+    /// An invalid value was passed for the threshold of statistical
+    /// significance.
+    InvalidSignificance(f64),
 }
 
 impl std::fmt::Display for Error {
@@ -47,6 +56,12 @@ impl std::fmt::Display for Error {
                     "max deviance must be between 0.0 and 1.0, received {value}"
                 )
             }
+            Error::InvalidSignificance(value) => {
+                write!(
+                    f,
+                    "significance must be between 0.0 and 1.0, received {value}"
+                )
+            }
         }
     }
 }
@@ -56,12 +71,19 @@ impl std::error::Error for Error {}
 /// A [`Result`](std::result::Result) with an [`Error`].
 type Result<T> = std::result::Result<T, Error>;
 
+/// The number of most significant bits listed within the report.
+const WORST_BITS: usize = 10;
+
 /// The results of a [`Test`](section::Test).
 #[derive(Debug)]
 pub struct Results {
     /// Whether the test succeeded or not.
     pub succeeded: bool,
 
+    /// The maximum deviance from `0.5` that any single bit was allowed to
+    /// have (if it could be computed).
+    pub max_deviance: Option<f64>,
+
     /// The maximum bias we encountered.
     ///
     /// * The first item in the tuple is the index where the max bias occurred.
@@ -91,11 +113,19 @@ pub struct Test<'a, H: BuildHasher, const N: usize> {
     /// The total number of experiments that have been carried out.
     total_experiments: usize,
 
-    /// The maximum deviance that any single bit can have from `0.5` for the
-    /// test to be considered successful.
+    /// The tolerance that determines the maximum deviance that any single bit
+    /// can have from `0.5` for the test to be considered successful.
     ///
-    /// Note that this is a fraction (`0.01`), not a percentage (`1`).
-    max_deviance: f64,
+    /// Note that a fixed tolerance is a fraction (`0.01`), not a percentage
+    /// (`1`).
+    tolerance: Tolerance,
+
+    /// The threshold of statistical significance for the family of output
+    /// bits.
+    significance: f64,
+
+    /// The procedure used to correct for the number of output bits tested.
+    correction: Procedure,
 }
 
 impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
@@ -108,28 +138,57 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
     /// use std::num::NonZeroUsize;
     ///
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
     /// use bitbelay_tests::avalanche::sac::Test;
+    /// use bitbelay_tests::tolerance::Tolerance;
     ///
     /// let hasher = RandomState::new();
     /// let test = Test::<RandomState, 64>::try_new(
     ///     &hasher,
     ///     Box::new(AlphanumericProvider::new(10)),
     ///     NonZeroUsize::try_from(1000).unwrap(),
-    ///     0.01,
+    ///     Tolerance::Fixed(0.01),
+    ///     0.05,
+    ///     Procedure::Holm,
     /// )
     /// .unwrap();
     ///
     /// assert_eq!(test.bit_flips().iter().sum::<usize>(), 0);
     /// assert_eq!(test.total_experiments(), 0);
+    ///
+    /// // A significance of exactly `0.0` or `1.0` is rejected.
+    /// assert!(
+    ///     Test::<RandomState, 64>::try_new(
+    ///         &hasher,
+    ///         Box::new(AlphanumericProvider::new(10)),
+    ///         NonZeroUsize::try_from(1000).unwrap(),
+    ///         Tolerance::Fixed(0.01),
+    ///         1.0,
+    ///         Procedure::Holm,
+    ///     )
+    ///     .is_err()
+    /// );
     /// ```
     pub fn try_new(
         build_hasher: &'a H,
         provider: Box<dyn Provider>,
         iterations_per_experiment: NonZeroUsize,
-        max_deviance: f64,
+        tolerance: Tolerance,
+        significance: f64,
+        correction: Procedure,
     ) -> Result<Self> {
-        if !(0.0..=1.0).contains(&max_deviance) {
-            return Err(Error::InvalidMaxDeviance(max_deviance));
+        match tolerance {
+            Tolerance::Fixed(max_deviance) if !(0.0..=1.0).contains(&max_deviance) => {
+                return Err(Error::InvalidMaxDeviance(max_deviance));
+            }
+            Tolerance::SampleSize(significance) if significance <= 0.0 || significance >= 1.0 => {
+                return Err(Error::InvalidSignificance(significance));
+            }
+            _ => {}
+        }
+
+        if significance <= 0.0 || significance >= 1.0 {
+            return Err(Error::InvalidSignificance(significance));
         }
 
         Ok(Self {
@@ -138,7 +197,9 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
             bit_flips: [0usize; N],
             iterations_per_experiment,
             total_experiments: 0,
-            max_deviance,
+            tolerance,
+            significance,
+            correction,
         })
     }
 
@@ -152,14 +213,18 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
     /// use std::num::NonZeroUsize;
     ///
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
     /// use bitbelay_tests::avalanche::sac::Test;
+    /// use bitbelay_tests::tolerance::Tolerance;
     ///
     /// let hasher = RandomState::new();
     /// let test = Test::<RandomState, 64>::try_new(
     ///     &hasher,
     ///     Box::new(AlphanumericProvider::new(10)),
     ///     NonZeroUsize::try_from(1000).unwrap(),
-    ///     0.01,
+    ///     Tolerance::Fixed(0.01),
+    ///     0.05,
+    ///     Procedure::Holm,
     /// )
     /// .unwrap();
     ///
@@ -180,7 +245,9 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
     ///
     /// use bitbelay_providers::Provider as _;
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
     /// use bitbelay_tests::avalanche::sac::Test;
+    /// use bitbelay_tests::tolerance::Tolerance;
     ///
     /// let provider = Box::new(AlphanumericProvider::new(10));
     /// let hasher = RandomState::new();
@@ -188,7 +255,9 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
     ///     &hasher,
     ///     provider.clone(),
     ///     NonZeroUsize::try_from(1000).unwrap(),
-    ///     0.01,
+    ///     Tolerance::Fixed(0.01),
+    ///     0.05,
+    ///     Procedure::Holm,
     /// )
     /// .unwrap();
     ///
@@ -207,14 +276,18 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
     /// use std::num::NonZeroUsize;
     ///
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
     /// use bitbelay_tests::avalanche::sac::Test;
+    /// use bitbelay_tests::tolerance::Tolerance;
     ///
     /// let hasher = RandomState::new();
     /// let test = Test::<RandomState, 64>::try_new(
     ///     &hasher,
     ///     Box::new(AlphanumericProvider::new(10)),
     ///     NonZeroUsize::try_from(1000).unwrap(),
-    ///     0.01,
+    ///     Tolerance::Fixed(0.01),
+    ///     0.05,
+    ///     Procedure::Holm,
     /// )
     /// .unwrap();
     ///
@@ -233,14 +306,18 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
     /// use std::num::NonZeroUsize;
     ///
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
     /// use bitbelay_tests::avalanche::sac::Test;
+    /// use bitbelay_tests::tolerance::Tolerance;
     ///
     /// let hasher = RandomState::new();
     /// let test = Test::<RandomState, 64>::try_new(
     ///     &hasher,
     ///     Box::new(AlphanumericProvider::new(10)),
     ///     NonZeroUsize::try_from(1000).unwrap(),
-    ///     0.01,
+    ///     Tolerance::Fixed(0.01),
+    ///     0.05,
+    ///     Procedure::Holm,
     /// )
     /// .unwrap();
     ///
@@ -259,14 +336,18 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
     /// use std::num::NonZeroUsize;
     ///
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
     /// use bitbelay_tests::avalanche::sac::Test;
+    /// use bitbelay_tests::tolerance::Tolerance;
     ///
     /// let hasher = RandomState::new();
     /// let test = Test::<RandomState, 64>::try_new(
     ///     &hasher,
     ///     Box::new(AlphanumericProvider::new(10)),
     ///     NonZeroUsize::try_from(1000).unwrap(),
-    ///     0.01,
+    ///     Tolerance::Fixed(0.01),
+    ///     0.05,
+    ///     Procedure::Holm,
     /// )
     /// .unwrap();
     ///
@@ -276,9 +357,108 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
         self.total_experiments
     }
 
+    /// Gets the tolerance for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::avalanche::sac::Test;
+    /// use bitbelay_tests::tolerance::Tolerance;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::<RandomState, 64>::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(1000).unwrap(),
+    ///     Tolerance::SampleSize(0.05),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(test.tolerance(), Tolerance::SampleSize(0.05));
+    /// ```
+    pub fn tolerance(&self) -> Tolerance {
+        self.tolerance
+    }
+
     /// Gets the max deviance allowed for any bit within the [`Test`] for the
     /// [`Test`] to be considered passing.
     ///
+    /// For a [`Tolerance::SampleSize`], the max deviance is derived from the
+    /// total number of iterations run so far such that a perfect hash
+    /// function exceeds it for _any_ output bit with a probability of at most
+    /// the tolerance's threshold of statistical significance. If no
+    /// experiments have been run, it cannot be derived and [`None`] is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::avalanche::sac::Test;
+    /// use bitbelay_tests::tolerance::Tolerance;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::<RandomState, 64>::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(1000).unwrap(),
+    ///     Tolerance::Fixed(0.01),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(test.max_deviance(), Some(0.01));
+    /// ```
+    pub fn max_deviance(&self) -> Option<f64> {
+        let iterations = (self.total_experiments * self.iterations_per_experiment.get()) as u64;
+        self.tolerance.proportion(iterations, 0.5, N)
+    }
+
+    /// Gets the threshold of statistical significance for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::avalanche::sac::Test;
+    /// use bitbelay_tests::tolerance::Tolerance;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::<RandomState, 64>::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(1000).unwrap(),
+    ///     Tolerance::Fixed(0.01),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(test.significance(), 0.05);
+    /// ```
+    pub fn significance(&self) -> f64 {
+        self.significance
+    }
+
+    /// Gets the procedure used to correct for the number of output bits
+    /// tested.
+    ///
     /// # Examples
     ///
     /// ```
@@ -286,21 +466,25 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
     /// use std::num::NonZeroUsize;
     ///
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
     /// use bitbelay_tests::avalanche::sac::Test;
+    /// use bitbelay_tests::tolerance::Tolerance;
     ///
     /// let hasher = RandomState::new();
     /// let test = Test::<RandomState, 64>::try_new(
     ///     &hasher,
     ///     Box::new(AlphanumericProvider::new(10)),
     ///     NonZeroUsize::try_from(1000).unwrap(),
-    ///     0.01,
+    ///     Tolerance::Fixed(0.01),
+    ///     0.05,
+    ///     Procedure::Bonferroni,
     /// )
     /// .unwrap();
     ///
-    /// assert_eq!(test.max_deviance(), 0.01);
+    /// assert_eq!(test.correction(), Procedure::Bonferroni);
     /// ```
-    pub fn max_deviance(&self) -> f64 {
-        self.max_deviance
+    pub fn correction(&self) -> Procedure {
+        self.correction
     }
 
     /// Runs a single experiment.
@@ -312,14 +496,18 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
     /// use std::num::NonZeroUsize;
     ///
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
     /// use bitbelay_tests::avalanche::sac::Test;
+    /// use bitbelay_tests::tolerance::Tolerance;
     ///
     /// let hasher = RandomState::new();
     /// let mut test = Test::<RandomState, 64>::try_new(
     ///     &hasher,
     ///     Box::new(AlphanumericProvider::new(10)),
     ///     NonZeroUsize::try_from(1000).unwrap(),
-    ///     0.01,
+    ///     Tolerance::Fixed(0.01),
+    ///     0.05,
+    ///     Procedure::Holm,
     /// )
     /// .unwrap();
     ///
@@ -354,14 +542,18 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
     /// use std::num::NonZeroUsize;
     ///
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
     /// use bitbelay_tests::avalanche::sac::Test;
+    /// use bitbelay_tests::tolerance::Tolerance;
     ///
     /// let hasher = RandomState::new();
     /// let mut test = Test::<RandomState, 64>::try_new(
     ///     &hasher,
     ///     Box::new(AlphanumericProvider::new(10)),
     ///     NonZeroUsize::try_from(100000).unwrap(),
-    ///     0.01,
+    ///     Tolerance::Fixed(0.01),
+    ///     0.05,
+    ///     Procedure::Holm,
     /// )
     /// .unwrap();
     ///
@@ -391,12 +583,172 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
 
         tracing::info!("Max bias is bit {} with {:.2}%", index, max_bias * 100.0);
 
+        let max_deviance = self.max_deviance();
+
         Results {
-            succeeded: *max_bias <= OrderedFloat(self.max_deviance),
+            succeeded: max_deviance.is_some_and(|max| *max_bias <= OrderedFloat(max)),
+            max_deviance,
             max_bias: (*index, *max_bias),
             bit_bias_offsets: bits,
         }
     }
+
+    /// Computes the exact, two-sided binomial p-value for the flip
+    /// probability of each output bit under the null hypothesis that each bit
+    /// flips with a probability of 50%.
+    ///
+    /// If no experiments have been run, [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::avalanche::sac::Test;
+    /// use bitbelay_tests::tolerance::Tolerance;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::<RandomState, 64>::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(1000).unwrap(),
+    ///     Tolerance::Fixed(0.01),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert!(test.p_values().is_none());
+    ///
+    /// test.run_single_experiment();
+    /// assert!(test.p_values().unwrap().iter().all(|p| *p <= 1.0));
+    /// ```
+    pub fn p_values(&self) -> Option<[f64; N]> {
+        let iterations = (self.total_experiments * self.iterations_per_experiment.get()) as u64;
+        let mut p_values = [0.0; N];
+
+        for (p_value, flips) in p_values.iter_mut().zip(self.bit_flips.iter()) {
+            *p_value = binomial::two_sided_p_value(*flips as u64, iterations, 0.5)?;
+        }
+
+        Some(p_values)
+    }
+
+    /// Computes the p-value for the flip probability of each output bit after
+    /// adjusting for the number of bits tested using the configured
+    /// [`Procedure`].
+    ///
+    /// If no experiments have been run, [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::avalanche::sac::Test;
+    /// use bitbelay_tests::tolerance::Tolerance;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::<RandomState, 64>::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(1000).unwrap(),
+    ///     Tolerance::Fixed(0.01),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// )
+    /// .unwrap();
+    ///
+    /// test.run_single_experiment();
+    ///
+    /// let raw = test.p_values().unwrap();
+    /// let adjusted = test.adjusted_p_values().unwrap();
+    /// assert!(raw.iter().zip(adjusted.iter()).all(|(r, a)| r <= a));
+    /// ```
+    pub fn adjusted_p_values(&self) -> Option<[f64; N]> {
+        let p_values = self.p_values()?;
+        let mut adjusted = [0.0; N];
+        adjusted.copy_from_slice(&self.correction.adjust(&p_values));
+        Some(adjusted)
+    }
+
+    /// Generates the [`Module`] for the family-wise significance of the bit
+    /// flip probabilities.
+    fn significance_module(&self) -> Module {
+        let name = "Family-wise Significance";
+
+        let (p_values, adjusted) = match (self.p_values(), self.adjusted_p_values()) {
+            (Some(p_values), Some(adjusted)) => (p_values, adjusted),
+            _ => {
+                return Module::new(
+                    module::Result::Inconclusive,
+                    name,
+                    None,
+                    Some(String::from(
+                        "The p-values were not able to be computed (try running more experiments).",
+                    )),
+                );
+            }
+        };
+
+        let minimum = adjusted.iter().copied().fold(f64::INFINITY, f64::min);
+        let failures = adjusted.iter().filter(|p| **p <= self.significance).count();
+
+        let (result, summary) = if failures == 0 {
+            (
+                module::Result::Pass,
+                format!(
+                    "The smallest p-value after applying the {} across {} bits was {:.2e}, which \
+                     is greater than (and, thus, failed to reach) the threshold of statistical \
+                     significance set at {:.2}. As such, the null hypothesis that every output \
+                     bit flips with a probability of 50% **cannot** be rejected.",
+                    self.correction.name(),
+                    N,
+                    minimum,
+                    self.significance
+                ),
+            )
+        } else {
+            (
+                module::Result::Fail,
+                format!(
+                    "{} output bit(s) had a p-value after applying the {} across {} bits that was \
+                     less than (and, thus, reached) the threshold of statistical significance set \
+                     at {:.2}. As such, the null hypothesis that every output bit flips with a \
+                     probability of 50% **is** rejected.",
+                    failures,
+                    self.correction.name(),
+                    N,
+                    self.significance
+                ),
+            )
+        };
+
+        let mut worst = p_values.iter().copied().enumerate().collect::<Vec<_>>();
+        worst.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+
+        let mut details = format!("{}\n\n{}\n", summary, "Most Significant Bits".italic());
+
+        for (index, p_value) in worst.into_iter().take(WORST_BITS) {
+            details.push_str(&format!(
+                "\n* Index {:>2} had a p-value of {:.2e} (adjusted p-value of {:.2e}).",
+                index, p_value, adjusted[index]
+            ));
+        }
+
+        Module::new(
+            result,
+            name,
+            Some(format!("{:.2e}", minimum)),
+            Some(details),
+        )
+    }
 }
 
 impl<'a, H: BuildHasher, const N: usize> crate::r#trait::Test for Test<'a, H, N> {
@@ -408,24 +760,38 @@ impl<'a, H: BuildHasher, const N: usize> crate::r#trait::Test for Test<'a, H, N>
         let mut results = self.results();
         let visual = generate_visual_from_bits(&results.bit_bias_offsets);
 
-        let (result, summary) = if results.succeeded {
-            (
+        let origin = match self.tolerance {
+            Tolerance::Fixed(_) => String::from("a fixed bias tolerance"),
+            Tolerance::SampleSize(significance) => format!(
+                "a bias tolerance derived from {} iterations at a significance of {}",
+                self.total_experiments * self.iterations_per_experiment.get(),
+                significance
+            ),
+        };
+
+        let (result, summary) = match results.max_deviance {
+            None => (
+                module::Result::Inconclusive,
+                String::from(
+                    "The bias tolerance was not able to be derived (try running more experiments).",
+                ),
+            ),
+            Some(max_deviance) if results.succeeded => (
                 module::Result::Pass,
                 format!(
-                    "The bias for every bit fell within a range of 0.5 ± {}.",
-                    self.max_deviance
+                    "The bias for every bit fell within a range of 0.5 ± {:.4} ({}).",
+                    max_deviance, origin
                 ),
-            )
-        } else {
-            (
+            ),
+            Some(max_deviance) => (
                 module::Result::Fail,
                 format!(
-                    "At least one bit had a bias that fell outside the range of 0.5 ± {}. See the \
-                     bit bias profile and the most biased bits below for more information on \
-                     which bits failed.",
-                    self.max_deviance
+                    "At least one bit had a bias that fell outside the range of 0.5 ± {:.4} ({}). \
+                     See the bit bias profile and the most biased bits below for more information \
+                     on which bits failed.",
+                    max_deviance, origin
                 ),
-            )
+            ),
         };
 
         let mut details = format!(
@@ -450,13 +816,16 @@ impl<'a, H: BuildHasher, const N: usize> crate::r#trait::Test for Test<'a, H, N>
             ));
         }
 
-        get_report_base()
+        get_report_base(self.correction)
             .push_module(Module::new(
                 result,
                 "Strict Avalanche Criterion",
-                None,
+                results
+                    .max_deviance
+                    .map(|max_deviance| format!("0.5 ± {:.4}", max_deviance)),
                 Some(details),
             ))
+            .push_module(self.significance_module())
             .try_build()
             .unwrap()
     }
@@ -464,7 +833,7 @@ impl<'a, H: BuildHasher, const N: usize> crate::r#trait::Test for Test<'a, H, N>
 
 /// Generates a visualization of which bits are biased (if any) from the bit
 /// bias offset contained within a [`Results`].
-fn generate_visual_from_bits(bit_bias_offsets: &[(usize, OrderedFloat<f64>)]) -> String {
+pub(crate) fn generate_visual_from_bits(bit_bias_offsets: &[(usize, OrderedFloat<f64>)]) -> String {
     let mut visual = String::from("[");
 
     for (_, probability) in bit_bias_offsets.iter() {
@@ -481,9 +850,40 @@ fn generate_visual_from_bits(bit_bias_offsets: &[(usize, OrderedFloat<f64>)]) ->
     visual
 }
 
+/// Computes the total number of iterations (across all experiments) needed
+/// for a [`Test`] with `bits` output bits to detect an output bit that flips
+/// with a probability of `0.5 + bias` with probability `power` at the given
+/// threshold of statistical `significance`.
+///
+/// Because every output bit is tested, the threshold of statistical
+/// significance is split evenly among them (a Bonferroni correction, which is
+/// conservative for the other correction procedures). If the number of
+/// iterations cannot be computed (see [`power::proportion()`]), [`None`] is
+/// returned.
+///
+/// # Examples
+///
+/// ```
+/// use bitbelay_tests::avalanche::sac;
+///
+/// let iterations = sac::required_iterations(64, 0.001, 0.05, 0.8).unwrap();
+/// assert!(iterations.get() > 1_000_000);
+///
+/// assert!(sac::required_iterations(64, 0.0, 0.05, 0.8).is_none());
+/// ```
+pub fn required_iterations(
+    bits: usize,
+    bias: f64,
+    significance: f64,
+    power: f64,
+) -> Option<NonZeroUsize> {
+    let iterations = power::proportion(0.5, bias, significance / bits as f64, power)?;
+    NonZeroUsize::try_from(usize::try_from(iterations).ok()?).ok()
+}
+
 /// Populates the boilerplate report information within a
 /// [`Test`](section::Test).
-pub fn get_report_base() -> section::test::Builder {
+pub fn get_report_base(correction: Procedure) -> section::test::Builder {
     let overview = "The Strict Avalanche Criterion (SAC) is a test to determine whether a hash \
                     function exhibits strong avalanching effects.\n\nBriefly, the avalanche \
                     effect is a desirable trait for a hash function whereby small changes in the \
@@ -496,7 +896,7 @@ pub fn get_report_base() -> section::test::Builder {
                     the hash's output bits for that input data should also change. Ideally, there \
                     won't be any bias as to which bits flip.";
 
-    let algorithm =
+    let algorithm = format!(
         "For the hash function and data provider chosen, the algorithm runs multiple experiments. \
          For each experiment,\n\n(1) An array with a length matching the number of bits in the \
          output hash is initialized, and every element is set to 0. Each index in the array \
@@ -509,15 +909,27 @@ pub fn get_report_base() -> section::test::Builder {
          incremented by 1 in the tally array.\n\nThis process continues for a specified number of \
          experiment iterations.\n\nAfter all iterations have completed, the fraction of \
          iterations where each output bit flipped is calculated. In hash functions with strong \
-         avlanching effects, each bit in the output should change roughly 50% of the time.";
-
-    let interpretation = "* Each test has a set bias tolerance. For the test to pass, the bias \
-                          for every output bit must fall within the range of the expected value \
-                          (50%) ± the bias tolerance provided.\n\n* A bit bias profile is graphed \
-                          below. This should give you a sense of which bits were biased and by \
-                          what magnitude.\n\n* The most biased bits are also sorted in the \
-                          respective section below. Use this list to determine the exact bias of \
-                          the most biased bits.";
+         avlanching effects, each bit in the output should change roughly 50% of the \
+         time.\n\nFinally, an exact, two-sided binomial test is performed for each output bit \
+         against the null hypothesis that the bit flips with a probability of 50%. Because every \
+         output bit is tested at once, the p-values are adjusted using the {} (which controls the \
+         {}) before being compared against the chosen threshold of statistical significance.",
+        correction.name(),
+        correction.controls()
+    );
+
+    let interpretation =
+        "* Each test has a bias tolerance. For the test to pass, the bias for every output bit \
+         must fall within the range of the expected value (50%) ± the bias tolerance. The bias \
+         tolerance is either fixed or derived from the number of iterations run, in which case a \
+         perfect hash function exceeds it for any output bit with a probability of at most the \
+         chosen threshold of statistical significance.\n\n* A bit bias profile is graphed below. \
+         This should give you a sense of which bits were biased and by what magnitude.\n\n* The \
+         most biased bits are also sorted in the respective section below. Use this list to \
+         determine the exact bias of the most biased bits.\n\n* A fixed bias tolerance does not \
+         account for the number of iterations run, so the family-wise significance of the bit \
+         flip probabilities is also reported: if any adjusted p-value is below the threshold of \
+         statistical significance, that bit is very likely biased.";
 
     Builder::default()
         .title("Strict Avalanche Criterion")
@@ -0,0 +1,815 @@
+//! Seed avalanche test.
+//!
+//! # Sources
+//!
+//! * [Aumasson and Bernstein] demonstrate key-recovery and seed-independent
+//!   collision attacks against hashers whose seed is poorly mixed into the
+//!   output.
+//!
+//! [Aumasson and Bernstein]: https://131002.net/siphash/siphash.pdf
+
+use std::hash::BuildHasher;
+
+use bitbelay_providers::Provider;
+use bitbelay_report::section;
+use bitbelay_report::section::test::Builder;
+use bitbelay_report::section::test::Module;
+use bitbelay_report::section::test::module;
+use colored::Colorize;
+use ordered_float::OrderedFloat;
+
+use crate::avalanche::sac::generate_visual_from_bits;
+use crate::seeded::Seeder;
+use crate::tolerance::Tolerance;
+
+/// The number of seed bits to report in the seed bit influence module.
+const REPORTED_SEED_BITS: usize = 10;
+
+/// An error related to a [`Test`].
+#[derive(Debug)]
+pub enum Error {
+    /// The hasher has an empty seed.
+    EmptySeed,
+
+    /// An invalid value was passed for max deviance.
+    InvalidMaxDeviance(f64),
+
+    /// An invalid value was passed for the threshold of statistical
+    /// significance.
+    InvalidSignificance(f64),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::EmptySeed => write!(f, "the hasher has an empty seed"),
+            Error::InvalidMaxDeviance(value) => {
+                write!(
+                    f,
+                    "max deviance must be between 0.0 and 1.0, received {value}"
+                )
+            }
+            Error::InvalidSignificance(value) => {
+                write!(
+                    f,
+                    "significance must be between 0.0 and 1.0, received {value}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A [`Result`](std::result::Result) with an [`Error`].
+type Result<T> = std::result::Result<T, Error>;
+
+/// The results of a [`Test`](section::Test).
+#[derive(Debug)]
+pub struct Results {
+    /// Whether the test succeeded or not.
+    pub succeeded: bool,
+
+    /// The maximum deviance from `0.5` that any single output bit was allowed
+    /// to have (if it could be computed).
+    pub max_deviance: Option<f64>,
+
+    /// The maximum deviance from `0.5` that any single seed bit was allowed to
+    /// have (if it could be computed).
+    pub max_seed_deviance: Option<f64>,
+
+    /// The maximum bias we encountered across output bits.
+    ///
+    /// * The first item in the tuple is the index of the output bit.
+    /// * The second item in the tuple is the bias itself.
+    pub max_bias: (usize, OrderedFloat<f64>),
+
+    /// The offset of each bit in the output from the expected bit flip
+    /// probability.
+    pub bit_bias_offsets: Vec<(usize, OrderedFloat<f64>)>,
+
+    /// The maximum bias we encountered across seed bits.
+    ///
+    /// * The first item in the tuple is the index of the seed bit.
+    /// * The second item in the tuple is the bias itself.
+    pub max_seed_bias: (usize, OrderedFloat<f64>),
+
+    /// The offset of the fraction of output bits flipped by each seed bit
+    /// from the expected fraction.
+    pub seed_bias_offsets: Vec<(usize, OrderedFloat<f64>)>,
+}
+
+/// A seed avalanche test.
+///
+/// For a fixed key, flipping any single bit of the seed should flip each output
+/// bit with a probability of one half.
+#[derive(Debug)]
+pub struct Test<H: BuildHasher, const N: usize> {
+    /// The seeder used to construct hashers.
+    seeder: Seeder<H>,
+
+    /// The data provider.
+    provider: Box<dyn Provider>,
+
+    /// The total number of bit flips for each bit in the output hash.
+    bit_flips: [usize; N],
+
+    /// The total number of output bits flipped by each bit in the seed.
+    seed_bit_flips: Vec<usize>,
+
+    /// The total number of experiments that have been carried out.
+    total_experiments: usize,
+
+    /// The tolerance that determines the maximum deviance that any single
+    /// output bit or seed bit can have from `0.5` for the test to be
+    /// considered successful.
+    ///
+    /// Note that a fixed tolerance is a fraction (`0.01`), not a percentage
+    /// (`1`).
+    tolerance: Tolerance,
+}
+
+impl<H: BuildHasher, const N: usize> Test<H, N> {
+    /// Creates a new [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::avalanche::seed::Test;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    /// use bitbelay_tests::tolerance::Tolerance;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let test = Test::<Hasher, 64>::try_new(
+    ///     Seeder::new(),
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Tolerance::Fixed(0.01),
+    /// )?;
+    ///
+    /// assert_eq!(test.bit_flips().iter().sum::<usize>(), 0);
+    /// assert_eq!(test.total_experiments(), 0);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_new(
+        seeder: Seeder<H>,
+        provider: Box<dyn Provider>,
+        tolerance: Tolerance,
+    ) -> Result<Self> {
+        if seeder.seed_bytes() == 0 {
+            return Err(Error::EmptySeed);
+        }
+
+        match tolerance {
+            Tolerance::Fixed(max_deviance) if !(0.0..=1.0).contains(&max_deviance) => {
+                return Err(Error::InvalidMaxDeviance(max_deviance));
+            }
+            Tolerance::SampleSize(significance) if significance <= 0.0 || significance >= 1.0 => {
+                return Err(Error::InvalidSignificance(significance));
+            }
+            _ => {}
+        }
+
+        Ok(Self {
+            seed_bit_flips: vec![0; seeder.seed_bytes() * 8],
+            seeder,
+            provider,
+            bit_flips: [0usize; N],
+            total_experiments: 0,
+            tolerance,
+        })
+    }
+
+    /// Gets the seeder for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::avalanche::seed::Test;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    /// use bitbelay_tests::tolerance::Tolerance;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let test = Test::<Hasher, 64>::try_new(
+    ///     Seeder::new(),
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Tolerance::Fixed(0.01),
+    /// )?;
+    ///
+    /// assert_eq!(test.seeder().seed_bytes(), 8);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn seeder(&self) -> Seeder<H> {
+        self.seeder
+    }
+
+    /// Gets the data provider for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// use bitbelay_providers::Provider as _;
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::avalanche::seed::Test;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    /// use bitbelay_tests::tolerance::Tolerance;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let test =
+    ///     Test::<Hasher, 64>::try_new(Seeder::new(), provider.clone(), Tolerance::Fixed(0.01))?;
+    ///
+    /// assert_eq!(test.provider().name(), provider.name());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn provider(&self) -> &dyn Provider {
+        self.provider.as_ref()
+    }
+
+    /// Gets the current number of flips for each output bit in the [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::avalanche::seed::Test;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    /// use bitbelay_tests::tolerance::Tolerance;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let test = Test::<Hasher, 64>::try_new(
+    ///     Seeder::new(),
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Tolerance::Fixed(0.01),
+    /// )?;
+    ///
+    /// assert_eq!(test.bit_flips().iter().sum::<usize>(), 0);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn bit_flips(&self) -> [usize; N] {
+        self.bit_flips
+    }
+
+    /// Gets the current number of output bits flipped by each seed bit in the
+    /// [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::avalanche::seed::Test;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    /// use bitbelay_tests::tolerance::Tolerance;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let test = Test::<Hasher, 64>::try_new(
+    ///     Seeder::new(),
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Tolerance::Fixed(0.01),
+    /// )?;
+    ///
+    /// assert_eq!(test.seed_bit_flips().len(), 64);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn seed_bit_flips(&self) -> &[usize] {
+        &self.seed_bit_flips
+    }
+
+    /// Gets the number of experiments that have been run within the [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::avalanche::seed::Test;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    /// use bitbelay_tests::tolerance::Tolerance;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let test = Test::<Hasher, 64>::try_new(
+    ///     Seeder::new(),
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Tolerance::Fixed(0.01),
+    /// )?;
+    ///
+    /// assert_eq!(test.total_experiments(), 0);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn total_experiments(&self) -> usize {
+        self.total_experiments
+    }
+
+    /// Gets the tolerance for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::avalanche::seed::Test;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    /// use bitbelay_tests::tolerance::Tolerance;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let test = Test::<Hasher, 64>::try_new(
+    ///     Seeder::new(),
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Tolerance::Fixed(0.01),
+    /// )?;
+    ///
+    /// assert_eq!(test.tolerance(), Tolerance::Fixed(0.01));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn tolerance(&self) -> Tolerance {
+        self.tolerance
+    }
+
+    /// Gets the max deviance allowed for any output bit within the [`Test`]
+    /// for the [`Test`] to be considered passing.
+    ///
+    /// For a [`Tolerance::SampleSize`], the max deviance is derived from the
+    /// number of seed bit flips made so far such that a perfect hash function
+    /// exceeds it for _any_ output bit with a probability of at most the
+    /// tolerance's threshold of statistical significance. If no experiments
+    /// have been run, it cannot be derived and [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::avalanche::seed::Test;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    /// use bitbelay_tests::tolerance::Tolerance;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let test = Test::<Hasher, 64>::try_new(
+    ///     Seeder::new(),
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Tolerance::Fixed(0.01),
+    /// )?;
+    ///
+    /// assert_eq!(test.max_deviance(), Some(0.01));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn max_deviance(&self) -> Option<f64> {
+        let trials = (self.total_experiments * self.seed_bit_flips.len()) as u64;
+        self.tolerance.proportion(trials, 0.5, N)
+    }
+
+    /// Gets the max deviance allowed for any seed bit within the [`Test`] for
+    /// the [`Test`] to be considered passing.
+    ///
+    /// For a [`Tolerance::SampleSize`], the max deviance is derived from the
+    /// number of output bits compared so far such that a perfect hash
+    /// function exceeds it for _any_ seed bit with a probability of at most
+    /// the tolerance's threshold of statistical significance. If no
+    /// experiments have been run, it cannot be derived and [`None`] is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::avalanche::seed::Test;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    /// use bitbelay_tests::tolerance::Tolerance;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let test = Test::<Hasher, 64>::try_new(
+    ///     Seeder::new(),
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Tolerance::Fixed(0.01),
+    /// )?;
+    ///
+    /// assert_eq!(test.max_seed_deviance(), Some(0.01));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn max_seed_deviance(&self) -> Option<f64> {
+        let trials = (self.total_experiments * N) as u64;
+        self.tolerance
+            .proportion(trials, 0.5, self.seed_bit_flips.len())
+    }
+
+    /// Runs a single experiment.
+    ///
+    /// Each experiment draws a key from the provider and a random seed. The
+    /// key is then hashed once with the original seed and once for every
+    /// single-bit change to the seed, and the output bits that flipped are
+    /// tallied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::avalanche::seed::Test;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    /// use bitbelay_tests::tolerance::Tolerance;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let mut test = Test::<Hasher, 64>::try_new(
+    ///     Seeder::new(),
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Tolerance::Fixed(0.01),
+    /// )?;
+    ///
+    /// test.run_single_experiment();
+    /// assert_eq!(test.total_experiments(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn run_single_experiment(&mut self) {
+        // SAFETY: we hardcode generating one value, so we know this pop must unwrap.
+        let data = self.provider.provide(1).pop().unwrap().to_vec();
+
+        let mut seed = (0..self.seeder.seed_bytes())
+            .map(|_| rand::random::<u8>())
+            .collect::<Vec<_>>();
+
+        let original = self.seeder.build(&seed).hash_one(&data);
+
+        for (j, flips) in self.seed_bit_flips.iter_mut().enumerate() {
+            seed[j / 8] ^= 1 << (j % 8);
+            let result = original ^ self.seeder.build(&seed).hash_one(&data);
+            seed[j / 8] ^= 1 << (j % 8);
+
+            #[allow(clippy::needless_range_loop)]
+            for i in 0..N {
+                if (result >> i) & 1 == 1 {
+                    self.bit_flips[i] += 1;
+                    *flips += 1;
+                }
+            }
+        }
+
+        self.total_experiments += 1;
+    }
+
+    /// Generates a set of [`Results`] based on the current state of the
+    /// [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::avalanche::seed::Test;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    /// use bitbelay_tests::tolerance::Tolerance;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let mut test = Test::<Hasher, 64>::try_new(
+    ///     Seeder::new(),
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Tolerance::Fixed(0.05),
+    /// )?;
+    ///
+    /// for _ in 0..1000 {
+    ///     test.run_single_experiment();
+    /// }
+    ///
+    /// let results = test.results();
+    /// assert!(results.succeeded);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn results(&self) -> Results {
+        let seed_bits = self.seed_bit_flips.len();
+
+        let bit_bias_offsets =
+            offsets(&self.bit_flips, (self.total_experiments * seed_bits) as f64);
+        let seed_bias_offsets = offsets(&self.seed_bit_flips, (self.total_experiments * N) as f64);
+
+        // SAFETY: there is always at least one output bit and one seed bit (the
+        // latter is checked upon construction), so these will always unwrap.
+        let max_bias = *bit_bias_offsets.iter().max_by_key(|(_, v)| v).unwrap();
+        let max_seed_bias = *seed_bias_offsets.iter().max_by_key(|(_, v)| v).unwrap();
+
+        tracing::info!(
+            "Max bias is output bit {} with {:.2}% and seed bit {} with {:.2}%",
+            max_bias.0,
+            max_bias.1 * 100.0,
+            max_seed_bias.0,
+            max_seed_bias.1 * 100.0
+        );
+
+        let max_deviance = self.max_deviance();
+        let max_seed_deviance = self.max_seed_deviance();
+
+        Results {
+            succeeded: max_deviance.is_some_and(|max| max_bias.1 <= OrderedFloat(max))
+                && max_seed_deviance.is_some_and(|max| max_seed_bias.1 <= OrderedFloat(max)),
+            max_deviance,
+            max_seed_deviance,
+            max_bias,
+            bit_bias_offsets,
+            max_seed_bias,
+            seed_bias_offsets,
+        }
+    }
+}
+
+impl<H: BuildHasher, const N: usize> crate::r#trait::Test for Test<H, N> {
+    fn title(&self) -> &'static str {
+        "Seed Avalanche"
+    }
+
+    fn report_section(&self) -> section::Test {
+        let mut results = self.results();
+
+        let origin = |trials: usize| match self.tolerance {
+            Tolerance::Fixed(_) => String::from("a fixed bias tolerance"),
+            Tolerance::SampleSize(significance) => format!(
+                "a bias tolerance derived from {} trials at a significance of {}",
+                trials, significance
+            ),
+        };
+
+        let (result, summary) = match results.max_deviance {
+            None => (
+                module::Result::Inconclusive,
+                String::from(
+                    "The bias tolerance was not able to be derived (try running more experiments).",
+                ),
+            ),
+            Some(max_deviance) if results.max_bias.1 <= OrderedFloat(max_deviance) => (
+                module::Result::Pass,
+                format!(
+                    "When single bits of the seed were flipped, the bias for every output bit \
+                     fell within a range of 0.5 ± {:.4} ({}).",
+                    max_deviance,
+                    origin(self.total_experiments * self.seed_bit_flips.len())
+                ),
+            ),
+            Some(max_deviance) => (
+                module::Result::Fail,
+                format!(
+                    "When single bits of the seed were flipped, at least one output bit had a \
+                     bias that fell outside the range of 0.5 ± {:.4} ({}).",
+                    max_deviance,
+                    origin(self.total_experiments * self.seed_bit_flips.len())
+                ),
+            ),
+        };
+
+        let output_details = format!(
+            "{}\n\n{}\n\nBit 0{}Bit {}\n{}\n",
+            summary,
+            "Bit Bias Profile".italic(),
+            " ".repeat((N + 2).saturating_sub(9 + N.to_string().len())),
+            N,
+            generate_visual_from_bits(&results.bit_bias_offsets),
+        );
+
+        let output_module = Module::new(
+            result,
+            "Output Bit Bias",
+            Some(format!("{:.2}%", results.max_bias.1 * 100.0)),
+            Some(output_details),
+        );
+
+        let (result, summary) = match results.max_seed_deviance {
+            None => (
+                module::Result::Inconclusive,
+                String::from(
+                    "The bias tolerance was not able to be derived (try running more experiments).",
+                ),
+            ),
+            Some(max_deviance) if results.max_seed_bias.1 <= OrderedFloat(max_deviance) => (
+                module::Result::Pass,
+                format!(
+                    "Every bit of the {}-bit seed flipped a fraction of the output bits that fell \
+                     within a range of 0.5 ± {:.4} ({}).",
+                    results.seed_bias_offsets.len(),
+                    max_deviance,
+                    origin(self.total_experiments * N)
+                ),
+            ),
+            Some(max_deviance) => (
+                module::Result::Fail,
+                format!(
+                    "At least one bit of the {}-bit seed flipped a fraction of the output bits \
+                     that fell outside the range of 0.5 ± {:.4} ({}). Seed bits that are poorly \
+                     mixed into the output make it easier to recover the seed or to find \
+                     collisions that hold regardless of the seed.",
+                    results.seed_bias_offsets.len(),
+                    max_deviance,
+                    origin(self.total_experiments * N)
+                ),
+            ),
+        };
+
+        let mut seed_details = format!("{}\n\n{}\n", summary, "Most Biased Seed Bits".italic());
+
+        results.seed_bias_offsets.sort_by_key(|(_, bias)| -*bias);
+        for (index, bias_offset) in results
+            .seed_bias_offsets
+            .into_iter()
+            .take(REPORTED_SEED_BITS)
+        {
+            seed_details.push_str(&format!(
+                "\n* Seed bit {:>3} had a bias offset of {:.2}%.",
+                index,
+                bias_offset * 100.0
+            ));
+        }
+
+        let seed_module = Module::new(
+            result,
+            "Seed Bit Influence",
+            Some(format!("{:.2}%", results.max_seed_bias.1 * 100.0)),
+            Some(seed_details),
+        );
+
+        get_report_base(self.seed_bit_flips.len())
+            .push_module(output_module)
+            .push_module(seed_module)
+            .try_build()
+            .unwrap()
+    }
+}
+
+/// Computes the offset of each tally from one half of `total`.
+fn offsets(tallies: &[usize], total: f64) -> Vec<(usize, OrderedFloat<f64>)> {
+    tallies
+        .iter()
+        .map(|flips| *flips as f64 / total)
+        .enumerate()
+        .map(|(i, value)| (i, OrderedFloat((value - 0.5).abs())))
+        .collect()
+}
+
+/// Populates the boilerplate report information within a
+/// [`Test`](section::Test).
+pub fn get_report_base(seed_bits: usize) -> section::test::Builder {
+    let overview = "The seed avalanche test determines whether changes to the seed of a hash \
+                    function propagate to its output as thoroughly as changes to its input \
+                    do.\n\nWhen a single bit of the seed is flipped, the output for any fixed key \
+                    should appear to be completely unrelated to the original output: each output \
+                    bit should flip with a probability of one half.";
+
+    let relation = "Seeded hash functions are used to defend hash tables against hash flooding, \
+                    where an attacker chooses keys that collide. This defense only holds if the \
+                    seed is thoroughly mixed into the output. If some seed bits have little \
+                    influence over the output, an attacker can recover the seed from observed \
+                    behavior or find collisions that hold for every seed.";
+
+    let algorithm = format!(
+        "For the hash function and data provider chosen, the algorithm runs multiple experiments. \
+         For each experiment,\n\n(1) A key is generated by the data provider and a random {}-bit \
+         seed is generated.\n\n(2) The key is hashed with a hasher constructed from the seed (the \
+         'original hash').\n\n(3) For every bit in the seed, that bit is flipped, the key is \
+         hashed with a hasher constructed from the modified seed, and each output bit that \
+         differs from the original hash is tallied both for the output bit and for the seed \
+         bit.\n\nAfter all experiments have completed, the fraction of flips for each output bit \
+         and the fraction of output bits flipped by each seed bit are calculated. Each should be \
+         roughly 50%.",
+        seed_bits
+    );
+
+    let interpretation =
+        "* For the test to pass, the bias of every output bit and of every seed bit must fall \
+         within the range of the expected value (50%) ± the bias tolerance. The bias tolerance is \
+         either fixed or derived from the number of trials, in which case a perfect hash function \
+         exceeds it for any output bit (or any seed bit) with a probability of at most the chosen \
+         threshold of statistical significance.\n\n* A seed bit with a bias near 50% has little \
+         or no influence on the output and should be treated as a serious weakness.";
+
+    Builder::default()
+        .title("Seed Avalanche")
+        .unwrap()
+        .description(format!(
+            "{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}",
+            "Overview".italic(),
+            overview,
+            "Relation to Hashing".italic(),
+            relation,
+            "Algorithm".italic(),
+            algorithm,
+            "Interpretation".italic(),
+            interpretation
+        ))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::BuildHasherDefault;
+    use std::hash::Hasher;
+
+    use bitbelay_providers::ascii::AlphanumericProvider;
+
+    use super::*;
+    use crate::seeded::SeedPrefixed;
+
+    /// A hasher that barely mixes its input.
+    #[derive(Debug, Default)]
+    struct RotateXor(u64);
+
+    impl Hasher for RotateXor {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            for byte in bytes {
+                self.0 = self.0.rotate_left(8) ^ u64::from(*byte);
+            }
+        }
+    }
+
+    #[test]
+    fn poorly_mixed_seeds_fail() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let mut test = Test::<SeedPrefixed<BuildHasherDefault<RotateXor>>, 64>::try_new(
+            Seeder::new(),
+            Box::new(AlphanumericProvider::new(10)),
+            Tolerance::Fixed(0.05),
+        )?;
+
+        for _ in 0..100 {
+            test.run_single_experiment();
+        }
+
+        assert!(!test.results().succeeded);
+        Ok(())
+    }
+
+    #[test]
+    fn well_mixed_seeds_pass() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let mut test = Test::<SeedPrefixed<BuildHasherDefault<DefaultHasher>>, 64>::try_new(
+            Seeder::new(),
+            Box::new(AlphanumericProvider::new(10)),
+            Tolerance::SampleSize(1e-6),
+        )?;
+
+        for _ in 0..100 {
+            test.run_single_experiment();
+        }
+
+        assert!(test.results().succeeded);
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+//! Balance tests.
+//!
+//! These tests evaluate whether the values of the output bits are balanced
+//! (i.e., each bit is equally likely to be zero or one).
+
+use std::hash::BuildHasher;
+
+use bitbelay_report::section;
+
+use crate::r#trait::Test as _;
+
+pub mod bit;
+
+/// A type of balance test.
+#[derive(Debug)]
+pub enum Test<'a, H: BuildHasher> {
+    /// Bit balance test.
+    Bit(bit::Test<'a, H>),
+}
+
+impl<'a, H: BuildHasher> Test<'a, H> {
+    /// Gets a reference to a [`bit::Test`] wrapped in [`Some`] if the
+    /// [`Test`] is a [`Test::Bit`]. Else, returns [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::balance::Test;
+    /// use bitbelay_tests::balance::bit;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::Bit(bit::Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// ));
+    ///
+    /// assert!(matches!(test.as_bit_test(), Some(_)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn as_bit_test(&self) -> Option<&bit::Test<'a, H>> {
+        match self {
+            Test::Bit(test) => Some(test),
+        }
+    }
+
+    /// Consumes the [`Test`] and returns a [`bit::Test`] wrapped in
+    /// [`Some`] if the [`Test`] is a [`Test::Bit`]. Else, returns
+    /// [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::balance::Test;
+    /// use bitbelay_tests::balance::bit;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::Bit(bit::Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// ));
+    ///
+    /// assert!(matches!(test.into_bit_test(), Some(_)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn into_bit_test(self) -> Option<bit::Test<'a, H>> {
+        match self {
+            Test::Bit(test) => Some(test),
+        }
+    }
+
+    /// Generates a report section for the [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::balance::Test;
+    /// use bitbelay_tests::balance::bit;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::Bit(bit::Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// ));
+    ///
+    /// let results = test.report_section();
+    /// // Include the section in a report.
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn report_section(&self) -> section::Test {
+        match self {
+            Test::Bit(test) => test.report_section(),
+        }
+    }
+}
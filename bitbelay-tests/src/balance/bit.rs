@@ -0,0 +1,564 @@
+//! Output bit balance test.
+
+use std::hash::BuildHasher;
+
+use bitbelay_providers::Provider;
+use bitbelay_report::section;
+use bitbelay_report::section::test;
+use bitbelay_report::section::test::Module;
+use bitbelay_report::section::test::module;
+use bitbelay_statistics::binomial;
+use bitbelay_statistics::correction::Procedure;
+use colored::Colorize;
+
+/// The number of bits in the output hash.
+const BITS: usize = u64::BITS as usize;
+
+/// The number of least balanced bits listed within the report.
+const WORST_BITS: usize = 5;
+
+/// An output bit balance test.
+#[derive(Debug)]
+pub struct Test<'a, H: BuildHasher> {
+    /// The build hasher.
+    build_hasher: &'a H,
+
+    /// The data provider.
+    provider: Box<dyn Provider>,
+
+    /// The number of times each output bit was set.
+    ones: [usize; BITS],
+
+    /// The number of keys hashed.
+    keys: usize,
+
+    /// The threshold of statistical significance for the family of bits.
+    threshold: f64,
+
+    /// The procedure used to correct for the number of bits tested.
+    correction: Procedure,
+}
+
+impl<'a, H: BuildHasher> Test<'a, H> {
+    /// Creates a new [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::balance::bit::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
+    ///
+    /// assert_eq!(test.keys(), 0);
+    /// ```
+    pub fn new(
+        build_hasher: &'a H,
+        provider: Box<dyn Provider>,
+        threshold: f64,
+        correction: Procedure,
+    ) -> Self {
+        Self {
+            build_hasher,
+            provider,
+            ones: [0; BITS],
+            keys: 0,
+            threshold,
+            correction,
+        }
+    }
+
+    /// Gets the build hasher for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::BuildHasher as _;
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::balance::bit::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
+    ///
+    /// // Used as a surrogate to test that the [`BuildHasher`]s are the same.
+    /// assert_eq!(test.build_hasher().hash_one("42"), hasher.hash_one("42"));
+    /// ```
+    pub fn build_hasher(&self) -> &H {
+        self.build_hasher
+    }
+
+    /// Gets the data provider for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::Provider as _;
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::balance::bit::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let test = Test::new(&hasher, provider.clone(), 0.05, Procedure::Holm);
+    ///
+    /// assert_eq!(test.provider().name(), provider.name());
+    /// ```
+    pub fn provider(&self) -> &dyn Provider {
+        self.provider.as_ref()
+    }
+
+    /// Gets the number of times each output bit was set within this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::balance::bit::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
+    ///
+    /// test.single_iteration();
+    /// assert!(test.ones().iter().all(|ones| *ones <= 1));
+    /// ```
+    pub fn ones(&self) -> &[usize; BITS] {
+        &self.ones
+    }
+
+    /// Gets the number of keys hashed within this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::balance::bit::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
+    ///
+    /// test.single_iteration();
+    /// assert_eq!(test.keys(), 1);
+    /// ```
+    pub fn keys(&self) -> usize {
+        self.keys
+    }
+
+    /// Gets the threshold of statistical significance for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::balance::bit::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
+    ///
+    /// assert_eq!(test.threshold(), 0.05);
+    /// ```
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Gets the procedure used to correct for the number of bits tested.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::balance::bit::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     0.05,
+    ///     Procedure::Bonferroni,
+    /// );
+    ///
+    /// assert_eq!(test.correction(), Procedure::Bonferroni);
+    /// ```
+    pub fn correction(&self) -> Procedure {
+        self.correction
+    }
+
+    /// Hashes a single key from the provider and counts the output bits that
+    /// are set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::balance::bit::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
+    ///
+    /// test.single_iteration();
+    /// assert_eq!(test.keys(), 1);
+    /// ```
+    pub fn single_iteration(&mut self) {
+        // SAFETY: we hardcode generating one value, so we know this pop must unwrap.
+        let key = self.provider.provide(1).pop().unwrap();
+        let hash = self.build_hasher.hash_one(key);
+
+        for (i, ones) in self.ones.iter_mut().enumerate() {
+            *ones += ((hash >> i) & 1) as usize;
+        }
+
+        self.keys += 1;
+    }
+
+    /// Computes the exact, two-sided binomial p-value for the balance of each
+    /// output bit.
+    ///
+    /// If no keys have been hashed, [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::balance::bit::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
+    ///
+    /// assert!(test.p_values().is_none());
+    ///
+    /// for _ in 0..100 {
+    ///     test.single_iteration();
+    /// }
+    ///
+    /// assert!(test.p_values().unwrap().iter().all(|p| *p <= 1.0));
+    /// ```
+    pub fn p_values(&self) -> Option<[f64; BITS]> {
+        let mut p_values = [0.0; BITS];
+
+        for (p_value, ones) in p_values.iter_mut().zip(self.ones.iter()) {
+            *p_value = binomial::two_sided_p_value(*ones as u64, self.keys as u64, 0.5)?;
+        }
+
+        Some(p_values)
+    }
+
+    /// Computes the p-value for the balance of each output bit after
+    /// adjusting for the number of bits tested using the configured
+    /// [`Procedure`].
+    ///
+    /// If no keys have been hashed, [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::balance::bit::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
+    ///
+    /// assert!(test.adjusted_p_values().is_none());
+    ///
+    /// for _ in 0..100 {
+    ///     test.single_iteration();
+    /// }
+    ///
+    /// let raw = test.p_values().unwrap();
+    /// let adjusted = test.adjusted_p_values().unwrap();
+    /// assert!(raw.iter().zip(adjusted.iter()).all(|(r, a)| r <= a));
+    /// ```
+    pub fn adjusted_p_values(&self) -> Option<[f64; BITS]> {
+        let p_values = self.p_values()?;
+        let mut adjusted = [0.0; BITS];
+        adjusted.copy_from_slice(&self.correction.adjust(&p_values));
+        Some(adjusted)
+    }
+
+    /// Generates the [`Module`] for the balance of the output bits.
+    fn module(&self) -> Module {
+        let (p_values, adjusted) = match (self.p_values(), self.adjusted_p_values()) {
+            (Some(p_values), Some(adjusted)) => (p_values, adjusted),
+            _ => {
+                return Module::new(
+                    module::Result::Inconclusive,
+                    "Bit Balance",
+                    None,
+                    Some(String::from(
+                        "The p-values were not able to be computed (try increasing the number of \
+                         keys).",
+                    )),
+                );
+            }
+        };
+
+        let minimum = adjusted.iter().copied().fold(f64::INFINITY, f64::min);
+        let failures = adjusted.iter().filter(|p| **p <= self.threshold).count();
+
+        let (result, summary) = if failures == 0 {
+            (
+                module::Result::Pass,
+                format!(
+                    "The smallest p-value after applying the {} across {} bits was {:.2e}, which \
+                     is greater than (and, thus, failed to reach) the threshold of statistical \
+                     significance set at {:.2}. As such, the null hypothesis that every output \
+                     bit is set with a probability of 50% **cannot** be rejected.",
+                    self.correction.name(),
+                    BITS,
+                    minimum,
+                    self.threshold
+                ),
+            )
+        } else {
+            (
+                module::Result::Fail,
+                format!(
+                    "{} output bit(s) had a p-value after applying the {} across {} bits that was \
+                     less than (and, thus, reached) the threshold of statistical significance set \
+                     at {:.2}. As such, the null hypothesis that every output bit is set with a \
+                     probability of 50% **is** rejected.",
+                    failures,
+                    self.correction.name(),
+                    BITS,
+                    self.threshold
+                ),
+            )
+        };
+
+        let mut worst = p_values.iter().copied().enumerate().collect::<Vec<_>>();
+        worst.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+
+        let mut details = format!(
+            "{}\n\n{}\n{}\n\n{}\n",
+            summary,
+            "Bit Profile".italic(),
+            generate_visual_from_p_values(&p_values, &adjusted, self.threshold),
+            "Least Balanced Bits".italic()
+        );
+
+        for (i, p_value) in worst.into_iter().take(WORST_BITS) {
+            details.push_str(&format!(
+                "\n* Bit {}: set {:.2}% of the time (p-value of {:.2e}, adjusted p-value of \
+                 {:.2e}).",
+                i,
+                self.ones[i] as f64 / self.keys as f64 * 100.0,
+                p_value,
+                adjusted[i]
+            ));
+        }
+
+        Module::new(
+            result,
+            "Bit Balance",
+            Some(format!("{:.2e}", minimum)),
+            Some(details),
+        )
+    }
+}
+
+impl<'a, H: BuildHasher> crate::r#trait::Test for Test<'a, H> {
+    fn title(&self) -> &'static str {
+        "Bit Balance"
+    }
+
+    fn report_section(&self) -> section::Test {
+        get_report_base(self.provider.as_ref(), self.keys, self.correction)
+            .push_module(self.module())
+            .try_build()
+            .unwrap()
+    }
+}
+
+/// Generates a visual profile of the output bits (from least to most
+/// significant) given their raw and adjusted p-values.
+///
+/// * Bits whose raw p-value is above `threshold` are rendered as a green `.`.
+/// * Bits whose raw p-value is below `threshold` but whose adjusted p-value is
+///   above `threshold` are rendered as a yellow `?`.
+/// * Bits whose adjusted p-value is below `threshold` are rendered as a red
+///   `!`.
+fn generate_visual_from_p_values(p_values: &[f64], adjusted: &[f64], threshold: f64) -> String {
+    let mut visual = String::from("[");
+
+    for (p_value, adjusted) in p_values.iter().zip(adjusted) {
+        if *p_value > threshold {
+            visual.push_str(&format!("{}", &".".green()));
+        } else if *adjusted > threshold {
+            visual.push_str(&format!("{}", &"?".yellow()));
+        } else {
+            visual.push_str(&format!("{}", &"!".red()));
+        }
+    }
+
+    visual.push(']');
+    visual
+}
+
+/// Populates the boilerplate report information within a
+/// [`Test`](section::Test).
+pub fn get_report_base(
+    provider: &dyn Provider,
+    keys: usize,
+    correction: Procedure,
+) -> section::test::Builder {
+    let overview = "The bit balance test checks that each output bit of the hash function is set \
+                    half of the time when hashing random input.";
+
+    let relation = "Neither the bitwise correlation test (whose diagonal is always perfectly \
+                    correlated) nor the Strict Avalanche Criterion (which measures bit flips \
+                    rather than bit values) detect an output bit that is biased towards zero or \
+                    one. Such a bit reduces the effective size of the output space and causes \
+                    hash tables that use that bit to distribute keys unevenly.";
+
+    let algorithm = format!(
+        "(1) For each of the {keys} keys generated by the data provider, the key is hashed and \
+         the number of times each of the {BITS} output bits is set is counted.\n\n(2) For each \
+         output bit, an exact, two-sided binomial test is performed against the null hypothesis \
+         that the bit is set with a probability of 50%.\n\n(3) Because {BITS} bits are tested at \
+         once, the p-values are adjusted using the {} (which controls the {}) before being \
+         compared against the chosen threshold.",
+        correction.name(),
+        correction.controls()
+    );
+
+    let interpretation = "Under this test design:\n\n* If any bit has an adjusted p-value below \
+                          the threshold, the test fails, as that bit is very likely biased.\n\n* \
+                          Within the bit profile (from least to most significant bit), a green \
+                          `.` is consistent with balance, a yellow `?` is a bit that would have \
+                          failed without correction (expected occasionally by chance), and a red \
+                          `!` is a bit that failed.";
+
+    let sources = format!(
+        "* https://en.wikipedia.org/wiki/Binomial_test\n* {}",
+        correction.source()
+    );
+
+    test::Builder::default()
+        .title(format!("Bit Balance / {} / {} keys", provider.name(), keys))
+        .unwrap()
+        .description(format!(
+            "{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}",
+            "Overview".italic(),
+            overview,
+            "Relation to Hashing".italic(),
+            relation,
+            "Algorithm".italic(),
+            algorithm,
+            "Interpretation".italic(),
+            interpretation,
+            "Sources".italic(),
+            sources,
+        ))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::BuildHasherDefault;
+    use std::hash::Hasher;
+
+    use bitbelay_providers::ascii::AlphanumericProvider;
+
+    use super::*;
+
+    /// A hasher whose least significant output bit is always set.
+    #[derive(Debug, Default)]
+    struct StuckBit(std::hash::DefaultHasher);
+
+    impl Hasher for StuckBit {
+        fn finish(&self) -> u64 {
+            self.0.finish() | 1
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            self.0.write(bytes);
+        }
+    }
+
+    #[test]
+    fn stuck_bits_fail() {
+        let hasher = BuildHasherDefault::<StuckBit>::default();
+        let mut test = Test::new(
+            &hasher,
+            Box::new(AlphanumericProvider::new(10)),
+            0.05,
+            Procedure::Holm,
+        );
+
+        for _ in 0..1_000 {
+            test.single_iteration();
+        }
+
+        let adjusted = test.adjusted_p_values().unwrap();
+        assert_eq!(test.ones()[0], 1_000);
+        assert!(adjusted[0] < test.threshold());
+    }
+}
@@ -0,0 +1,179 @@
+//! Strategies for mapping a hash to a bucket.
+//!
+//! Hash-based data structures rarely use the full output of a hash function.
+//! Instead, they reduce each hash to an index within a finite set of buckets,
+//! and the way in which that reduction happens determines which bits of the
+//! hash actually matter. For example, a hash function may distribute well when
+//! reduced modulo a prime number of buckets but cluster badly when only the low
+//! bits are kept (as is the case for power-of-two tables such as those in
+//! [`hashbrown`](https://crates.io/crates/hashbrown)).
+//!
+//! # Sources
+//!
+//! * Lemire describes the multiply-shift "fastrange" reduction in [this blog
+//!   post][fastrange].
+//!
+//! [fastrange]: https://lemire.me/blog/2016/06/27/a-fast-alternative-to-the-modulo-reduction/
+
+use std::num::NonZeroUsize;
+
+/// A strategy for mapping a hash to a bucket.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum Bucketing {
+    /// The remainder of the hash divided by the number of buckets.
+    #[cfg_attr(feature = "clap", clap(name = "modulo"))]
+    #[default]
+    Modulo,
+
+    /// The low bits of the hash (the number of buckets must be a power of
+    /// two).
+    #[cfg_attr(feature = "clap", clap(name = "low-bit-mask"))]
+    LowBitMask,
+
+    /// The high bits of the hash (the number of buckets must be a power of
+    /// two).
+    #[cfg_attr(feature = "clap", clap(name = "high-bit-shift"))]
+    HighBitShift,
+
+    /// Lemire's multiply-shift reduction into the range of buckets.
+    #[cfg_attr(feature = "clap", clap(name = "fastrange"))]
+    FastRange,
+}
+
+impl Bucketing {
+    /// Returns whether this [`Bucketing`] strategy supports the provided
+    /// number of buckets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_tests::bucketing::Bucketing;
+    ///
+    /// let primes = NonZeroUsize::try_from(251).unwrap();
+    /// assert!(Bucketing::Modulo.supports(primes));
+    /// assert!(Bucketing::FastRange.supports(primes));
+    /// assert!(!Bucketing::LowBitMask.supports(primes));
+    /// assert!(!Bucketing::HighBitShift.supports(primes));
+    ///
+    /// let powers = NonZeroUsize::try_from(256).unwrap();
+    /// assert!(Bucketing::LowBitMask.supports(powers));
+    /// assert!(Bucketing::HighBitShift.supports(powers));
+    /// ```
+    pub fn supports(&self, buckets: NonZeroUsize) -> bool {
+        match self {
+            Bucketing::Modulo | Bucketing::FastRange => true,
+            Bucketing::LowBitMask | Bucketing::HighBitShift => buckets.is_power_of_two(),
+        }
+    }
+
+    /// Maps a hash to a bucket index in the range `[0, buckets)`.
+    ///
+    /// # Notes
+    ///
+    /// * The number of buckets must be [supported](Bucketing::supports) by the
+    ///   strategy—otherwise, the returned index is meaningless (and may fall
+    ///   outside of the range of buckets).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_tests::bucketing::Bucketing;
+    ///
+    /// let buckets = NonZeroUsize::try_from(16).unwrap();
+    /// let hash = 0xF000_0000_0000_0003;
+    ///
+    /// assert_eq!(Bucketing::Modulo.bucket(hash, buckets), 3);
+    /// assert_eq!(Bucketing::LowBitMask.bucket(hash, buckets), 3);
+    /// assert_eq!(Bucketing::HighBitShift.bucket(hash, buckets), 15);
+    /// assert_eq!(Bucketing::FastRange.bucket(hash, buckets), 15);
+    /// ```
+    pub fn bucket(&self, hash: u64, buckets: NonZeroUsize) -> usize {
+        debug_assert!(self.supports(buckets));
+
+        match self {
+            Bucketing::Modulo => (hash % buckets.get() as u64) as usize,
+            Bucketing::LowBitMask => (hash & (buckets.get() as u64 - 1)) as usize,
+            Bucketing::HighBitShift => {
+                let bits = buckets.trailing_zeros();
+
+                // NOTE: shifting a `u64` by 64 bits overflows, so the single
+                // bucket case is handled separately.
+                if bits == 0 {
+                    0
+                } else {
+                    (hash >> (u64::BITS - bits)) as usize
+                }
+            }
+            Bucketing::FastRange => ((hash as u128 * buckets.get() as u128) >> 64) as usize,
+        }
+    }
+
+    /// Gets a short, human-readable description of how the [`Bucketing`]
+    /// strategy maps a hash to a bucket.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_tests::bucketing::Bucketing;
+    ///
+    /// assert_eq!(
+    ///     Bucketing::Modulo.description(),
+    ///     "the remainder of the hash divided by the number of buckets"
+    /// );
+    /// ```
+    pub fn description(&self) -> &'static str {
+        match self {
+            Bucketing::Modulo => "the remainder of the hash divided by the number of buckets",
+            Bucketing::LowBitMask => "the low `log2(buckets)` bits of the hash",
+            Bucketing::HighBitShift => "the high `log2(buckets)` bits of the hash",
+            Bucketing::FastRange => {
+                "the high 64 bits of the 128-bit product of the hash and the number of buckets"
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Bucketing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Bucketing::Modulo => write!(f, "modulo"),
+            Bucketing::LowBitMask => write!(f, "low-bit-mask"),
+            Bucketing::HighBitShift => write!(f, "high-bit-shift"),
+            Bucketing::FastRange => write!(f, "fastrange"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_strategy_stays_within_range() {
+        let hashes = [0, 1, u64::MAX, u64::MAX / 3, 0x8000_0000_0000_0000];
+
+        for buckets in [1usize, 2, 7, 64, 251, 1 << 20] {
+            let buckets = NonZeroUsize::try_from(buckets).unwrap();
+
+            for bucketing in [
+                Bucketing::Modulo,
+                Bucketing::LowBitMask,
+                Bucketing::HighBitShift,
+                Bucketing::FastRange,
+            ] {
+                if !bucketing.supports(buckets) {
+                    continue;
+                }
+
+                for hash in hashes {
+                    assert!(bucketing.bucket(hash, buckets) < buckets.get());
+                }
+            }
+        }
+    }
+}
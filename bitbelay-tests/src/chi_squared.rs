@@ -7,12 +7,28 @@ use bitbelay_report::section;
 use crate::r#trait::Test as _;
 
 pub mod goodness_of_fit;
+pub mod independence;
+pub mod popcount;
+pub mod sweep;
+pub mod uniformity;
 
 /// A type of chi-squared test.
 #[derive(Debug)]
 pub enum Test<'a, H: BuildHasher> {
     /// Goodness of fit test.
     GoodnessOfFit(goodness_of_fit::Test<'a, H>),
+
+    /// Goodness of fit sweep across table sizes.
+    Sweep(sweep::Test<'a, H>),
+
+    /// Hamming weight (popcount) test.
+    Popcount(popcount::Test<'a, H>),
+
+    /// Empirical distribution function uniformity test.
+    Uniformity(uniformity::Test<'a, H>),
+
+    /// Sub-field independence test.
+    Independence(independence::Test<'a, H>),
 }
 
 impl<'a, H: BuildHasher> Test<'a, H> {
@@ -26,18 +42,20 @@ impl<'a, H: BuildHasher> Test<'a, H> {
     /// use std::num::NonZeroUsize;
     ///
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
     /// use bitbelay_tests::chi_squared::Test;
     /// use bitbelay_tests::chi_squared::goodness_of_fit;
     ///
     /// let provider = Box::new(AlphanumericProvider::new(10));
     ///
     /// let hasher = RandomState::new();
-    /// let mut test = Test::GoodnessOfFit(goodness_of_fit::Test::new(
+    /// let mut test = Test::GoodnessOfFit(goodness_of_fit::Test::try_new(
     ///     &hasher,
     ///     provider,
     ///     NonZeroUsize::try_from(256).unwrap(),
+    ///     Bucketing::Modulo,
     ///     0.05,
-    /// ));
+    /// )?);
     ///
     /// assert!(matches!(test.as_goodness_of_fit_test(), Some(_)));
     ///
@@ -46,6 +64,7 @@ impl<'a, H: BuildHasher> Test<'a, H> {
     pub fn as_goodness_of_fit_test(&self) -> Option<&goodness_of_fit::Test<'a, H>> {
         match self {
             Test::GoodnessOfFit(test) => Some(test),
+            _ => None,
         }
     }
 
@@ -60,18 +79,20 @@ impl<'a, H: BuildHasher> Test<'a, H> {
     /// use std::num::NonZeroUsize;
     ///
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
     /// use bitbelay_tests::chi_squared::Test;
     /// use bitbelay_tests::chi_squared::goodness_of_fit;
     ///
     /// let provider = Box::new(AlphanumericProvider::new(10));
     ///
     /// let hasher = RandomState::new();
-    /// let mut test = Test::GoodnessOfFit(goodness_of_fit::Test::new(
+    /// let mut test = Test::GoodnessOfFit(goodness_of_fit::Test::try_new(
     ///     &hasher,
     ///     provider,
     ///     NonZeroUsize::try_from(256).unwrap(),
+    ///     Bucketing::Modulo,
     ///     0.05,
-    /// ));
+    /// )?);
     ///
     /// assert!(matches!(test.into_goodness_of_fit_test(), Some(_)));
     ///
@@ -80,6 +101,254 @@ impl<'a, H: BuildHasher> Test<'a, H> {
     pub fn into_goodness_of_fit_test(self) -> Option<goodness_of_fit::Test<'a, H>> {
         match self {
             Test::GoodnessOfFit(test) => Some(test),
+            _ => None,
+        }
+    }
+
+    /// Gets a reference to a [`sweep::Test`] wrapped in [`Some`] if the
+    /// [`Test`] is a [`Test::Sweep`]. Else, returns [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    /// use bitbelay_tests::chi_squared::Test;
+    /// use bitbelay_tests::chi_squared::sweep;
+    ///
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::Sweep(sweep::Test::try_new(
+    ///     &hasher,
+    ///     provider,
+    ///     vec![NonZeroUsize::try_from(256).unwrap()],
+    ///     Bucketing::Modulo,
+    ///     0.05,
+    /// )?);
+    ///
+    /// assert!(matches!(test.as_sweep_test(), Some(_)));
+    /// assert!(matches!(test.as_goodness_of_fit_test(), None));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn as_sweep_test(&self) -> Option<&sweep::Test<'a, H>> {
+        match self {
+            Test::Sweep(test) => Some(test),
+            _ => None,
+        }
+    }
+
+    /// Consumes the [`Test`] and returns a [`sweep::Test`] wrapped in
+    /// [`Some`] if the [`Test`] is a [`Test::Sweep`]. Else, returns [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    /// use bitbelay_tests::chi_squared::Test;
+    /// use bitbelay_tests::chi_squared::sweep;
+    ///
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::Sweep(sweep::Test::try_new(
+    ///     &hasher,
+    ///     provider,
+    ///     vec![NonZeroUsize::try_from(256).unwrap()],
+    ///     Bucketing::Modulo,
+    ///     0.05,
+    /// )?);
+    ///
+    /// assert!(matches!(test.into_sweep_test(), Some(_)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn into_sweep_test(self) -> Option<sweep::Test<'a, H>> {
+        match self {
+            Test::Sweep(test) => Some(test),
+            _ => None,
+        }
+    }
+
+    /// Gets a reference to a [`popcount::Test`] wrapped in [`Some`] if the
+    /// [`Test`] is a [`Test::Popcount`]. Else, returns [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::chi_squared::Test;
+    /// use bitbelay_tests::chi_squared::popcount;
+    ///
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::Popcount(popcount::Test::new(&hasher, provider, 0.05));
+    ///
+    /// assert!(matches!(test.as_popcount_test(), Some(_)));
+    /// assert!(matches!(test.as_goodness_of_fit_test(), None));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn as_popcount_test(&self) -> Option<&popcount::Test<'a, H>> {
+        match self {
+            Test::Popcount(test) => Some(test),
+            _ => None,
+        }
+    }
+
+    /// Consumes the [`Test`] and returns a [`popcount::Test`] wrapped in
+    /// [`Some`] if the [`Test`] is a [`Test::Popcount`]. Else, returns
+    /// [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::chi_squared::Test;
+    /// use bitbelay_tests::chi_squared::popcount;
+    ///
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::Popcount(popcount::Test::new(&hasher, provider, 0.05));
+    ///
+    /// assert!(matches!(test.into_popcount_test(), Some(_)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn into_popcount_test(self) -> Option<popcount::Test<'a, H>> {
+        match self {
+            Test::Popcount(test) => Some(test),
+            _ => None,
+        }
+    }
+
+    /// Gets a reference to a [`uniformity::Test`] wrapped in [`Some`] if
+    /// the [`Test`] is a [`Test::Uniformity`]. Else, returns [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::chi_squared::Test;
+    /// use bitbelay_tests::chi_squared::uniformity;
+    ///
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::Uniformity(uniformity::Test::new(&hasher, provider, 0.05));
+    ///
+    /// assert!(matches!(test.as_uniformity_test(), Some(_)));
+    /// assert!(matches!(test.as_goodness_of_fit_test(), None));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn as_uniformity_test(&self) -> Option<&uniformity::Test<'a, H>> {
+        match self {
+            Test::Uniformity(test) => Some(test),
+            _ => None,
+        }
+    }
+
+    /// Consumes the [`Test`] and returns a [`uniformity::Test`] wrapped in
+    /// [`Some`] if the [`Test`] is a [`Test::Uniformity`]. Else, returns
+    /// [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::chi_squared::Test;
+    /// use bitbelay_tests::chi_squared::uniformity;
+    ///
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::Uniformity(uniformity::Test::new(&hasher, provider, 0.05));
+    ///
+    /// assert!(matches!(test.into_uniformity_test(), Some(_)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn into_uniformity_test(self) -> Option<uniformity::Test<'a, H>> {
+        match self {
+            Test::Uniformity(test) => Some(test),
+            _ => None,
+        }
+    }
+
+    /// Gets a reference to an [`independence::Test`] wrapped in [`Some`] if
+    /// the [`Test`] is a [`Test::Independence`]. Else, returns [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::chi_squared::Test;
+    /// use bitbelay_tests::chi_squared::independence;
+    ///
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::Independence(independence::Test::try_new(&hasher, provider, 8, 0.05)?);
+    ///
+    /// assert!(matches!(test.as_independence_test(), Some(_)));
+    /// assert!(matches!(test.as_goodness_of_fit_test(), None));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn as_independence_test(&self) -> Option<&independence::Test<'a, H>> {
+        match self {
+            Test::Independence(test) => Some(test),
+            _ => None,
+        }
+    }
+
+    /// Consumes the [`Test`] and returns an [`independence::Test`] wrapped in
+    /// [`Some`] if the [`Test`] is a [`Test::Independence`]. Else, returns
+    /// [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::chi_squared::Test;
+    /// use bitbelay_tests::chi_squared::independence;
+    ///
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::Independence(independence::Test::try_new(&hasher, provider, 8, 0.05)?);
+    ///
+    /// assert!(matches!(test.into_independence_test(), Some(_)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn into_independence_test(self) -> Option<independence::Test<'a, H>> {
+        match self {
+            Test::Independence(test) => Some(test),
+            _ => None,
         }
     }
 
@@ -92,18 +361,20 @@ impl<'a, H: BuildHasher> Test<'a, H> {
     /// use std::num::NonZeroUsize;
     ///
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
     /// use bitbelay_tests::chi_squared::Test;
     /// use bitbelay_tests::chi_squared::goodness_of_fit;
     ///
     /// let provider = Box::new(AlphanumericProvider::new(10));
     ///
     /// let hasher = RandomState::new();
-    /// let mut test = Test::GoodnessOfFit(goodness_of_fit::Test::new(
+    /// let mut test = Test::GoodnessOfFit(goodness_of_fit::Test::try_new(
     ///     &hasher,
     ///     provider,
     ///     NonZeroUsize::try_from(256).unwrap(),
+    ///     Bucketing::Modulo,
     ///     0.05,
-    /// ));
+    /// )?);
     ///
     /// let section = test.report_section();
     /// // Do something with `section`.
@@ -113,6 +384,10 @@ impl<'a, H: BuildHasher> Test<'a, H> {
     pub fn report_section(&self) -> section::Test {
         match self {
             Test::GoodnessOfFit(test) => test.report_section(),
+            Test::Sweep(test) => test.report_section(),
+            Test::Popcount(test) => test.report_section(),
+            Test::Uniformity(test) => test.report_section(),
+            Test::Independence(test) => test.report_section(),
         }
     }
 }
@@ -9,8 +9,35 @@ use bitbelay_report::section::test;
 use bitbelay_report::section::test::Module;
 use bitbelay_report::section::test::module;
 use bitbelay_statistics::chi_squared::UniformPearsonTest;
+use bitbelay_statistics::power;
 use colored::Colorize;
 
+use crate::bucketing::Bucketing;
+
+/// An error related to a [`Test`].
+#[derive(Debug)]
+pub enum Error {
+    /// The bucketing strategy does not support the number of buckets.
+    UnsupportedBuckets(Bucketing, NonZeroUsize),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::UnsupportedBuckets(bucketing, buckets) => write!(
+                f,
+                "{bucketing} bucketing does not support {buckets} buckets (the number of buckets \
+                 must be a power of two)"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A [`Result`](std::result::Result) with an [`Error`].
+type Result<T> = std::result::Result<T, Error>;
+
 /// A chi-squared goodness of fit test.
 #[derive(Debug)]
 pub struct Test<'a, H: BuildHasher> {
@@ -23,12 +50,20 @@ pub struct Test<'a, H: BuildHasher> {
     /// The number of buckets to use within the test.
     buckets: Vec<usize>,
 
+    /// The strategy used to map each hash to a bucket.
+    bucketing: Bucketing,
+
     /// The threshold of statistical signficance to use.
     threshold: f64,
 }
 
 impl<'a, H: BuildHasher> Test<'a, H> {
-    /// Creates a new [`Test`].
+    /// Attempts to create a new [`Test`].
+    ///
+    /// # Notes
+    ///
+    /// * If `bucketing` does not [support](Bucketing::supports) the number of
+    ///   buckets provided, an [`Error::UnsupportedBuckets`] is returned.
     ///
     /// # Examples
     ///
@@ -39,33 +74,43 @@ impl<'a, H: BuildHasher> Test<'a, H> {
     ///
     /// use bitbelay_providers::Provider as _;
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
     /// use bitbelay_tests::chi_squared::goodness_of_fit::Test;
     ///
     /// let hasher = RandomState::new();
     /// let provider = Box::new(AlphanumericProvider::new(10));
-    /// let test = Test::new(
+    /// let test = Test::try_new(
     ///     &hasher,
     ///     provider.clone(),
     ///     NonZeroUsize::try_from(2048).unwrap(),
+    ///     Bucketing::Modulo,
     ///     0.05,
-    /// );
+    /// )?;
     ///
     /// assert_eq!(test.build_hasher().hash_one("42"), hasher.hash_one("42"));
     /// assert_eq!(test.provider().name(), provider.name());
     /// assert_eq!(test.buckets().len(), 2048);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn new(
+    pub fn try_new(
         build_hasher: &'a H,
         provider: Box<dyn Provider>,
         num_buckets: NonZeroUsize,
+        bucketing: Bucketing,
         threshold: f64,
-    ) -> Self {
-        Test {
+    ) -> Result<Self> {
+        if !bucketing.supports(num_buckets) {
+            return Err(Error::UnsupportedBuckets(bucketing, num_buckets));
+        }
+
+        Ok(Test {
             build_hasher,
             provider,
             buckets: vec![0; num_buckets.get()],
+            bucketing,
             threshold,
-        }
+        })
     }
 
     /// Gets the [`BuildHasher`] from the [`Test`] by reference.
@@ -78,18 +123,22 @@ impl<'a, H: BuildHasher> Test<'a, H> {
     /// use std::num::NonZeroUsize;
     ///
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
     /// use bitbelay_tests::chi_squared::goodness_of_fit::Test;
     ///
     /// let hasher = RandomState::new();
     /// let provider = Box::new(AlphanumericProvider::new(10));
-    /// let test = Test::new(
+    /// let test = Test::try_new(
     ///     &hasher,
     ///     provider,
     ///     NonZeroUsize::try_from(2048).unwrap(),
+    ///     Bucketing::Modulo,
     ///     0.05,
-    /// );
+    /// )?;
     ///
     /// assert_eq!(test.build_hasher().hash_one("42"), hasher.hash_one("42"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn build_hasher(&self) -> &H {
         self.build_hasher
@@ -105,18 +154,22 @@ impl<'a, H: BuildHasher> Test<'a, H> {
     ///
     /// use bitbelay_providers::Provider as _;
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
     /// use bitbelay_tests::chi_squared::goodness_of_fit::Test;
     ///
     /// let hasher = RandomState::new();
     /// let provider = Box::new(AlphanumericProvider::new(10));
-    /// let test = Test::new(
+    /// let test = Test::try_new(
     ///     &hasher,
     ///     provider.clone(),
     ///     NonZeroUsize::try_from(2048).unwrap(),
+    ///     Bucketing::Modulo,
     ///     0.05,
-    /// );
+    /// )?;
     ///
     /// assert_eq!(test.provider().name(), provider.name());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn provider(&self) -> &dyn Provider {
         self.provider.as_ref()
@@ -131,23 +184,69 @@ impl<'a, H: BuildHasher> Test<'a, H> {
     /// use std::num::NonZeroUsize;
     ///
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
     /// use bitbelay_tests::chi_squared::goodness_of_fit::Test;
     ///
     /// let hasher = RandomState::new();
     /// let provider = Box::new(AlphanumericProvider::new(10));
-    /// let test = Test::new(
+    /// let test = Test::try_new(
     ///     &hasher,
     ///     provider,
     ///     NonZeroUsize::try_from(2048).unwrap(),
+    ///     Bucketing::Modulo,
     ///     0.05,
-    /// );
+    /// )?;
     ///
     /// assert_eq!(test.buckets().len(), 2048);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn buckets(&self) -> &Vec<usize> {
         &self.buckets
     }
 
+    /// Gets the bucketing strategy from the [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    /// use bitbelay_tests::chi_squared::goodness_of_fit::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let test = Test::try_new(
+    ///     &hasher,
+    ///     provider,
+    ///     NonZeroUsize::try_from(2048).unwrap(),
+    ///     Bucketing::LowBitMask,
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert_eq!(test.bucketing(), Bucketing::LowBitMask);
+    ///
+    /// // Only a power of two number of buckets is supported by a bitmask.
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let result = Test::try_new(
+    ///     &hasher,
+    ///     provider,
+    ///     NonZeroUsize::try_from(2047).unwrap(),
+    ///     Bucketing::LowBitMask,
+    ///     0.05,
+    /// );
+    ///
+    /// assert!(result.is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn bucketing(&self) -> Bucketing {
+        self.bucketing
+    }
+
     /// Gets the threshold from the [`Test`].
     ///
     /// # Examples
@@ -157,18 +256,22 @@ impl<'a, H: BuildHasher> Test<'a, H> {
     /// use std::num::NonZeroUsize;
     ///
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
     /// use bitbelay_tests::chi_squared::goodness_of_fit::Test;
     ///
     /// let provider = Box::new(AlphanumericProvider::new(10));
     /// let hasher = RandomState::new();
-    /// let test = Test::new(
+    /// let test = Test::try_new(
     ///     &hasher,
     ///     provider,
     ///     NonZeroUsize::try_from(2048).unwrap(),
+    ///     Bucketing::Modulo,
     ///     0.05,
-    /// );
+    /// )?;
     ///
     /// assert_eq!(test.threshold(), 0.05);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn threshold(&self) -> f64 {
         self.threshold
@@ -183,29 +286,71 @@ impl<'a, H: BuildHasher> Test<'a, H> {
     /// use std::num::NonZeroUsize;
     ///
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
     /// use bitbelay_tests::chi_squared::goodness_of_fit::Test;
     ///
     /// let provider = Box::new(AlphanumericProvider::new(10));
     /// let hasher = RandomState::new();
-    /// let mut test = Test::new(
+    /// let mut test = Test::try_new(
     ///     &hasher,
     ///     provider,
     ///     NonZeroUsize::try_from(2048).unwrap(),
+    ///     Bucketing::Modulo,
     ///     0.05,
-    /// );
+    /// )?;
     ///
     /// test.single_iteration();
     ///
     /// assert_eq!(test.buckets().iter().sum::<usize>(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn single_iteration(&mut self) {
         let data = *self.provider.provide(1).first().unwrap();
         let hash = self.build_hasher.hash_one(data);
-        let bucket = (hash as usize) % self.buckets.len();
+        // SAFETY: the buckets are created from a [`NonZeroUsize`], so this will
+        // always unwrap.
+        let bucket = self
+            .bucketing
+            .bucket(hash, NonZeroUsize::try_from(self.buckets.len()).unwrap());
 
         self.buckets[bucket] += 1;
     }
 
+    /// Clears the bucket counts so that the [`Test`] can be repeated with
+    /// fresh data from the provider.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    /// use bitbelay_tests::chi_squared::goodness_of_fit::Test;
+    ///
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::try_new(
+    ///     &hasher,
+    ///     provider,
+    ///     NonZeroUsize::try_from(256).unwrap(),
+    ///     Bucketing::Modulo,
+    ///     0.05,
+    /// )?;
+    ///
+    /// test.single_iteration();
+    /// test.reset();
+    ///
+    /// assert_eq!(test.buckets().iter().sum::<usize>(), 0);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn reset(&mut self) {
+        self.buckets.fill(0);
+    }
+
     /// Gets the p-value of the test.
     ///
     /// # Examples
@@ -215,16 +360,18 @@ impl<'a, H: BuildHasher> Test<'a, H> {
     /// use std::num::NonZeroUsize;
     ///
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
     /// use bitbelay_tests::chi_squared::goodness_of_fit::Test;
     ///
     /// let provider = Box::new(AlphanumericProvider::new(10));
     /// let hasher = RandomState::new();
-    /// let mut test = Test::new(
+    /// let mut test = Test::try_new(
     ///     &hasher,
     ///     provider,
     ///     NonZeroUsize::try_from(256).unwrap(),
+    ///     Bucketing::Modulo,
     ///     0.05,
-    /// );
+    /// )?;
     ///
     /// for _ in 0..10 {
     ///     test.single_iteration();
@@ -238,6 +385,8 @@ impl<'a, H: BuildHasher> Test<'a, H> {
     /// }
     ///
     /// assert!(test.p_value().unwrap() <= 1.0);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn p_value(&self) -> Option<f64> {
         UniformPearsonTest::goodness_of_fit(self.buckets())
@@ -294,7 +443,7 @@ impl<'a, H: BuildHasher> crate::r#trait::Test for Test<'a, H> {
 
         // SAFETY: all of the pieces of this [`Builder`] are hand-crafted to not
         // fail, so all of the below will unwrap.
-        get_report_base(self.provider.as_ref(), iterations)
+        get_report_base(self.provider.as_ref(), self.bucketing, iterations)
             .push_module(Module::new(
                 result,
                 "Failure to Reject the Null Hypothesis",
@@ -306,9 +455,45 @@ impl<'a, H: BuildHasher> crate::r#trait::Test for Test<'a, H> {
     }
 }
 
+/// Computes the number of iterations needed for a [`Test`] across `buckets`
+/// buckets to detect a deviation from a uniform distribution with an effect
+/// size (Cohen's _w_) of at least `effect_size` with probability `power` at
+/// the given `threshold` of statistical significance.
+///
+/// If the number of iterations cannot be computed (see
+/// [`power::goodness_of_fit()`]), [`None`] is returned.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroUsize;
+///
+/// use bitbelay_tests::chi_squared::goodness_of_fit;
+///
+/// let buckets = NonZeroUsize::try_from(64).unwrap();
+///
+/// let iterations = goodness_of_fit::required_iterations(buckets, 0.05, 0.05, 0.8).unwrap();
+/// assert!(iterations.get() > 10_000);
+///
+/// assert!(goodness_of_fit::required_iterations(buckets, 0.0, 0.05, 0.8).is_none());
+/// ```
+pub fn required_iterations(
+    buckets: NonZeroUsize,
+    effect_size: f64,
+    threshold: f64,
+    power: f64,
+) -> Option<NonZeroUsize> {
+    let iterations = power::goodness_of_fit(buckets.get(), effect_size, threshold, power)?;
+    NonZeroUsize::try_from(usize::try_from(iterations).ok()?).ok()
+}
+
 /// Populates the boilerplate report information within a
 /// [`Test`](section::Test).
-pub fn get_report_base(provider: &dyn Provider, iterations: usize) -> section::test::Builder {
+pub fn get_report_base(
+    provider: &dyn Provider,
+    bucketing: Bucketing,
+    iterations: usize,
+) -> section::test::Builder {
     let overview =
         "The chi-squared goodness of fit test assesses whether there is a significant difference \
          between an observed distribution of data and a chosen theoretical distribution.\n\nThe \
@@ -337,23 +522,25 @@ pub fn get_report_base(provider: &dyn Provider, iterations: usize) -> section::t
          of observed hashed values assigned to a set of buckets against (b) the expected \
          frequency if the buckets were assigned from a random, uniform distribution.";
 
-    let algorithm =
-        "For a specified hash function, data provider, and predefined number of buckets:\n\n(1) \
-         An array with a length matching the number of buckets is allocated. This represents the \
-         number of hashes that are assigned to each respective bucket. Each value in the array is \
-         initialized to 0 to indicate that no values have been assigned to any bucket yet.\n\n(2) \
-         The following process is carried out for a specified number of iterations:\n\n  * A \
-         random input is generated using the specified data provider.\n  * The input data is \
-         hashed and assigned to one of the buckets:\n    * The hash is divided by the number of \
-         buckets.\n    * The remainder of that operation is used as the bucket index.\n  * The \
-         value for the selected bucket index is incremented by 1.\n\nIn this way, the array now \
-         contains the frequency of hash values assigned to each bucket.\n\n(3) The expected \
-         frequency of values for each bucket under a normal distribution is simply the total \
-         number of iterations divided by the number of buckets.\n\n(4) The chi-squared statistic \
-         can now be calculated, and a p-value can be backed out by calculating the cumulative \
-         distribution function (CDF) for the chi-squared statistic. The CDF must be calculated \
-         for the chi-squared _distribution_ given the appropriate degrees of freedom for a \
-         goodness of fit test (in this case, `number of buckets - 1`).";
+    let algorithm = format!(
+        "For a specified hash function, data provider, bucketing strategy, and predefined number \
+         of buckets:\n\n(1) An array with a length matching the number of buckets is allocated. \
+         This represents the number of hashes that are assigned to each respective bucket. Each \
+         value in the array is initialized to 0 to indicate that no values have been assigned to \
+         any bucket yet.\n\n(2) The following process is carried out for a specified number of \
+         iterations:\n\n  * A random input is generated using the specified data provider.\n  * \
+         The input data is hashed and assigned to one of the buckets using the bucketing strategy \
+         (in this case, the bucket index is {}).\n  * The value for the selected bucket index is \
+         incremented by 1.\n\nIn this way, the array now contains the frequency of hash values \
+         assigned to each bucket.\n\n(3) The expected frequency of values for each bucket under a \
+         normal distribution is simply the total number of iterations divided by the number of \
+         buckets.\n\n(4) The chi-squared statistic can now be calculated, and a p-value can be \
+         backed out by calculating the cumulative distribution function (CDF) for the chi-squared \
+         statistic. The CDF must be calculated for the chi-squared _distribution_ given the \
+         appropriate degrees of freedom for a goodness of fit test (in this case, `number of \
+         buckets - 1`).",
+        bucketing.description()
+    );
 
     let interpretation =
         "Under this test design:\n\n* A p-value that is greater than or equal to the \
@@ -369,8 +556,9 @@ pub fn get_report_base(provider: &dyn Provider, iterations: usize) -> section::t
 
     test::Builder::default()
         .title(format!(
-            "Goodness of Fit / {} / {} iterations",
+            "Goodness of Fit / {} / {} bucketing / {} iterations",
             provider.name(),
+            bucketing,
             iterations
         ))
         .unwrap()
@@ -0,0 +1,470 @@
+//! Sub-field independence test.
+
+use std::hash::BuildHasher;
+
+use bitbelay_providers::Provider;
+use bitbelay_report::section;
+use bitbelay_report::section::test;
+use bitbelay_report::section::test::Module;
+use bitbelay_report::section::test::module;
+use bitbelay_statistics::chi_squared::ContingencyTest;
+use bitbelay_statistics::chi_squared::Statistic;
+use colored::Colorize;
+
+/// The largest supported width (in bits) of each sub-field.
+pub const MAX_FIELD_BITS: u32 = 12;
+
+/// The minimum mean expected count of the cells of the contingency table for
+/// the test to be conclusive.
+const MIN_EXPECTED: usize = 5;
+
+/// An error related to a [`Test`].
+#[derive(Debug)]
+pub enum Error {
+    /// The width of the sub-fields was zero or greater than
+    /// [`MAX_FIELD_BITS`].
+    InvalidFieldBits(u32),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidFieldBits(bits) => write!(
+                f,
+                "invalid field width: {bits} bits (must be between 1 and {MAX_FIELD_BITS})"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A [`Result`](std::result::Result) with an [`Error`].
+type Result<T> = std::result::Result<T, Error>;
+
+/// A chi-squared test of independence between the low and high sub-fields of
+/// output hashes.
+#[derive(Debug)]
+pub struct Test<'a, H: BuildHasher> {
+    /// The hash function builder.
+    build_hasher: &'a H,
+
+    /// The data provider.
+    provider: Box<dyn Provider>,
+
+    /// The width (in bits) of each sub-field.
+    field_bits: u32,
+
+    /// The contingency table (indexed first by the value of the low sub-field
+    /// and then by the value of the high sub-field).
+    table: Vec<Vec<usize>>,
+
+    /// The threshold of statistical signficance to use.
+    threshold: f64,
+}
+
+impl<'a, H: BuildHasher> Test<'a, H> {
+    /// Attempts to create a new [`Test`].
+    ///
+    /// The low sub-field contains the lowest `field_bits` bits of each hash,
+    /// while the high sub-field contains the highest `field_bits` bits.
+    ///
+    /// # Notes
+    ///
+    /// * If `field_bits` is zero or greater than [`MAX_FIELD_BITS`], an
+    ///   [`Error::InvalidFieldBits`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::chi_squared::independence::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::try_new(&hasher, Box::new(AlphanumericProvider::new(10)), 8, 0.05)?;
+    ///
+    /// assert_eq!(test.table().len(), 256);
+    ///
+    /// assert!(Test::try_new(&hasher, Box::new(AlphanumericProvider::new(10)), 0, 0.05).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_new(
+        build_hasher: &'a H,
+        provider: Box<dyn Provider>,
+        field_bits: u32,
+        threshold: f64,
+    ) -> Result<Self> {
+        if field_bits == 0 || field_bits > MAX_FIELD_BITS {
+            return Err(Error::InvalidFieldBits(field_bits));
+        }
+
+        let values = 1 << field_bits;
+
+        Ok(Self {
+            build_hasher,
+            provider,
+            field_bits,
+            table: vec![vec![0; values]; values],
+            threshold,
+        })
+    }
+
+    /// Gets the [`BuildHasher`] from the [`Test`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::BuildHasher as _;
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::chi_squared::independence::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::try_new(&hasher, Box::new(AlphanumericProvider::new(10)), 8, 0.05)?;
+    ///
+    /// assert_eq!(test.build_hasher().hash_one("42"), hasher.hash_one("42"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn build_hasher(&self) -> &H {
+        self.build_hasher
+    }
+
+    /// Gets the [`Provider`] from the [`Test`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::Provider as _;
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::chi_squared::independence::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let test = Test::try_new(&hasher, provider.clone(), 8, 0.05)?;
+    ///
+    /// assert_eq!(test.provider().name(), provider.name());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn provider(&self) -> &dyn Provider {
+        self.provider.as_ref()
+    }
+
+    /// Gets the width (in bits) of each sub-field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::chi_squared::independence::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::try_new(&hasher, Box::new(AlphanumericProvider::new(10)), 4, 0.05)?;
+    ///
+    /// assert_eq!(test.field_bits(), 4);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn field_bits(&self) -> u32 {
+        self.field_bits
+    }
+
+    /// Gets the contingency table (indexed first by the value of the low
+    /// sub-field and then by the value of the high sub-field).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::chi_squared::independence::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::try_new(&hasher, Box::new(AlphanumericProvider::new(10)), 4, 0.05)?;
+    ///
+    /// test.single_iteration();
+    ///
+    /// assert_eq!(test.table().iter().flatten().sum::<usize>(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn table(&self) -> &[Vec<usize>] {
+        &self.table
+    }
+
+    /// Gets the threshold of statistical significance for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::chi_squared::independence::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::try_new(&hasher, Box::new(AlphanumericProvider::new(10)), 8, 0.05)?;
+    ///
+    /// assert_eq!(test.threshold(), 0.05);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Gets the minimum number of iterations needed for the cells of the
+    /// contingency table to have a mean expected count of at least 5.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::chi_squared::independence::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::try_new(&hasher, Box::new(AlphanumericProvider::new(10)), 4, 0.05)?;
+    ///
+    /// assert_eq!(test.min_iterations(), 1280);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn min_iterations(&self) -> usize {
+        MIN_EXPECTED << (2 * self.field_bits)
+    }
+
+    /// Runs a single iteration of the test.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::chi_squared::independence::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::try_new(&hasher, Box::new(AlphanumericProvider::new(10)), 8, 0.05)?;
+    ///
+    /// test.single_iteration();
+    ///
+    /// assert_eq!(test.table().iter().flatten().sum::<usize>(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn single_iteration(&mut self) {
+        let data = *self.provider.provide(1).first().unwrap();
+        let hash = self.build_hasher.hash_one(data);
+
+        let mask = (1u64 << self.field_bits) - 1;
+        let low = (hash & mask) as usize;
+        let high = (hash >> (u64::BITS - self.field_bits)) as usize;
+
+        self.table[low][high] += 1;
+    }
+
+    /// Gets the p-value of the test.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::chi_squared::independence::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::try_new(&hasher, Box::new(AlphanumericProvider::new(10)), 4, 0.05)?;
+    ///
+    /// assert!(test.p_value().is_none());
+    ///
+    /// for _ in 0..10_000 {
+    ///     test.single_iteration();
+    /// }
+    ///
+    /// assert!(test.p_value().unwrap() <= 1.0);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn p_value(&self) -> Option<f64> {
+        ContingencyTest::independence(&self.table, Statistic::Pearson)
+    }
+}
+
+impl<'a, H: BuildHasher> crate::r#trait::Test for Test<'a, H> {
+    fn title(&self) -> &'static str {
+        "Sub-field Independence"
+    }
+
+    fn report_section(&self) -> bitbelay_report::section::Test {
+        let iterations = self.table.iter().flatten().sum::<usize>();
+
+        let (result, value, details) = if let Some(p_value) = self.p_value() {
+            if p_value > self.threshold {
+                (
+                    module::Result::Pass,
+                    Some(format!("{:.2}", p_value)),
+                    format!(
+                        "The resulting p-value of {:.2} was greater than (and, thus, failed to \
+                         reach) the predetermined threshold of statistical significance set at \
+                         {:.2}. As such, the null hypothesis that the low and high {}-bit \
+                         sub-fields of the hashes are independent **cannot** be rejected.",
+                        p_value, self.threshold, self.field_bits
+                    ),
+                )
+            } else {
+                (
+                    module::Result::Fail,
+                    Some(format!("{:.2}", p_value)),
+                    format!(
+                        "The resulting p-value of {:.2} was less than (and, thus, reached) the \
+                         predetermined threshold of statistical significance set at {:.2}. As \
+                         such, the null hypothesis that the low and high {}-bit sub-fields of the \
+                         hashes are independent **is** rejected.",
+                        p_value, self.threshold, self.field_bits
+                    ),
+                )
+            }
+        } else {
+            (
+                module::Result::Inconclusive,
+                None,
+                format!(
+                    "The p-value was not able to be computed. At least {} iterations are \
+                     generally needed for the cells of the contingency table to have a mean \
+                     expected count of {} (only {} were run).",
+                    self.min_iterations(),
+                    MIN_EXPECTED,
+                    iterations
+                ),
+            )
+        };
+
+        // SAFETY: all of the pieces of this [`Builder`] are hand-crafted to not
+        // fail, so all of the below will unwrap.
+        get_report_base(self.provider.as_ref(), self.field_bits, iterations)
+            .push_module(Module::new(
+                result,
+                "Failure to Reject the Null Hypothesis",
+                value,
+                Some(details),
+            ))
+            .try_build()
+            .unwrap()
+    }
+}
+
+/// Populates the boilerplate report information within a
+/// [`Test`](section::Test).
+pub fn get_report_base(
+    provider: &dyn Provider,
+    field_bits: u32,
+    iterations: usize,
+) -> section::test::Builder {
+    let overview = format!(
+        "The sub-field independence test assesses whether the lowest {field_bits} bits and the \
+         highest {field_bits} bits of each output hash are statistically independent of one \
+         another."
+    );
+
+    let relation = "Hash tables commonly derive bucket indices from either the low bits (by \
+                    masking) or the high bits (by shifting) of a hash, and some data structures \
+                    use both at once (e.g., one for the bucket and one as a tag). If the value of \
+                    one sub-field is informative of the value of the other, then those uses are \
+                    not independent, and keys that collide in one are more likely to collide in \
+                    the other.";
+
+    let values = 1usize << field_bits;
+
+    let algorithm = format!(
+        "(1) A {values}x{values} contingency table of counters is initialized to 0.\n\n(2) For a \
+         number of iterations, a random input is generated using the data provider and hashed, \
+         and the counter at the row given by the low {field_bits} bits and the column given by \
+         the high {field_bits} bits of the hash is incremented by 1.\n\n(3) The expected count of \
+         each cell under independence is computed as `row total * column total / total`.\n\n(4) A \
+         Pearson chi-squared test of independence is performed on the contingency table (with \
+         `(rows - 1)(columns - 1)` degrees of freedom)."
+    );
+
+    let interpretation = "Under this test design:\n\n* A p-value that is greater than or equal to \
+                          the pre-determined signficance value (typically, 0.05) is **good**, as \
+                          there is no significant evidence that the sub-fields are \
+                          dependent.\n\n* A p-value that is less than the pre-determined \
+                          signficance value is **bad**, as the value of one sub-field is likely \
+                          informative of the value of the other.";
+
+    let sources = "* https://en.wikipedia.org/wiki/Contingency_table\n* https://en.wikipedia.org/wiki/Pearson%27s_chi-squared_test#Testing_for_statistical_independence";
+
+    test::Builder::default()
+        .title(format!(
+            "Sub-field Independence / {} / {} bits / {} iterations",
+            provider.name(),
+            field_bits,
+            iterations
+        ))
+        .unwrap()
+        .description(format!(
+            "{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}",
+            "Overview".italic(),
+            overview,
+            "Relation to Hashing".italic(),
+            relation,
+            "Algorithm".italic(),
+            algorithm,
+            "Interpretation".italic(),
+            interpretation,
+            "Sources".italic(),
+            sources
+        ))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::BuildHasherDefault;
+    use std::hash::Hasher;
+
+    use bitbelay_providers::ascii::AlphanumericProvider;
+
+    use super::*;
+
+    /// A hasher whose highest four bits mirror its lowest four bits.
+    #[derive(Debug, Default)]
+    struct Mirrored(std::hash::DefaultHasher);
+
+    impl Hasher for Mirrored {
+        fn finish(&self) -> u64 {
+            let hash = self.0.finish() & (u64::MAX >> 4);
+            hash | ((hash & 0xF) << 60)
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            self.0.write(bytes);
+        }
+    }
+
+    #[test]
+    fn dependent_fields_fail() {
+        let hasher = BuildHasherDefault::<Mirrored>::default();
+        let mut test =
+            Test::try_new(&hasher, Box::new(AlphanumericProvider::new(10)), 4, 0.05).unwrap();
+
+        for _ in 0..10_000 {
+            test.single_iteration();
+        }
+
+        assert!(test.p_value().unwrap() < 0.05);
+    }
+}
@@ -0,0 +1,416 @@
+//! Hamming weight (popcount) test.
+
+use std::hash::BuildHasher;
+
+use bitbelay_providers::Provider;
+use bitbelay_report::section;
+use bitbelay_report::section::test;
+use bitbelay_report::section::test::Module;
+use bitbelay_report::section::test::module;
+use bitbelay_statistics::chi_squared::MultinomialTest;
+use bitbelay_statistics::chi_squared::Statistic;
+use colored::Colorize;
+use statrs::distribution::Binomial;
+use statrs::distribution::Discrete as _;
+
+/// The number of bits in the output hash.
+const BITS: usize = u64::BITS as usize;
+
+/// A chi-squared goodness of fit test of the Hamming weights (popcounts) of
+/// output hashes against a binomial distribution.
+#[derive(Debug)]
+pub struct Test<'a, H: BuildHasher> {
+    /// The hash function builder.
+    build_hasher: &'a H,
+
+    /// The data provider.
+    provider: Box<dyn Provider>,
+
+    /// The number of hashes observed with each Hamming weight (indexed by the
+    /// Hamming weight).
+    weights: Vec<usize>,
+
+    /// The threshold of statistical signficance to use.
+    threshold: f64,
+}
+
+impl<'a, H: BuildHasher> Test<'a, H> {
+    /// Creates a new [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::chi_squared::popcount::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::new(&hasher, Box::new(AlphanumericProvider::new(10)), 0.05);
+    ///
+    /// assert_eq!(test.weights().len(), 65);
+    /// ```
+    pub fn new(build_hasher: &'a H, provider: Box<dyn Provider>, threshold: f64) -> Self {
+        Self {
+            build_hasher,
+            provider,
+            weights: vec![0; BITS + 1],
+            threshold,
+        }
+    }
+
+    /// Gets the [`BuildHasher`] from the [`Test`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::BuildHasher as _;
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::chi_squared::popcount::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::new(&hasher, Box::new(AlphanumericProvider::new(10)), 0.05);
+    ///
+    /// assert_eq!(test.build_hasher().hash_one("42"), hasher.hash_one("42"));
+    /// ```
+    pub fn build_hasher(&self) -> &H {
+        self.build_hasher
+    }
+
+    /// Gets the [`Provider`] from the [`Test`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::Provider as _;
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::chi_squared::popcount::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let test = Test::new(&hasher, provider.clone(), 0.05);
+    ///
+    /// assert_eq!(test.provider().name(), provider.name());
+    /// ```
+    pub fn provider(&self) -> &dyn Provider {
+        self.provider.as_ref()
+    }
+
+    /// Gets the number of hashes observed with each Hamming weight (indexed by
+    /// the Hamming weight).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::chi_squared::popcount::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(&hasher, Box::new(AlphanumericProvider::new(10)), 0.05);
+    ///
+    /// test.single_iteration();
+    ///
+    /// assert_eq!(test.weights().iter().sum::<usize>(), 1);
+    /// ```
+    pub fn weights(&self) -> &[usize] {
+        &self.weights
+    }
+
+    /// Gets the threshold of statistical significance for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::chi_squared::popcount::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::new(&hasher, Box::new(AlphanumericProvider::new(10)), 0.05);
+    ///
+    /// assert_eq!(test.threshold(), 0.05);
+    /// ```
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Runs a single iteration of the test.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::chi_squared::popcount::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(&hasher, Box::new(AlphanumericProvider::new(10)), 0.05);
+    ///
+    /// test.single_iteration();
+    ///
+    /// assert_eq!(test.weights().iter().sum::<usize>(), 1);
+    /// ```
+    pub fn single_iteration(&mut self) {
+        let data = *self.provider.provide(1).first().unwrap();
+        let hash = self.build_hasher.hash_one(data);
+
+        self.weights[hash.count_ones() as usize] += 1;
+    }
+
+    /// Gets the expected number of hashes with each Hamming weight (indexed by
+    /// the Hamming weight) under a Binomial(64, 0.5) distribution.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::chi_squared::popcount::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(&hasher, Box::new(AlphanumericProvider::new(10)), 0.05);
+    ///
+    /// for _ in 0..1000 {
+    ///     test.single_iteration();
+    /// }
+    ///
+    /// let expected = test.expected();
+    /// assert!((expected.iter().sum::<f64>() - 1000.0).abs() < 1e-6);
+    /// assert!(expected[32] > expected[31]);
+    /// ```
+    pub fn expected(&self) -> Vec<f64> {
+        let iterations = self.weights.iter().sum::<usize>() as f64;
+
+        // SAFETY: a probability of 0.5 is always valid.
+        let distribution = Binomial::new(0.5, BITS as u64).unwrap();
+
+        (0..=BITS as u64)
+            .map(|weight| distribution.pmf(weight) * iterations)
+            .collect()
+    }
+
+    /// Gets the p-value of the test.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::chi_squared::popcount::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(&hasher, Box::new(AlphanumericProvider::new(10)), 0.05);
+    ///
+    /// assert!(test.p_value().is_none());
+    ///
+    /// for _ in 0..1000 {
+    ///     test.single_iteration();
+    /// }
+    ///
+    /// assert!(test.p_value().unwrap() <= 1.0);
+    /// ```
+    pub fn p_value(&self) -> Option<f64> {
+        MultinomialTest::goodness_of_fit(&self.weights, &self.expected(), Statistic::Pearson)
+    }
+}
+
+impl<'a, H: BuildHasher> crate::r#trait::Test for Test<'a, H> {
+    fn title(&self) -> &'static str {
+        "Hamming Weight"
+    }
+
+    fn report_section(&self) -> bitbelay_report::section::Test {
+        let iterations = self.weights.iter().sum::<usize>();
+
+        let (result, value, summary) = if let Some(p_value) = self.p_value() {
+            if p_value > self.threshold {
+                (
+                    module::Result::Pass,
+                    Some(format!("{:.2}", p_value)),
+                    format!(
+                        "The resulting p-value of {:.2} was greater than (and, thus, failed to \
+                         reach) the predetermined threshold of statistical significance set at \
+                         {:.2}. As such, the null hypothesis that the Hamming weights of the \
+                         hashes follow a Binomial(64, 0.5) distribution **cannot** be rejected.",
+                        p_value, self.threshold
+                    ),
+                )
+            } else {
+                (
+                    module::Result::Fail,
+                    Some(format!("{:.2}", p_value)),
+                    format!(
+                        "The resulting p-value of {:.2} was less than (and, thus, reached) the \
+                         predetermined threshold of statistical significance set at {:.2}. As \
+                         such, the null hypothesis that the Hamming weights of the hashes follow \
+                         a Binomial(64, 0.5) distribution **is** rejected.",
+                        p_value, self.threshold
+                    ),
+                )
+            }
+        } else {
+            (
+                module::Result::Inconclusive,
+                None,
+                String::from(
+                    "The p-value was not able to be computed (try increasing the number of \
+                     iterations).",
+                ),
+            )
+        };
+
+        let details = if iterations > 0 {
+            let total = self
+                .weights
+                .iter()
+                .enumerate()
+                .map(|(weight, count)| weight * count)
+                .sum::<usize>();
+            let mean = total as f64 / iterations as f64;
+
+            let variance = self
+                .weights
+                .iter()
+                .enumerate()
+                .map(|(weight, count)| (weight as f64 - mean).powi(2) * *count as f64)
+                .sum::<f64>()
+                / iterations as f64;
+
+            format!(
+                "{}\n\n{}\n\nMean: {:.2} (expected {:.2})\nVariance: {:.2} (expected {:.2})",
+                summary,
+                "Hamming Weights".italic(),
+                mean,
+                BITS as f64 / 2.0,
+                variance,
+                BITS as f64 / 4.0
+            )
+        } else {
+            summary
+        };
+
+        // SAFETY: all of the pieces of this [`Builder`] are hand-crafted to not
+        // fail, so all of the below will unwrap.
+        get_report_base(self.provider.as_ref(), iterations)
+            .push_module(Module::new(
+                result,
+                "Failure to Reject the Null Hypothesis",
+                value,
+                Some(details),
+            ))
+            .try_build()
+            .unwrap()
+    }
+}
+
+/// Populates the boilerplate report information within a
+/// [`Test`](section::Test).
+pub fn get_report_base(provider: &dyn Provider, iterations: usize) -> section::test::Builder {
+    let overview = "The Hamming weight test assesses whether the number of set bits (the \
+                    'popcount') of each output hash follows the distribution expected of random \
+                    64-bit values.";
+
+    let relation = "If every output bit of a hash function is independently set with a \
+                    probability of 50%, then the Hamming weight of the output follows a \
+                    Binomial(64, 0.5) distribution. Hash functions whose output bits are \
+                    individually balanced can still fail this test if their bits are dependent on \
+                    one another (e.g., outputs that tend to have either many or few set bits), \
+                    which reduces the effective size of the output space.";
+
+    let algorithm = "(1) An array of 65 counters (one for each possible Hamming weight from 0 to \
+                     64) is initialized to 0.\n\n(2) For a number of iterations, a random input \
+                     is generated using the data provider and hashed, and the counter for the \
+                     Hamming weight of the hash is incremented by 1.\n\n(3) The expected number \
+                     of hashes with each Hamming weight is computed from the probability mass \
+                     function of a Binomial(64, 0.5) distribution.\n\n(4) Because the tails of \
+                     the binomial distribution have very small expected frequencies, adjacent \
+                     Hamming weights are pooled until every pooled bin has an expected frequency \
+                     of at least 5.\n\n(5) A chi-squared goodness of fit test is performed \
+                     between the observed and expected frequencies of the pooled bins (with \
+                     `number of pooled bins - 1` degrees of freedom).";
+
+    let interpretation = "Under this test design:\n\n* A p-value that is greater than or equal to \
+                          the pre-determined signficance value (typically, 0.05) is **good**, as \
+                          there is no significant difference between the observed Hamming weights \
+                          and a Binomial(64, 0.5) distribution.\n\n* A p-value that is less than \
+                          the pre-determined signficance value is **bad**, as there is a \
+                          significant difference between the observed Hamming weights and a \
+                          Binomial(64, 0.5) distribution. Comparing the observed mean and \
+                          variance against their expected values can help to explain why.";
+
+    let sources = "* https://en.wikipedia.org/wiki/Hamming_weight\n* https://en.wikipedia.org/wiki/Pearson%27s_chi-squared_test#Chi-squared_goodness_of_fit_test";
+
+    test::Builder::default()
+        .title(format!(
+            "Hamming Weight / {} / {} iterations",
+            provider.name(),
+            iterations
+        ))
+        .unwrap()
+        .description(format!(
+            "{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}",
+            "Overview".italic(),
+            overview,
+            "Relation to Hashing".italic(),
+            relation,
+            "Algorithm".italic(),
+            algorithm,
+            "Interpretation".italic(),
+            interpretation,
+            "Sources".italic(),
+            sources
+        ))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::BuildHasherDefault;
+    use std::hash::Hasher;
+
+    use bitbelay_providers::ascii::AlphanumericProvider;
+
+    use super::*;
+
+    /// A hasher whose output bits are individually balanced but whose outputs
+    /// are either all zeros or all ones.
+    #[derive(Debug, Default)]
+    struct AllOrNothing(std::hash::DefaultHasher);
+
+    impl Hasher for AllOrNothing {
+        fn finish(&self) -> u64 {
+            if self.0.finish() & 1 == 0 {
+                0
+            } else {
+                u64::MAX
+            }
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            self.0.write(bytes);
+        }
+    }
+
+    #[test]
+    fn dependent_bits_fail() {
+        let hasher = BuildHasherDefault::<AllOrNothing>::default();
+        let mut test = Test::new(&hasher, Box::new(AlphanumericProvider::new(10)), 0.05);
+
+        for _ in 0..1_000 {
+            test.single_iteration();
+        }
+
+        assert_eq!(test.weights()[0] + test.weights()[BITS], 1_000);
+        assert!(test.p_value().unwrap() < 0.05);
+    }
+}
@@ -0,0 +1,642 @@
+//! Goodness of fit sweep across table sizes.
+
+use std::hash::BuildHasher;
+use std::num::NonZeroUsize;
+
+use bitbelay_providers::Provider;
+use bitbelay_report::section;
+use bitbelay_report::section::test;
+use bitbelay_report::section::test::Module;
+use bitbelay_report::section::test::module;
+use bitbelay_statistics::chi_squared::UniformPearsonTest;
+use bitbelay_statistics::correction::Procedure;
+use colored::Colorize;
+
+use crate::bucketing::Bucketing;
+
+/// The procedure used to correct for the number of table sizes tested.
+const CORRECTION: Procedure = Procedure::Holm;
+
+/// An error related to a [`Test`].
+#[derive(Debug)]
+pub enum Error {
+    /// No bucket counts were provided to sweep over.
+    NoBucketCounts,
+
+    /// The bucketing strategy does not support one of the bucket counts.
+    UnsupportedBuckets(Bucketing, NonZeroUsize),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::NoBucketCounts => write!(f, "at least one bucket count must be provided"),
+            Error::UnsupportedBuckets(bucketing, buckets) => write!(
+                f,
+                "{bucketing} bucketing does not support {buckets} buckets (the number of buckets \
+                 must be a power of two)"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A [`Result`](std::result::Result) with an [`Error`].
+type Result<T> = std::result::Result<T, Error>;
+
+/// The result of a goodness of fit test for a single table size within a
+/// [`Test`].
+#[derive(Debug)]
+pub struct Entry {
+    /// The number of buckets.
+    pub buckets: NonZeroUsize,
+
+    /// The p-value of the goodness of fit test (or [`None`] if a p-value could
+    /// not be computed, generally because there were not enough iterations
+    /// for the number of buckets).
+    pub p_value: Option<f64>,
+}
+
+/// A chi-squared goodness of fit test repeated across many table sizes.
+///
+/// Hashes are computed once and then reused for every number of buckets in
+/// the sweep, so the results for each table size are directly comparable.
+#[derive(Debug)]
+pub struct Test<'a, H: BuildHasher> {
+    /// The hash function builder.
+    build_hasher: &'a H,
+
+    /// The data provider.
+    provider: Box<dyn Provider>,
+
+    /// The hashes computed so far.
+    hashes: Vec<u64>,
+
+    /// The numbers of buckets to test.
+    bucket_counts: Vec<NonZeroUsize>,
+
+    /// The strategy used to map each hash to a bucket.
+    bucketing: Bucketing,
+
+    /// The threshold of statistical signficance to use.
+    threshold: f64,
+}
+
+impl<'a, H: BuildHasher> Test<'a, H> {
+    /// Attempts to create a new [`Test`].
+    ///
+    /// # Notes
+    ///
+    /// * If `bucket_counts` is empty, an [`Error::NoBucketCounts`] is returned.
+    /// * If `bucketing` does not [support](Bucketing::supports) every number of
+    ///   buckets provided, an [`Error::UnsupportedBuckets`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::BuildHasher as _;
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::Provider as _;
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    /// use bitbelay_tests::chi_squared::sweep::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let test = Test::try_new(
+    ///     &hasher,
+    ///     provider.clone(),
+    ///     vec![
+    ///         NonZeroUsize::try_from(16).unwrap(),
+    ///         NonZeroUsize::try_from(61).unwrap(),
+    ///     ],
+    ///     Bucketing::Modulo,
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert_eq!(test.build_hasher().hash_one("42"), hasher.hash_one("42"));
+    /// assert_eq!(test.provider().name(), provider.name());
+    /// assert_eq!(test.bucket_counts().len(), 2);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_new(
+        build_hasher: &'a H,
+        provider: Box<dyn Provider>,
+        bucket_counts: Vec<NonZeroUsize>,
+        bucketing: Bucketing,
+        threshold: f64,
+    ) -> Result<Self> {
+        if bucket_counts.is_empty() {
+            return Err(Error::NoBucketCounts);
+        }
+
+        if let Some(buckets) = bucket_counts.iter().find(|b| !bucketing.supports(**b)) {
+            return Err(Error::UnsupportedBuckets(bucketing, *buckets));
+        }
+
+        Ok(Self {
+            build_hasher,
+            provider,
+            hashes: Vec::new(),
+            bucket_counts,
+            bucketing,
+            threshold,
+        })
+    }
+
+    /// Gets the [`BuildHasher`] from the [`Test`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::BuildHasher as _;
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    /// use bitbelay_tests::chi_squared::sweep::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let test = Test::try_new(
+    ///     &hasher,
+    ///     provider,
+    ///     vec![NonZeroUsize::try_from(16).unwrap()],
+    ///     Bucketing::Modulo,
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert_eq!(test.build_hasher().hash_one("42"), hasher.hash_one("42"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn build_hasher(&self) -> &H {
+        self.build_hasher
+    }
+
+    /// Gets the [`Provider`] from the [`Test`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::Provider as _;
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    /// use bitbelay_tests::chi_squared::sweep::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let test = Test::try_new(
+    ///     &hasher,
+    ///     provider.clone(),
+    ///     vec![NonZeroUsize::try_from(16).unwrap()],
+    ///     Bucketing::Modulo,
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert_eq!(test.provider().name(), provider.name());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn provider(&self) -> &dyn Provider {
+        self.provider.as_ref()
+    }
+
+    /// Gets the hashes computed within the [`Test`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    /// use bitbelay_tests::chi_squared::sweep::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let mut test = Test::try_new(
+    ///     &hasher,
+    ///     provider,
+    ///     vec![NonZeroUsize::try_from(16).unwrap()],
+    ///     Bucketing::Modulo,
+    ///     0.05,
+    /// )?;
+    ///
+    /// test.single_iteration();
+    /// assert_eq!(test.hashes().len(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn hashes(&self) -> &[u64] {
+        &self.hashes
+    }
+
+    /// Gets the numbers of buckets swept within the [`Test`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    /// use bitbelay_tests::chi_squared::sweep::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let test = Test::try_new(
+    ///     &hasher,
+    ///     provider,
+    ///     vec![
+    ///         NonZeroUsize::try_from(16).unwrap(),
+    ///         NonZeroUsize::try_from(32).unwrap(),
+    ///     ],
+    ///     Bucketing::LowBitMask,
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert_eq!(test.bucket_counts()[1].get(), 32);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn bucket_counts(&self) -> &[NonZeroUsize] {
+        &self.bucket_counts
+    }
+
+    /// Gets the bucketing strategy from the [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    /// use bitbelay_tests::chi_squared::sweep::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let test = Test::try_new(
+    ///     &hasher,
+    ///     provider,
+    ///     vec![NonZeroUsize::try_from(16).unwrap()],
+    ///     Bucketing::HighBitShift,
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert_eq!(test.bucketing(), Bucketing::HighBitShift);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn bucketing(&self) -> Bucketing {
+        self.bucketing
+    }
+
+    /// Gets the threshold from the [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    /// use bitbelay_tests::chi_squared::sweep::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let test = Test::try_new(
+    ///     &hasher,
+    ///     provider,
+    ///     vec![NonZeroUsize::try_from(16).unwrap()],
+    ///     Bucketing::Modulo,
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert_eq!(test.threshold(), 0.05);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Performs a single iteration of the test.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    /// use bitbelay_tests::chi_squared::sweep::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let mut test = Test::try_new(
+    ///     &hasher,
+    ///     provider,
+    ///     vec![NonZeroUsize::try_from(16).unwrap()],
+    ///     Bucketing::Modulo,
+    ///     0.05,
+    /// )?;
+    ///
+    /// test.single_iteration();
+    ///
+    /// assert_eq!(test.hashes().len(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn single_iteration(&mut self) {
+        let data = *self.provider.provide(1).first().unwrap();
+        self.hashes.push(self.build_hasher.hash_one(data));
+    }
+
+    /// Computes the number of hashes assigned to each bucket for a given
+    /// number of buckets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    /// use bitbelay_tests::chi_squared::sweep::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let mut test = Test::try_new(
+    ///     &hasher,
+    ///     provider,
+    ///     vec![NonZeroUsize::try_from(16).unwrap()],
+    ///     Bucketing::Modulo,
+    ///     0.05,
+    /// )?;
+    ///
+    /// for _ in 0..100 {
+    ///     test.single_iteration();
+    /// }
+    ///
+    /// let buckets = test.buckets(NonZeroUsize::try_from(16).unwrap());
+    /// assert_eq!(buckets.len(), 16);
+    /// assert_eq!(buckets.iter().sum::<usize>(), 100);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn buckets(&self, buckets: NonZeroUsize) -> Vec<usize> {
+        let mut counts = vec![0usize; buckets.get()];
+
+        for hash in &self.hashes {
+            counts[self.bucketing.bucket(*hash, buckets)] += 1;
+        }
+
+        counts
+    }
+
+    /// Gets the goodness of fit result for each number of buckets in the
+    /// sweep.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    /// use bitbelay_tests::chi_squared::sweep::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let mut test = Test::try_new(
+    ///     &hasher,
+    ///     provider,
+    ///     vec![
+    ///         NonZeroUsize::try_from(16).unwrap(),
+    ///         NonZeroUsize::try_from(4096).unwrap(),
+    ///     ],
+    ///     Bucketing::Modulo,
+    ///     0.05,
+    /// )?;
+    ///
+    /// for _ in 0..1000 {
+    ///     test.single_iteration();
+    /// }
+    ///
+    /// let results = test.results();
+    /// assert!(results[0].p_value.unwrap() <= 1.0);
+    ///
+    /// // Not enough data to compute a p-value.
+    /// assert!(results[1].p_value.is_none());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn results(&self) -> Vec<Entry> {
+        self.bucket_counts
+            .iter()
+            .map(|buckets| Entry {
+                buckets: *buckets,
+                p_value: UniformPearsonTest::goodness_of_fit(&self.buckets(*buckets)),
+            })
+            .collect()
+    }
+}
+
+impl<'a, H: BuildHasher> crate::r#trait::Test for Test<'a, H> {
+    fn title(&self) -> &'static str {
+        "Goodness of Fit Sweep"
+    }
+
+    fn report_section(&self) -> bitbelay_report::section::Test {
+        let results = self.results();
+
+        // The p-values are adjusted across every table size where a p-value could be
+        // computed, as testing many table sizes at once makes an occasional raw
+        // p-value below the threshold likely even for an ideal hash function.
+        let raw = results
+            .iter()
+            .filter_map(|entry| entry.p_value)
+            .collect::<Vec<_>>();
+        let mut adjusted = CORRECTION.adjust(&raw).into_iter();
+        let adjusted = results
+            .iter()
+            .map(|entry| entry.p_value.and_then(|_| adjusted.next()))
+            .collect::<Vec<_>>();
+
+        let failed = results
+            .iter()
+            .zip(&adjusted)
+            .filter(|(_, adjusted)| matches!(adjusted, Some(p) if *p <= self.threshold))
+            .map(|(entry, _)| entry.buckets.to_string())
+            .collect::<Vec<_>>();
+        let computed = raw.len();
+
+        let (result, summary) = if !failed.is_empty() {
+            (
+                module::Result::Fail,
+                format!(
+                    "After applying the {} across {} table sizes, the null hypothesis that the \
+                     observed data follows a random, uniform distribution **is** rejected at a \
+                     threshold of {:.2} for the following numbers of buckets: {}.",
+                    CORRECTION.name(),
+                    computed,
+                    self.threshold,
+                    failed.join(", ")
+                ),
+            )
+        } else if computed > 0 {
+            (
+                module::Result::Pass,
+                format!(
+                    "After applying the {} across {} table sizes, the null hypothesis that the \
+                     observed data follows a random, uniform distribution **cannot** be rejected \
+                     at a threshold of {:.2} for any number of buckets where a p-value could be \
+                     computed.",
+                    CORRECTION.name(),
+                    computed,
+                    self.threshold
+                ),
+            )
+        } else {
+            (
+                module::Result::Inconclusive,
+                String::from(
+                    "A p-value was not able to be computed for any number of buckets (try \
+                     increasing the number of iterations).",
+                ),
+            )
+        };
+
+        let mut details = format!(
+            "{}\n\n{}\n\n  {:>10}  {:>8}  {:>8}  Result",
+            summary,
+            "P-values by Table Size".italic(),
+            "Buckets",
+            "P-value",
+            "Adjusted"
+        );
+
+        for (entry, adjusted) in results.iter().zip(&adjusted) {
+            let (p_value, adjusted, result) = match (entry.p_value, adjusted) {
+                (Some(p), Some(adjusted)) => (
+                    format!("{:.4}", p),
+                    format!("{:.4}", adjusted),
+                    if *adjusted > self.threshold {
+                        module::Result::Pass
+                    } else {
+                        module::Result::Fail
+                    },
+                ),
+                _ => (
+                    String::from("N/A"),
+                    String::from("N/A"),
+                    module::Result::Inconclusive,
+                ),
+            };
+
+            details.push_str(&format!(
+                "\n  {:>10}  {:>8}  {:>8}  {:#}",
+                entry.buckets, p_value, adjusted, result
+            ));
+        }
+
+        // SAFETY: all of the pieces of this [`Builder`] are hand-crafted to not
+        // fail, so all of the below will unwrap.
+        get_report_base(self.provider.as_ref(), self.bucketing, self.hashes.len())
+            .push_module(Module::new(
+                result,
+                "Failure to Reject the Null Hypothesis (All Table Sizes)",
+                Some(format!(
+                    "{}/{} passed",
+                    computed - failed.len(),
+                    results.len()
+                )),
+                Some(details),
+            ))
+            .try_build()
+            .unwrap()
+    }
+}
+
+/// Populates the boilerplate report information within a
+/// [`Test`](section::Test).
+pub fn get_report_base(
+    provider: &dyn Provider,
+    bucketing: Bucketing,
+    iterations: usize,
+) -> section::test::Builder {
+    let overview = "The goodness of fit sweep repeats the chi-squared goodness of fit test for \
+                    many different numbers of buckets (table sizes) using the same set of hashed \
+                    values. Doing so reveals the particular table sizes where a hash function \
+                    stops distributing values uniformly.";
+
+    let algorithm = format!(
+        "For a specified hash function, data provider, bucketing strategy, and set of table \
+         sizes:\n\n(1) Hashes are computed for random inputs generated by the data provider for \
+         the specified number of iterations. These hashes are retained for the remainder of the \
+         test.\n\n(2) For each table size, every retained hash is assigned to a bucket using the \
+         bucketing strategy (in this case, the bucket index is {}), and the frequency of hashes \
+         in each bucket is tallied.\n\n(3) A chi-squared goodness of fit test is performed on the \
+         bucket frequencies for each table size against a theoretical uniform \
+         distribution.\n\n(4) Because many table sizes are tested at once, the p-values are \
+         adjusted using the {} (which controls the {}) before being compared against the chosen \
+         threshold of statistical significance.",
+        bucketing.description(),
+        CORRECTION.name(),
+        CORRECTION.controls()
+    );
+
+    let interpretation =
+        "* Each table size is reported with its raw and adjusted p-values. An adjusted p-value \
+         that is less than or equal to the pre-determined significance value is **bad**, as it \
+         indicates a significant difference between the observed distribution and a theoretical \
+         uniform distribution for that table size.\n\n* Table sizes where not enough hashes were \
+         computed to expect at least five hashes per bucket are reported as inconclusive.\n\n* \
+         Note that, because many tests are performed, an occasional raw p-value below the \
+         threshold is expected even for an ideal hash function, which is why the verdict is based \
+         on the adjusted p-values. Failures that cluster around particular table sizes (e.g., \
+         only large powers of two) are much more concerning than isolated failures.";
+
+    let sources = format!(
+        "* https://en.wikipedia.org/wiki/Pearson%27s_chi-squared_test#Chi-squared_goodness_of_fit_test\n* {}",
+        CORRECTION.source()
+    );
+
+    test::Builder::default()
+        .title(format!(
+            "Goodness of Fit Sweep / {} / {} bucketing / {} iterations",
+            provider.name(),
+            bucketing,
+            iterations
+        ))
+        .unwrap()
+        .description(format!(
+            "{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}",
+            "Overview".italic(),
+            overview,
+            "Algorithm".italic(),
+            algorithm,
+            "Interpretation".italic(),
+            interpretation,
+            "Sources".italic(),
+            sources
+        ))
+        .unwrap()
+}
@@ -0,0 +1,397 @@
+//! Empirical distribution function uniformity test.
+
+use std::hash::BuildHasher;
+
+use bitbelay_providers::Provider;
+use bitbelay_report::section;
+use bitbelay_report::section::test;
+use bitbelay_report::section::test::Module;
+use bitbelay_report::section::test::module;
+use bitbelay_statistics::kolmogorov_smirnov::UniformAndersonDarlingTest;
+use bitbelay_statistics::kolmogorov_smirnov::UniformKolmogorovSmirnovTest;
+use bitbelay_statistics::kolmogorov_smirnov::uniform_statistic;
+use colored::Colorize;
+
+/// The number of bits of each hash that are kept when mapping it to the unit
+/// interval (one less than the number of bits in the mantissa of an [`f64`],
+/// so that the midpoint of every sub-interval is exactly representable).
+const MANTISSA_BITS: u32 = f64::MANTISSA_DIGITS - 1;
+
+/// Maps a hash to the open interval `(0, 1)`.
+///
+/// The top [`MANTISSA_BITS`] bits of the hash are kept (as an [`f64`] cannot
+/// represent all 64 bits) and the value is placed at the midpoint of its
+/// interval so that neither `0` nor `1` is ever produced.
+fn to_unit_interval(hash: u64) -> f64 {
+    ((hash >> (u64::BITS - MANTISSA_BITS)) as f64 + 0.5) / (1u64 << MANTISSA_BITS) as f64
+}
+
+/// A uniformity test of the hash values using their empirical distribution
+/// function.
+#[derive(Debug)]
+pub struct Test<'a, H: BuildHasher> {
+    /// The hash function builder.
+    build_hasher: &'a H,
+
+    /// The data provider.
+    provider: Box<dyn Provider>,
+
+    /// The hashes mapped to the unit interval.
+    samples: Vec<f64>,
+
+    /// The threshold of statistical signficance to use.
+    threshold: f64,
+}
+
+impl<'a, H: BuildHasher> Test<'a, H> {
+    /// Creates a new [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::chi_squared::uniformity::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::new(&hasher, Box::new(AlphanumericProvider::new(10)), 0.05);
+    ///
+    /// assert!(test.samples().is_empty());
+    /// ```
+    pub fn new(build_hasher: &'a H, provider: Box<dyn Provider>, threshold: f64) -> Self {
+        Self {
+            build_hasher,
+            provider,
+            samples: Vec::new(),
+            threshold,
+        }
+    }
+
+    /// Gets the [`BuildHasher`] from the [`Test`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::BuildHasher as _;
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::chi_squared::uniformity::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::new(&hasher, Box::new(AlphanumericProvider::new(10)), 0.05);
+    ///
+    /// assert_eq!(test.build_hasher().hash_one("42"), hasher.hash_one("42"));
+    /// ```
+    pub fn build_hasher(&self) -> &H {
+        self.build_hasher
+    }
+
+    /// Gets the [`Provider`] from the [`Test`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::Provider as _;
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::chi_squared::uniformity::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let test = Test::new(&hasher, provider.clone(), 0.05);
+    ///
+    /// assert_eq!(test.provider().name(), provider.name());
+    /// ```
+    pub fn provider(&self) -> &dyn Provider {
+        self.provider.as_ref()
+    }
+
+    /// Gets the hashes (mapped to the open interval `(0, 1)`) from the
+    /// [`Test`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::chi_squared::uniformity::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(&hasher, Box::new(AlphanumericProvider::new(10)), 0.05);
+    ///
+    /// test.single_iteration();
+    ///
+    /// assert_eq!(test.samples().len(), 1);
+    /// assert!(test.samples()[0] > 0.0 && test.samples()[0] < 1.0);
+    /// ```
+    pub fn samples(&self) -> &[f64] {
+        &self.samples
+    }
+
+    /// Gets the threshold of statistical significance for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::chi_squared::uniformity::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::new(&hasher, Box::new(AlphanumericProvider::new(10)), 0.05);
+    ///
+    /// assert_eq!(test.threshold(), 0.05);
+    /// ```
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Runs a single iteration of the test.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::chi_squared::uniformity::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(&hasher, Box::new(AlphanumericProvider::new(10)), 0.05);
+    ///
+    /// test.single_iteration();
+    ///
+    /// assert_eq!(test.samples().len(), 1);
+    /// ```
+    pub fn single_iteration(&mut self) {
+        let data = *self.provider.provide(1).first().unwrap();
+        let hash = self.build_hasher.hash_one(data);
+
+        self.samples.push(to_unit_interval(hash));
+    }
+
+    /// Gets the Kolmogorov–Smirnov p-value of the test.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::chi_squared::uniformity::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(&hasher, Box::new(AlphanumericProvider::new(10)), 0.05);
+    ///
+    /// assert!(test.kolmogorov_smirnov_p_value().is_none());
+    ///
+    /// for _ in 0..1000 {
+    ///     test.single_iteration();
+    /// }
+    ///
+    /// assert!(test.kolmogorov_smirnov_p_value().unwrap() <= 1.0);
+    /// ```
+    pub fn kolmogorov_smirnov_p_value(&self) -> Option<f64> {
+        UniformKolmogorovSmirnovTest::goodness_of_fit(&self.samples)
+    }
+
+    /// Gets the Anderson–Darling p-value of the test.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::chi_squared::uniformity::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(&hasher, Box::new(AlphanumericProvider::new(10)), 0.05);
+    ///
+    /// assert!(test.anderson_darling_p_value().is_none());
+    ///
+    /// for _ in 0..1000 {
+    ///     test.single_iteration();
+    /// }
+    ///
+    /// assert!(test.anderson_darling_p_value().unwrap() <= 1.0);
+    /// ```
+    pub fn anderson_darling_p_value(&self) -> Option<f64> {
+        UniformAndersonDarlingTest::goodness_of_fit(&self.samples)
+    }
+
+    /// Generates a [`Module`] for a p-value computed by the named statistical
+    /// test.
+    fn module(&self, name: &str, p_value: Option<f64>, details: Option<String>) -> Module {
+        let (result, value, summary) = match p_value {
+            Some(p_value) if p_value > self.threshold => (
+                module::Result::Pass,
+                Some(format!("{:.2}", p_value)),
+                format!(
+                    "The resulting p-value of {:.2} was greater than (and, thus, failed to reach) \
+                     the predetermined threshold of statistical significance set at {:.2}. As \
+                     such, the null hypothesis that the hash values follow a continuous, uniform \
+                     distribution **cannot** be rejected.",
+                    p_value, self.threshold
+                ),
+            ),
+            Some(p_value) => (
+                module::Result::Fail,
+                Some(format!("{:.2}", p_value)),
+                format!(
+                    "The resulting p-value of {:.2} was less than (and, thus, reached) the \
+                     predetermined threshold of statistical significance set at {:.2}. As such, \
+                     the null hypothesis that the hash values follow a continuous, uniform \
+                     distribution **is** rejected.",
+                    p_value, self.threshold
+                ),
+            ),
+            None => (
+                module::Result::Inconclusive,
+                None,
+                String::from("The p-value was not able to be computed."),
+            ),
+        };
+
+        let details = match details {
+            Some(details) => format!("{}\n\n{}", summary, details),
+            None => summary,
+        };
+
+        Module::new(result, name, value, Some(details))
+    }
+}
+
+impl<'a, H: BuildHasher> crate::r#trait::Test for Test<'a, H> {
+    fn title(&self) -> &'static str {
+        "Uniformity"
+    }
+
+    fn report_section(&self) -> bitbelay_report::section::Test {
+        let statistic = uniform_statistic(&self.samples).map(|d| {
+            format!(
+                "The largest distance between the empirical and uniform CDFs (D) was {:.2e}.",
+                d
+            )
+        });
+
+        // SAFETY: all of the pieces of this [`Builder`] are hand-crafted to not
+        // fail, so all of the below will unwrap.
+        get_report_base(self.provider.as_ref(), self.samples.len())
+            .push_module(self.module(
+                "Kolmogorov–Smirnov",
+                self.kolmogorov_smirnov_p_value(),
+                statistic,
+            ))
+            .push_module(self.module("Anderson–Darling", self.anderson_darling_p_value(), None))
+            .try_build()
+            .unwrap()
+    }
+}
+
+/// Populates the boilerplate report information within a
+/// [`Test`](section::Test).
+pub fn get_report_base(provider: &dyn Provider, iterations: usize) -> section::test::Builder {
+    let overview = "The uniformity test compares the empirical cumulative distribution function \
+                    (CDF) of the hash values against the CDF of a continuous, uniform \
+                    distribution using the Kolmogorov–Smirnov and Anderson–Darling tests.";
+
+    let relation = "The chi-squared goodness of fit test only examines a single, binned view of \
+                    the hash values, so it is blind to any non-uniformity that averages out \
+                    within each bucket. By comparing CDFs directly, this test examines the hash \
+                    values at their full resolution. The Kolmogorov–Smirnov test is most \
+                    sensitive to deviations near the middle of the distribution, while the \
+                    Anderson–Darling test is more sensitive to deviations in the tails (e.g., \
+                    hashes that avoid very small or very large values).";
+
+    let algorithm = format!(
+        "(1) For a number of iterations, a random input is generated using the data provider and \
+         hashed.\n\n(2) Each hash is mapped to the open interval (0, 1) by keeping its top \
+         {MANTISSA_BITS} bits (within the precision of a 64-bit float) and placing it at the \
+         midpoint of its sub-interval.\n\n(3) The mapped values are sorted to form the empirical \
+         CDF.\n\n(4) The Kolmogorov–Smirnov statistic (the largest distance between the empirical \
+         and uniform CDFs) and the Anderson–Darling statistic (a weighted squared distance that \
+         emphasizes the tails) are computed, and p-values are computed from their asymptotic \
+         distributions."
+    );
+
+    let interpretation = "Under this test design:\n\n* A p-value that is greater than or equal to \
+                          the pre-determined signficance value (typically, 0.05) is **good**, as \
+                          there is no significant difference between the distribution of hash \
+                          values and a continuous, uniform distribution.\n\n* A p-value that is \
+                          less than the pre-determined signficance value is **bad**, as there is \
+                          a significant difference between the distribution of hash values and a \
+                          continuous, uniform distribution.";
+
+    let sources = "* https://en.wikipedia.org/wiki/Kolmogorov%E2%80%93Smirnov_test\n* https://en.wikipedia.org/wiki/Anderson%E2%80%93Darling_test";
+
+    test::Builder::default()
+        .title(format!(
+            "Uniformity / {} / {} iterations",
+            provider.name(),
+            iterations
+        ))
+        .unwrap()
+        .description(format!(
+            "{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}",
+            "Overview".italic(),
+            overview,
+            "Relation to Hashing".italic(),
+            relation,
+            "Algorithm".italic(),
+            algorithm,
+            "Interpretation".italic(),
+            interpretation,
+            "Sources".italic(),
+            sources
+        ))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::BuildHasherDefault;
+    use std::hash::Hasher;
+
+    use bitbelay_providers::ascii::AlphanumericProvider;
+
+    use super::*;
+
+    /// A hasher whose most significant output bit is never set.
+    #[derive(Debug, Default)]
+    struct LowHalf(std::hash::DefaultHasher);
+
+    impl Hasher for LowHalf {
+        fn finish(&self) -> u64 {
+            self.0.finish() >> 1
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            self.0.write(bytes);
+        }
+    }
+
+    #[test]
+    fn unit_interval_is_open() {
+        assert!(to_unit_interval(0) > 0.0);
+        assert!(to_unit_interval(u64::MAX) < 1.0);
+    }
+
+    #[test]
+    fn skewed_hashes_fail() {
+        let hasher = BuildHasherDefault::<LowHalf>::default();
+        let mut test = Test::new(&hasher, Box::new(AlphanumericProvider::new(10)), 0.05);
+
+        for _ in 0..1_000 {
+            test.single_iteration();
+        }
+
+        assert!(test.kolmogorov_smirnov_p_value().unwrap() < 0.05);
+        assert!(test.anderson_darling_p_value().unwrap() < 0.05);
+    }
+}
@@ -0,0 +1,123 @@
+//! Collision tests.
+//!
+//! A hash function should only produce collisions as often as a random function
+//! would. The tests in this module search for collisions between inputs that
+//! are related to one another, where weak hash functions tend to collide far
+//! more often than expected.
+
+use std::hash::BuildHasher;
+
+use bitbelay_report::section;
+
+use crate::r#trait::Test as _;
+
+pub mod differential;
+
+/// A type of collision test.
+#[derive(Debug)]
+pub enum Test<'a, H: BuildHasher> {
+    /// Differential collision test.
+    Differential(differential::Test<'a, H>),
+}
+
+impl<'a, H: BuildHasher> Test<'a, H> {
+    /// Gets a reference to a [`differential::Test`] wrapped in [`Some`] if the
+    /// [`Test`] is a [`Test::Differential`]. Else, returns [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::collision::Test;
+    /// use bitbelay_tests::collision::differential;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::Differential(differential::Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(8)),
+    ///     NonZeroUsize::try_from(64).unwrap(),
+    ///     3,
+    ///     32,
+    ///     0.05,
+    /// )?);
+    ///
+    /// assert!(matches!(test.as_differential_test(), Some(_)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn as_differential_test(&self) -> Option<&differential::Test<'a, H>> {
+        match self {
+            Test::Differential(test) => Some(test),
+        }
+    }
+
+    /// Consumes the [`Test`] and returns a [`differential::Test`] wrapped in
+    /// [`Some`] if the [`Test`] is a [`Test::Differential`]. Else, returns
+    /// [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::collision::Test;
+    /// use bitbelay_tests::collision::differential;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::Differential(differential::Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(8)),
+    ///     NonZeroUsize::try_from(64).unwrap(),
+    ///     3,
+    ///     32,
+    ///     0.05,
+    /// )?);
+    ///
+    /// assert!(matches!(test.into_differential_test(), Some(_)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn into_differential_test(self) -> Option<differential::Test<'a, H>> {
+        match self {
+            Test::Differential(test) => Some(test),
+        }
+    }
+
+    /// Generates a report section for the [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::collision::Test;
+    /// use bitbelay_tests::collision::differential;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::Differential(differential::Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(8)),
+    ///     NonZeroUsize::try_from(64).unwrap(),
+    ///     3,
+    ///     32,
+    ///     0.05,
+    /// )?);
+    ///
+    /// let results = test.report_section();
+    /// // Include the section in a report.
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn report_section(&self) -> section::Test {
+        match self {
+            Test::Differential(test) => test.report_section(),
+        }
+    }
+}
@@ -0,0 +1,768 @@
+//! Differential collision test.
+//!
+//! # Sources
+//!
+//! * [SMHasher] popularized this test (as `DiffTest`) for evaluating
+//!   non-cryptographic hash functions.
+//!
+//! [SMHasher]: https://github.com/aappleby/smhasher
+
+use std::hash::BuildHasher;
+use std::num::NonZeroUsize;
+
+use bitbelay_providers::Provider;
+use bitbelay_report::section;
+use bitbelay_report::section::test;
+use bitbelay_report::section::test::Module;
+use bitbelay_report::section::test::module;
+use bitbelay_statistics::power;
+use colored::Colorize;
+use statrs::distribution::DiscreteCDF as _;
+use statrs::distribution::Poisson;
+
+use crate::difference;
+pub use crate::difference::Error;
+pub use crate::difference::MAX_WEIGHT;
+use crate::util::hex;
+
+/// The number of collisions listed within the details of a report module.
+const REPORTED_COLLISIONS: usize = 10;
+
+/// A [`Result`](std::result::Result) with an [`Error`].
+type Result<T> = std::result::Result<T, Error>;
+
+/// A key whose hash collided with the hash of a modified copy of itself.
+#[derive(Clone, Debug)]
+pub struct Collision {
+    /// The key.
+    pub key: Vec<u8>,
+
+    /// The key bits that were flipped to produce the modified copy.
+    pub difference: Vec<usize>,
+}
+
+/// A differential collision test.
+#[derive(Debug)]
+pub struct Test<'a, H: BuildHasher> {
+    /// The build hasher.
+    build_hasher: &'a H,
+
+    /// The data provider.
+    provider: Box<dyn Provider>,
+
+    /// The number of leading key bits that may be flipped.
+    key_bits: usize,
+
+    /// The maximum number of key bits flipped within a modified copy.
+    max_weight: usize,
+
+    /// The number of low output bits compared when checking for a collision.
+    bits: u32,
+
+    /// The number of keys hashed.
+    keys: usize,
+
+    /// The number of pairs compared.
+    pairs: usize,
+
+    /// The collisions that have been found.
+    collisions: Vec<Collision>,
+
+    /// The threshold of statistical significance.
+    threshold: f64,
+}
+
+impl<'a, H: BuildHasher> Test<'a, H> {
+    /// Attempts to create a new [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::collision::differential::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(8)),
+    ///     NonZeroUsize::try_from(64).unwrap(),
+    ///     3,
+    ///     32,
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert_eq!(test.keys(), 0);
+    /// assert!(test.collisions().is_empty());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_new(
+        build_hasher: &'a H,
+        provider: Box<dyn Provider>,
+        key_bits: NonZeroUsize,
+        max_weight: usize,
+        bits: u32,
+        threshold: f64,
+    ) -> Result<Self> {
+        difference::validate(bits, max_weight)?;
+
+        Ok(Self {
+            build_hasher,
+            provider,
+            key_bits: key_bits.get(),
+            max_weight,
+            bits,
+            keys: 0,
+            pairs: 0,
+            collisions: Vec::new(),
+            threshold,
+        })
+    }
+
+    /// Gets the build hasher for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::BuildHasher as _;
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::collision::differential::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(8)),
+    ///     NonZeroUsize::try_from(64).unwrap(),
+    ///     3,
+    ///     32,
+    ///     0.05,
+    /// )?;
+    ///
+    /// // Used as a surrogate to test that the [`BuildHasher`]s are the same.
+    /// assert_eq!(test.build_hasher().hash_one("42"), hasher.hash_one("42"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn build_hasher(&self) -> &H {
+        self.build_hasher
+    }
+
+    /// Gets the data provider for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::Provider as _;
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::collision::differential::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(8));
+    /// let test = Test::try_new(
+    ///     &hasher,
+    ///     provider.clone(),
+    ///     NonZeroUsize::try_from(64).unwrap(),
+    ///     3,
+    ///     32,
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert_eq!(test.provider().name(), provider.name());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn provider(&self) -> &dyn Provider {
+        self.provider.as_ref()
+    }
+
+    /// Gets the number of leading key bits that may be flipped.
+    ///
+    /// Keys shorter than this are flipped across their entire length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::collision::differential::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(8)),
+    ///     NonZeroUsize::try_from(64).unwrap(),
+    ///     3,
+    ///     32,
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert_eq!(test.key_bits(), 64);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn key_bits(&self) -> usize {
+        self.key_bits
+    }
+
+    /// Gets the maximum number of key bits flipped within a modified copy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::collision::differential::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(8)),
+    ///     NonZeroUsize::try_from(64).unwrap(),
+    ///     3,
+    ///     32,
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert_eq!(test.max_weight(), 3);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn max_weight(&self) -> usize {
+        self.max_weight
+    }
+
+    /// Gets the number of low output bits compared when checking for a
+    /// collision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::collision::differential::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(8)),
+    ///     NonZeroUsize::try_from(64).unwrap(),
+    ///     3,
+    ///     32,
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert_eq!(test.bits(), 32);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// Gets the number of keys hashed within this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::collision::differential::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(8)),
+    ///     NonZeroUsize::try_from(64).unwrap(),
+    ///     3,
+    ///     32,
+    ///     0.05,
+    /// )?;
+    ///
+    /// test.single_iteration();
+    /// assert_eq!(test.keys(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn keys(&self) -> usize {
+        self.keys
+    }
+
+    /// Gets the number of pairs compared within this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::collision::differential::Test;
+    /// use bitbelay_tests::difference;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(8)),
+    ///     NonZeroUsize::try_from(64).unwrap(),
+    ///     3,
+    ///     32,
+    ///     0.05,
+    /// )?;
+    ///
+    /// test.single_iteration();
+    /// assert_eq!(test.pairs(), difference::count(64, 3));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn pairs(&self) -> usize {
+        self.pairs
+    }
+
+    /// Gets the collisions found within this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::collision::differential::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(8)),
+    ///     NonZeroUsize::try_from(64).unwrap(),
+    ///     3,
+    ///     64,
+    ///     0.05,
+    /// )?;
+    ///
+    /// test.single_iteration();
+    /// assert!(test.collisions().is_empty());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn collisions(&self) -> &[Collision] {
+        &self.collisions
+    }
+
+    /// Gets the threshold of statistical significance for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::collision::differential::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(8)),
+    ///     NonZeroUsize::try_from(64).unwrap(),
+    ///     3,
+    ///     32,
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert_eq!(test.threshold(), 0.05);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Hashes a single key from the provider along with every modified copy of
+    /// the key that differs in at most [`max_weight()`](Test::max_weight) of
+    /// the leading [`key_bits()`](Test::key_bits) bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::collision::differential::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(8)),
+    ///     NonZeroUsize::try_from(64).unwrap(),
+    ///     3,
+    ///     32,
+    ///     0.05,
+    /// )?;
+    ///
+    /// test.single_iteration();
+    /// assert_eq!(test.keys(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn single_iteration(&mut self) {
+        // SAFETY: we hardcode generating one value, so we know this pop must unwrap.
+        let key = self.provider.provide(1).pop().unwrap().to_vec();
+        let mask = u64::MAX >> (64 - self.bits);
+        let original = self.build_hasher.hash_one(&key);
+
+        let mut input = key.clone();
+        let mut pairs = 0;
+        let mut collisions = Vec::new();
+
+        let key_bits = self.key_bits.min(key.len() * 8);
+        difference::for_each(key_bits, self.max_weight, |difference| {
+            difference::flip(&mut input, difference);
+
+            if (self.build_hasher.hash_one(&input) ^ original) & mask == 0 {
+                collisions.push(difference.to_vec());
+            }
+
+            difference::flip(&mut input, difference);
+            pairs += 1;
+        });
+
+        self.collisions
+            .extend(collisions.into_iter().map(|difference| Collision {
+                key: key.clone(),
+                difference,
+            }));
+        self.pairs += pairs;
+        self.keys += 1;
+    }
+
+    /// Gets the number of collisions expected for a random function given the
+    /// number of pairs compared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::collision::differential::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(8)),
+    ///     NonZeroUsize::try_from(64).unwrap(),
+    ///     1,
+    ///     4,
+    ///     0.05,
+    /// )?;
+    ///
+    /// test.single_iteration();
+    /// assert_eq!(test.expected_collisions(), 4.0);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn expected_collisions(&self) -> f64 {
+        self.pairs as f64 * 0.5f64.powi(self.bits as i32)
+    }
+
+    /// Computes the p-value of observing at least as many collisions as were
+    /// found for a random function.
+    ///
+    /// The number of collisions for a random function follows a Poisson
+    /// distribution with a mean of
+    /// [`expected_collisions()`](Test::expected_collisions). If no pairs have
+    /// been compared, [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::collision::differential::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(8)),
+    ///     NonZeroUsize::try_from(64).unwrap(),
+    ///     3,
+    ///     32,
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert!(test.p_value().is_none());
+    ///
+    /// test.single_iteration();
+    /// assert!(test.p_value().unwrap() <= 1.0);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn p_value(&self) -> Option<f64> {
+        if self.pairs == 0 {
+            return None;
+        }
+
+        if self.collisions.is_empty() {
+            return Some(1.0);
+        }
+
+        // SAFETY: the expected number of collisions is always positive when at
+        // least one pair has been compared, so this will always unwrap.
+        let distribution = Poisson::new(self.expected_collisions()).unwrap();
+
+        // NOTE: the survival function is `P(X > x)`, so this computes
+        // `P(X >= collisions)`.
+        Some(distribution.sf(self.collisions.len() as u64 - 1))
+    }
+}
+
+impl<'a, H: BuildHasher> crate::r#trait::Test for Test<'a, H> {
+    fn title(&self) -> &'static str {
+        "Differential"
+    }
+
+    fn report_section(&self) -> section::Test {
+        let hypothesis = "the number of collisions between keys and their modified copies is \
+                          consistent with a random function";
+
+        let (result, value, details) = match self.p_value() {
+            Some(p_value) if p_value > self.threshold => (
+                module::Result::Pass,
+                Some(format!("{:.2}", p_value)),
+                format!(
+                    "The resulting p-value of {:.2} was greater than (and, thus, failed to reach) \
+                     the predetermined threshold of statistical significance set at {:.2}. As \
+                     such, the null hypothesis that {} **cannot** be rejected.",
+                    p_value, self.threshold, hypothesis
+                ),
+            ),
+            Some(p_value) => (
+                module::Result::Fail,
+                Some(format!("{:.2}", p_value)),
+                format!(
+                    "The resulting p-value of {:.2} was less than (and, thus, reached) the \
+                     predetermined threshold of statistical significance set at {:.2}. As such, \
+                     the null hypothesis that {} **is** rejected.",
+                    p_value, self.threshold, hypothesis
+                ),
+            ),
+            None => (
+                module::Result::Inconclusive,
+                None,
+                String::from(
+                    "The p-value was not able to be computed (try increasing the number of keys).",
+                ),
+            ),
+        };
+
+        let mut details = format!(
+            "{}\n\nCompared {} pairs across {} keys: {} pairs collided in the low {} output bits \
+             ({:.2} expected).",
+            details,
+            self.pairs,
+            self.keys,
+            self.collisions.len(),
+            self.bits,
+            self.expected_collisions()
+        );
+
+        if !self.collisions.is_empty() {
+            details.push_str(&format!("\n\n{}\n", "Collisions".italic()));
+
+            for collision in self.collisions.iter().take(REPORTED_COLLISIONS) {
+                details.push_str(&format!(
+                    "\n* Key 0x{} collided when bits {:?} were flipped.",
+                    hex(&collision.key),
+                    collision.difference
+                ));
+            }
+        }
+
+        get_report_base(
+            self.provider.as_ref(),
+            self.key_bits,
+            self.max_weight,
+            self.bits,
+        )
+        .push_module(Module::new(result, "Collisions", value, Some(details)))
+        .try_build()
+        .unwrap()
+    }
+}
+
+/// Computes the number of keys needed for a [`Test`] to detect a hash
+/// function that produces at least `ratio` times as many collisions as a
+/// random function with probability `power` at the given `threshold` of
+/// statistical significance.
+///
+/// Each key is compared against every copy of itself that differs in up to
+/// `max_weight` of its leading `key_bits` bits, and two hashes collide when
+/// their low `bits` bits agree. If the number of keys cannot be computed (see
+/// [`power::collisions()`]), [`None`] is returned.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroUsize;
+///
+/// use bitbelay_tests::collision::differential;
+///
+/// let key_bits = NonZeroUsize::try_from(64).unwrap();
+///
+/// let keys = differential::required_keys(key_bits, 3, 16, 2.0, 0.05, 0.8).unwrap();
+/// assert!(keys.get() > 1);
+///
+/// assert!(differential::required_keys(key_bits, 3, 16, 1.0, 0.05, 0.8).is_none());
+/// ```
+pub fn required_keys(
+    key_bits: NonZeroUsize,
+    max_weight: usize,
+    bits: u32,
+    ratio: f64,
+    threshold: f64,
+    power: f64,
+) -> Option<NonZeroUsize> {
+    if !(1..=64).contains(&bits) || !(1..=MAX_WEIGHT).contains(&max_weight) {
+        return None;
+    }
+
+    let pairs = power::collisions(0.5f64.powi(bits as i32), ratio, threshold, power)?;
+    let pairs_per_key = difference::count(key_bits.get(), max_weight) as u64;
+    let keys = pairs.div_ceil(pairs_per_key);
+
+    NonZeroUsize::try_from(usize::try_from(keys).ok()?).ok()
+}
+
+/// Populates the boilerplate report information within a
+/// [`Test`](section::Test).
+pub fn get_report_base(
+    provider: &dyn Provider,
+    key_bits: usize,
+    max_weight: usize,
+    bits: u32,
+) -> section::test::Builder {
+    let overview = "The differential test hashes random keys along with every copy of each key \
+                    that differs in only a few bits and counts how often a key collides with one \
+                    of its modified copies.";
+
+    let relation = "Inputs to hash tables are frequently similar to one another (e.g., sequential \
+                    identifiers or strings that differ by a single character). A hash function \
+                    whose mixing steps allow a small difference in the input to cancel out \
+                    produces far more collisions between such inputs than a random function \
+                    would. This test catches most broken mixers, as structured differences \
+                    exercise the exact internal state transitions that a weak mixer fails to \
+                    diffuse.";
+
+    let algorithm = format!(
+        "For the hash function, data provider, and number of keys chosen:\n\n(1) A key is \
+         generated by the data provider and hashed.\n\n(2) For every combination of at most {} of \
+         the first {} key bits, a copy of the key with those bits flipped is hashed and compared \
+         with the hash of the original key. A collision is counted if the low {} bits of both \
+         hashes agree.\n\n(3) The total number of collisions is compared against a Poisson \
+         distribution whose mean is the number of pairs compared multiplied by 2^-{} (the \
+         expected number of collisions for a random function). The p-value is the probability of \
+         observing at least as many collisions.",
+        max_weight, key_bits, bits, bits
+    );
+
+    let interpretation = "Under this test design:\n\n* A number of collisions that is consistent \
+                          with a random function is **good**.\n\n* Significantly more collisions \
+                          than expected is **bad**, as it indicates that some low-weight input \
+                          differences cancel out within the hash function. The colliding keys and \
+                          differences are listed so they can be reproduced.";
+
+    let sources = "* https://github.com/aappleby/smhasher/wiki/SMHasher";
+
+    test::Builder::default()
+        .title(format!(
+            "Differential / {} / {} key bits / weight <= {} / {} bits",
+            provider.name(),
+            key_bits,
+            max_weight,
+            bits
+        ))
+        .unwrap()
+        .description(format!(
+            "{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}",
+            "Overview".italic(),
+            overview,
+            "Relation to Hashing".italic(),
+            relation,
+            "Algorithm".italic(),
+            algorithm,
+            "Interpretation".italic(),
+            interpretation,
+            "Sources".italic(),
+            sources
+        ))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::BuildHasherDefault;
+    use std::hash::Hasher;
+
+    use bitbelay_providers::ascii::AlphanumericProvider;
+
+    use super::*;
+
+    /// A hasher that sums its input bytes, so that differences which move a
+    /// bit between bytes cancel out.
+    #[derive(Debug, Default)]
+    struct Sum(u64);
+
+    impl Hasher for Sum {
+        fn finish(&self) -> u64 {
+            self.0.wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            for byte in bytes {
+                self.0 = self.0.wrapping_add(u64::from(*byte));
+            }
+        }
+    }
+
+    #[test]
+    fn cancelling_differences_fail() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let hasher = BuildHasherDefault::<Sum>::default();
+        let mut test = Test::try_new(
+            &hasher,
+            Box::new(AlphanumericProvider::new(8)),
+            NonZeroUsize::try_from(64).unwrap(),
+            2,
+            64,
+            0.05,
+        )?;
+
+        for _ in 0..10 {
+            test.single_iteration();
+        }
+
+        assert!(!test.collisions().is_empty());
+        assert!(test.p_value().unwrap() < 0.05);
+        Ok(())
+    }
+}
@@ -7,12 +7,28 @@ use bitbelay_report::section;
 use crate::r#trait::Test as _;
 
 pub mod bitwise;
+pub mod input_output;
+pub mod mutual_information;
+pub mod serial;
+pub mod spearman;
 
 /// A type of correlation test.
 #[derive(Debug)]
 pub enum Test<'a, H: BuildHasher, const N: usize> {
     /// Bitwise test.
     Bitwise(bitwise::Test<'a, H, N>),
+
+    /// Serial correlation test.
+    Serial(serial::Test<'a, H>),
+
+    /// Input-output correlation test.
+    InputOutput(input_output::Test<'a, H, N>),
+
+    /// Spearman sub-word correlation test.
+    Spearman(spearman::Test<'a, H>),
+
+    /// Mutual information test.
+    MutualInformation(mutual_information::Test<'a, H>),
 }
 
 impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
@@ -27,11 +43,18 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
     /// use std::num::NonZeroUsize;
     ///
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
     /// use bitbelay_tests::correlation::Test;
     /// use bitbelay_tests::correlation::bitwise;
+    /// use bitbelay_tests::tolerance::Tolerance;
     ///
     /// let hasher = RandomState::new();
-    /// let test = Test::Bitwise(bitwise::Test::<RandomState, 64>::new(&hasher, 0.05));
+    /// let test = Test::Bitwise(bitwise::Test::<RandomState, 64>::new(
+    ///     &hasher,
+    ///     Tolerance::Fixed(0.05),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// ));
     /// assert!(matches!(test.as_bitwise_test(), Some(_)));
     ///
     /// # Ok::<(), Box<dyn std::error::Error>>(())
@@ -39,6 +62,7 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
     pub fn as_bitwise_test(&self) -> Option<&bitwise::Test<'a, H, N>> {
         match self {
             Test::Bitwise(test) => Some(test),
+            _ => None,
         }
     }
 
@@ -53,11 +77,18 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
     /// use std::num::NonZeroUsize;
     ///
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
     /// use bitbelay_tests::correlation::Test;
     /// use bitbelay_tests::correlation::bitwise;
+    /// use bitbelay_tests::tolerance::Tolerance;
     ///
     /// let hasher = RandomState::new();
-    /// let test = Test::Bitwise(bitwise::Test::<RandomState, 64>::new(&hasher, 0.05));
+    /// let test = Test::Bitwise(bitwise::Test::<RandomState, 64>::new(
+    ///     &hasher,
+    ///     Tolerance::Fixed(0.05),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// ));
     /// assert!(matches!(test.into_bitwise_test(), Some(_)));
     ///
     /// # Ok::<(), Box<dyn std::error::Error>>(())
@@ -65,6 +96,261 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
     pub fn into_bitwise_test(self) -> Option<bitwise::Test<'a, H, N>> {
         match self {
             Test::Bitwise(test) => Some(test),
+            _ => None,
+        }
+    }
+
+    /// Gets a reference to a [`serial::Test`] wrapped in [`Some`] if the
+    /// [`Test`] is a [`Test::Serial`]. Else, returns [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::Test;
+    /// use bitbelay_tests::correlation::serial;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::<RandomState, 64>::Serial(serial::Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// ));
+    ///
+    /// assert!(matches!(test.as_serial_test(), Some(_)));
+    /// assert!(matches!(test.as_bitwise_test(), None));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn as_serial_test(&self) -> Option<&serial::Test<'a, H>> {
+        match self {
+            Test::Serial(test) => Some(test),
+            _ => None,
+        }
+    }
+
+    /// Consumes the [`Test`] and returns a [`serial::Test`] wrapped in
+    /// [`Some`] if the [`Test`] is a [`Test::Serial`]. Else, returns [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::Test;
+    /// use bitbelay_tests::correlation::serial;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::<RandomState, 64>::Serial(serial::Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// ));
+    ///
+    /// assert!(matches!(test.into_serial_test(), Some(_)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn into_serial_test(self) -> Option<serial::Test<'a, H>> {
+        match self {
+            Test::Serial(test) => Some(test),
+            _ => None,
+        }
+    }
+
+    /// Gets a reference to an [`input_output::Test`] wrapped in [`Some`] if
+    /// the [`Test`] is a [`Test::InputOutput`]. Else, returns [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::correlation::Test;
+    /// use bitbelay_tests::correlation::input_output;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::InputOutput(input_output::Test::<RandomState, 64>::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(256).unwrap(),
+    ///     0.05,
+    /// ));
+    ///
+    /// assert!(matches!(test.as_input_output_test(), Some(_)));
+    /// assert!(matches!(test.as_bitwise_test(), None));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn as_input_output_test(&self) -> Option<&input_output::Test<'a, H, N>> {
+        match self {
+            Test::InputOutput(test) => Some(test),
+            _ => None,
+        }
+    }
+
+    /// Consumes the [`Test`] and returns an [`input_output::Test`] wrapped in
+    /// [`Some`] if the [`Test`] is a [`Test::InputOutput`]. Else, returns
+    /// [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::correlation::Test;
+    /// use bitbelay_tests::correlation::input_output;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::InputOutput(input_output::Test::<RandomState, 64>::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(256).unwrap(),
+    ///     0.05,
+    /// ));
+    ///
+    /// assert!(matches!(test.into_input_output_test(), Some(_)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn into_input_output_test(self) -> Option<input_output::Test<'a, H, N>> {
+        match self {
+            Test::InputOutput(test) => Some(test),
+            _ => None,
+        }
+    }
+
+    /// Gets a reference to a [`spearman::Test`] wrapped in [`Some`] if the
+    /// [`Test`] is a [`Test::Spearman`]. Else, returns [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_tests::correlation::Test;
+    /// use bitbelay_tests::correlation::spearman;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::<RandomState, 64>::Spearman(spearman::Test::new(&hasher, 0.05));
+    ///
+    /// assert!(matches!(test.as_spearman_test(), Some(_)));
+    /// assert!(matches!(test.as_bitwise_test(), None));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn as_spearman_test(&self) -> Option<&spearman::Test<'a, H>> {
+        match self {
+            Test::Spearman(test) => Some(test),
+            _ => None,
+        }
+    }
+
+    /// Consumes the [`Test`] and returns a [`spearman::Test`] wrapped in
+    /// [`Some`] if the [`Test`] is a [`Test::Spearman`]. Else, returns
+    /// [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_tests::correlation::Test;
+    /// use bitbelay_tests::correlation::spearman;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::<RandomState, 64>::Spearman(spearman::Test::new(&hasher, 0.05));
+    ///
+    /// assert!(matches!(test.into_spearman_test(), Some(_)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn into_spearman_test(self) -> Option<spearman::Test<'a, H>> {
+        match self {
+            Test::Spearman(test) => Some(test),
+            _ => None,
+        }
+    }
+
+    /// Gets a reference to a [`mutual_information::Test`] wrapped in [`Some`]
+    /// if the [`Test`] is a [`Test::MutualInformation`]. Else, returns
+    /// [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::Test;
+    /// use bitbelay_tests::correlation::mutual_information;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::<RandomState, 64>::MutualInformation(mutual_information::Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// ));
+    ///
+    /// assert!(matches!(test.as_mutual_information_test(), Some(_)));
+    /// assert!(matches!(test.as_bitwise_test(), None));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn as_mutual_information_test(&self) -> Option<&mutual_information::Test<'a, H>> {
+        match self {
+            Test::MutualInformation(test) => Some(test),
+            _ => None,
+        }
+    }
+
+    /// Consumes the [`Test`] and returns a [`mutual_information::Test`]
+    /// wrapped in [`Some`] if the [`Test`] is a [`Test::MutualInformation`].
+    /// Else, returns [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::Test;
+    /// use bitbelay_tests::correlation::mutual_information;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::<RandomState, 64>::MutualInformation(mutual_information::Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// ));
+    ///
+    /// assert!(matches!(test.into_mutual_information_test(), Some(_)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn into_mutual_information_test(self) -> Option<mutual_information::Test<'a, H>> {
+        match self {
+            Test::MutualInformation(test) => Some(test),
+            _ => None,
         }
     }
 
@@ -78,12 +364,19 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
     ///
     /// use bitbelay_providers::Provider;
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
     /// use bitbelay_tests::correlation::Test;
     /// use bitbelay_tests::correlation::bitwise;
+    /// use bitbelay_tests::tolerance::Tolerance;
     ///
     /// let mut provider: Box<dyn Provider> = Box::new(AlphanumericProvider::new(10));
     /// let hasher = RandomState::new();
-    /// let mut test = bitwise::Test::<RandomState, 64>::new(&hasher, 0.05);
+    /// let mut test = bitwise::Test::<RandomState, 64>::new(
+    ///     &hasher,
+    ///     Tolerance::Fixed(0.05),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
     /// test.run(&mut provider, NonZeroUsize::try_from(10).unwrap());
     ///
     /// let mut test = Test::Bitwise(test);
@@ -93,6 +386,10 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
     pub fn report_section(&self) -> section::Test {
         match self {
             Test::Bitwise(test) => test.report_section(),
+            Test::Serial(test) => test.report_section(),
+            Test::InputOutput(test) => test.report_section(),
+            Test::Spearman(test) => test.report_section(),
+            Test::MutualInformation(test) => test.report_section(),
         }
     }
 }
@@ -1,6 +1,5 @@
 //! Bitwise correlation test.
 
-use std::collections::HashMap;
 use std::hash::BuildHasher;
 use std::hash::Hasher as _;
 use std::num::NonZeroUsize;
@@ -9,14 +8,52 @@ use bitbelay_providers::Provider;
 use bitbelay_report::section;
 use bitbelay_report::section::test;
 use bitbelay_report::section::test::module;
+use bitbelay_statistics::correction::Procedure;
+use bitbelay_statistics::correlation::matrix::CorrelationMatrix;
+use bitbelay_statistics::correlation::matrix::SymmetricMatrix;
 use bitbelay_statistics::correlation::pearson;
+use bitbelay_statistics::power;
 use colored::Colorize as _;
 use ordered_float::OrderedFloat;
 use tracing::debug;
 use tracing::info;
 
+use crate::tolerance::Tolerance;
+
+/// The number of most significant pairs listed within the report.
+const WORST_PAIRS: usize = 10;
+
+/// The number of hashes computed at a time when running iterations.
+///
+/// This bounds the memory used by a [`Test`] regardless of the number of
+/// iterations run, and it should be a multiple of [`BLOCK_SIZE`].
+const BATCH_SIZE: usize = 1 << 16;
+
+/// The number of hashes transposed into bit-packed columns at a time.
+const BLOCK_SIZE: usize = u64::BITS as usize;
+
 /// Results from a bitwise correlation test.
-pub type Results = HashMap<(usize, usize), Option<f64>>;
+///
+/// The entry at row `i` and column `j` is the correlation between output bits
+/// `i` and `j`.
+pub type Results = CorrelationMatrix;
+
+/// The p-value of the correlation between a pair of output bits.
+#[derive(Clone, Copy, Debug)]
+pub struct PValue {
+    /// The two-sided p-value under the null hypothesis of no correlation.
+    pub raw: f64,
+
+    /// The p-value after adjusting for the number of pairs tested.
+    pub adjusted: f64,
+}
+
+/// The p-values of a bitwise correlation test.
+///
+/// The entry at row `i` and column `j` (where `i != j`) is the [`PValue`] of
+/// the correlation between output bits `i` and `j`. Entries on the diagonal, as
+/// well as entries where the correlation is undefined, are [`None`].
+pub type PValues = SymmetricMatrix<Option<PValue>>;
 
 /// A bitwise correlation test.
 #[derive(Debug)]
@@ -24,36 +61,84 @@ pub struct Test<'a, H: BuildHasher, const N: usize> {
     /// The build hasher.
     build_hasher: &'a H,
 
-    /// The bit values accumulated for each bit in the output hash.
-    bit_values: [Vec<f64>; N],
+    /// The number of iterations run.
+    iterations: usize,
 
-    /// The threshold of correlation at which any non-diagonal value causes the
-    /// test to fail.
-    threshold: f64,
+    /// The number of times each output bit was set.
+    ones: [usize; N],
+
+    /// The number of times each pair of output bits `(i, j)` were both set.
+    ///
+    /// Only the strict upper triangle (where `i < j`) is stored in row-major
+    /// order (see [`pair_index()`]), as the counts are symmetric and the
+    /// diagonal is the same as [`ones`](Test::ones).
+    both_ones: Vec<usize>,
+
+    /// The tolerance that determines the threshold of correlation at which any
+    /// non-diagonal value causes the test to fail.
+    tolerance: Tolerance,
+
+    /// The threshold of statistical significance for the family of pairs.
+    significance: f64,
+
+    /// The procedure used to correct for the number of pairs tested.
+    correction: Procedure,
 }
 
 impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
     /// Creates a new [`Test`].
     ///
+    /// The number of output bits, `N`, must be between 1 and 64 (the number of
+    /// bits within a [`u64`] hash), and any other value fails to compile.
+    ///
     /// # Examples
     ///
     /// ```
     /// use std::hash::RandomState;
     ///
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::bitwise::Test;
+    /// use bitbelay_tests::tolerance::Tolerance;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::<RandomState, 64>::new(&hasher, Tolerance::Fixed(0.05), 0.05, Procedure::Holm);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_statistics::correction::Procedure;
     /// use bitbelay_tests::correlation::bitwise::Test;
+    /// use bitbelay_tests::tolerance::Tolerance;
     ///
     /// let hasher = RandomState::new();
-    /// let test = Test::<RandomState, 64>::new(&hasher, 0.05);
+    /// let test = Test::<RandomState, 128>::new(&hasher, Tolerance::Fixed(0.05), 0.05, Procedure::Holm);
     /// ```
-    pub fn new(build_hasher: &'a H, threshold: f64) -> Self {
+    pub fn new(
+        build_hasher: &'a H,
+        tolerance: Tolerance,
+        significance: f64,
+        correction: Procedure,
+    ) -> Self {
+        const {
+            assert!(
+                N > 0 && N <= u64::BITS as usize,
+                "the number of output bits must be between 1 and 64"
+            )
+        };
+
         Self {
             build_hasher,
-            bit_values: [(); N].map(|_| Vec::new()),
-            threshold,
+            iterations: 0,
+            ones: [0; N],
+            both_ones: vec![0; N * (N - 1) / 2],
+            tolerance,
+            significance,
+            correction,
         }
     }
 
-    /// Gets the bit values of this [`Test`] by reference.
+    /// Gets the number of iterations run within this [`Test`].
     ///
     /// # Examples
     ///
@@ -63,36 +148,191 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
     ///
     /// use bitbelay_providers::Provider;
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
     /// use bitbelay_tests::correlation::bitwise::Test;
+    /// use bitbelay_tests::tolerance::Tolerance;
     ///
     /// let mut provider: Box<dyn Provider> = Box::new(AlphanumericProvider::new(10));
     /// let hasher = RandomState::new();
-    /// let mut test = Test::<RandomState, 64>::new(&hasher, 0.05);
+    /// let mut test =
+    ///     Test::<RandomState, 64>::new(&hasher, Tolerance::Fixed(0.05), 0.05, Procedure::Holm);
     ///
     /// test.run(&mut provider, NonZeroUsize::try_from(10).unwrap());
     ///
-    /// assert_eq!(test.bit_values()[0].len(), 10);
+    /// assert_eq!(test.iterations(), 10);
     /// ```
-    pub fn bit_values(&self) -> &[Vec<f64>; N] {
-        &self.bit_values
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    /// Gets the number of times each output bit was set within this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::Provider;
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::bitwise::Test;
+    /// use bitbelay_tests::tolerance::Tolerance;
+    ///
+    /// let mut provider: Box<dyn Provider> = Box::new(AlphanumericProvider::new(10));
+    /// let hasher = RandomState::new();
+    /// let mut test =
+    ///     Test::<RandomState, 64>::new(&hasher, Tolerance::Fixed(0.05), 0.05, Procedure::Holm);
+    ///
+    /// test.run(&mut provider, NonZeroUsize::try_from(10).unwrap());
+    ///
+    /// assert!(test.ones().iter().all(|ones| *ones <= 10));
+    /// ```
+    pub fn ones(&self) -> &[usize; N] {
+        &self.ones
+    }
+
+    /// Gets the number of times output bits `i` and `j` were both set within
+    /// this [`Test`].
+    ///
+    /// The counts are symmetric, and the count for a bit paired with itself is
+    /// the number of times that bit was set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either `i` or `j` is not less than `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::Provider;
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::bitwise::Test;
+    /// use bitbelay_tests::tolerance::Tolerance;
+    ///
+    /// let mut provider: Box<dyn Provider> = Box::new(AlphanumericProvider::new(10));
+    /// let hasher = RandomState::new();
+    /// let mut test =
+    ///     Test::<RandomState, 64>::new(&hasher, Tolerance::Fixed(0.05), 0.05, Procedure::Holm);
+    ///
+    /// test.run(&mut provider, NonZeroUsize::try_from(1000).unwrap());
+    ///
+    /// assert_eq!(test.both_ones(3, 5), test.both_ones(5, 3));
+    /// assert_eq!(test.both_ones(3, 3), test.ones()[3]);
+    /// assert!(test.both_ones(3, 5) <= test.ones()[3].min(test.ones()[5]));
+    /// ```
+    pub fn both_ones(&self, i: usize, j: usize) -> usize {
+        assert!(i < N && j < N, "output bit out of range");
+
+        match i.cmp(&j) {
+            std::cmp::Ordering::Less => self.both_ones[pair_index::<N>(i, j)],
+            std::cmp::Ordering::Equal => self.ones[i],
+            std::cmp::Ordering::Greater => self.both_ones[pair_index::<N>(j, i)],
+        }
     }
 
-    /// Gets the threshold of this [`Test`] by reference.
+    /// Gets the tolerance of this [`Test`].
     ///
     /// # Examples
     ///
     /// ```
     /// use std::hash::RandomState;
     ///
+    /// use bitbelay_statistics::correction::Procedure;
     /// use bitbelay_tests::correlation::bitwise::Test;
+    /// use bitbelay_tests::tolerance::Tolerance;
     ///
     /// let hasher = RandomState::new();
-    /// let mut test = Test::<RandomState, 64>::new(&hasher, 0.05);
+    /// let test = Test::<RandomState, 64>::new(&hasher, Tolerance::Fixed(0.05), 0.05, Procedure::Holm);
     ///
-    /// assert_eq!(test.threshold(), 0.05);
+    /// assert_eq!(test.tolerance(), Tolerance::Fixed(0.05));
     /// ```
-    pub fn threshold(&self) -> f64 {
-        self.threshold
+    pub fn tolerance(&self) -> Tolerance {
+        self.tolerance
+    }
+
+    /// Gets the threshold of correlation at which any non-diagonal value
+    /// causes this [`Test`] to fail.
+    ///
+    /// For a [`Tolerance::SampleSize`], the threshold is derived from the
+    /// number of iterations run so far such that a perfect hash function
+    /// reaches it for _any_ pair of output bits with a probability of at most
+    /// the tolerance's threshold of statistical significance. If too few
+    /// iterations have been run, it cannot be derived and [`None`] is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::Provider;
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::bitwise::Test;
+    /// use bitbelay_tests::tolerance::Tolerance;
+    ///
+    /// let mut provider: Box<dyn Provider> = Box::new(AlphanumericProvider::new(10));
+    /// let hasher = RandomState::new();
+    ///
+    /// let test = Test::<RandomState, 64>::new(&hasher, Tolerance::Fixed(0.05), 0.05, Procedure::Holm);
+    /// assert_eq!(test.threshold(), Some(0.05));
+    ///
+    /// let mut test =
+    ///     Test::<RandomState, 64>::new(&hasher, Tolerance::SampleSize(0.05), 0.05, Procedure::Holm);
+    /// assert_eq!(test.threshold(), None);
+    ///
+    /// test.run(&mut provider, NonZeroUsize::try_from(10_000).unwrap());
+    /// assert!(test.threshold().unwrap() < 0.05);
+    /// ```
+    pub fn threshold(&self) -> Option<f64> {
+        self.tolerance.correlation(self.iterations, N * (N - 1) / 2)
+    }
+
+    /// Gets the threshold of statistical significance of this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::bitwise::Test;
+    /// use bitbelay_tests::tolerance::Tolerance;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::<RandomState, 64>::new(&hasher, Tolerance::Fixed(0.05), 0.01, Procedure::Holm);
+    ///
+    /// assert_eq!(test.significance(), 0.01);
+    /// ```
+    pub fn significance(&self) -> f64 {
+        self.significance
+    }
+
+    /// Gets the procedure used to correct for the number of pairs tested.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::bitwise::Test;
+    /// use bitbelay_tests::tolerance::Tolerance;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test =
+    ///     Test::<RandomState, 64>::new(&hasher, Tolerance::Fixed(0.05), 0.05, Procedure::Bonferroni);
+    ///
+    /// assert_eq!(test.correction(), Procedure::Bonferroni);
+    /// ```
+    pub fn correction(&self) -> Procedure {
+        self.correction
     }
 
     /// Runs a set of iterations using a [`Provider`].
@@ -106,28 +346,68 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
     /// use bitbelay_providers::Provider;
     /// use bitbelay_providers::ascii::AlphanumericProvider;
     /// use bitbelay_providers::numeric::Unsigned64BitProvider;
+    /// use bitbelay_statistics::correction::Procedure;
     /// use bitbelay_tests::correlation::bitwise::Test;
+    /// use bitbelay_tests::tolerance::Tolerance;
     ///
     /// let mut alphas: Box<dyn Provider> = Box::new(AlphanumericProvider::new(10));
     /// let mut numbers: Box<dyn Provider> = Box::new(Unsigned64BitProvider::new(10));
     ///
     /// let hasher = RandomState::new();
-    /// let mut test = Test::<RandomState, 64>::new(&hasher, 0.05);
+    /// let mut test =
+    ///     Test::<RandomState, 64>::new(&hasher, Tolerance::Fixed(0.05), 0.05, Procedure::Holm);
     ///
     /// test.run(&mut alphas, NonZeroUsize::try_from(10).unwrap());
     /// test.run(&mut numbers, NonZeroUsize::try_from(10).unwrap());
     ///
-    /// assert_eq!(test.bit_values()[0].len(), 20);
+    /// assert_eq!(test.iterations(), 20);
     /// ```
     pub fn run(&mut self, provider: &mut Box<dyn Provider>, iterations: NonZeroUsize) {
-        let hashes = compute_hashes(self.build_hasher, provider, iterations);
-        let newly_computed_bit_values = extract_bit_values_from_hashes::<u64, N>(&hashes);
-
-        for (i, values) in newly_computed_bit_values.iter().enumerate() {
-            // SAFETY: the length of the `newly_computed_bit_values` array is statically
-            // guarenteed to be `N`, which is the same as the size of `self.bit_values`.
-            // As such, this indexing will always succeed.
-            self.bit_values[i].extend(values);
+        info!("Computing {} hashes.", iterations);
+
+        let mut remaining = iterations.get();
+
+        while remaining > 0 {
+            let batch = remaining.min(BATCH_SIZE);
+            let hashes = compute_hashes(self.build_hasher, provider, batch);
+            self.accumulate(&hashes);
+
+            remaining -= batch;
+            debug!("Computed {} hashes.", iterations.get() - remaining);
+        }
+    }
+
+    /// Accumulates the bit counts of a set of hashes.
+    ///
+    /// The hashes are transposed into bit-packed columns of [`BLOCK_SIZE`]
+    /// hashes at a time, where bit `k` of column `i` is bit `i` of hash `k`.
+    /// The number of times bits `i` and `j` were both set within the block is
+    /// then the number of ones in the bitwise AND of columns `i` and `j`.
+    fn accumulate(&mut self, hashes: &[u64]) {
+        for block in hashes.chunks(BLOCK_SIZE) {
+            let mut columns = [0u64; N];
+
+            for (k, hash) in block.iter().enumerate() {
+                for (i, column) in columns.iter_mut().enumerate() {
+                    *column |= ((hash >> i) & 1) << k;
+                }
+            }
+
+            // The pairs are visited in the same row-major order in which they are
+            // stored (see [`pair_index()`]).
+            let mut pairs = self.both_ones.iter_mut();
+
+            for i in 0..N {
+                self.ones[i] += columns[i].count_ones() as usize;
+
+                for j in (i + 1)..N {
+                    // SAFETY: there are exactly `N * (N - 1) / 2` pairs where `i < j`,
+                    // which is the length of `both_ones`.
+                    *pairs.next().unwrap() += (columns[i] & columns[j]).count_ones() as usize;
+                }
+            }
+
+            self.iterations += block.len();
         }
     }
 
@@ -142,11 +422,14 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
     ///
     /// use bitbelay_providers::Provider;
     /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
     /// use bitbelay_tests::correlation::bitwise::Test;
+    /// use bitbelay_tests::tolerance::Tolerance;
     ///
     /// let mut provider: Box<dyn Provider> = Box::new(AlphanumericProvider::new(10));
     /// let hasher = RandomState::new();
-    /// let mut test = Test::<RandomState, 64>::new(&hasher, 0.05);
+    /// let mut test =
+    ///     Test::<RandomState, 64>::new(&hasher, Tolerance::Fixed(0.05), 0.05, Procedure::Holm);
     ///
     /// test.run(&mut provider, NonZeroUsize::try_from(10).unwrap());
     ///
@@ -156,26 +439,233 @@ impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
     pub fn results(&self) -> Option<Results> {
         info!("Computing Pearson correlations for each bit-bit mapping.");
 
-        // SAFETY: there should always be at least one output bit, so this should always
-        // unwrap.
-        if self.bit_values.first().unwrap().is_empty() {
+        if self.iterations == 0 {
             return None;
         }
 
-        let mut results = HashMap::new();
+        Some(CorrelationMatrix::from_fn(N, |i, j| {
+            if i == j {
+                debug!("Computing all bit-bit correlations for bit index {}.", i);
+            }
+
+            pearson::phi(
+                self.iterations,
+                self.ones[i],
+                self.ones[j],
+                self.both_ones(i, j),
+            )
+        }))
+    }
+
+    /// Gets the [`PValues`] of a [`Test`] using the [`Test`]'s current
+    /// internal state.
+    ///
+    /// The p-values of the pairs where the correlation is undefined (e.g.,
+    /// where either output bit never changed) are excluded from the correction
+    /// and are [`None`]. If no iterations have been run, [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::Provider;
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::bitwise::Test;
+    /// use bitbelay_tests::tolerance::Tolerance;
+    ///
+    /// let mut provider: Box<dyn Provider> = Box::new(AlphanumericProvider::new(10));
+    /// let hasher = RandomState::new();
+    /// let mut test =
+    ///     Test::<RandomState, 64>::new(&hasher, Tolerance::Fixed(0.05), 0.05, Procedure::Holm);
+    ///
+    /// assert!(test.p_values().is_none());
+    ///
+    /// test.run(&mut provider, NonZeroUsize::try_from(1000).unwrap());
+    ///
+    /// let p_values = test.p_values().unwrap();
+    /// assert_eq!(p_values.upper_triangle().count(), 64 * 63 / 2);
+    /// assert!(p_values.get(0, 0).is_none());
+    ///
+    /// let p_value = p_values.get(0, 1).unwrap();
+    /// assert!(p_value.raw <= p_value.adjusted);
+    /// ```
+    pub fn p_values(&self) -> Option<PValues> {
+        Some(self.p_values_from_results(&self.results()?))
+    }
+
+    /// Computes the [`PValues`] from a set of previously computed
+    /// [`Results`].
+    fn p_values_from_results(&self, results: &Results) -> PValues {
+        let raw = results
+            .upper_triangle()
+            .map(|(_, correlation)| {
+                correlation.and_then(|correlation| pearson::p_value(correlation, self.iterations))
+            })
+            .collect::<Vec<_>>();
+
+        let defined = raw.iter().flatten().copied().collect::<Vec<_>>();
+        let mut adjusted = self.correction.adjust(&defined).into_iter();
+        let mut raw = raw.into_iter();
 
-        for i in 0..N {
-            for j in 0..N {
-                // SAFETY: we checked above that there was at least test iteration run. As
-                // such, this should always unwrap.
-                let correlation = pearson::correlation(&self.bit_values[i], &self.bit_values[j]);
-                results.insert((i, j), correlation);
+        SymmetricMatrix::from_fn(N, |i, j| {
+            if i == j {
+                return None;
             }
 
-            debug!("Computed all bit-bit correlations for bit index {}.", i);
+            // SAFETY: the pairs are visited in the same row-major order as the
+            // upper triangle iterated above, and exactly one adjusted p-value
+            // was computed for each defined raw p-value.
+            let raw = raw.next().unwrap()?;
+            let adjusted = adjusted.next().unwrap();
+
+            Some(PValue { raw, adjusted })
+        })
+    }
+
+    /// Gets the output bits that never changed (i.e., were always or never
+    /// set) across the iterations run, which leaves their correlations
+    /// undefined.
+    fn constant_bits(&self) -> Vec<usize> {
+        self.ones
+            .iter()
+            .enumerate()
+            .filter(|(_, ones)| **ones == 0 || **ones == self.iterations)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Describes the pairs of output bits whose correlations are undefined
+    /// within `results` (if there are any).
+    fn undefined_note(&self, results: &Results) -> Option<String> {
+        let undefined = results
+            .upper_triangle()
+            .filter(|(_, correlation)| correlation.is_none())
+            .count();
+
+        if undefined == 0 {
+            return None;
         }
 
-        Some(results)
+        let bits = self
+            .constant_bits()
+            .iter()
+            .map(|bit| bit.to_string())
+            .collect::<Vec<_>>();
+
+        Some(format!(
+            "The correlations of {} pair(s) of bits were undefined, as output bit(s) {} never \
+             changed across the {} iterations run (try increasing the number of iterations).",
+            undefined,
+            bits.join(", "),
+            self.iterations
+        ))
+    }
+
+    /// Generates the [`Module`](module::Module) for the family-wise
+    /// significance of the correlations.
+    fn significance_module(&self, results: &Results) -> module::Module {
+        let name = "Family-wise Significance";
+
+        let undefined = self.undefined_note(results);
+        let mut p_values = self
+            .p_values_from_results(results)
+            .upper_triangle()
+            .filter_map(|(pair, p_value)| p_value.map(|p_value| (pair, p_value)))
+            .collect::<Vec<_>>();
+
+        if p_values.is_empty() {
+            return module::Module::new(
+                module::Result::Inconclusive,
+                name,
+                None,
+                Some(undefined.unwrap_or_else(|| {
+                    String::from(
+                        "The p-values were not able to be computed (try increasing the number of \
+                         iterations).",
+                    )
+                })),
+            );
+        }
+
+        p_values.sort_by(|(_, a), (_, b)| a.raw.total_cmp(&b.raw));
+
+        let tests = p_values.len();
+        let failures = p_values
+            .iter()
+            .filter(|(_, p_value)| p_value.adjusted <= self.significance)
+            .count();
+        let minimum = p_values
+            .first()
+            .map(|(_, p_value)| p_value.adjusted)
+            .unwrap_or(1.0);
+
+        let (result, mut details) = if failures == 0 {
+            (
+                if undefined.is_some() {
+                    module::Result::Inconclusive
+                } else {
+                    module::Result::Pass
+                },
+                format!(
+                    "The smallest p-value after applying the {} across {} pairs was {:.2e}, which \
+                     is greater than (and, thus, failed to reach) the threshold of statistical \
+                     significance set at {:.2}. As such, the null hypothesis that the output bits \
+                     are uncorrelated **cannot** be rejected.\n\n",
+                    self.correction.name(),
+                    tests,
+                    minimum,
+                    self.significance
+                ),
+            )
+        } else {
+            (
+                module::Result::Fail,
+                format!(
+                    "{} pair(s) of bits had a p-value after applying the {} across {} pairs that \
+                     was less than (and, thus, reached) the threshold of statistical significance \
+                     set at {:.2}. As such, the null hypothesis that the output bits are \
+                     uncorrelated **is** rejected.\n\n",
+                    failures,
+                    self.correction.name(),
+                    tests,
+                    self.significance
+                ),
+            )
+        };
+
+        if let Some(undefined) = &undefined {
+            details.push_str(undefined);
+            details.push_str("\n\n");
+        }
+
+        details.push_str("Most significant pairs:\n");
+
+        p_values
+            .iter()
+            .take(WORST_PAIRS)
+            .map(|((i, j), p_value)| {
+                format!(
+                    "\n  * ({}, {}) => {:.4} (p-value of {:.2e}, adjusted p-value of {:.2e})",
+                    i,
+                    j,
+                    // SAFETY: a p-value is only computed for a pair when its
+                    // correlation is defined.
+                    results.get(*i, *j).unwrap(),
+                    p_value.raw,
+                    p_value.adjusted
+                )
+            })
+            .for_each(|s| details.push_str(&s));
+
+        module::Module::new(
+            result,
+            name,
+            Some(format!("{:.2e}", minimum)),
+            Some(details),
+        )
     }
 }
 
@@ -185,56 +675,76 @@ impl<'a, H: BuildHasher, const N: usize> crate::r#trait::Test for Test<'a, H, N>
     }
 
     fn report_section(&self) -> bitbelay_report::section::Test {
-        // SAFETY: there should always be at least one output bit, so this should always
-        // unwrap.
-        if self.bit_values.first().unwrap().is_empty() {
+        if self.iterations == 0 {
             panic!("a report can only be generated when at least one test has been run!");
         }
 
-        let mut correlations = self
-            .results()
-            // SAFETY: we checked above that there was at least test iteration run. As
-            // such, this should always unwrap.
-            .unwrap()
-            .into_iter()
-            .filter(|((i, j), _)| i != j)
-            .map(|(pos, corr)| {
-                (
-                    pos,
-                    OrderedFloat(match corr {
-                        Some(corr) => corr.abs(),
-                        None => panic!(
-                            "one of the correlations was [`None`], which means that there was \
-                             some issue computing the correlation metrics. This is especially \
-                             possible when there were not many iterations run: for example, if \
-                             every bit in one of the arrays was a 0. We recommend trying the test \
-                             again with more samples. If this continue to happen, please file an \
-                             issue."
-                        ),
-                    }),
-                )
-            })
+        // SAFETY: we checked above that there was at least test iteration run. As
+        // such, this should always unwrap.
+        let results = self.results().unwrap();
+
+        let mut correlations = results
+            .upper_triangle()
+            .filter_map(|(pos, corr)| corr.map(|corr| (pos, OrderedFloat(corr.abs()))))
             .collect::<Vec<_>>();
         correlations.sort_by(|(_, a), (_, b)| b.cmp(a));
 
-        let (result, mut details) = if correlations
-            .iter()
-            .any(|(_, correlation)| correlation.into_inner() >= self.threshold)
-        {
-            (
-                module::Result::Fail,
+        let undefined = self.undefined_note(&results);
+
+        let threshold = self.threshold();
+        let origin = match self.tolerance {
+            Tolerance::Fixed(_) => String::from("a fixed threshold"),
+            Tolerance::SampleSize(significance) => format!(
+                "a threshold derived from {} iterations at a significance of {}",
+                self.iterations, significance
+            ),
+        };
+
+        let (result, mut details) = match threshold {
+            None => (
+                module::Result::Inconclusive,
                 String::from(
-                    "One or more non-diagonals had a correlation greater than or equal to the \
-                     threshold.\n\n",
+                    "The correlation threshold was not able to be derived (try increasing the \
+                     number of iterations).\n\n",
                 ),
-            )
-        } else {
-            (
+            ),
+            Some(threshold)
+                if results
+                    .max_off_diagonal()
+                    .is_some_and(|(_, correlation)| correlation.abs() >= threshold) =>
+            {
+                (
+                    module::Result::Fail,
+                    format!(
+                        "One or more non-diagonals had a correlation greater than or equal to the \
+                         threshold of {:.4} ({}).\n\n",
+                        threshold, origin
+                    ),
+                )
+            }
+            Some(threshold) if undefined.is_some() => (
+                module::Result::Inconclusive,
+                format!(
+                    "All non-diagonals with a defined correlation had a correlation lower than \
+                     the threshold of {:.4} ({}).\n\n",
+                    threshold, origin
+                ),
+            ),
+            Some(threshold) => (
                 module::Result::Pass,
-                String::from("All non-diagonals had a correlation lower than the threshold.\n\n"),
-            )
+                format!(
+                    "All non-diagonals had a correlation lower than the threshold of {:.4} \
+                     ({}).\n\n",
+                    threshold, origin
+                ),
+            ),
         };
 
+        if let Some(undefined) = undefined {
+            details.push_str(&undefined);
+            details.push_str("\n\n");
+        }
+
         details.push_str("Maxmium correlation values:\n");
 
         correlations
@@ -243,30 +753,40 @@ impl<'a, H: BuildHasher, const N: usize> crate::r#trait::Test for Test<'a, H, N>
             .map(|((i, j), correlation)| format!("\n  * ({}, {}) => {:.4}", i, j, correlation))
             .for_each(|s| details.push_str(&s));
 
-        let module =
-            module::Module::new(result, "Pearson correlation threshold", None, Some(details));
-        get_report_base().push_module(module).try_build().unwrap()
+        let module = module::Module::new(
+            result,
+            "Pearson correlation threshold",
+            threshold.map(|threshold| format!("|r| < {:.4}", threshold)),
+            Some(details),
+        );
+
+        get_report_base(self.correction)
+            .push_module(module)
+            .push_module(self.significance_module(&results))
+            .try_build()
+            .unwrap()
     }
 }
 
-/// Computes `iterations` number of hashes using the hasher provided in
-/// `build_hasher` and the data provided by `provider`.
+/// Gets the index of the pair of output bits `(i, j)` (where `i < j`) within
+/// the row-major, strict upper triangle of an `N` by `N` matrix.
+fn pair_index<const N: usize>(i: usize, j: usize) -> usize {
+    // The rows before row `i` hold `(N - 1) + (N - 2) + ... + (N - i)` pairs, and
+    // row `i` starts at column `i + 1`.
+    i * (2 * N - i - 1) / 2 + (j - i - 1)
+}
+
+/// Computes `count` hashes using the hasher provided in `build_hasher` and the
+/// data provided by `provider`.
 fn compute_hashes<H: BuildHasher>(
     build_hasher: &H,
     provider: &mut Box<dyn Provider>,
-    iterations: NonZeroUsize,
+    count: usize,
 ) -> Vec<u64> {
-    info!("Computing {} hashes.", iterations);
-
     provider
-        .provide(iterations.get())
+        .provide(count)
         .into_iter()
-        .enumerate()
-        .map(|(i, input)| {
-            if i % 1_000 == 0 && i > 0 {
-                debug!("Computed {} hashes.", i);
-            }
-
+        .map(|input| {
             let mut hasher = build_hasher.build_hasher();
             hasher.write(input);
             hasher.finish()
@@ -274,71 +794,96 @@ fn compute_hashes<H: BuildHasher>(
         .collect()
 }
 
-/// Extracts each bit within a set of hashes to a [`Vec`] of their own.
+/// Computes the number of iterations needed for a [`Test`] with `bits` output
+/// bits to detect a pair of output bits with a correlation of at least
+/// `correlation` with probability `power` at the given threshold of
+/// statistical `significance`.
 ///
-/// For example, bit 0 from every hash is pulled into the first [`Vec<f64>`]
-/// returned, bit 1 from every hash is pulled into the second [`Vec<f64>`]
-/// return, etc.
-fn extract_bit_values_from_hashes<T, const N: usize>(hashes: &[T]) -> Vec<Vec<f64>>
-where
-    T: Copy
-        + std::ops::Shr<usize, Output = T>
-        + std::ops::BitAnd<Output = T>
-        + From<u64>
-        + Into<u64>,
-{
-    info!(
-        "Extracing bit values across {} {}-bit hashes.",
-        hashes.len(),
-        N
-    );
-
-    let mut bit_values: Vec<Vec<f64>> = vec![Vec::new(); N];
-
-    for hash in hashes {
-        for (i, bit_value) in bit_values.iter_mut().enumerate() {
-            let as_byte = ((*hash >> i) & T::from(1_u64)).into();
-            bit_value.push(as_byte as f64);
-        }
+/// Because every unique pair of output bits is tested, the threshold of
+/// statistical significance is split evenly among them (a Bonferroni
+/// correction, which is conservative for the other correction procedures). If
+/// the number of iterations cannot be computed (see
+/// [`power::correlation()`]), [`None`] is returned.
+///
+/// # Examples
+///
+/// ```
+/// use bitbelay_tests::correlation::bitwise;
+///
+/// let iterations = bitwise::required_iterations(64, 0.01, 0.05, 0.8).unwrap();
+/// assert!(iterations.get() > 100_000);
+///
+/// assert!(bitwise::required_iterations(1, 0.01, 0.05, 0.8).is_none());
+/// ```
+pub fn required_iterations(
+    bits: usize,
+    correlation: f64,
+    significance: f64,
+    power: f64,
+) -> Option<NonZeroUsize> {
+    let pairs = bits * bits.saturating_sub(1) / 2;
+
+    if pairs == 0 {
+        return None;
     }
 
-    bit_values
+    let iterations = power::correlation(correlation, significance / pairs as f64, power)?;
+    NonZeroUsize::try_from(usize::try_from(iterations).ok()?).ok()
 }
 
 /// Populates the boilerplate report information within a
 /// [`Test`](section::Test).
-pub fn get_report_base() -> section::test::Builder {
+pub fn get_report_base(correction: Procedure) -> section::test::Builder {
     let overview = "The bitwise correlation test assesses the correlation between each pair of \
                     bits, known as 'bit-bit comparisons', for a set of output hashes using the \
                     Pearson correlation coefficient.";
 
-    let algorithm =
+    let algorithm = format!(
         "For a specified hash function, a provider and number of iterations is specified:\n\n(1) \
          A random input is generated from the provider and the output hash is computed. This \
-         happens for the number of iterations specified, and the results are accumulated in an \
-         array. You can think of this as, roughly, a matrix of bits where each row is an output \
-         hash and each column is the bit at position _i_ of the output hash.\n\n(2) This matrix \
-         of bits is effectively transposed, meaning that an array is created for each bit \
-         position, with each array containing the values of that bit across all hashes. These \
-         arrays are referred to as 'bit values' for their respective bit positions.\n\n(3) For \
-         each pair of bit positions, the Pearson correlation is calculated between their \
-         corresponding arrays of bit values. This measures the level of correlation between every \
-         pair of output bits. Note that, though Pearson correlation is symmetric (meaning the \
-         correlation of (i, j) is the same as the correlation of (j, i)), all pairwise \
-         comparisons are computed.\n\n(4) The resulting correlations are stored in a HashMap, \
-         with each key being a tuple `(i, j)` representing the Pearson correlation coefficient \
-         between the bit values at position _i_ and the bit values at position _j_.";
-
-    let interpretation = "* A 'good' result is one where bits within the output hashes are not \
-                          highly correlated with one another. This indicates that the bits are \
-                          largely independent under the test.\n\n * There is one exception, \
-                          called the 'diagonal' of the correlation matrix. The rationale behind \
-                          this is straightforward: when an array of bit values at position _i_ is \
-                          compared against itself, the arrays are identical, and the correlation \
-                          should be 1.0. This presence of this phenomenon is often used as a \
-                          check to ensure that results are being calculated as expected.";
-
-    let sources = "* https://en.wikipedia.org/wiki/Pearson_correlation_coefficient";
+         happens for the number of iterations specified.\n\n(2) The hashes are transposed in \
+         blocks of 64, such that bit _k_ of the _i_th column is bit _i_ of the _k_th hash. For \
+         each bit position _i_, the number of times the bit was set is accumulated by counting \
+         the ones in its column, and, for each pair of bit positions _(i, j)_, the number of \
+         times both bits were set is accumulated by counting the ones in the bitwise AND of their \
+         columns. Only these counts are kept, so the memory used does not grow with the number of \
+         iterations.\n\n(3) For each pair of bit positions, the Pearson correlation is derived \
+         from these counts (for binary variables, this is known as the phi coefficient). This \
+         measures the level of correlation between every pair of output bits. Because Pearson \
+         correlation is symmetric (meaning the correlation of (i, j) is the same as the \
+         correlation of (j, i)), only the correlations where _i_ <= _j_ are computed.\n\n(4) The \
+         resulting correlations are stored in a dense, symmetric correlation matrix, where the \
+         entry at row _i_ and column _j_ is the Pearson correlation coefficient between the bit \
+         values at position _i_ and the bit values at position _j_.\n\n(5) For each unique pair \
+         of bit positions, a two-sided p-value is computed under the null hypothesis that the \
+         bits are uncorrelated (the correlation multiplied by the square root of the number of \
+         iterations is approximately standard normal). Because every pair is tested at once, the \
+         p-values are adjusted using the {} (which controls the {}) before being compared against \
+         the chosen threshold of statistical significance.",
+        correction.name(),
+        correction.controls()
+    );
+
+    let interpretation =
+        "* A 'good' result is one where bits within the output hashes are not highly correlated \
+         with one another. This indicates that the bits are largely independent under the \
+         test.\n\n * There is one exception, called the 'diagonal' of the correlation matrix. The \
+         rationale behind this is straightforward: when an array of bit values at position _i_ is \
+         compared against itself, the arrays are identical, and the correlation should be 1.0. \
+         This presence of this phenomenon is often used as a check to ensure that results are \
+         being calculated as expected.\n\n* The correlation threshold is either fixed or derived \
+         from the number of iterations run using the Fisher transformation, in which case a \
+         perfect hash function reaches it for any pair of output bits with a probability of at \
+         most the chosen threshold of statistical significance.\n\n* A fixed correlation \
+         threshold does not account for the number of iterations run, so the family-wise \
+         significance of the correlations is also reported: if any adjusted p-value is below the \
+         threshold of statistical significance, the output bits are very likely correlated.";
+
+    let sources = format!(
+        "* https://en.wikipedia.org/wiki/Pearson_correlation_coefficient\n* \
+         https://en.wikipedia.org/wiki/Fisher_transformation\n* {}",
+        correction.source()
+    );
 
     test::Builder::default()
         .title("Bitwise Pearson Correlation".to_string())
@@ -359,19 +904,119 @@ pub fn get_report_base() -> section::test::Builder {
 
 #[cfg(test)]
 mod tests {
-    use crate::correlation::bitwise::extract_bit_values_from_hashes;
+    use std::hash::RandomState;
+
+    use bitbelay_report::section::test::module::Result;
+    use bitbelay_statistics::correction::Procedure;
+    use bitbelay_statistics::correlation::pearson;
+
+    use crate::correlation::bitwise::Test;
+    use crate::correlation::bitwise::pair_index;
+    use crate::tolerance::Tolerance;
+    use crate::r#trait::Test as _;
+
+    fn test(hasher: &RandomState) -> Test<'_, RandomState, 64> {
+        Test::new(hasher, Tolerance::Fixed(0.05), 0.05, Procedure::Holm)
+    }
 
     #[test]
-    fn it_extracts_bit_values_from_hashes_correctly() {
-        let hashes: [u64; 4] = [0x00, 0x01, 0x02, 0x03];
-        let bit_values = extract_bit_values_from_hashes::<u64, 64>(&hashes[..]);
+    fn it_accumulates_bit_counts_correctly() {
+        let hasher = RandomState::new();
+        let mut test = test(&hasher);
+
+        test.accumulate(&[0x00, 0x01, 0x02, 0x03]);
+
+        assert_eq!(test.iterations(), 4);
+        assert_eq!(test.ones()[0], 2);
+        assert_eq!(test.ones()[1], 2);
+        assert!(test.ones()[2..].iter().all(|ones| *ones == 0));
+        assert_eq!(test.both_ones(0, 1), 1);
+        assert_eq!(test.both_ones(1, 0), 1);
+        assert_eq!(test.both_ones(0, 2), 0);
+    }
+
+    #[test]
+    fn pair_indices_are_packed_in_row_major_order() {
+        let mut expected = 0;
 
-        #[allow(clippy::needless_range_loop)]
-        for i in 2..64 {
-            assert_eq!(bit_values[i], &[0., 0., 0., 0.]);
+        for i in 0..64 {
+            for j in (i + 1)..64 {
+                assert_eq!(pair_index::<64>(i, j), expected);
+                expected += 1;
+            }
         }
 
-        assert_eq!(bit_values[1], &[0., 0., 1., 1.]);
-        assert_eq!(bit_values[0], &[0., 1., 0., 1.]);
+        assert_eq!(expected, 64 * 63 / 2);
+    }
+
+    #[test]
+    fn it_matches_pearson_correlation_across_blocks() {
+        // A simple linear congruential generator, with the top bit of each hash
+        // tied to the bottom bit to introduce a known correlation.
+        let mut state = 0x853C49E6748FEA9B_u64;
+        let hashes = (0..1_000)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                let hash = state ^ (state >> 29);
+                (hash & !(1 << 63)) | ((hash & 1) << 63)
+            })
+            .collect::<Vec<_>>();
+
+        let hasher = RandomState::new();
+        let mut test = test(&hasher);
+
+        // Accumulate in uneven chunks to cover partial blocks.
+        for chunk in hashes.chunks(100) {
+            test.accumulate(chunk);
+        }
+
+        let bits = |i: usize| {
+            hashes
+                .iter()
+                .map(|hash| ((hash >> i) & 1) as f64)
+                .collect::<Vec<_>>()
+        };
+
+        let results = test.results().unwrap();
+
+        for (i, j) in [(0, 1), (5, 17), (0, 63), (62, 63)] {
+            let expected = pearson::correlation(&bits(i), &bits(j)).unwrap();
+            assert!((results.get(i, j).unwrap() - expected).abs() < 1e-12);
+            assert!((results.get(j, i).unwrap() - expected).abs() < 1e-12);
+        }
+
+        assert!((results.get(0, 63).unwrap() - 1.0).abs() < 1e-12);
+        assert!((results.get(7, 7).unwrap() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn it_reports_undefined_correlations_as_inconclusive() {
+        let mut state = 0x853C49E6748FEA9B_u64;
+        let hashes = (0..1_000)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                // The top bit is never set.
+                (state ^ (state >> 29)) & !(1 << 63)
+            })
+            .collect::<Vec<_>>();
+
+        let hasher = RandomState::new();
+        let mut test = test(&hasher);
+        test.accumulate(&hashes);
+
+        let p_values = test.p_values().unwrap();
+        assert!(p_values.get(0, 63).is_none());
+        assert!(p_values.get(0, 1).is_some());
+
+        let section = test.report_section();
+
+        for module in section.modules().iter() {
+            assert!(!matches!(module.result(), Result::Pass));
+            assert!(module.details().unwrap().contains("output bit(s) 63"));
+        }
     }
 }
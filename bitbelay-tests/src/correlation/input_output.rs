@@ -0,0 +1,525 @@
+//! Input-output bit correlation test.
+
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+use std::num::NonZeroUsize;
+
+use bitbelay_providers::Provider;
+use bitbelay_report::section;
+use bitbelay_report::section::test;
+use bitbelay_report::section::test::module;
+use bitbelay_statistics::correlation::pearson;
+use colored::Colorize as _;
+
+/// The number of strongest correlations listed within the report.
+const STRONGEST_CORRELATIONS: usize = 10;
+
+/// Results from an input-output correlation test.
+///
+/// Each key is a tuple of `(input bit, output bit)`, and each value is the
+/// phi coefficient between the two bits (or [`None`] if either bit never
+/// changed).
+pub type Results = HashMap<(usize, usize), Option<f64>>;
+
+/// An input-output bit correlation test.
+#[derive(Debug)]
+pub struct Test<'a, H: BuildHasher, const N: usize> {
+    /// The build hasher.
+    build_hasher: &'a H,
+
+    /// The data provider.
+    provider: Box<dyn Provider>,
+
+    /// The number of leading input bits that are correlated with the output
+    /// bits.
+    input_bits: usize,
+
+    /// The number of keys hashed.
+    keys: usize,
+
+    /// The number of times each input bit was set.
+    input_ones: Vec<usize>,
+
+    /// The number of times each output bit was set.
+    output_ones: [usize; N],
+
+    /// The number of times each input bit and output bit were both set
+    /// (indexed by input bit, then output bit).
+    both_ones: Vec<[usize; N]>,
+
+    /// The threshold of correlation at which any input-output pair causes the
+    /// test to fail.
+    threshold: f64,
+}
+
+impl<'a, H: BuildHasher, const N: usize> Test<'a, H, N> {
+    /// Creates a new [`Test`].
+    ///
+    /// Only the first `max_input_bits` bits of each key (or every bit, if the
+    /// keys are shorter) are correlated with the output bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::correlation::input_output::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::<RandomState, 64>::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(256).unwrap(),
+    ///     0.05,
+    /// );
+    ///
+    /// assert_eq!(test.input_bits(), 80);
+    /// ```
+    pub fn new(
+        build_hasher: &'a H,
+        mut provider: Box<dyn Provider>,
+        max_input_bits: NonZeroUsize,
+        threshold: f64,
+    ) -> Self {
+        let input_bits = (provider.bytes_per_input() * 8).min(max_input_bits.get());
+
+        Self {
+            build_hasher,
+            provider,
+            input_bits,
+            keys: 0,
+            input_ones: vec![0; input_bits],
+            output_ones: [0; N],
+            both_ones: vec![[0; N]; input_bits],
+            threshold,
+        }
+    }
+
+    /// Gets the build hasher for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::BuildHasher as _;
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::correlation::input_output::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::<RandomState, 64>::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(256).unwrap(),
+    ///     0.05,
+    /// );
+    ///
+    /// // Used as a surrogate to test that the [`BuildHasher`]s are the same.
+    /// assert_eq!(test.build_hasher().hash_one("42"), hasher.hash_one("42"));
+    /// ```
+    pub fn build_hasher(&self) -> &H {
+        self.build_hasher
+    }
+
+    /// Gets the data provider for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::Provider as _;
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::correlation::input_output::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let test = Test::<RandomState, 64>::new(
+    ///     &hasher,
+    ///     provider.clone(),
+    ///     NonZeroUsize::try_from(256).unwrap(),
+    ///     0.05,
+    /// );
+    ///
+    /// assert_eq!(test.provider().name(), provider.name());
+    /// ```
+    pub fn provider(&self) -> &dyn Provider {
+        self.provider.as_ref()
+    }
+
+    /// Gets the number of leading input bits that are correlated with the
+    /// output bits within this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::correlation::input_output::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::<RandomState, 64>::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(16).unwrap(),
+    ///     0.05,
+    /// );
+    ///
+    /// assert_eq!(test.input_bits(), 16);
+    /// ```
+    pub fn input_bits(&self) -> usize {
+        self.input_bits
+    }
+
+    /// Gets the number of keys hashed within this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::correlation::input_output::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::<RandomState, 64>::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(256).unwrap(),
+    ///     0.05,
+    /// );
+    ///
+    /// test.single_iteration();
+    /// assert_eq!(test.keys(), 1);
+    /// ```
+    pub fn keys(&self) -> usize {
+        self.keys
+    }
+
+    /// Gets the threshold of this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::correlation::input_output::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::<RandomState, 64>::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(256).unwrap(),
+    ///     0.05,
+    /// );
+    ///
+    /// assert_eq!(test.threshold(), 0.05);
+    /// ```
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Hashes a single key from the provider and records which input bits and
+    /// output bits were set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::correlation::input_output::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::<RandomState, 64>::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(256).unwrap(),
+    ///     0.05,
+    /// );
+    ///
+    /// test.single_iteration();
+    /// assert_eq!(test.keys(), 1);
+    /// ```
+    pub fn single_iteration(&mut self) {
+        // SAFETY: we hardcode generating one value, so we know this pop must unwrap.
+        let key = self.provider.provide(1).pop().unwrap();
+        let hash = self.build_hasher.hash_one(key);
+
+        let output = (0..N).filter(|j| (hash >> j) & 1 == 1).collect::<Vec<_>>();
+
+        for j in &output {
+            self.output_ones[*j] += 1;
+        }
+
+        for i in 0..self.input_bits.min(key.len() * 8) {
+            if (key[i / 8] >> (i % 8)) & 1 == 1 {
+                self.input_ones[i] += 1;
+
+                for j in &output {
+                    self.both_ones[i][*j] += 1;
+                }
+            }
+        }
+
+        self.keys += 1;
+    }
+
+    /// Gets the [`Results`] of a [`Test`] using the [`Test`]'s current interal
+    /// state.
+    ///
+    /// If no keys have been hashed, [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::correlation::input_output::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::<RandomState, 64>::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(256).unwrap(),
+    ///     0.05,
+    /// );
+    ///
+    /// assert!(test.results().is_none());
+    ///
+    /// for _ in 0..100 {
+    ///     test.single_iteration();
+    /// }
+    ///
+    /// let results = test.results().unwrap();
+    /// assert_eq!(results.len(), 80 * 64);
+    /// ```
+    pub fn results(&self) -> Option<Results> {
+        if self.keys == 0 {
+            return None;
+        }
+
+        let mut results = HashMap::new();
+
+        for i in 0..self.input_bits {
+            for j in 0..N {
+                let phi = pearson::phi(
+                    self.keys,
+                    self.input_ones[i],
+                    self.output_ones[j],
+                    self.both_ones[i][j],
+                );
+
+                results.insert((i, j), phi);
+            }
+        }
+
+        Some(results)
+    }
+}
+
+impl<'a, H: BuildHasher, const N: usize> crate::r#trait::Test for Test<'a, H, N> {
+    fn title(&self) -> &'static str {
+        "Input-Output Correlation"
+    }
+
+    fn report_section(&self) -> bitbelay_report::section::Test {
+        let results = match self.results() {
+            Some(results) => results,
+            None => panic!("a report can only be generated when at least one test has been run!"),
+        };
+
+        let constant = self
+            .input_ones
+            .iter()
+            .filter(|ones| **ones == 0 || **ones == self.keys)
+            .count();
+
+        let mut correlations = results
+            .into_iter()
+            .filter_map(|(pos, phi)| phi.map(|phi| (pos, phi)))
+            .collect::<Vec<_>>();
+        correlations.sort_by(|(_, a), (_, b)| b.abs().total_cmp(&a.abs()));
+
+        let (result, mut details) = if correlations.is_empty() {
+            (
+                module::Result::Inconclusive,
+                String::from(
+                    "No correlations were able to be computed (try increasing the number of \
+                     iterations).",
+                ),
+            )
+        } else if correlations
+            .iter()
+            .any(|(_, phi)| phi.abs() >= self.threshold)
+        {
+            (
+                module::Result::Fail,
+                String::from(
+                    "One or more input-output bit pairs had a correlation greater than or equal \
+                     to the threshold.",
+                ),
+            )
+        } else {
+            (
+                module::Result::Pass,
+                String::from(
+                    "All input-output bit pairs had a correlation lower than the threshold.",
+                ),
+            )
+        };
+
+        if constant > 0 {
+            details.push_str(&format!(
+                " Note that {} input bit(s) never changed across the keys provided and were \
+                 excluded.",
+                constant
+            ));
+        }
+
+        if !correlations.is_empty() {
+            details.push_str(&format!("\n\n{}\n", "Strongest Correlations".italic()));
+
+            correlations
+                .iter()
+                .take(STRONGEST_CORRELATIONS)
+                .map(|((i, j), phi)| format!("\n  * (input {}, output {}) => {:.4}", i, j, phi))
+                .for_each(|s| details.push_str(&s));
+        }
+
+        let value = correlations
+            .first()
+            .map(|(_, phi)| format!("{:.4}", phi.abs()));
+
+        let module = module::Module::new(result, "Phi correlation threshold", value, Some(details));
+
+        get_report_base(self.provider.as_ref(), self.input_bits, self.keys)
+            .push_module(module)
+            .try_build()
+            .unwrap()
+    }
+}
+
+/// Populates the boilerplate report information within a
+/// [`Test`](section::Test).
+pub fn get_report_base(
+    provider: &dyn Provider,
+    input_bits: usize,
+    iterations: usize,
+) -> section::test::Builder {
+    let overview = "The input-output correlation test assesses the correlation between each bit \
+                    of the input keys and each bit of the output hashes using the phi coefficient \
+                    (the Pearson correlation coefficient for two binary variables).";
+
+    let relation = "The bitwise correlation test only examines whether output bits are correlated \
+                    with one another. A hash function may pass that test while still leaking its \
+                    input linearly: for example, a hash function that XORs an input byte directly \
+                    into its output leaves an output bit that is strongly correlated with a \
+                    specific input bit. Such leakage allows an adversary to predict (or control) \
+                    parts of the output from the input.";
+
+    let algorithm = format!(
+        "(1) For each of the {iterations} keys generated by the data provider, the key is hashed. \
+         For each of the first {input_bits} input bits and each output bit, the number of times \
+         the input bit was set, the output bit was set, and both were set is counted.\n\n(2) For \
+         each (input bit, output bit) pair, the phi coefficient is computed from these counts. \
+         Input bits that never change (e.g., the most significant bit of an ASCII character) are \
+         excluded, as their correlation is undefined.\n\n(3) The absolute values of the \
+         correlations are compared against the chosen threshold."
+    );
+
+    let interpretation =
+        "Under this test design:\n\n* If every input-output pair has a correlation lower than the \
+         threshold, the test passes.\n\n* If any input-output pair has a correlation greater than \
+         or equal to the threshold, the test fails, as that output bit leaks information about \
+         that input bit. The strongest correlations are listed to help locate the leakage.";
+
+    let sources = "* https://en.wikipedia.org/wiki/Phi_coefficient";
+
+    test::Builder::default()
+        .title(format!(
+            "Input-Output Correlation / {} / {} input bits / {} iterations",
+            provider.name(),
+            input_bits,
+            iterations
+        ))
+        .unwrap()
+        .description(format!(
+            "{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}",
+            "Overview".italic(),
+            overview,
+            "Relation to Hashing".italic(),
+            relation,
+            "Algorithm".italic(),
+            algorithm,
+            "Interpretation".italic(),
+            interpretation,
+            "Sources".italic(),
+            sources,
+        ))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::BuildHasherDefault;
+    use std::hash::Hasher;
+
+    use bitbelay_providers::ascii::AlphanumericProvider;
+
+    use super::*;
+
+    /// A hasher that places the first byte of the most recent write (i.e., the
+    /// first byte of the key, as slices are prefixed by their length) into an
+    /// otherwise well-mixed output.
+    #[derive(Debug, Default)]
+    struct Leaky {
+        /// The inner hasher.
+        inner: std::hash::DefaultHasher,
+
+        /// The first byte of the most recent write.
+        leaked: u8,
+    }
+
+    impl Hasher for Leaky {
+        fn finish(&self) -> u64 {
+            (self.inner.finish() & !0xFF) | u64::from(self.leaked)
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            self.leaked = bytes.first().copied().unwrap_or_default();
+            self.inner.write(bytes);
+        }
+    }
+
+    #[test]
+    fn leaked_bits_are_correlated() {
+        let hasher = BuildHasherDefault::<Leaky>::default();
+        let mut test = Test::<_, 64>::new(
+            &hasher,
+            Box::new(AlphanumericProvider::new(10)),
+            NonZeroUsize::try_from(256).unwrap(),
+            0.05,
+        );
+
+        for _ in 0..1_000 {
+            test.single_iteration();
+        }
+
+        let results = test.results().unwrap();
+        assert!((results[&(0, 0)].unwrap() - 1.0).abs() < 1e-9);
+        assert!(results[&(8, 0)].unwrap().abs() < 0.5);
+    }
+}
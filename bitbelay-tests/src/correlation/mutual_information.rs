@@ -0,0 +1,630 @@
+//! Mutual information test.
+
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+
+use bitbelay_providers::Provider;
+use bitbelay_report::section;
+use bitbelay_report::section::test;
+use bitbelay_report::section::test::module;
+use bitbelay_statistics::chi_squared::ContingencyTest;
+use bitbelay_statistics::chi_squared::Statistic;
+use bitbelay_statistics::correction::Procedure;
+use bitbelay_statistics::entropy;
+use colored::Colorize as _;
+
+/// The number of bytes in the output hash.
+const BYTES: usize = (u64::BITS / u8::BITS) as usize;
+
+/// The number of distinct values that a byte can take on.
+const VALUES: usize = 1 << u8::BITS;
+
+/// The number of pairs of output bytes.
+const PAIRS: usize = BYTES * (BYTES - 1) / 2;
+
+/// The minimum mean expected count of the cells of a joint histogram for the
+/// G-test to be considered reliable.
+const MIN_EXPECTED: usize = 5;
+
+/// The minimum number of hashes needed for the test to be conclusive.
+pub const MIN_ITERATIONS: usize = MIN_EXPECTED * VALUES * VALUES;
+
+/// The number of most significant pairs listed within the report.
+const WORST_PAIRS: usize = 5;
+
+/// The estimated mutual information between a pair of output bytes.
+#[derive(Clone, Copy, Debug)]
+pub struct Estimate {
+    /// The mutual information (in bits).
+    pub mutual_information: f64,
+
+    /// The p-value of the G-test of independence.
+    pub p_value: f64,
+
+    /// The p-value of the G-test of independence after adjusting for the
+    /// number of pairs tested.
+    pub adjusted_p_value: f64,
+}
+
+/// Results from a mutual information test.
+///
+/// Each key is a pair of output bytes `(i, j)` (where `i < j` and byte `0` is
+/// the least significant byte), and each value is the [`Estimate`] for that
+/// pair (or [`None`] if it could not be computed).
+pub type Results = HashMap<(usize, usize), Option<Estimate>>;
+
+/// Gets every pair of output bytes `(i, j)` where `i < j`.
+fn pairs() -> impl Iterator<Item = (usize, usize)> {
+    (0..BYTES).flat_map(|i| ((i + 1)..BYTES).map(move |j| (i, j)))
+}
+
+/// Extracts an output byte from a hash.
+fn byte(hash: u64, index: usize) -> usize {
+    ((hash >> (index as u32 * u8::BITS)) & 0xFF) as usize
+}
+
+/// A mutual information test.
+#[derive(Debug)]
+pub struct Test<'a, H: BuildHasher> {
+    /// The build hasher.
+    build_hasher: &'a H,
+
+    /// The data provider.
+    provider: Box<dyn Provider>,
+
+    /// The joint histogram of every pair of output bytes (in the order
+    /// returned by [`pairs()`]).
+    histograms: Vec<Vec<Vec<usize>>>,
+
+    /// The number of hashes computed.
+    hashes: usize,
+
+    /// The threshold of statistical significance for the family of pairs.
+    threshold: f64,
+
+    /// The procedure used to correct for the number of pairs tested.
+    correction: Procedure,
+}
+
+impl<'a, H: BuildHasher> Test<'a, H> {
+    /// Creates a new [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::mutual_information::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
+    ///
+    /// assert_eq!(test.hashes(), 0);
+    /// ```
+    pub fn new(
+        build_hasher: &'a H,
+        provider: Box<dyn Provider>,
+        threshold: f64,
+        correction: Procedure,
+    ) -> Self {
+        Self {
+            build_hasher,
+            provider,
+            histograms: vec![vec![vec![0; VALUES]; VALUES]; PAIRS],
+            hashes: 0,
+            threshold,
+            correction,
+        }
+    }
+
+    /// Gets the build hasher for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::BuildHasher as _;
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::mutual_information::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
+    ///
+    /// // Used as a surrogate to test that the [`BuildHasher`]s are the same.
+    /// assert_eq!(test.build_hasher().hash_one("42"), hasher.hash_one("42"));
+    /// ```
+    pub fn build_hasher(&self) -> &H {
+        self.build_hasher
+    }
+
+    /// Gets the data provider for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::Provider as _;
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::mutual_information::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let test = Test::new(&hasher, provider.clone(), 0.05, Procedure::Holm);
+    ///
+    /// assert_eq!(test.provider().name(), provider.name());
+    /// ```
+    pub fn provider(&self) -> &dyn Provider {
+        self.provider.as_ref()
+    }
+
+    /// Gets the number of hashes computed within this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::mutual_information::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
+    ///
+    /// test.single_iteration();
+    /// assert_eq!(test.hashes(), 1);
+    /// ```
+    pub fn hashes(&self) -> usize {
+        self.hashes
+    }
+
+    /// Gets the threshold of statistical significance for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::mutual_information::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
+    ///
+    /// assert_eq!(test.threshold(), 0.05);
+    /// ```
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Gets the procedure used to correct for the number of pairs of output
+    /// bytes tested.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::mutual_information::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     0.05,
+    ///     Procedure::BenjaminiHochberg,
+    /// );
+    ///
+    /// assert_eq!(test.correction(), Procedure::BenjaminiHochberg);
+    /// ```
+    pub fn correction(&self) -> Procedure {
+        self.correction
+    }
+
+    /// Gets the mutual information (in bits) that is expected between a pair
+    /// of output bytes purely due to the bias of the estimator when the bytes
+    /// are truly independent.
+    ///
+    /// If no hashes have been computed, [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::mutual_information::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
+    ///
+    /// assert!(test.expected_bias().is_none());
+    ///
+    /// test.single_iteration();
+    /// assert!(test.expected_bias().unwrap() > 0.0);
+    /// ```
+    pub fn expected_bias(&self) -> Option<f64> {
+        if self.hashes == 0 {
+            return None;
+        }
+
+        let cells = ((VALUES - 1) * (VALUES - 1)) as f64;
+        Some(cells / (2.0 * self.hashes as f64 * std::f64::consts::LN_2))
+    }
+
+    /// Hashes the next key from the provider and updates the joint histogram
+    /// of every pair of output bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::mutual_information::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
+    ///
+    /// test.single_iteration();
+    /// assert_eq!(test.hashes(), 1);
+    /// ```
+    pub fn single_iteration(&mut self) {
+        // SAFETY: we hardcode generating one value, so we know this pop must unwrap.
+        let key = self.provider.provide(1).pop().unwrap();
+        let hash = self.build_hasher.hash_one(key);
+
+        for (histogram, (i, j)) in self.histograms.iter_mut().zip(pairs()) {
+            histogram[byte(hash, i)][byte(hash, j)] += 1;
+        }
+
+        self.hashes += 1;
+    }
+
+    /// Gets the [`Results`] of a [`Test`] using the [`Test`]'s current interal
+    /// state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::mutual_information::MIN_ITERATIONS;
+    /// use bitbelay_tests::correlation::mutual_information::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
+    ///
+    /// for _ in 0..MIN_ITERATIONS {
+    ///     test.single_iteration();
+    /// }
+    ///
+    /// let results = test.results();
+    /// assert_eq!(results.len(), 28);
+    ///
+    /// let estimate = results[&(0, 7)].unwrap();
+    /// assert!(estimate.p_value <= estimate.adjusted_p_value);
+    /// ```
+    pub fn results(&self) -> Results {
+        let estimates = self
+            .histograms
+            .iter()
+            .map(|histogram| {
+                entropy::mutual_information(histogram)
+                    .zip(ContingencyTest::independence(histogram, Statistic::G))
+            })
+            .collect::<Vec<_>>();
+
+        // NOTE: only the pairs for which an estimate could be computed are
+        // included within the family when adjusting the p-values.
+        let mut adjusted = self
+            .correction
+            .adjust(
+                &estimates
+                    .iter()
+                    .flatten()
+                    .map(|(_, p_value)| *p_value)
+                    .collect::<Vec<_>>(),
+            )
+            .into_iter();
+
+        estimates
+            .into_iter()
+            .zip(pairs())
+            .map(|(estimate, pair)| {
+                let estimate = estimate.map(|(mutual_information, p_value)| Estimate {
+                    mutual_information,
+                    p_value,
+                    // SAFETY: one adjusted p-value is returned for every
+                    // computed estimate, so this must unwrap.
+                    adjusted_p_value: adjusted.next().unwrap(),
+                });
+
+                (pair, estimate)
+            })
+            .collect()
+    }
+}
+
+impl<'a, H: BuildHasher> crate::r#trait::Test for Test<'a, H> {
+    fn title(&self) -> &'static str {
+        "Mutual Information"
+    }
+
+    fn report_section(&self) -> section::Test {
+        let builder = get_report_base(self.provider.as_ref(), self.hashes, self.correction);
+        let name = "Byte Independence";
+
+        if self.hashes < MIN_ITERATIONS {
+            return builder
+                .push_module(module::Module::new(
+                    module::Result::Inconclusive,
+                    name,
+                    None,
+                    Some(format!(
+                        "At least {} iterations are needed for the cells of the joint histograms \
+                         to have a mean expected count of {} (only {} were run).",
+                        MIN_ITERATIONS, MIN_EXPECTED, self.hashes
+                    )),
+                ))
+                .try_build()
+                .unwrap();
+        }
+
+        let mut results = Vec::new();
+
+        for (pair, estimate) in self.results() {
+            match estimate {
+                Some(estimate) => results.push((pair, estimate)),
+                None => {
+                    return builder
+                        .push_module(module::Module::new(
+                            module::Result::Inconclusive,
+                            name,
+                            None,
+                            Some(format!(
+                                "The mutual information between bytes {} and {} was not able to \
+                                 be computed (one of the bytes may be constant).",
+                                pair.0, pair.1
+                            )),
+                        ))
+                        .try_build()
+                        .unwrap();
+                }
+            }
+        }
+
+        results.sort_by(|(_, a), (_, b)| a.p_value.total_cmp(&b.p_value));
+
+        // SAFETY: there are always 28 pairs of output bytes.
+        let (_, minimum) = *results.first().unwrap();
+        let failures = results
+            .iter()
+            .filter(|(_, estimate)| estimate.adjusted_p_value <= self.threshold)
+            .count();
+
+        let summary = if failures == 0 {
+            format!(
+                "The smallest p-value after applying the {} across {} pairs was {:.2e}, which is \
+                 greater than (and, thus, failed to reach) the threshold of statistical \
+                 significance set at {:.2}. As such, the null hypothesis that the output bytes \
+                 are independent **cannot** be rejected.",
+                self.correction.name(),
+                PAIRS,
+                minimum.adjusted_p_value,
+                self.threshold
+            )
+        } else {
+            format!(
+                "{} pair(s) of bytes had a p-value after applying the {} across {} pairs that was \
+                 less than (and, thus, reached) the threshold of statistical significance set at \
+                 {:.2}. As such, the null hypothesis that the output bytes are independent **is** \
+                 rejected.",
+                failures,
+                self.correction.name(),
+                PAIRS,
+                self.threshold
+            )
+        };
+
+        // SAFETY: at least one hash was computed, so the bias can be computed.
+        let mut details = format!(
+            "{}\n\nThe expected mutual information between independent bytes due to estimator \
+             bias alone is {:.2e} bits.\n\n{}\n",
+            summary,
+            self.expected_bias().unwrap(),
+            "Most Significant".italic()
+        );
+
+        for ((i, j), estimate) in results.iter().take(WORST_PAIRS) {
+            details.push_str(&format!(
+                "\n* Bytes ({}, {}): mutual information of {:.2e} bits (p-value of {:.2e}, \
+                 adjusted p-value of {:.2e}).",
+                i, j, estimate.mutual_information, estimate.p_value, estimate.adjusted_p_value
+            ));
+        }
+
+        builder
+            .push_module(module::Module::new(
+                if failures == 0 {
+                    module::Result::Pass
+                } else {
+                    module::Result::Fail
+                },
+                name,
+                Some(format!("{:.2e}", minimum.adjusted_p_value)),
+                Some(details),
+            ))
+            .try_build()
+            .unwrap()
+    }
+}
+
+/// Populates the boilerplate report information within a
+/// [`Test`](section::Test).
+pub fn get_report_base(
+    provider: &dyn Provider,
+    iterations: usize,
+    correction: Procedure,
+) -> section::test::Builder {
+    let overview = "The mutual information test assesses whether any pair of output bytes shares \
+                    information, regardless of the shape of the relationship between them.";
+
+    let relation = "Correlation coefficients only detect linear (or, in the case of Spearman, \
+                    monotonic) relationships. A poorly mixed hash function may produce bytes that \
+                    depend on one another in more complex ways (e.g., one byte being the XOR of \
+                    another byte with a constant). Mutual information is zero if and only if two \
+                    variables are independent, so it detects any such dependence given enough \
+                    observations.";
+
+    let algorithm = format!(
+        "(1) For {iterations} iterations, the next key is generated from the data provider and \
+         hashed.\n\n(2) For each of the {PAIRS} pairs of output bytes, a {VALUES}x{VALUES} joint \
+         histogram of the values of the two bytes is accumulated.\n\n(3) The mutual information \
+         between each pair of bytes is estimated from its joint histogram.\n\n(4) Under the null \
+         hypothesis that the bytes are independent, the G statistic (2N ln(2) times the mutual \
+         information in bits) follows a chi-squared distribution with {dof} degrees of freedom, \
+         from which a p-value is computed. Because {PAIRS} pairs are tested at once, the p-values \
+         are adjusted using the {name} (which controls the {controls}) before being compared \
+         against the chosen threshold.",
+        dof = (VALUES - 1) * (VALUES - 1),
+        name = correction.name(),
+        controls = correction.controls()
+    );
+
+    let interpretation = format!(
+        "Under this test design:\n\n* If every adjusted p-value is above the threshold, the test \
+         passes, as there is no evidence that any pair of output bytes is dependent.\n\n* If any \
+         adjusted p-value is below the threshold, the test fails, as the pair of output bytes is \
+         very likely dependent.\n\n* If fewer than {MIN_ITERATIONS} iterations are run, the test \
+         is inconclusive, as the chi-squared approximation is not reliable."
+    );
+
+    let sources = format!(
+        "* https://en.wikipedia.org/wiki/Mutual_information\n* https://en.wikipedia.org/wiki/G-test\n* {}",
+        correction.source()
+    );
+
+    test::Builder::default()
+        .title(format!(
+            "Mutual Information / {} / {} iterations",
+            provider.name(),
+            iterations
+        ))
+        .unwrap()
+        .description(format!(
+            "{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}",
+            "Overview".italic(),
+            overview,
+            "Relation to Hashing".italic(),
+            relation,
+            "Algorithm".italic(),
+            algorithm,
+            "Interpretation".italic(),
+            interpretation,
+            "Sources".italic(),
+            sources,
+        ))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::BuildHasherDefault;
+    use std::hash::DefaultHasher;
+    use std::hash::Hasher;
+
+    use bitbelay_providers::ascii::AlphanumericProvider;
+
+    use super::*;
+
+    /// A hasher whose second byte is its first byte XOR'd with a constant, so
+    /// the two bytes are dependent but uncorrelated.
+    #[derive(Debug, Default)]
+    struct Xored(DefaultHasher);
+
+    impl Hasher for Xored {
+        fn finish(&self) -> u64 {
+            let hash = self.0.finish() & !0xFF00;
+            hash | (((hash & 0xFF) ^ 0xA5) << 8)
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            self.0.write(bytes);
+        }
+    }
+
+    #[test]
+    fn dependent_bytes_fail() {
+        let hasher = BuildHasherDefault::<Xored>::default();
+        let mut test = Test::new(
+            &hasher,
+            Box::new(AlphanumericProvider::new(10)),
+            0.05,
+            Procedure::Holm,
+        );
+
+        for _ in 0..MIN_ITERATIONS {
+            test.single_iteration();
+        }
+
+        let results = test.results();
+
+        // The first two bytes share all eight bits of information.
+        let estimate = results[&(0, 1)].unwrap();
+        assert!(estimate.mutual_information > 7.9);
+        assert!(estimate.adjusted_p_value < test.threshold());
+
+        // The other bytes are independent (aside from the bias of the estimator).
+        let estimate = results[&(2, 3)].unwrap();
+        assert!(estimate.mutual_information < 1.1 * test.expected_bias().unwrap());
+    }
+}
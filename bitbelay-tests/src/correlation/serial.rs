@@ -0,0 +1,742 @@
+//! Serial correlation test.
+
+use std::hash::BuildHasher;
+use std::num::NonZeroUsize;
+
+use bitbelay_providers::Provider;
+use bitbelay_report::section;
+use bitbelay_report::section::test;
+use bitbelay_report::section::test::Module;
+use bitbelay_report::section::test::module;
+use bitbelay_statistics::correction::Procedure;
+use bitbelay_statistics::correlation::pearson;
+use colored::Colorize as _;
+
+/// The number of bits in the output hash.
+const BITS: usize = u64::BITS as usize;
+
+/// The number of most significant correlations listed within the report.
+const WORST_CORRELATIONS: usize = 5;
+
+/// A serial correlation test.
+#[derive(Debug)]
+pub struct Test<'a, H: BuildHasher> {
+    /// The build hasher.
+    build_hasher: &'a H,
+
+    /// The data provider.
+    provider: Box<dyn Provider>,
+
+    /// The stream of hashes (in the order that their keys were provided).
+    hashes: Vec<u64>,
+
+    /// The largest lag for which the autocorrelation is computed.
+    max_lag: NonZeroUsize,
+
+    /// The threshold of statistical significance for the family of
+    /// correlations.
+    threshold: f64,
+
+    /// The procedure used to correct for the number of correlations tested.
+    correction: Procedure,
+}
+
+impl<'a, H: BuildHasher> Test<'a, H> {
+    /// Creates a new [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::serial::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
+    ///
+    /// assert!(test.hashes().is_empty());
+    /// ```
+    pub fn new(
+        build_hasher: &'a H,
+        provider: Box<dyn Provider>,
+        max_lag: NonZeroUsize,
+        threshold: f64,
+        correction: Procedure,
+    ) -> Self {
+        Self {
+            build_hasher,
+            provider,
+            hashes: Vec::new(),
+            max_lag,
+            threshold,
+            correction,
+        }
+    }
+
+    /// Gets the build hasher for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::BuildHasher as _;
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::serial::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
+    ///
+    /// // Used as a surrogate to test that the [`BuildHasher`]s are the same.
+    /// assert_eq!(test.build_hasher().hash_one("42"), hasher.hash_one("42"));
+    /// ```
+    pub fn build_hasher(&self) -> &H {
+        self.build_hasher
+    }
+
+    /// Gets the data provider for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::Provider as _;
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::serial::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let test = Test::new(
+    ///     &hasher,
+    ///     provider.clone(),
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
+    ///
+    /// assert_eq!(test.provider().name(), provider.name());
+    /// ```
+    pub fn provider(&self) -> &dyn Provider {
+        self.provider.as_ref()
+    }
+
+    /// Gets the stream of hashes for this [`Test`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::serial::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
+    ///
+    /// test.single_iteration();
+    /// assert_eq!(test.hashes().len(), 1);
+    /// ```
+    pub fn hashes(&self) -> &[u64] {
+        &self.hashes
+    }
+
+    /// Gets the largest lag for which the autocorrelation is computed within
+    /// this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::serial::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
+    ///
+    /// assert_eq!(test.max_lag().get(), 8);
+    /// ```
+    pub fn max_lag(&self) -> NonZeroUsize {
+        self.max_lag
+    }
+
+    /// Gets the threshold of statistical significance for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::serial::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
+    ///
+    /// assert_eq!(test.threshold(), 0.05);
+    /// ```
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Gets the procedure used to correct for the number of correlations
+    /// tested (one per lag and one per output bit).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::serial::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    ///     Procedure::Bonferroni,
+    /// );
+    ///
+    /// assert_eq!(test.correction(), Procedure::Bonferroni);
+    /// ```
+    pub fn correction(&self) -> Procedure {
+        self.correction
+    }
+
+    /// Hashes the next key from the provider and appends it to the stream of
+    /// hashes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::serial::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
+    ///
+    /// test.single_iteration();
+    /// assert_eq!(test.hashes().len(), 1);
+    /// ```
+    pub fn single_iteration(&mut self) {
+        // SAFETY: we hardcode generating one value, so we know this pop must unwrap.
+        let key = self.provider.provide(1).pop().unwrap();
+        self.hashes.push(self.build_hasher.hash_one(key));
+    }
+
+    /// Computes the Pearson autocorrelation of the stream of hashes (each
+    /// treated as a number) for every lag from `1` to the maximum lag
+    /// (inclusive).
+    ///
+    /// The autocorrelation for a lag is [`None`] if it cannot be computed
+    /// (e.g., not enough hashes have been computed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::serial::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
+    ///
+    /// for _ in 0..1000 {
+    ///     test.single_iteration();
+    /// }
+    ///
+    /// let autocorrelations = test.autocorrelations();
+    /// assert_eq!(autocorrelations.len(), 8);
+    /// assert!(autocorrelations[0].unwrap().abs() < 0.5);
+    /// ```
+    pub fn autocorrelations(&self) -> Vec<Option<f64>> {
+        // NOTE: hashes are scaled to `[0, 1)` to avoid losing precision when
+        // squaring very large values within the Pearson correlation.
+        let values = self
+            .hashes
+            .iter()
+            .map(|hash| *hash as f64 / 2f64.powi(BITS as i32))
+            .collect::<Vec<_>>();
+
+        (1..=self.max_lag.get())
+            .map(|lag| {
+                if lag >= values.len() {
+                    return None;
+                }
+
+                pearson::correlation(&values[..values.len() - lag], &values[lag..])
+            })
+            .collect()
+    }
+
+    /// Computes the Pearson correlation between each output bit of every hash
+    /// and the same output bit of the next hash in the stream.
+    ///
+    /// The correlation for a bit is [`None`] if it cannot be computed (e.g.,
+    /// not enough hashes have been computed or the bit never changes).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::serial::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
+    ///
+    /// for _ in 0..1000 {
+    ///     test.single_iteration();
+    /// }
+    ///
+    /// let bit_correlations = test.bit_correlations();
+    /// assert_eq!(bit_correlations.len(), 64);
+    /// assert!(bit_correlations[0].unwrap().abs() < 0.5);
+    /// ```
+    pub fn bit_correlations(&self) -> Vec<Option<f64>> {
+        (0..BITS)
+            .map(|bit| {
+                if self.hashes.len() < 2 {
+                    return None;
+                }
+
+                let values = self
+                    .hashes
+                    .iter()
+                    .map(|hash| ((hash >> bit) & 1) as f64)
+                    .collect::<Vec<_>>();
+
+                pearson::correlation(&values[..values.len() - 1], &values[1..])
+            })
+            .collect()
+    }
+
+    /// Computes the two-sided p-value of every correlation tested (the
+    /// autocorrelations followed by the bit correlations) under the null
+    /// hypothesis of no correlation.
+    ///
+    /// The p-value for a correlation is [`None`] if the correlation cannot be
+    /// computed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::serial::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
+    ///
+    /// assert!(test.p_values().iter().all(|p| p.is_none()));
+    ///
+    /// for _ in 0..1000 {
+    ///     test.single_iteration();
+    /// }
+    ///
+    /// let p_values = test.p_values();
+    /// assert_eq!(p_values.len(), 8 + 64);
+    /// assert!(p_values.iter().all(|p| p.is_some()));
+    /// ```
+    pub fn p_values(&self) -> Vec<Option<f64>> {
+        let hashes = self.hashes.len();
+
+        let autocorrelations = self
+            .autocorrelations()
+            .into_iter()
+            .enumerate()
+            .map(|(i, correlation)| pearson::p_value(correlation?, hashes - (i + 1)));
+
+        let bits = self
+            .bit_correlations()
+            .into_iter()
+            .map(|correlation| pearson::p_value(correlation?, hashes - 1));
+
+        autocorrelations.chain(bits).collect()
+    }
+
+    /// Computes the p-value of every correlation tested (in the same order as
+    /// [`Test::p_values()`]) after adjusting for the number of correlations
+    /// tested using the configured [`Procedure`].
+    ///
+    /// The adjusted p-value for a correlation is [`None`] if the correlation
+    /// cannot be computed (and only the correlations that can be computed are
+    /// included within the family).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::correlation::serial::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// );
+    ///
+    /// for _ in 0..1000 {
+    ///     test.single_iteration();
+    /// }
+    ///
+    /// let raw = test.p_values();
+    /// let adjusted = test.adjusted_p_values();
+    /// assert!(raw.iter().zip(adjusted.iter()).all(|(r, a)| r <= a));
+    /// ```
+    pub fn adjusted_p_values(&self) -> Vec<Option<f64>> {
+        let p_values = self.p_values();
+
+        let mut adjusted = self
+            .correction
+            .adjust(&p_values.iter().flatten().copied().collect::<Vec<_>>())
+            .into_iter();
+
+        p_values
+            .into_iter()
+            .map(|p_value| p_value.and_then(|_| adjusted.next()))
+            .collect()
+    }
+
+    /// Generates a [`Module`] for a set of correlations, where each
+    /// correlation is labelled by `label` and has the raw and adjusted
+    /// p-values found at the same index within `p_values` and `adjusted`.
+    fn module(
+        &self,
+        name: &str,
+        correlations: Vec<Option<f64>>,
+        label: impl Fn(usize) -> String,
+        p_values: &[Option<f64>],
+        adjusted: &[Option<f64>],
+    ) -> Module {
+        let mut results = Vec::new();
+
+        for (i, correlation) in correlations.into_iter().enumerate() {
+            match (correlation, p_values[i], adjusted[i]) {
+                (Some(correlation), Some(p_value), Some(adjusted)) => {
+                    results.push((i, correlation, p_value, adjusted))
+                }
+                _ => {
+                    return Module::new(
+                        module::Result::Inconclusive,
+                        name,
+                        None,
+                        Some(format!(
+                            "The correlation for {} was not able to be computed (try increasing \
+                             the number of iterations).",
+                            label(i)
+                        )),
+                    );
+                }
+            }
+        }
+
+        results.sort_by(|(_, _, a, _), (_, _, b, _)| a.total_cmp(b));
+
+        // SAFETY: there is always at least one lag and one output bit.
+        let (_, _, _, minimum) = *results.first().unwrap();
+        let failures = results
+            .iter()
+            .filter(|(_, _, _, p)| *p <= self.threshold)
+            .count();
+
+        let summary = if failures == 0 {
+            format!(
+                "The smallest p-value after applying the {} was {:.2e}, which is greater than \
+                 (and, thus, failed to reach) the threshold of statistical significance set at \
+                 {:.2}. As such, the null hypothesis that consecutive hashes are uncorrelated \
+                 **cannot** be rejected.",
+                self.correction.name(),
+                minimum,
+                self.threshold
+            )
+        } else {
+            format!(
+                "{} correlation(s) had a p-value after applying the {} that was less than (and, \
+                 thus, reached) the threshold of statistical significance set at {:.2}. As such, \
+                 the null hypothesis that consecutive hashes are uncorrelated **is** rejected.",
+                failures,
+                self.correction.name(),
+                self.threshold
+            )
+        };
+
+        let mut details = format!("{}\n\n{}\n", summary, "Most Significant".italic());
+
+        for (i, correlation, p_value, adjusted) in results.iter().take(WORST_CORRELATIONS) {
+            details.push_str(&format!(
+                "\n* {}: correlation of {:.4} (p-value of {:.2e}, adjusted p-value of {:.2e}).",
+                label(*i),
+                correlation,
+                p_value,
+                adjusted
+            ));
+        }
+
+        Module::new(
+            if failures == 0 {
+                module::Result::Pass
+            } else {
+                module::Result::Fail
+            },
+            name,
+            Some(format!("{:.2e}", minimum)),
+            Some(details),
+        )
+    }
+}
+
+impl<'a, H: BuildHasher> crate::r#trait::Test for Test<'a, H> {
+    fn title(&self) -> &'static str {
+        "Serial Correlation"
+    }
+
+    fn report_section(&self) -> section::Test {
+        let hashes = self.hashes.len();
+        let lags = self.max_lag.get();
+
+        // NOTE: the correlations are corrected as a single family, so the
+        // p-values are computed once and split between the two modules.
+        let p_values = self.p_values();
+        let adjusted = self.adjusted_p_values();
+
+        let autocorrelation = self.module(
+            "Hash Autocorrelation",
+            self.autocorrelations(),
+            |i| format!("Lag {}", i + 1),
+            &p_values[..lags],
+            &adjusted[..lags],
+        );
+
+        let bits = self.module(
+            "Consecutive Bit Correlation",
+            self.bit_correlations(),
+            |i| format!("Bit {}", i),
+            &p_values[lags..],
+            &adjusted[lags..],
+        );
+
+        get_report_base(
+            self.provider.as_ref(),
+            self.max_lag,
+            hashes,
+            self.correction,
+        )
+        .push_module(autocorrelation)
+        .push_module(bits)
+        .try_build()
+        .unwrap()
+    }
+}
+
+/// Populates the boilerplate report information within a
+/// [`Test`](section::Test).
+pub fn get_report_base(
+    provider: &dyn Provider,
+    max_lag: NonZeroUsize,
+    iterations: usize,
+    correction: Procedure,
+) -> section::test::Builder {
+    let overview = "The serial correlation test assesses whether consecutive hashes within a \
+                    stream of hashes are correlated with one another.";
+
+    let relation = "Keys are often generated in order (e.g., sequential identifiers or \
+                    overlapping k-mers from a sequence), so consecutive keys are closely related. \
+                    A hash function that does not sufficiently mix its input may produce \
+                    consecutive hashes that are correlated, which defeats the purpose of hashing \
+                    such keys and causes them to cluster within hash tables.";
+
+    let algorithm = format!(
+        "(1) For {iterations} iterations, the next key is generated from the data provider and \
+         hashed, and the hash is appended to a stream of hashes.\n\n(2) Treating each hash as a \
+         number, the Pearson autocorrelation of the stream is computed for each lag from 1 to \
+         {max_lag} (i.e., the correlation between hash _i_ and hash _i + lag_).\n\n(3) For each \
+         of the {BITS} output bits, the Pearson correlation between the bit within hash _i_ and \
+         the same bit within hash _i + 1_ is computed.\n\n(4) Under the null hypothesis that \
+         consecutive hashes are uncorrelated, each correlation multiplied by the square root of \
+         the number of pairs is approximately standard normal, from which a two-sided p-value is \
+         computed. Because {tests} correlations are tested at once, the p-values are adjusted as \
+         a single family using the {name} (which controls the {controls}) before being compared \
+         against the chosen threshold.",
+        tests = max_lag.get() + BITS,
+        name = correction.name(),
+        controls = correction.controls()
+    );
+
+    let interpretation = "Under this test design:\n\n* If every adjusted p-value is above the \
+                          threshold, the test passes, as there is no evidence that consecutive \
+                          hashes are correlated.\n\n* If any adjusted p-value is below the \
+                          threshold, the test fails, as consecutive hashes are very likely \
+                          correlated at that lag (or for that bit). Note that random data \
+                          providers produce unrelated consecutive keys, so this test is most \
+                          informative with providers that generate keys in order.";
+
+    let sources = format!(
+        "* https://en.wikipedia.org/wiki/Autocorrelation\n* {}",
+        correction.source()
+    );
+
+    test::Builder::default()
+        .title(format!(
+            "Serial Correlation / {} / {} lags / {} iterations",
+            provider.name(),
+            max_lag,
+            iterations
+        ))
+        .unwrap()
+        .description(format!(
+            "{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}",
+            "Overview".italic(),
+            overview,
+            "Relation to Hashing".italic(),
+            relation,
+            "Algorithm".italic(),
+            algorithm,
+            "Interpretation".italic(),
+            interpretation,
+            "Sources".italic(),
+            sources,
+        ))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::BuildHasherDefault;
+    use std::hash::Hasher;
+
+    use bitbelay_providers::ascii::AlphanumericProvider;
+
+    use super::*;
+
+    /// A hasher that returns a running counter (ignoring its input), so that
+    /// consecutive hashes are nearly identical.
+    #[derive(Debug, Default)]
+    struct Counter;
+
+    thread_local! {
+        static COUNTER: std::cell::Cell<u64> = const { std::cell::Cell::new(0) };
+    }
+
+    impl Hasher for Counter {
+        fn finish(&self) -> u64 {
+            COUNTER.with(|counter| {
+                let value = counter.get();
+                counter.set(value.wrapping_add(1 << 48));
+                value
+            })
+        }
+
+        fn write(&mut self, _: &[u8]) {}
+    }
+
+    #[test]
+    fn sequential_hashes_fail() {
+        let hasher = BuildHasherDefault::<Counter>::default();
+        let mut test = Test::new(
+            &hasher,
+            Box::new(AlphanumericProvider::new(10)),
+            NonZeroUsize::try_from(4).unwrap(),
+            0.05,
+            Procedure::Holm,
+        );
+
+        for _ in 0..1_000 {
+            test.single_iteration();
+        }
+
+        let adjusted = test.adjusted_p_values();
+        assert!(adjusted[0].unwrap() < test.threshold());
+    }
+}
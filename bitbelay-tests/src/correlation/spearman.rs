@@ -0,0 +1,444 @@
+//! Spearman sub-word correlation test.
+
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+use std::num::NonZeroUsize;
+
+use bitbelay_providers::Provider;
+use bitbelay_report::section;
+use bitbelay_report::section::test;
+use bitbelay_report::section::test::module;
+use bitbelay_statistics::correlation::spearman;
+use colored::Colorize as _;
+use tracing::debug;
+use tracing::info;
+
+/// The number of strongest correlations listed within the report for each
+/// [`Width`].
+const STRONGEST_CORRELATIONS: usize = 5;
+
+/// The width of the sub-word lanes that an output hash is split into.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Width {
+    /// Eight 8-bit lanes (bytes).
+    U8,
+
+    /// Four 16-bit lanes.
+    U16,
+
+    /// Two 32-bit lanes (the low and high halves).
+    U32,
+}
+
+impl Width {
+    /// All supported widths.
+    pub const ALL: [Width; 3] = [Width::U8, Width::U16, Width::U32];
+
+    /// Gets the number of bits within each lane.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_tests::correlation::spearman::Width;
+    ///
+    /// assert_eq!(Width::U16.bits(), 16);
+    /// ```
+    pub fn bits(&self) -> u32 {
+        match self {
+            Width::U8 => 8,
+            Width::U16 => 16,
+            Width::U32 => 32,
+        }
+    }
+
+    /// Gets the number of lanes within a 64-bit hash.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_tests::correlation::spearman::Width;
+    ///
+    /// assert_eq!(Width::U8.lanes(), 8);
+    /// assert_eq!(Width::U32.lanes(), 2);
+    /// ```
+    pub fn lanes(&self) -> usize {
+        (u64::BITS / self.bits()) as usize
+    }
+
+    /// Extracts the value of a lane (where lane `0` contains the least
+    /// significant bits) from a hash.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_tests::correlation::spearman::Width;
+    ///
+    /// assert_eq!(Width::U16.lane(0x1111_2222_3333_4444, 1), 0x3333);
+    /// ```
+    pub fn lane(&self, hash: u64, lane: usize) -> u64 {
+        let bits = self.bits();
+        (hash >> (lane as u32 * bits)) & ((1u64 << bits) - 1)
+    }
+}
+
+impl std::fmt::Display for Width {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-bit", self.bits())
+    }
+}
+
+/// Results from a Spearman sub-word correlation test.
+///
+/// Each key is a tuple of `(width, lane, lane)`, and each value is the
+/// Spearman rank correlation between the two lanes (or [`None`] if it could
+/// not be computed).
+pub type Results = HashMap<(Width, usize, usize), Option<f64>>;
+
+/// A Spearman sub-word correlation test.
+#[derive(Debug)]
+pub struct Test<'a, H: BuildHasher> {
+    /// The build hasher.
+    build_hasher: &'a H,
+
+    /// The hashes computed.
+    hashes: Vec<u64>,
+
+    /// The threshold of correlation at which any pair of lanes causes the test
+    /// to fail.
+    threshold: f64,
+}
+
+impl<'a, H: BuildHasher> Test<'a, H> {
+    /// Creates a new [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_tests::correlation::spearman::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::new(&hasher, 0.05);
+    ///
+    /// assert!(test.hashes().is_empty());
+    /// ```
+    pub fn new(build_hasher: &'a H, threshold: f64) -> Self {
+        Self {
+            build_hasher,
+            hashes: Vec::new(),
+            threshold,
+        }
+    }
+
+    /// Gets the hashes computed within this [`Test`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::Provider;
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::correlation::spearman::Test;
+    ///
+    /// let mut provider: Box<dyn Provider> = Box::new(AlphanumericProvider::new(10));
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(&hasher, 0.05);
+    ///
+    /// test.run(&mut provider, NonZeroUsize::try_from(10).unwrap());
+    ///
+    /// assert_eq!(test.hashes().len(), 10);
+    /// ```
+    pub fn hashes(&self) -> &[u64] {
+        &self.hashes
+    }
+
+    /// Gets the threshold of this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_tests::correlation::spearman::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::new(&hasher, 0.05);
+    ///
+    /// assert_eq!(test.threshold(), 0.05);
+    /// ```
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Runs a set of iterations using a [`Provider`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::Provider;
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_providers::numeric::Unsigned64BitProvider;
+    /// use bitbelay_tests::correlation::spearman::Test;
+    ///
+    /// let mut alphas: Box<dyn Provider> = Box::new(AlphanumericProvider::new(10));
+    /// let mut numbers: Box<dyn Provider> = Box::new(Unsigned64BitProvider::new(10));
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(&hasher, 0.05);
+    ///
+    /// test.run(&mut alphas, NonZeroUsize::try_from(10).unwrap());
+    /// test.run(&mut numbers, NonZeroUsize::try_from(10).unwrap());
+    ///
+    /// assert_eq!(test.hashes().len(), 20);
+    /// ```
+    pub fn run(&mut self, provider: &mut Box<dyn Provider>, iterations: NonZeroUsize) {
+        info!("Computing {} hashes.", iterations);
+
+        for (i, input) in provider.provide(iterations.get()).into_iter().enumerate() {
+            if i % 1_000 == 0 && i > 0 {
+                debug!("Computed {} hashes.", i);
+            }
+
+            self.hashes.push(self.build_hasher.hash_one(input));
+        }
+    }
+
+    /// Gets the [`Results`] of a [`Test`] using the [`Test`]'s current interal
+    /// state.
+    ///
+    /// If no hashes have been computed, [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::Provider;
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::correlation::spearman::Test;
+    /// use bitbelay_tests::correlation::spearman::Width;
+    ///
+    /// let mut provider: Box<dyn Provider> = Box::new(AlphanumericProvider::new(10));
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(&hasher, 0.05);
+    ///
+    /// test.run(&mut provider, NonZeroUsize::try_from(100).unwrap());
+    ///
+    /// let results = test.results().unwrap();
+    /// assert!(results.contains_key(&(Width::U32, 0, 1)));
+    /// // 28 pairs of bytes, 6 pairs of 16-bit lanes, and 1 pair of halves.
+    /// assert_eq!(results.len(), 35);
+    /// ```
+    pub fn results(&self) -> Option<Results> {
+        if self.hashes.is_empty() {
+            return None;
+        }
+
+        info!("Computing Spearman correlations for each pair of sub-word lanes.");
+
+        let mut results = HashMap::new();
+
+        for width in Width::ALL {
+            let lanes = (0..width.lanes())
+                .map(|lane| {
+                    self.hashes
+                        .iter()
+                        .map(|hash| width.lane(*hash, lane))
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+
+            for i in 0..lanes.len() {
+                for j in (i + 1)..lanes.len() {
+                    let correlation = spearman::correlation(&lanes[i], &lanes[j]);
+                    results.insert((width, i, j), correlation);
+                }
+            }
+
+            debug!("Computed all {} lane correlations.", width);
+        }
+
+        Some(results)
+    }
+}
+
+impl<'a, H: BuildHasher> crate::r#trait::Test for Test<'a, H> {
+    fn title(&self) -> &'static str {
+        "Spearman Sub-word Correlation"
+    }
+
+    fn report_section(&self) -> bitbelay_report::section::Test {
+        let results = match self.results() {
+            Some(results) => results,
+            None => panic!("a report can only be generated when at least one test has been run!"),
+        };
+
+        let mut builder = get_report_base(self.hashes.len());
+
+        for width in Width::ALL {
+            let mut correlations = results
+                .iter()
+                .filter(|((w, ..), _)| *w == width)
+                .map(|((_, i, j), correlation)| ((*i, *j), *correlation))
+                .collect::<Vec<_>>();
+
+            let name = format!("{} lanes", width);
+
+            if correlations
+                .iter()
+                .any(|(_, correlation)| correlation.is_none())
+            {
+                builder = builder.push_module(module::Module::new(
+                    module::Result::Inconclusive,
+                    name,
+                    None,
+                    Some(String::from(
+                        "One or more correlations were not able to be computed (try increasing \
+                         the number of iterations).",
+                    )),
+                ));
+                continue;
+            }
+
+            // SAFETY: we just checked that every correlation is [`Some`].
+            correlations.sort_by(|(_, a), (_, b)| b.unwrap().abs().total_cmp(&a.unwrap().abs()));
+
+            let (result, mut details) = if correlations
+                .iter()
+                .any(|(_, correlation)| correlation.unwrap().abs() >= self.threshold)
+            {
+                (
+                    module::Result::Fail,
+                    String::from(
+                        "One or more pairs of lanes had a correlation greater than or equal to \
+                         the threshold.\n\n",
+                    ),
+                )
+            } else {
+                (
+                    module::Result::Pass,
+                    String::from(
+                        "All pairs of lanes had a correlation lower than the threshold.\n\n",
+                    ),
+                )
+            };
+
+            details.push_str(&format!("{}\n", "Strongest Correlations".italic()));
+
+            correlations
+                .iter()
+                .take(STRONGEST_CORRELATIONS)
+                .map(|((i, j), correlation)| {
+                    format!("\n  * ({}, {}) => {:.4}", i, j, correlation.unwrap())
+                })
+                .for_each(|s| details.push_str(&s));
+
+            let value = correlations
+                .first()
+                .map(|(_, correlation)| format!("{:.4}", correlation.unwrap().abs()));
+
+            builder = builder.push_module(module::Module::new(result, name, value, Some(details)));
+        }
+
+        builder.try_build().unwrap()
+    }
+}
+
+/// Populates the boilerplate report information within a
+/// [`Test`](section::Test).
+pub fn get_report_base(iterations: usize) -> section::test::Builder {
+    let overview = "The Spearman sub-word correlation test assesses the correlation between \
+                    sub-words of each output hash (bytes, 16-bit lanes, and the low and high \
+                    32-bit halves) using the Spearman rank correlation coefficient.";
+
+    let relation = "The bitwise correlation test only detects linear relationships between \
+                    individual output bits. A hash function may still produce outputs where one \
+                    part of the hash is a monotonic, non-linear function of another part (e.g., \
+                    the high half tends to be large when the low half is large). Because the \
+                    Spearman rank correlation only depends on the order of the values, it detects \
+                    such relationships regardless of their shape.";
+
+    let algorithm = format!(
+        "(1) For {iterations} iterations, a random input is generated from the provider and the \
+         output hash is computed.\n\n(2) Each hash is split into eight 8-bit lanes, four 16-bit \
+         lanes, and two 32-bit lanes (lane 0 always contains the least significant bits).\n\n(3) \
+         For every pair of lanes of the same width, the Spearman rank correlation between the \
+         values of the two lanes across all hashes is computed (tied values are assigned their \
+         average rank).\n\n(4) The absolute values of the correlations are compared against the \
+         chosen threshold."
+    );
+
+    let interpretation = "Under this test design:\n\n* If every pair of lanes of a given width \
+                          has a correlation lower than the threshold, that width passes.\n\n* If \
+                          any pair of lanes has a correlation greater than or equal to the \
+                          threshold, that width fails, as one part of the output is predictable \
+                          from another part.";
+
+    let sources = "* https://en.wikipedia.org/wiki/Spearman%27s_rank_correlation_coefficient";
+
+    test::Builder::default()
+        .title(format!(
+            "Spearman Sub-word Correlation / {} iterations",
+            iterations
+        ))
+        .unwrap()
+        .description(format!(
+            "{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}",
+            "Overview".italic(),
+            overview,
+            "Relation to Hashing".italic(),
+            relation,
+            "Algorithm".italic(),
+            algorithm,
+            "Interpretation".italic(),
+            interpretation,
+            "Sources".italic(),
+            sources,
+        ))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::BuildHasherDefault;
+    use std::hash::Hasher;
+
+    use bitbelay_providers::ascii::AlphanumericProvider;
+
+    use super::*;
+
+    /// A hasher whose high half is the square of its low half (shifted down),
+    /// so that the halves are monotonically (but not linearly) related.
+    #[derive(Debug, Default)]
+    struct Squared(std::hash::DefaultHasher);
+
+    impl Hasher for Squared {
+        fn finish(&self) -> u64 {
+            let low = self.0.finish() & 0xFFFF_FFFF;
+            ((low * low) & 0xFFFF_FFFF_0000_0000) | low
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            self.0.write(bytes);
+        }
+    }
+
+    #[test]
+    fn monotonic_halves_are_correlated() {
+        let hasher = BuildHasherDefault::<Squared>::default();
+        let mut provider: Box<dyn Provider> = Box::new(AlphanumericProvider::new(10));
+        let mut test = Test::new(&hasher, 0.05);
+
+        test.run(&mut provider, NonZeroUsize::try_from(1_000).unwrap());
+
+        let results = test.results().unwrap();
+        assert!(results[&(Width::U32, 0, 1)].unwrap() > 0.99);
+    }
+}
@@ -0,0 +1,149 @@
+//! Low-weight input differences.
+//!
+//! A difference is a set of bit indices that are flipped within an input. Many
+//! weaknesses in hash functions are only exposed by inputs that differ in very
+//! few bits, so several tests enumerate every difference up to a given weight
+//! (the number of flipped bits).
+
+/// The maximum number of input bits that can differ within a difference.
+pub const MAX_WEIGHT: usize = 3;
+
+/// An error related to the parameters of a test that enumerates differences.
+#[derive(Debug)]
+pub enum Error {
+    /// An invalid number of compared output bits was provided.
+    InvalidBits(u32),
+
+    /// An invalid maximum weight was provided.
+    InvalidWeight(usize),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidBits(bits) => {
+                write!(f, "compared bits must be between 1 and 64, received {bits}")
+            }
+            Error::InvalidWeight(weight) => write!(
+                f,
+                "max weight must be between 1 and {MAX_WEIGHT}, received {weight}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Ensures that `bits` compared output bits and a maximum weight of
+/// `max_weight` are valid.
+///
+/// # Examples
+///
+/// ```
+/// use bitbelay_tests::difference::Error;
+/// use bitbelay_tests::difference::validate;
+///
+/// assert!(validate(16, 3).is_ok());
+/// assert!(matches!(validate(0, 3), Err(Error::InvalidBits(0))));
+/// assert!(matches!(validate(16, 4), Err(Error::InvalidWeight(4))));
+/// ```
+pub fn validate(bits: u32, max_weight: usize) -> Result<(), Error> {
+    if !(1..=64).contains(&bits) {
+        return Err(Error::InvalidBits(bits));
+    }
+
+    if !(1..=MAX_WEIGHT).contains(&max_weight) {
+        return Err(Error::InvalidWeight(max_weight));
+    }
+
+    Ok(())
+}
+
+/// Flips the specified bits within `data`.
+///
+/// Bit `i` refers to bit `i % 8` (least significant first) of byte `i / 8`.
+///
+/// # Examples
+///
+/// ```
+/// use bitbelay_tests::difference::flip;
+///
+/// let mut data = vec![0b0000_0000, 0b0000_0001];
+/// flip(&mut data, &[1, 8]);
+///
+/// assert_eq!(data, vec![0b0000_0010, 0b0000_0000]);
+/// ```
+pub fn flip(data: &mut [u8], bits: &[usize]) {
+    for bit in bits {
+        data[bit / 8] ^= 1 << (bit % 8);
+    }
+}
+
+/// Calls `f` with every set of at least one and at most `max_weight` distinct
+/// bit indices below `bits`.
+///
+/// The indices within each set are sorted in ascending order.
+///
+/// # Examples
+///
+/// ```
+/// use bitbelay_tests::difference::for_each;
+///
+/// let mut differences = Vec::new();
+/// for_each(4, 2, |difference| differences.push(difference.to_vec()));
+///
+/// // 4 single-bit differences and 6 two-bit differences.
+/// assert_eq!(differences.len(), 10);
+/// assert!(differences.contains(&vec![0, 3]));
+/// ```
+pub fn for_each(bits: usize, max_weight: usize, mut f: impl FnMut(&[usize])) {
+    /// Recursively extends `prefix` with larger indices.
+    fn extend(
+        bits: usize,
+        max_weight: usize,
+        prefix: &mut Vec<usize>,
+        f: &mut impl FnMut(&[usize]),
+    ) {
+        let start = prefix.last().map(|last| last + 1).unwrap_or(0);
+
+        for index in start..bits {
+            prefix.push(index);
+            f(prefix);
+
+            if prefix.len() < max_weight {
+                extend(bits, max_weight, prefix, f);
+            }
+
+            prefix.pop();
+        }
+    }
+
+    extend(
+        bits,
+        max_weight,
+        &mut Vec::with_capacity(max_weight),
+        &mut f,
+    );
+}
+
+/// Counts the number of differences that [`for_each()`] enumerates.
+///
+/// # Examples
+///
+/// ```
+/// use bitbelay_tests::difference::count;
+///
+/// assert_eq!(count(4, 2), 10);
+/// assert_eq!(count(64, 3), 64 + 2016 + 41664);
+/// ```
+pub fn count(bits: usize, max_weight: usize) -> usize {
+    let mut total = 0;
+    let mut combinations = 1;
+
+    for weight in 1..=max_weight.min(bits) {
+        combinations = combinations * (bits - weight + 1) / weight;
+        total += combinations;
+    }
+
+    total
+}
@@ -0,0 +1,113 @@
+//! Extension tests.
+//!
+//! These tests evaluate whether extending an input (e.g., with trailing zero
+//! bytes) or changing how it is written to a hasher produces outputs that are
+//! unrelated to the output for the original input.
+
+use std::hash::BuildHasher;
+
+use bitbelay_report::section;
+
+use crate::r#trait::Test as _;
+
+pub mod length;
+
+/// A type of extension test.
+#[derive(Debug)]
+pub enum Test<'a, H: BuildHasher> {
+    /// Length extension test.
+    Length(length::Test<'a, H>),
+}
+
+impl<'a, H: BuildHasher> Test<'a, H> {
+    /// Gets a reference to a [`length::Test`] wrapped in [`Some`] if the
+    /// [`Test`] is a [`Test::Length`]. Else, returns [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::extension::Test;
+    /// use bitbelay_tests::extension::length;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::Length(length::Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     8,
+    ///     0.05,
+    /// )?);
+    ///
+    /// assert!(matches!(test.as_length_test(), Some(_)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn as_length_test(&self) -> Option<&length::Test<'a, H>> {
+        match self {
+            Test::Length(test) => Some(test),
+        }
+    }
+
+    /// Consumes the [`Test`] and returns a [`length::Test`] wrapped in
+    /// [`Some`] if the [`Test`] is a [`Test::Length`]. Else, returns
+    /// [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::extension::Test;
+    /// use bitbelay_tests::extension::length;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::Length(length::Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     8,
+    ///     0.05,
+    /// )?);
+    ///
+    /// assert!(matches!(test.into_length_test(), Some(_)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn into_length_test(self) -> Option<length::Test<'a, H>> {
+        match self {
+            Test::Length(test) => Some(test),
+        }
+    }
+
+    /// Generates a report section for the [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::extension::Test;
+    /// use bitbelay_tests::extension::length;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::Length(length::Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     8,
+    ///     0.05,
+    /// )?);
+    ///
+    /// let results = test.report_section();
+    /// // Include the section in a report.
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn report_section(&self) -> section::Test {
+        match self {
+            Test::Length(test) => test.report_section(),
+        }
+    }
+}
@@ -0,0 +1,611 @@
+//! Length extension test.
+
+use std::hash::BuildHasher;
+use std::hash::Hasher as _;
+
+use bitbelay_providers::Provider;
+use bitbelay_report::section;
+use bitbelay_report::section::test;
+use bitbelay_report::section::test::Module;
+use bitbelay_report::section::test::module;
+use colored::Colorize;
+use statrs::distribution::ContinuousCDF as _;
+use statrs::distribution::Normal;
+
+/// An error related to a [`Test`].
+#[derive(Debug)]
+pub enum Error {
+    /// An invalid maximum extension was provided.
+    InvalidMaxExtension(usize),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidMaxExtension(value) => {
+                write!(f, "max extension must be at least two, received {value}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A [`Result`](std::result::Result) with an [`Error`].
+type Result<T> = std::result::Result<T, Error>;
+
+/// A kind of variant that is compared with the original key.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Kind {
+    /// The key with `k` zero bytes appended.
+    Appended,
+
+    /// The key with `k` zero bytes prepended.
+    Prepended,
+
+    /// The key written across `k` calls to `Hasher::write`.
+    Split,
+}
+
+impl Kind {
+    /// Gets the smallest value of `k` for this [`Kind`].
+    fn min(&self) -> usize {
+        match self {
+            Kind::Appended | Kind::Prepended => 1,
+            Kind::Split => 2,
+        }
+    }
+
+    /// Describes a single variant of this [`Kind`].
+    fn describe(&self, k: usize) -> String {
+        match self {
+            Kind::Appended => format!("{k} zero byte(s) appended"),
+            Kind::Prepended => format!("{k} zero byte(s) prepended"),
+            Kind::Split => format!("written in {k} chunks"),
+        }
+    }
+}
+
+/// Tallies of the comparisons between keys and one variant of those keys.
+#[derive(Clone, Debug, Default)]
+pub struct Tally {
+    /// The number of keys compared.
+    pub comparisons: usize,
+
+    /// The number of keys whose variant hashed to the same output.
+    pub collisions: usize,
+
+    /// The number of output bits that agreed between keys and their variants,
+    /// excluding collisions.
+    pub agreeing_bits: usize,
+}
+
+impl Tally {
+    /// Records a single comparison.
+    fn record(&mut self, original: u64, variant: u64) {
+        self.comparisons += 1;
+
+        if original == variant {
+            self.collisions += 1;
+        } else {
+            self.agreeing_bits += (!(original ^ variant)).count_ones() as usize;
+        }
+    }
+
+    /// Gets the total number of output bits compared, excluding collisions.
+    fn bits(&self) -> usize {
+        (self.comparisons - self.collisions) * u64::BITS as usize
+    }
+}
+
+/// A length extension test.
+#[derive(Debug)]
+pub struct Test<'a, H: BuildHasher> {
+    /// The build hasher.
+    build_hasher: &'a H,
+
+    /// The data provider.
+    provider: Box<dyn Provider>,
+
+    /// The maximum number of zero bytes added and chunks written.
+    max_extension: usize,
+
+    /// The number of keys hashed.
+    keys: usize,
+
+    /// Tallies for keys with zero bytes appended (indexed by the number of
+    /// zero bytes minus one).
+    appended: Vec<Tally>,
+
+    /// Tallies for keys with zero bytes prepended (indexed by the number of
+    /// zero bytes minus one).
+    prepended: Vec<Tally>,
+
+    /// Tallies for keys written in chunks (indexed by the number of chunks
+    /// minus two).
+    split: Vec<Tally>,
+
+    /// The threshold of statistical significance.
+    threshold: f64,
+}
+
+impl<'a, H: BuildHasher> Test<'a, H> {
+    /// Attempts to create a new [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::extension::length::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::try_new(&hasher, Box::new(AlphanumericProvider::new(10)), 8, 0.05)?;
+    ///
+    /// assert_eq!(test.keys(), 0);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_new(
+        build_hasher: &'a H,
+        provider: Box<dyn Provider>,
+        max_extension: usize,
+        threshold: f64,
+    ) -> Result<Self> {
+        if max_extension < 2 {
+            return Err(Error::InvalidMaxExtension(max_extension));
+        }
+
+        Ok(Self {
+            build_hasher,
+            provider,
+            max_extension,
+            keys: 0,
+            appended: vec![Tally::default(); max_extension],
+            prepended: vec![Tally::default(); max_extension],
+            split: vec![Tally::default(); max_extension - 1],
+            threshold,
+        })
+    }
+
+    /// Gets the build hasher for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::BuildHasher as _;
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::extension::length::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::try_new(&hasher, Box::new(AlphanumericProvider::new(10)), 8, 0.05)?;
+    ///
+    /// // Used as a surrogate to test that the [`BuildHasher`]s are the same.
+    /// assert_eq!(test.build_hasher().hash_one("42"), hasher.hash_one("42"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn build_hasher(&self) -> &H {
+        self.build_hasher
+    }
+
+    /// Gets the data provider for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::Provider as _;
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::extension::length::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let test = Test::try_new(&hasher, provider.clone(), 8, 0.05)?;
+    ///
+    /// assert_eq!(test.provider().name(), provider.name());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn provider(&self) -> &dyn Provider {
+        self.provider.as_ref()
+    }
+
+    /// Gets the maximum number of zero bytes added and chunks written within
+    /// this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::extension::length::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::try_new(&hasher, Box::new(AlphanumericProvider::new(10)), 8, 0.05)?;
+    ///
+    /// assert_eq!(test.max_extension(), 8);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn max_extension(&self) -> usize {
+        self.max_extension
+    }
+
+    /// Gets the number of keys hashed within this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::extension::length::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::try_new(&hasher, Box::new(AlphanumericProvider::new(10)), 8, 0.05)?;
+    ///
+    /// test.single_iteration();
+    /// assert_eq!(test.keys(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn keys(&self) -> usize {
+        self.keys
+    }
+
+    /// Gets the tallies for a [`Kind`] of variant.
+    ///
+    /// The first tally corresponds to the smallest extension (one zero byte or
+    /// two chunks).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::extension::length::Kind;
+    /// use bitbelay_tests::extension::length::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::try_new(&hasher, Box::new(AlphanumericProvider::new(10)), 8, 0.05)?;
+    ///
+    /// test.single_iteration();
+    ///
+    /// assert_eq!(test.tallies(Kind::Appended).len(), 8);
+    /// assert_eq!(test.tallies(Kind::Split).len(), 7);
+    /// assert_eq!(test.tallies(Kind::Appended)[0].collisions, 0);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn tallies(&self, kind: Kind) -> &[Tally] {
+        match kind {
+            Kind::Appended => &self.appended,
+            Kind::Prepended => &self.prepended,
+            Kind::Split => &self.split,
+        }
+    }
+
+    /// Gets the threshold of statistical significance for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::extension::length::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::try_new(&hasher, Box::new(AlphanumericProvider::new(10)), 8, 0.05)?;
+    ///
+    /// assert_eq!(test.threshold(), 0.05);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Hashes `chunks` with one call to
+    /// [`Hasher::write()`](std::hash::Hasher::write) per chunk.
+    fn hash<'b>(&self, chunks: impl IntoIterator<Item = &'b [u8]>) -> u64 {
+        let mut hasher = self.build_hasher.build_hasher();
+
+        for chunk in chunks {
+            hasher.write(chunk);
+        }
+
+        hasher.finish()
+    }
+
+    /// Hashes a single key from the provider along with every variant of the
+    /// key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::extension::length::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::try_new(&hasher, Box::new(AlphanumericProvider::new(10)), 8, 0.05)?;
+    ///
+    /// test.single_iteration();
+    /// assert_eq!(test.keys(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn single_iteration(&mut self) {
+        // SAFETY: we hardcode generating one value, so we know this pop must unwrap.
+        let key = self.provider.provide(1).pop().unwrap().to_vec();
+        let original = self.hash([key.as_slice()]);
+        let zeros = vec![0u8; self.max_extension];
+
+        for k in 1..=self.max_extension {
+            let appended = self.hash([key.as_slice(), &zeros[..k]]);
+            self.appended[k - 1].record(original, appended);
+
+            let prepended = self.hash([&zeros[..k], key.as_slice()]);
+            self.prepended[k - 1].record(original, prepended);
+        }
+
+        for k in 2..=self.max_extension {
+            let split = self.hash((0..k).map(|i| &key[i * key.len() / k..(i + 1) * key.len() / k]));
+            self.split[k - 2].record(original, split);
+        }
+
+        self.keys += 1;
+    }
+
+    /// Computes the two-sided p-value of the agreement between the output bits
+    /// of keys and their variants for a [`Kind`] of variant (excluding
+    /// collisions).
+    ///
+    /// If no bits have been compared, [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::extension::length::Kind;
+    /// use bitbelay_tests::extension::length::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::try_new(&hasher, Box::new(AlphanumericProvider::new(10)), 8, 0.05)?;
+    ///
+    /// assert!(test.p_value(Kind::Appended).is_none());
+    ///
+    /// for _ in 0..100 {
+    ///     test.single_iteration();
+    /// }
+    ///
+    /// assert!(test.p_value(Kind::Appended).unwrap() <= 1.0);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn p_value(&self, kind: Kind) -> Option<f64> {
+        let tallies = self.tallies(kind);
+        let bits = tallies.iter().map(Tally::bits).sum::<usize>() as f64;
+        let agreeing = tallies.iter().map(|t| t.agreeing_bits).sum::<usize>() as f64;
+
+        if bits == 0.0 {
+            return None;
+        }
+
+        // NOTE: for unrelated outputs, each bit agrees with probability 1/2,
+        // so the number of agreeing bits is binomial and is well-approximated
+        // by a normal distribution.
+        let z = (agreeing - bits / 2.0) / (bits / 4.0).sqrt();
+
+        // SAFETY: a standard normal distribution is always valid.
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        Some(2.0 * (1.0 - normal.cdf(z.abs())))
+    }
+
+    /// Generates a [`Module`] for a [`Kind`] of variant.
+    fn module(&self, kind: Kind, name: &str) -> Module {
+        let tallies = self.tallies(kind);
+        let collisions = tallies.iter().map(|t| t.collisions).sum::<usize>();
+        let comparisons = tallies.iter().map(|t| t.comparisons).sum::<usize>();
+        let p_value = self.p_value(kind);
+
+        // NOTE: a hasher that treats its input as a stream (i.e., its output
+        // only depends on the concatenation of everything written) is expected
+        // to produce identical outputs for split writes.
+        let streaming = kind == Kind::Split && collisions == comparisons;
+        let collided = kind != Kind::Split && collisions > 0;
+
+        let (result, summary) = match p_value {
+            _ if collided => (
+                module::Result::Fail,
+                format!(
+                    "{} of {} variants hashed to the same output as the original key, which \
+                     indicates that the hasher does not mix the length of its input into the \
+                     output.",
+                    collisions, comparisons
+                ),
+            ),
+            _ if streaming => (
+                module::Result::Pass,
+                String::from(
+                    "Every variant hashed to the same output as the original key, which indicates \
+                     that the hasher treats its input as a stream of bytes.",
+                ),
+            ),
+            Some(p_value) if p_value > self.threshold => (
+                module::Result::Pass,
+                format!(
+                    "The resulting p-value of {:.2} for the agreement between the output bits of \
+                     keys and their variants was greater than the predetermined threshold of \
+                     statistical significance set at {:.2}, so the outputs are consistent with \
+                     being unrelated.",
+                    p_value, self.threshold
+                ),
+            ),
+            Some(p_value) => (
+                module::Result::Fail,
+                format!(
+                    "The resulting p-value of {:.2} for the agreement between the output bits of \
+                     keys and their variants was less than the predetermined threshold of \
+                     statistical significance set at {:.2}, so the outputs are correlated.",
+                    p_value, self.threshold
+                ),
+            ),
+            None => (
+                module::Result::Inconclusive,
+                String::from(
+                    "The p-value was not able to be computed (try increasing the number of keys).",
+                ),
+            ),
+        };
+
+        let mut details = format!("{}\n\n{}\n", summary, "Variants".italic());
+
+        for (i, tally) in tallies.iter().enumerate() {
+            let agreement = match tally.bits() {
+                0 => String::from("n/a"),
+                bits => format!("{:.2}%", tally.agreeing_bits as f64 / bits as f64 * 100.0),
+            };
+
+            details.push_str(&format!(
+                "\n* Keys {}: {} collision(s), {} bit agreement.",
+                kind.describe(i + kind.min()),
+                tally.collisions,
+                agreement
+            ));
+        }
+
+        Module::new(
+            result,
+            name,
+            p_value.map(|p_value| format!("{:.2}", p_value)),
+            Some(details),
+        )
+    }
+}
+
+impl<'a, H: BuildHasher> crate::r#trait::Test for Test<'a, H> {
+    fn title(&self) -> &'static str {
+        "Length Extension"
+    }
+
+    fn report_section(&self) -> section::Test {
+        get_report_base(self.provider.as_ref(), self.max_extension, self.keys)
+            .push_module(self.module(Kind::Appended, "Appended Zeros"))
+            .push_module(self.module(Kind::Prepended, "Prepended Zeros"))
+            .push_module(self.module(Kind::Split, "Split Writes"))
+            .try_build()
+            .unwrap()
+    }
+}
+
+/// Populates the boilerplate report information within a
+/// [`Test`](section::Test).
+pub fn get_report_base(
+    provider: &dyn Provider,
+    max_extension: usize,
+    keys: usize,
+) -> section::test::Builder {
+    let overview = "The length extension test compares the hash of each key with the hashes of \
+                    variants of the key that have zero bytes appended or prepended, or that are \
+                    written across several calls to `Hasher::write`.";
+
+    let relation = "A common bug within hash functions is failing to mix the length of the input \
+                    into the output. Such a hasher processes zero bytes as a no-op (e.g., a \
+                    multiply-and-add over zero words), so `key` and `key\\0` collide—as do any \
+                    keys that only differ in trailing or leading zeros. Similarly, a hasher that \
+                    only partially buffers its input may produce outputs for split writes that \
+                    are related to (but not the same as) the output for a single write.";
+
+    let algorithm = format!(
+        "For each key generated by the data provider, the key is hashed with a single call to \
+         `Hasher::write` (the 'original hash') and compared with the hashes of:\n\n* the key with \
+         1 to {max} zero bytes appended,\n* the key with 1 to {max} zero bytes prepended, and\n* \
+         the key written in 2 to {max} (nearly) equally sized chunks.\n\nFor each comparison, \
+         collisions are counted. For non-colliding comparisons, the number of output bits that \
+         agree between the original hash and the variant's hash is tallied and compared against \
+         the 50% expected for unrelated outputs using a two-sided z-test.",
+        max = max_extension
+    );
+
+    let interpretation = "Under this test design:\n\n* Any collision between a key and the key \
+                          with zeros appended or prepended is **bad**, as it indicates that the \
+                          length of the input is not mixed into the output.\n\n* Identical \
+                          outputs for split writes are **expected** for hashers that treat their \
+                          input as a stream, while unrelated outputs are expected for hashers \
+                          that do not.\n\n* Significant bit agreement for any kind of variant is \
+                          **bad**, as it indicates that variants produce related outputs.";
+
+    test::Builder::default()
+        .title(format!(
+            "Length Extension / {} / {} bytes / {} keys",
+            provider.name(),
+            max_extension,
+            keys
+        ))
+        .unwrap()
+        .description(format!(
+            "{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}",
+            "Overview".italic(),
+            overview,
+            "Relation to Hashing".italic(),
+            relation,
+            "Algorithm".italic(),
+            algorithm,
+            "Interpretation".italic(),
+            interpretation,
+        ))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::BuildHasherDefault;
+    use std::hash::Hasher;
+
+    use bitbelay_providers::ascii::AlphanumericProvider;
+
+    use super::*;
+
+    /// A hasher that multiplies and adds each byte without mixing in the
+    /// length, so that appended zeros only shift the state.
+    #[derive(Debug, Default)]
+    struct MultiplyAdd(u64);
+
+    impl Hasher for MultiplyAdd {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            for byte in bytes {
+                self.0 = self.0.wrapping_add(u64::from(*byte)).wrapping_mul(31);
+            }
+        }
+    }
+
+    #[test]
+    fn unmixed_lengths_fail() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let hasher = BuildHasherDefault::<MultiplyAdd>::default();
+        let mut test = Test::try_new(&hasher, Box::new(AlphanumericProvider::new(10)), 4, 0.05)?;
+
+        for _ in 0..100 {
+            test.single_iteration();
+        }
+
+        // Prepended zeros leave the state untouched.
+        assert_eq!(test.tallies(Kind::Prepended)[0].collisions, 100);
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+//! Hash flooding tests.
+//!
+//! Keyed hashers rely on a secret seed to prevent an attacker from predicting
+//! which keys collide. The tests in this module search for weaknesses that
+//! allow collisions to be found regardless of the seed.
+
+use std::hash::BuildHasher;
+
+use bitbelay_report::section;
+
+use crate::r#trait::Test as _;
+
+pub mod persistent;
+
+/// A type of hash flooding test.
+#[derive(Debug)]
+pub enum Test<H: BuildHasher> {
+    /// Persistent collision test.
+    Persistent(persistent::Test<H>),
+}
+
+impl<H: BuildHasher> Test<H> {
+    /// Gets a reference to a [`persistent::Test`] wrapped in [`Some`] if the
+    /// [`Test`] is a [`Test::Persistent`]. Else, returns [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::flooding::Test;
+    /// use bitbelay_tests::flooding::persistent;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let test = Test::Persistent(persistent::Test::<Hasher>::try_new(
+    ///     Seeder::new(),
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     16,
+    ///     2,
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    /// )?);
+    ///
+    /// assert!(matches!(test.as_persistent_test(), Some(_)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn as_persistent_test(&self) -> Option<&persistent::Test<H>> {
+        match self {
+            Test::Persistent(test) => Some(test),
+        }
+    }
+
+    /// Consumes the [`Test`] and returns a [`persistent::Test`] wrapped in
+    /// [`Some`] if the [`Test`] is a [`Test::Persistent`]. Else, returns
+    /// [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::flooding::Test;
+    /// use bitbelay_tests::flooding::persistent;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let test = Test::Persistent(persistent::Test::<Hasher>::try_new(
+    ///     Seeder::new(),
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     16,
+    ///     2,
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    /// )?);
+    ///
+    /// assert!(matches!(test.into_persistent_test(), Some(_)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn into_persistent_test(self) -> Option<persistent::Test<H>> {
+        match self {
+            Test::Persistent(test) => Some(test),
+        }
+    }
+
+    /// Generates a report section for the [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::flooding::Test;
+    /// use bitbelay_tests::flooding::persistent;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let mut test = persistent::Test::<Hasher>::try_new(
+    ///     Seeder::new(),
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     8,
+    ///     1,
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// for _ in 0..10 {
+    ///     test.single_iteration();
+    /// }
+    ///
+    /// let test = Test::Persistent(test);
+    /// let results = test.report_section();
+    /// // Include the section in a report.
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn report_section(&self) -> section::Test {
+        match self {
+            Test::Persistent(test) => test.report_section(),
+        }
+    }
+}
@@ -0,0 +1,857 @@
+//! Persistent collision test.
+//!
+//! # Sources
+//!
+//! * [Aumasson, Bernstein, and Boßlet] describe seed-independent collisions
+//!   (multicollisions) in MurmurHash and CityHash that enabled hash flooding
+//!   against keyed hash tables.
+//!
+//! [Aumasson, Bernstein, and Boßlet]: https://131002.net/siphash/#at
+
+use std::hash::BuildHasher;
+
+use bitbelay_providers::Provider;
+use bitbelay_report::section;
+use bitbelay_report::section::test;
+use colored::Colorize;
+use statrs::distribution::Binomial;
+use statrs::distribution::DiscreteCDF as _;
+
+use crate::difference;
+pub use crate::difference::Error;
+pub use crate::difference::MAX_WEIGHT;
+use crate::seeded::Seeder;
+use crate::util::hex;
+use crate::util::p_value_module;
+
+/// The number of pairs listed within the details of a report module.
+const REPORTED_PAIRS: usize = 10;
+
+/// A [`Result`](std::result::Result) with an [`Error`].
+type Result<T> = std::result::Result<T, Error>;
+
+/// A pair of inputs that collided under the search seed.
+#[derive(Clone, Debug)]
+pub struct Pair {
+    /// The first input.
+    pub first: Vec<u8>,
+
+    /// The input bits that were flipped to derive the second input from the
+    /// first input.
+    pub difference: Vec<usize>,
+
+    /// The number of fresh seeds under which the pair also collided.
+    pub seeds: usize,
+}
+
+impl Pair {
+    /// Gets the second input of the pair.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_tests::flooding::persistent::Pair;
+    ///
+    /// let pair = Pair {
+    ///     first: vec![0b0000_0000, 0b0000_0001],
+    ///     difference: vec![1, 8],
+    ///     seeds: 0,
+    /// };
+    ///
+    /// assert_eq!(pair.second(), vec![0b0000_0010, 0b0000_0000]);
+    /// ```
+    pub fn second(&self) -> Vec<u8> {
+        let mut second = self.first.clone();
+        difference::flip(&mut second, &self.difference);
+        second
+    }
+}
+
+/// A persistent collision test.
+#[derive(Debug)]
+pub struct Test<H: BuildHasher> {
+    /// The seeder used to construct hashers.
+    seeder: Seeder<H>,
+
+    /// The hasher used to search for collisions.
+    search: H,
+
+    /// The data provider.
+    provider: Box<dyn Provider>,
+
+    /// The number of low output bits compared when checking for a collision.
+    bits: u32,
+
+    /// The maximum number of input bits that differ within a pair.
+    max_weight: usize,
+
+    /// The number of fresh seeds under which each candidate pair is
+    /// re-checked.
+    reseeds: usize,
+
+    /// The number of keys searched.
+    keys: usize,
+
+    /// The number of pairs searched.
+    searched: usize,
+
+    /// The pairs that collided under the search seed.
+    candidates: Vec<Pair>,
+
+    /// The threshold of statistical significance.
+    threshold: f64,
+}
+
+impl<H: BuildHasher> Test<H> {
+    /// Attempts to create a new [`Test`].
+    ///
+    /// The search seed is chosen at random using the [`Seeder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::flooding::persistent::Test;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let test = Test::<Hasher>::try_new(
+    ///     Seeder::new(),
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     16,
+    ///     2,
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert_eq!(test.keys(), 0);
+    /// assert!(test.candidates().is_empty());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_new(
+        seeder: Seeder<H>,
+        provider: Box<dyn Provider>,
+        bits: u32,
+        max_weight: usize,
+        reseeds: std::num::NonZeroUsize,
+        threshold: f64,
+    ) -> Result<Self> {
+        difference::validate(bits, max_weight)?;
+
+        Ok(Self {
+            search: seeder.random(),
+            seeder,
+            provider,
+            bits,
+            max_weight,
+            reseeds: reseeds.get(),
+            keys: 0,
+            searched: 0,
+            candidates: Vec::new(),
+            threshold,
+        })
+    }
+
+    /// Gets the data provider for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::Provider as _;
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::flooding::persistent::Test;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let test = Test::<Hasher>::try_new(
+    ///     Seeder::new(),
+    ///     provider.clone(),
+    ///     16,
+    ///     2,
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert_eq!(test.provider().name(), provider.name());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn provider(&self) -> &dyn Provider {
+        self.provider.as_ref()
+    }
+
+    /// Gets the number of low output bits compared when checking for a
+    /// collision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::flooding::persistent::Test;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let test = Test::<Hasher>::try_new(
+    ///     Seeder::new(),
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     16,
+    ///     2,
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert_eq!(test.bits(), 16);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// Gets the maximum number of input bits that differ within a pair.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::flooding::persistent::Test;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let test = Test::<Hasher>::try_new(
+    ///     Seeder::new(),
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     16,
+    ///     2,
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert_eq!(test.max_weight(), 2);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn max_weight(&self) -> usize {
+        self.max_weight
+    }
+
+    /// Gets the number of fresh seeds under which each candidate pair is
+    /// re-checked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::flooding::persistent::Test;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let test = Test::<Hasher>::try_new(
+    ///     Seeder::new(),
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     16,
+    ///     2,
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert_eq!(test.reseeds(), 8);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn reseeds(&self) -> usize {
+        self.reseeds
+    }
+
+    /// Gets the number of keys searched within this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::flooding::persistent::Test;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let mut test = Test::<Hasher>::try_new(
+    ///     Seeder::new(),
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     16,
+    ///     2,
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// test.single_iteration();
+    /// assert_eq!(test.keys(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn keys(&self) -> usize {
+        self.keys
+    }
+
+    /// Gets the number of pairs searched within this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::flooding::persistent::Test;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let mut test = Test::<Hasher>::try_new(
+    ///     Seeder::new(),
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     16,
+    ///     1,
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// test.single_iteration();
+    ///
+    /// // Every single-bit difference within the 80-bit key is searched.
+    /// assert_eq!(test.searched(), 80);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn searched(&self) -> usize {
+        self.searched
+    }
+
+    /// Gets the pairs that collided under the search seed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::flooding::persistent::Test;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let mut test = Test::<Hasher>::try_new(
+    ///     Seeder::new(),
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     4,
+    ///     1,
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// test.single_iteration();
+    ///
+    /// for pair in test.candidates() {
+    ///     assert_eq!(pair.difference.len(), 1);
+    /// }
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn candidates(&self) -> &[Pair] {
+        &self.candidates
+    }
+
+    /// Gets the threshold of statistical significance for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::flooding::persistent::Test;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let test = Test::<Hasher>::try_new(
+    ///     Seeder::new(),
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     16,
+    ///     2,
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert_eq!(test.threshold(), 0.05);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// The mask selecting the compared output bits.
+    fn mask(&self) -> u64 {
+        u64::MAX >> (64 - self.bits)
+    }
+
+    /// The probability that a pair of unrelated inputs collides under a single
+    /// seed.
+    fn collision_probability(&self) -> f64 {
+        0.5f64.powi(self.bits as i32)
+    }
+
+    /// Searches a single key from the provider.
+    ///
+    /// Every input that differs from the key in at most
+    /// [`max_weight()`](Test::max_weight) bits is hashed under the search
+    /// seed. Each pair that collides is then re-checked under
+    /// [`reseeds()`](Test::reseeds) fresh seeds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::flooding::persistent::Test;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let mut test = Test::<Hasher>::try_new(
+    ///     Seeder::new(),
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     16,
+    ///     2,
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// test.single_iteration();
+    /// assert_eq!(test.keys(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn single_iteration(&mut self) {
+        // SAFETY: we hardcode generating one value, so we know this pop must unwrap.
+        let key = self.provider.provide(1).pop().unwrap().to_vec();
+        let mask = self.mask();
+        let original = self.search.hash_one(&key);
+
+        let mut candidates = Vec::new();
+        let mut searched = 0;
+        let mut input = key.clone();
+
+        difference::for_each(key.len() * 8, self.max_weight, |difference| {
+            difference::flip(&mut input, difference);
+
+            if (self.search.hash_one(&input) ^ original) & mask == 0 {
+                candidates.push(difference.to_vec());
+            }
+
+            difference::flip(&mut input, difference);
+            searched += 1;
+        });
+
+        for difference in candidates {
+            let mut pair = Pair {
+                first: key.clone(),
+                difference,
+                seeds: 0,
+            };
+
+            let second = pair.second();
+
+            for _ in 0..self.reseeds {
+                let hasher = self.seeder.random();
+
+                if (hasher.hash_one(&pair.first) ^ hasher.hash_one(&second)) & mask == 0 {
+                    pair.seeds += 1;
+                }
+            }
+
+            self.candidates.push(pair);
+        }
+
+        self.searched += searched;
+        self.keys += 1;
+    }
+
+    /// Gets the candidate pairs that collided under every fresh seed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::flooding::persistent::Test;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let mut test = Test::<Hasher>::try_new(
+    ///     Seeder::new(),
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     16,
+    ///     2,
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// for _ in 0..10 {
+    ///     test.single_iteration();
+    /// }
+    ///
+    /// assert!(test.persistent().is_empty());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn persistent(&self) -> Vec<&Pair> {
+        self.candidates
+            .iter()
+            .filter(|pair| pair.seeds == self.reseeds)
+            .collect()
+    }
+
+    /// Computes the p-value for the number of candidate pairs that collided
+    /// under every fresh seed.
+    ///
+    /// If there are no candidate pairs, [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::flooding::persistent::Test;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let mut test = Test::<Hasher>::try_new(
+    ///     Seeder::new(),
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     4,
+    ///     1,
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// for _ in 0..10 {
+    ///     test.single_iteration();
+    /// }
+    ///
+    /// assert!(test.persistent_p_value().unwrap() <= 1.0);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn persistent_p_value(&self) -> Option<f64> {
+        let probability = self.collision_probability().powf(self.reseeds as f64);
+        p_value(probability, self.candidates.len(), self.persistent().len())
+    }
+
+    /// Computes the p-value for the total number of collisions observed when
+    /// re-checking the candidate pairs under fresh seeds.
+    ///
+    /// If there are no candidate pairs, [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::flooding::persistent::Test;
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let mut test = Test::<Hasher>::try_new(
+    ///     Seeder::new(),
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     4,
+    ///     1,
+    ///     NonZeroUsize::try_from(8).unwrap(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// for _ in 0..10 {
+    ///     test.single_iteration();
+    /// }
+    ///
+    /// assert!(test.recheck_p_value().unwrap() <= 1.0);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn recheck_p_value(&self) -> Option<f64> {
+        let rechecks = self.candidates.len() * self.reseeds;
+        let collisions = self.candidates.iter().map(|pair| pair.seeds).sum();
+        p_value(self.collision_probability(), rechecks, collisions)
+    }
+}
+
+impl<H: BuildHasher> crate::r#trait::Test for Test<H> {
+    fn title(&self) -> &'static str {
+        "Persistent Collisions"
+    }
+
+    fn report_section(&self) -> section::Test {
+        let expected = self.searched as f64 * self.collision_probability();
+
+        let persistent = self.persistent();
+        let mut context = format!(
+            "Searched {} pairs across {} keys under a single seed: {} pairs collided in the low \
+             {} output bits ({:.2} expected), and {} of those collided under all {} fresh seeds.",
+            self.searched,
+            self.keys,
+            self.candidates.len(),
+            self.bits,
+            expected,
+            persistent.len(),
+            self.reseeds
+        );
+
+        if !persistent.is_empty() {
+            context.push_str(&format!("\n\n{}\n", "Persistent Pairs".italic()));
+
+            for pair in persistent.into_iter().take(REPORTED_PAIRS) {
+                context.push_str(&format!(
+                    "\n* 0x{} and 0x{} (input bits {:?} flipped).",
+                    hex(&pair.first),
+                    hex(&pair.second()),
+                    pair.difference
+                ));
+            }
+        }
+
+        let inconclusive = "No candidate pairs collided under the search seed, so the p-value was \
+                            not able to be computed (try increasing the number of keys or \
+                            decreasing the number of compared bits).";
+
+        let persistent = p_value_module(
+            "Persistent Collisions",
+            self.persistent_p_value(),
+            self.threshold,
+            "the candidate pairs collide under each fresh seed only by chance",
+            inconclusive,
+            Some(context),
+        );
+
+        let rechecks = self.candidates.len() * self.reseeds;
+        let recheck = p_value_module(
+            "Re-check Collision Rate",
+            self.recheck_p_value(),
+            self.threshold,
+            "the candidate pairs collide under fresh seeds no more often than unrelated inputs",
+            inconclusive,
+            Some(format!(
+                "Re-checking the candidate pairs under fresh seeds resulted in {} collisions out \
+                 of {} re-checks ({:.2} expected).",
+                self.candidates.iter().map(|pair| pair.seeds).sum::<usize>(),
+                rechecks,
+                rechecks as f64 * self.collision_probability()
+            )),
+        );
+
+        get_report_base(self.provider.as_ref(), self.bits, self.max_weight)
+            .push_module(persistent)
+            .push_module(recheck)
+            .try_build()
+            .unwrap()
+    }
+}
+
+/// Computes `P(X >= observed)` where `X ~ Binomial(trials, probability)`.
+///
+/// If there are no trials, [`None`] is returned.
+fn p_value(probability: f64, trials: usize, observed: usize) -> Option<f64> {
+    if trials == 0 {
+        return None;
+    }
+
+    if observed == 0 {
+        return Some(1.0);
+    }
+
+    // SAFETY: the probability is always within [0, 1], so this will always
+    // unwrap.
+    let distribution = Binomial::new(probability, trials as u64).unwrap();
+
+    // NOTE: the survival function is `P(X > x)`, so this computes
+    // `P(X >= observed)`.
+    Some(distribution.sf(observed as u64 - 1))
+}
+
+/// Populates the boilerplate report information within a
+/// [`Test`](section::Test).
+pub fn get_report_base(
+    provider: &dyn Provider,
+    bits: u32,
+    max_weight: usize,
+) -> section::test::Builder {
+    let overview = "The persistent collision test searches for pairs of inputs that collide for \
+                    _every_ seed of a keyed hasher. Pairs of inputs that differ in only a few \
+                    bits are searched for collisions under a single seed, and each pair that \
+                    collides is re-checked under many fresh seeds.";
+
+    let relation = "Keyed hashers are used to defend hash tables against hash flooding (HashDoS), \
+                    where an attacker submits many keys that collide in order to degrade the \
+                    table to linear time. This defense only holds if the attacker cannot predict \
+                    collisions without knowing the seed. If a pair of inputs collides regardless \
+                    of the seed (often because a small input difference cancels out within the \
+                    hasher's internal state before the seed has any effect), an attacker can \
+                    generate an unbounded number of colliding keys offline.";
+
+    let algorithm = format!(
+        "For the hash function, data provider, and number of keys chosen:\n\n(1) A search seed is \
+         chosen at random.\n\n(2) For each key generated by the data provider, every input that \
+         differs from the key in at most {} bit(s) is hashed under the search seed. Pairs whose \
+         hashes agree in the low {} bits are kept as candidates.\n\n(3) Each candidate pair is \
+         re-checked under a number of fresh, randomly chosen seeds, and the number of seeds under \
+         which the pair also collides is counted.\n\n(4) Two one-sided binomial tests are \
+         performed: one on the number of candidate pairs that collided under every fresh seed and \
+         one on the total number of collisions across all re-checks. Under the null hypothesis, \
+         each re-check collides with a probability of 2^-{}.",
+        max_weight, bits, bits
+    );
+
+    let interpretation = "Under this test design:\n\n* Candidate pairs that stop colliding once \
+                          the seed changes are **good**, as they indicate the collisions under \
+                          the search seed were coincidences.\n\n* Candidate pairs that keep \
+                          colliding under fresh seeds are **bad**, as they are seed-independent \
+                          collisions that enable hash flooding regardless of the seed. Any \
+                          persistent pairs are listed so they can be reproduced.";
+
+    let sources = "* https://131002.net/siphash/#at\n* https://en.wikipedia.org/wiki/Collision_attack#Hash_flooding";
+
+    test::Builder::default()
+        .title(format!(
+            "Persistent Collisions / {} / {} bits / weight <= {}",
+            provider.name(),
+            bits,
+            max_weight
+        ))
+        .unwrap()
+        .description(format!(
+            "{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}",
+            "Overview".italic(),
+            overview,
+            "Relation to Hashing".italic(),
+            relation,
+            "Algorithm".italic(),
+            algorithm,
+            "Interpretation".italic(),
+            interpretation,
+            "Sources".italic(),
+            sources
+        ))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::BuildHasherDefault;
+    use std::hash::Hasher;
+    use std::num::NonZeroUsize;
+
+    use bitbelay_providers::ascii::AlphanumericProvider;
+
+    use super::*;
+    use crate::seeded::SeedPrefixed;
+
+    /// A hasher that XORs its input into the state, so that single-bit input
+    /// differences in the same position of different words cancel out
+    /// regardless of the seed.
+    #[derive(Debug, Default)]
+    struct FoldXor(u64);
+
+    impl Hasher for FoldXor {
+        fn finish(&self) -> u64 {
+            self.0.wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            for (i, byte) in bytes.iter().enumerate() {
+                self.0 ^= u64::from(*byte) << ((i % 8) * 8);
+            }
+        }
+    }
+
+    #[test]
+    fn seed_independent_collisions_fail() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let mut test = Test::<SeedPrefixed<BuildHasherDefault<FoldXor>>>::try_new(
+            Seeder::new(),
+            Box::new(AlphanumericProvider::new(16)),
+            64,
+            2,
+            NonZeroUsize::try_from(8).unwrap(),
+            0.05,
+        )?;
+
+        test.single_iteration();
+
+        assert!(!test.persistent().is_empty());
+        assert!(test.persistent_p_value().unwrap() < 0.05);
+        Ok(())
+    }
+}
@@ -0,0 +1,123 @@
+//! Tests modeled on the internals of [`hashbrown`](https://crates.io/crates/hashbrown).
+//!
+//! `hashbrown` (and, by extension, the standard library's `HashMap`) does not
+//! use a hash as a single value. Instead, it splits every hash into two parts:
+//!
+//! * **H1**, the full hash, which is masked to choose the position where
+//!   probing starts within the table, and
+//! * **H2**, the top 7 bits of the hash, which is stored as a tag within the
+//!   control byte of each occupied slot and used to filter candidate slots
+//!   sixteen at a time (one "group").
+//!
+//! A hash function can therefore distribute H1 well and still perform poorly
+//! within a `hashbrown` table if its top bits are clustered.
+
+use std::hash::BuildHasher;
+
+use bitbelay_report::section;
+
+use crate::r#trait::Test as _;
+
+pub mod split;
+
+/// A type of `hashbrown` test.
+#[derive(Debug)]
+pub enum Test<'a, H: BuildHasher> {
+    /// H1/H2 split quality test.
+    Split(split::Test<'a, H>),
+}
+
+impl<'a, H: BuildHasher> Test<'a, H> {
+    /// Gets a reference to a [`split::Test`] wrapped in [`Some`] if the
+    /// [`Test`] is a [`Test::Split`]. Else, returns [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::hashbrown::Test;
+    /// use bitbelay_tests::hashbrown::split;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::Split(split::Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     0.05,
+    /// )?);
+    ///
+    /// assert!(matches!(test.as_split_test(), Some(_)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn as_split_test(&self) -> Option<&split::Test<'a, H>> {
+        match self {
+            Test::Split(test) => Some(test),
+        }
+    }
+
+    /// Consumes the [`Test`] and returns a [`split::Test`] wrapped in [`Some`]
+    /// if the [`Test`] is a [`Test::Split`]. Else, returns [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::hashbrown::Test;
+    /// use bitbelay_tests::hashbrown::split;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::Split(split::Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     0.05,
+    /// )?);
+    ///
+    /// assert!(matches!(test.into_split_test(), Some(_)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn into_split_test(self) -> Option<split::Test<'a, H>> {
+        match self {
+            Test::Split(test) => Some(test),
+        }
+    }
+
+    /// Generates a report section for the [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::hashbrown::Test;
+    /// use bitbelay_tests::hashbrown::split;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::Split(split::Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     0.05,
+    /// )?);
+    ///
+    /// let results = test.report_section();
+    /// // Include the section in a report.
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn report_section(&self) -> section::Test {
+        match self {
+            Test::Split(test) => test.report_section(),
+        }
+    }
+}
@@ -0,0 +1,1041 @@
+//! H1/H2 split quality test.
+
+use std::hash::BuildHasher;
+use std::num::NonZeroUsize;
+
+use bitbelay_providers::Provider;
+use bitbelay_report::section;
+use bitbelay_report::section::test;
+use bitbelay_statistics::chi_squared::ContingencyTest;
+use bitbelay_statistics::chi_squared::Statistic;
+use bitbelay_statistics::chi_squared::UniformPearsonTest;
+use colored::Colorize;
+use statrs::distribution::Binomial;
+use statrs::distribution::DiscreteCDF as _;
+
+use crate::util::p_value_module;
+
+/// The number of control bytes that are matched at once within a probe group.
+pub const GROUP_WIDTH: usize = 16;
+
+/// The number of bits of the hash used as the H2 tag.
+pub const H2_BITS: u32 = 7;
+
+/// The number of distinct H2 tags.
+pub const H2_TAGS: usize = 1 << H2_BITS;
+
+/// The number of low H1 bits used when checking the independence of H1 and H2.
+pub const H1_INDEPENDENCE_BITS: u32 = 4;
+
+/// The details of a report module whose p-value was not able to be computed.
+const INCONCLUSIVE: &str =
+    "The p-value was not able to be computed (try increasing the number of iterations).";
+
+/// The control byte for an empty slot.
+const EMPTY: u8 = 0xFF;
+
+/// An error related to a [`Test`].
+#[derive(Debug)]
+pub enum Error {
+    /// The number of buckets is not a power of two of at least
+    /// [`GROUP_WIDTH`].
+    InvalidBuckets(NonZeroUsize),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidBuckets(buckets) => write!(
+                f,
+                "invalid number of buckets: {buckets} (must be a power of two of at least \
+                 {GROUP_WIDTH})"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A [`Result`](std::result::Result) with an [`Error`].
+type Result<T> = std::result::Result<T, Error>;
+
+/// Gets the H1 portion of a hash (the value masked to choose the position
+/// where probing starts).
+///
+/// # Examples
+///
+/// ```
+/// use bitbelay_tests::hashbrown::split::h1;
+///
+/// assert_eq!(h1(0xFE00_0000_0000_0042), 0xFE00_0000_0000_0042);
+/// ```
+pub fn h1(hash: u64) -> u64 {
+    hash
+}
+
+/// Gets the H2 portion of a hash (the top seven bits, which are stored in the
+/// control byte of an occupied slot).
+///
+/// # Examples
+///
+/// ```
+/// use bitbelay_tests::hashbrown::split::h2;
+///
+/// assert_eq!(h2(0xFE00_0000_0000_0042), 0x7F);
+/// assert_eq!(h2(0x01FF_FFFF_FFFF_FFFF), 0x00);
+/// ```
+pub fn h2(hash: u64) -> u8 {
+    (hash >> (u64::BITS - H2_BITS)) as u8
+}
+
+/// A test of how well a hash function behaves when split into H1 and H2 in
+/// the manner of a `hashbrown` table.
+///
+/// Every iteration hashes a single input and records its H2 tag (along with
+/// the low bits of H1). The first iterations are also inserted into a
+/// simulated table until it reaches `hashbrown`'s maximum load factor of 7/8.
+/// Every subsequent iteration is looked up within that table, and the number of
+/// occupied slots with a matching tag (i.e., false matches) is recorded.
+#[derive(Debug)]
+pub struct Test<'a, H: BuildHasher> {
+    /// The hash function builder.
+    build_hasher: &'a H,
+
+    /// The data provider.
+    provider: Box<dyn Provider>,
+
+    /// The control bytes of the simulated table.
+    control: Vec<u8>,
+
+    /// The number of items inserted into the simulated table.
+    items: usize,
+
+    /// The number of hashes observed with each H2 tag.
+    h2_counts: Vec<usize>,
+
+    /// The number of hashes observed for each combination of the low H1 bits
+    /// and the H2 tag.
+    joint_counts: Vec<usize>,
+
+    /// The number of lookups performed against the simulated table.
+    lookups: usize,
+
+    /// The number of probe groups visited during lookups.
+    groups_probed: usize,
+
+    /// The number of occupied slots compared against during lookups.
+    slots_probed: usize,
+
+    /// The number of occupied slots with a matching tag during lookups.
+    false_matches: usize,
+
+    /// The threshold of statistical signficance to use.
+    threshold: f64,
+}
+
+impl<'a, H: BuildHasher> Test<'a, H> {
+    /// Attempts to create a new [`Test`].
+    ///
+    /// # Notes
+    ///
+    /// * If `buckets` is not a power of two of at least [`GROUP_WIDTH`], an
+    ///   [`Error::InvalidBuckets`] is returned (these are the only table sizes
+    ///   that `hashbrown` uses once probing is done in groups).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::BuildHasher as _;
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::Provider as _;
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::hashbrown::split::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let test = Test::try_new(
+    ///     &hasher,
+    ///     provider.clone(),
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert_eq!(test.build_hasher().hash_one("42"), hasher.hash_one("42"));
+    /// assert_eq!(test.provider().name(), provider.name());
+    /// assert_eq!(test.buckets().get(), 1024);
+    ///
+    /// // Only powers of two are supported.
+    /// assert!(
+    ///     Test::try_new(
+    ///         &hasher,
+    ///         provider,
+    ///         NonZeroUsize::try_from(1000).unwrap(),
+    ///         0.05,
+    ///     )
+    ///     .is_err()
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_new(
+        build_hasher: &'a H,
+        provider: Box<dyn Provider>,
+        buckets: NonZeroUsize,
+        threshold: f64,
+    ) -> Result<Self> {
+        if !buckets.is_power_of_two() || buckets.get() < GROUP_WIDTH {
+            return Err(Error::InvalidBuckets(buckets));
+        }
+
+        Ok(Self {
+            build_hasher,
+            provider,
+            control: vec![EMPTY; buckets.get()],
+            items: 0,
+            h2_counts: vec![0; H2_TAGS],
+            joint_counts: vec![0; H2_TAGS << H1_INDEPENDENCE_BITS],
+            lookups: 0,
+            groups_probed: 0,
+            slots_probed: 0,
+            false_matches: 0,
+            threshold,
+        })
+    }
+
+    /// Gets the [`BuildHasher`] from the [`Test`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::BuildHasher as _;
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::hashbrown::split::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let test = Test::try_new(
+    ///     &hasher,
+    ///     provider,
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert_eq!(test.build_hasher().hash_one("42"), hasher.hash_one("42"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn build_hasher(&self) -> &H {
+        self.build_hasher
+    }
+
+    /// Gets the [`Provider`] from the [`Test`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::Provider as _;
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::hashbrown::split::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let test = Test::try_new(
+    ///     &hasher,
+    ///     provider.clone(),
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert_eq!(test.provider().name(), provider.name());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn provider(&self) -> &dyn Provider {
+        self.provider.as_ref()
+    }
+
+    /// Gets the number of buckets within the simulated table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::hashbrown::split::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let test = Test::try_new(
+    ///     &hasher,
+    ///     provider,
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert_eq!(test.buckets().get(), 1024);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn buckets(&self) -> NonZeroUsize {
+        // SAFETY: the control bytes are created from a [`NonZeroUsize`], so
+        // this will always unwrap.
+        NonZeroUsize::try_from(self.control.len()).unwrap()
+    }
+
+    /// Gets the threshold from the [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::hashbrown::split::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let test = Test::try_new(
+    ///     &hasher,
+    ///     provider,
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert_eq!(test.threshold(), 0.05);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Gets the number of hashes observed with each H2 tag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::hashbrown::split::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let mut test = Test::try_new(
+    ///     &hasher,
+    ///     provider,
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// test.single_iteration();
+    ///
+    /// assert_eq!(test.h2_counts().len(), 128);
+    /// assert_eq!(test.h2_counts().iter().sum::<usize>(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn h2_counts(&self) -> &[usize] {
+        &self.h2_counts
+    }
+
+    /// Gets the number of hashes observed for each combination of the low
+    /// [`H1_INDEPENDENCE_BITS`] bits of H1 and the H2 tag.
+    ///
+    /// The counts are laid out such that the count for a given H1 value and H2
+    /// tag is at index `(h1 << 7) | h2`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::hashbrown::split::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let mut test = Test::try_new(
+    ///     &hasher,
+    ///     provider,
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// test.single_iteration();
+    ///
+    /// assert_eq!(test.joint_counts().len(), 16 * 128);
+    /// assert_eq!(test.joint_counts().iter().sum::<usize>(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn joint_counts(&self) -> &[usize] {
+        &self.joint_counts
+    }
+
+    /// Gets the number of items inserted into the simulated table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::hashbrown::split::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let mut test = Test::try_new(&hasher, provider, NonZeroUsize::try_from(16).unwrap(), 0.05)?;
+    ///
+    /// for _ in 0..100 {
+    ///     test.single_iteration();
+    /// }
+    ///
+    /// // The table is filled to a load factor of 7/8.
+    /// assert_eq!(test.items(), 14);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn items(&self) -> usize {
+        self.items
+    }
+
+    /// Gets the number of lookups performed against the simulated table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::hashbrown::split::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let mut test = Test::try_new(&hasher, provider, NonZeroUsize::try_from(16).unwrap(), 0.05)?;
+    ///
+    /// for _ in 0..100 {
+    ///     test.single_iteration();
+    /// }
+    ///
+    /// assert_eq!(test.lookups(), 100 - 14);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn lookups(&self) -> usize {
+        self.lookups
+    }
+
+    /// Gets the number of probe groups visited during lookups.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::hashbrown::split::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let mut test = Test::try_new(&hasher, provider, NonZeroUsize::try_from(16).unwrap(), 0.05)?;
+    ///
+    /// for _ in 0..100 {
+    ///     test.single_iteration();
+    /// }
+    ///
+    /// // Every lookup visits at least one group.
+    /// assert!(test.groups_probed() >= test.lookups());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn groups_probed(&self) -> usize {
+        self.groups_probed
+    }
+
+    /// Gets the number of occupied slots whose tags were compared against
+    /// during lookups.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::hashbrown::split::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let mut test = Test::try_new(&hasher, provider, NonZeroUsize::try_from(16).unwrap(), 0.05)?;
+    ///
+    /// for _ in 0..100 {
+    ///     test.single_iteration();
+    /// }
+    ///
+    /// // Every lookup in a table of one group compares against every item.
+    /// assert_eq!(test.slots_probed(), test.lookups() * test.items());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn slots_probed(&self) -> usize {
+        self.slots_probed
+    }
+
+    /// Gets the number of occupied slots with a matching tag (i.e., false
+    /// matches) during lookups.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::hashbrown::split::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let mut test = Test::try_new(&hasher, provider, NonZeroUsize::try_from(16).unwrap(), 0.05)?;
+    ///
+    /// for _ in 0..100 {
+    ///     test.single_iteration();
+    /// }
+    ///
+    /// assert!(test.false_matches() <= test.slots_probed());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn false_matches(&self) -> usize {
+        self.false_matches
+    }
+
+    /// Gets the number of false matches expected if every H2 tag were
+    /// independent and uniformly distributed (one in every 128 occupied slots
+    /// compared against).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::hashbrown::split::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let mut test = Test::try_new(&hasher, provider, NonZeroUsize::try_from(16).unwrap(), 0.05)?;
+    ///
+    /// for _ in 0..100 {
+    ///     test.single_iteration();
+    /// }
+    ///
+    /// assert_eq!(
+    ///     test.expected_false_matches(),
+    ///     test.slots_probed() as f64 / 128.0
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn expected_false_matches(&self) -> f64 {
+        self.slots_probed as f64 / H2_TAGS as f64
+    }
+
+    /// Performs a single iteration of the test.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::hashbrown::split::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let mut test = Test::try_new(
+    ///     &hasher,
+    ///     provider,
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// test.single_iteration();
+    ///
+    /// assert_eq!(test.items(), 1);
+    /// assert_eq!(test.lookups(), 0);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn single_iteration(&mut self) {
+        let data = *self.provider.provide(1).first().unwrap();
+        let hash = self.build_hasher.hash_one(data);
+
+        let h1 = h1(hash);
+        let h2 = h2(hash);
+
+        self.h2_counts[h2 as usize] += 1;
+
+        let low = (h1 & ((1 << H1_INDEPENDENCE_BITS) - 1)) as usize;
+        self.joint_counts[(low << H2_BITS) | h2 as usize] += 1;
+
+        if self.items < self.max_items() {
+            self.insert(h1, h2);
+        } else {
+            self.lookup(h1, h2);
+        }
+    }
+
+    /// Gets the maximum number of items held by the simulated table (a load
+    /// factor of 7/8, matching `hashbrown`).
+    fn max_items(&self) -> usize {
+        self.control.len() / 8 * 7
+    }
+
+    /// Gets the probe sequence (the starting position of each group to visit)
+    /// for a given H1.
+    ///
+    /// Like `hashbrown`, this uses triangular probing, which is guaranteed to
+    /// visit every group in a power-of-two table.
+    fn probe_sequence(&self, h1: u64) -> impl Iterator<Item = usize> {
+        let mask = self.control.len() - 1;
+        let groups = self.control.len() / GROUP_WIDTH;
+        let start = h1 as usize & mask;
+
+        (0..groups).scan((start, 0), move |(position, stride), _| {
+            let current = *position;
+            *stride += GROUP_WIDTH;
+            *position = (*position + *stride) & mask;
+            Some(current)
+        })
+    }
+
+    /// Inserts a hash into the first empty slot along its probe sequence.
+    fn insert(&mut self, h1: u64, h2: u8) {
+        let mask = self.control.len() - 1;
+
+        for position in self.probe_sequence(h1).collect::<Vec<_>>() {
+            for offset in 0..GROUP_WIDTH {
+                let slot = (position + offset) & mask;
+
+                if self.control[slot] == EMPTY {
+                    self.control[slot] = h2;
+                    self.items += 1;
+                    return;
+                }
+            }
+        }
+
+        unreachable!("the simulated table is never filled beyond its maximum load factor");
+    }
+
+    /// Looks up a hash (that is assumed to be absent) within the simulated
+    /// table, recording every tag comparison along the way.
+    fn lookup(&mut self, h1: u64, h2: u8) {
+        let mask = self.control.len() - 1;
+        self.lookups += 1;
+
+        for position in self.probe_sequence(h1).collect::<Vec<_>>() {
+            self.groups_probed += 1;
+            let mut found_empty = false;
+
+            for offset in 0..GROUP_WIDTH {
+                match self.control[(position + offset) & mask] {
+                    EMPTY => found_empty = true,
+                    tag => {
+                        self.slots_probed += 1;
+
+                        if tag == h2 {
+                            self.false_matches += 1;
+                        }
+                    }
+                }
+            }
+
+            // NOTE: like `hashbrown`, a lookup stops at the first group that
+            // contains an empty slot.
+            if found_empty {
+                return;
+            }
+        }
+    }
+
+    /// Gets the p-value of a goodness of fit test for the H2 tags against a
+    /// random, uniform distribution.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::hashbrown::split::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let mut test = Test::try_new(
+    ///     &hasher,
+    ///     provider,
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// // Not enough data to compute a p-value.
+    /// assert!(test.h2_p_value().is_none());
+    ///
+    /// for _ in 0..1024 {
+    ///     test.single_iteration();
+    /// }
+    ///
+    /// assert!(test.h2_p_value().unwrap() <= 1.0);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn h2_p_value(&self) -> Option<f64> {
+        UniformPearsonTest::goodness_of_fit(&self.h2_counts)
+    }
+
+    /// Gets the p-value of a chi-squared test of independence between the low
+    /// H1 bits and the H2 tags.
+    ///
+    /// The joint tallies are arranged as a contingency table with one row per
+    /// value of the low H1 bits and one column per H2 tag, and the expected
+    /// count of each cell is derived from the row and column totals. As such,
+    /// a skew within the low H1 bits or the H2 tags alone does not cause this
+    /// test to fail (see [`ContingencyTest::independence()`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::hashbrown::split::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let mut test = Test::try_new(
+    ///     &hasher,
+    ///     provider,
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// for _ in 0..32_768 {
+    ///     test.single_iteration();
+    /// }
+    ///
+    /// assert!(test.independence_p_value().unwrap() <= 1.0);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn independence_p_value(&self) -> Option<f64> {
+        let table = self
+            .joint_counts
+            .chunks(H2_TAGS)
+            .map(|row| row.to_vec())
+            .collect::<Vec<_>>();
+
+        ContingencyTest::independence(&table, Statistic::Pearson)
+    }
+
+    /// Gets the (one-sided) p-value of observing at least as many false
+    /// matches as were observed if every H2 tag were independent and uniformly
+    /// distributed.
+    ///
+    /// # Notes
+    ///
+    /// * If no occupied slots have been compared against yet (i.e., the table
+    ///   has not yet been filled), then no result is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::hashbrown::split::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let mut test = Test::try_new(
+    ///     &hasher,
+    ///     provider,
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     0.05,
+    /// )?;
+    ///
+    /// assert!(test.false_match_p_value().is_none());
+    ///
+    /// for _ in 0..4096 {
+    ///     test.single_iteration();
+    /// }
+    ///
+    /// assert!(test.false_match_p_value().unwrap() <= 1.0);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn false_match_p_value(&self) -> Option<f64> {
+        if self.slots_probed == 0 {
+            return None;
+        }
+
+        if self.false_matches == 0 {
+            return Some(1.0);
+        }
+
+        // SAFETY: the probability is always within [0, 1], so this will always
+        // unwrap.
+        let distribution = Binomial::new(1.0 / H2_TAGS as f64, self.slots_probed as u64).unwrap();
+
+        // NOTE: the survival function is `P(X > x)`, so this computes
+        // `P(X >= false matches)`.
+        Some(distribution.sf(self.false_matches as u64 - 1))
+    }
+}
+
+impl<'a, H: BuildHasher> crate::r#trait::Test for Test<'a, H> {
+    fn title(&self) -> &'static str {
+        "H1/H2 Split"
+    }
+
+    fn report_section(&self) -> section::Test {
+        let h2 = p_value_module(
+            "H2 Uniformity",
+            self.h2_p_value(),
+            self.threshold,
+            "the H2 tags follow a random, uniform distribution",
+            INCONCLUSIVE,
+            None,
+        );
+
+        let independence = p_value_module(
+            "H1/H2 Independence",
+            self.independence_p_value(),
+            self.threshold,
+            "the low H1 bits and the H2 tags are independent",
+            INCONCLUSIVE,
+            None,
+        );
+
+        let context = (self.groups_probed > 0).then(|| {
+            format!(
+                "Across {} lookups, {} probe groups were visited and {} false matches were \
+                 observed, or {:.4} false matches per probe group (versus {:.4} expected).",
+                self.lookups,
+                self.groups_probed,
+                self.false_matches,
+                self.false_matches as f64 / self.groups_probed as f64,
+                self.expected_false_matches() / self.groups_probed as f64
+            )
+        });
+
+        let false_match = p_value_module(
+            "False Match Rate",
+            self.false_match_p_value(),
+            self.threshold,
+            "false matches within a probe group occur no more often than they would for random, \
+             uniform H2 tags",
+            INCONCLUSIVE,
+            context,
+        );
+
+        let iterations = self.h2_counts.iter().sum::<usize>();
+
+        // SAFETY: all of the pieces of this [`Builder`] are hand-crafted to not
+        // fail, so all of the below will unwrap.
+        get_report_base(self.provider.as_ref(), self.buckets(), iterations)
+            .push_module(h2)
+            .push_module(independence)
+            .push_module(false_match)
+            .try_build()
+            .unwrap()
+    }
+}
+
+/// Populates the boilerplate report information within a
+/// [`Test`](section::Test).
+pub fn get_report_base(
+    provider: &dyn Provider,
+    buckets: NonZeroUsize,
+    iterations: usize,
+) -> section::test::Builder {
+    let overview = "The H1/H2 split test evaluates whether a hash function is well-suited for use \
+                    within a `hashbrown` table (the implementation underlying the Rust standard \
+                    library's `HashMap`). Rather than treating a hash as a single value, \
+                    `hashbrown` splits it into two parts: H1 (the full hash, masked to the size \
+                    of the table), which selects the position where probing starts, and H2 (the \
+                    top 7 bits of the hash), which is stored as a tag in the control byte of \
+                    every occupied slot. Lookups compare the H2 tag of the key against sixteen \
+                    control bytes at once (a \"group\") and only compare keys for the slots whose \
+                    tags match.";
+
+    let relation = "A hash function can distribute H1 perfectly and still perform poorly within \
+                    `hashbrown` if its top bits are clustered (or correlated with its low bits). \
+                    Every occupied slot whose tag matches the tag of the key being looked up is a \
+                    false match that requires a full key comparison, so clustered tags directly \
+                    translate into slower lookups. Ideally, the H2 tag would behave as if it were \
+                    drawn from a random, uniform distribution that is independent of H1, in which \
+                    case a false match would occur for 1 in every 128 occupied slots compared \
+                    against.";
+
+    let algorithm =
+        "For a specified hash function, data provider, and number of buckets:\n\n(1) A simulated \
+         table of control bytes is allocated with the specified number of buckets, each initially \
+         marked as empty.\n\n(2) The following process is carried out for a specified number of \
+         iterations:\n\n  * A random input is generated using the specified data provider and \
+         hashed.\n  * The H2 tag (the top 7 bits of the hash) is tallied, as is the combination \
+         of the low 4 bits of H1 and the H2 tag.\n  * If the table is below `hashbrown`'s maximum \
+         load factor of 7/8, the H2 tag is inserted into the first empty slot along its \
+         triangular probe sequence of groups.\n  * Otherwise, the hash is looked up within the \
+         table: each group along the probe sequence is visited until a group containing an empty \
+         slot is found, and every occupied slot whose tag matches the H2 tag is counted as a \
+         false match.\n\n(3) A chi-squared goodness of fit test is performed on the H2 tallies \
+         against a random, uniform distribution over 128 tags.\n\n(4) A chi-squared test of \
+         independence is performed on the joint tallies of the low H1 bits and the H2 tags, \
+         arranged as a 16 × 128 contingency table whose expected counts are derived from its row \
+         and column totals.\n\n(5) The number of false matches is compared against a binomial \
+         distribution with one trial per occupied slot compared against and a success probability \
+         of 1/128, and the probability of observing at least as many false matches is computed.";
+
+    let interpretation = "Under this test design:\n\n* A p-value that is greater than or equal to \
+                          the pre-determined signficance value (typically, 0.05) is **good** for \
+                          every module, as it means there _is not_ enough evidence to reject the \
+                          null hypothesis that the H2 tags are uniform, independent of H1, and \
+                          produce false matches at the expected rate.\n\n* A p-value that is less \
+                          than the pre-determined signficance value is **bad**, as it means there \
+                          _is_ enough evidence to reject the null hypothesis. A failure of the \
+                          false match rate module in particular indicates that the hash function \
+                          will cause extra key comparisons when used within a `hashbrown` table.";
+
+    let sources =
+        "* https://github.com/rust-lang/hashbrown\n* https://abseil.io/about/design/swisstables";
+
+    test::Builder::default()
+        .title(format!(
+            "H1/H2 Split / {} / {} buckets / {} iterations",
+            provider.name(),
+            buckets,
+            iterations
+        ))
+        .unwrap()
+        .description(format!(
+            "{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}",
+            "Overview".italic(),
+            overview,
+            "Relation to Hashing".italic(),
+            relation,
+            "Algorithm".italic(),
+            algorithm,
+            "Interpretation".italic(),
+            interpretation,
+            "Sources".italic(),
+            sources
+        ))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::BuildHasherDefault;
+    use std::hash::Hasher;
+
+    use bitbelay_providers::ascii::AlphanumericProvider;
+
+    use super::*;
+
+    /// A hasher that distributes its low bits well but always produces the
+    /// same H2 tag.
+    #[derive(Default)]
+    struct ClusteredHasher(u64);
+
+    impl Hasher for ClusteredHasher {
+        fn finish(&self) -> u64 {
+            self.0 & 0x01FF_FFFF_FFFF_FFFF
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            for byte in bytes {
+                self.0 = (self.0 ^ *byte as u64).wrapping_mul(0x0000_0100_0000_01B3);
+            }
+        }
+    }
+
+    #[test]
+    fn clustered_tags_fail() {
+        let hasher = BuildHasherDefault::<ClusteredHasher>::default();
+        let mut test = Test::try_new(
+            &hasher,
+            Box::new(AlphanumericProvider::new(10)),
+            NonZeroUsize::try_from(1024).unwrap(),
+            0.05,
+        )
+        .unwrap();
+
+        for _ in 0..16_384 {
+            test.single_iteration();
+        }
+
+        assert!(test.h2_p_value().unwrap() < 0.05);
+        assert!(test.false_match_p_value().unwrap() < 0.05);
+        assert_eq!(test.false_matches(), test.slots_probed());
+    }
+
+    /// A hasher that distributes its bits well except for the top bit, which
+    /// is always cleared (so only half of the H2 tags are ever produced).
+    #[derive(Default)]
+    struct HalfTagHasher(std::collections::hash_map::DefaultHasher);
+
+    impl Hasher for HalfTagHasher {
+        fn finish(&self) -> u64 {
+            self.0.finish() & !(1 << 63)
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            self.0.write(bytes);
+        }
+    }
+
+    #[test]
+    fn skewed_tags_alone_are_still_independent() {
+        let hasher = BuildHasherDefault::<HalfTagHasher>::default();
+        let mut test = Test::try_new(
+            &hasher,
+            Box::new(AlphanumericProvider::new(10)),
+            NonZeroUsize::try_from(1024).unwrap(),
+            0.05,
+        )
+        .unwrap();
+
+        for _ in 0..16_384 {
+            test.single_iteration();
+        }
+
+        assert!(test.h2_p_value().unwrap() < 0.05);
+
+        // The skew is entirely within H2, so it should not be mistaken for a
+        // dependence between H1 and H2.
+        assert!(test.independence_p_value().unwrap() > 1e-4);
+    }
+}
@@ -1,9 +1,22 @@
 //! Individual tests within `bitbelay`.
 
 pub mod avalanche;
+pub mod balance;
+pub mod bucketing;
 pub mod chi_squared;
+pub mod collision;
 pub mod correlation;
+pub mod difference;
+pub mod extension;
+pub mod flooding;
+pub mod hashbrown;
 pub mod performance;
+pub mod probing;
+pub mod sanity;
+pub mod seed;
+pub mod seeded;
+pub mod tolerance;
+mod util;
 
 /// Traits for `bitbelay` tests.
 pub mod r#trait {
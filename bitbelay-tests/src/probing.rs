@@ -0,0 +1,134 @@
+//! Tests that simulate open addressing hash tables.
+//!
+//! Statistical uniformity does not directly translate into the performance of a
+//! hash table. The tests in this module insert keys into simulated tables and
+//! measure the resulting behavior of the table itself (e.g., how far items are
+//! displaced from their home slots).
+
+use std::hash::BuildHasher;
+
+use bitbelay_report::section;
+
+use crate::r#trait::Test as _;
+
+pub mod length;
+
+/// A type of probing test.
+#[derive(Debug)]
+pub enum Test<'a, H: BuildHasher> {
+    /// Probe sequence length test.
+    ProbeLength(length::Test<'a, H>),
+}
+
+impl<'a, H: BuildHasher> Test<'a, H> {
+    /// Gets a reference to a [`length::Test`] wrapped in [`Some`] if the
+    /// [`Test`] is a [`Test::ProbeLength`]. Else, returns [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    /// use bitbelay_tests::probing::Test;
+    /// use bitbelay_tests::probing::length;
+    /// use bitbelay_tests::probing::length::Scheme;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::ProbeLength(length::Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Scheme::Linear,
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     Bucketing::Modulo,
+    ///     0.75,
+    ///     0.05,
+    ///     0.01,
+    /// )?);
+    ///
+    /// assert!(matches!(test.as_probe_length_test(), Some(_)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn as_probe_length_test(&self) -> Option<&length::Test<'a, H>> {
+        match self {
+            Test::ProbeLength(test) => Some(test),
+        }
+    }
+
+    /// Consumes the [`Test`] and returns a [`length::Test`] wrapped in [`Some`]
+    /// if the [`Test`] is a [`Test::ProbeLength`]. Else, returns [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    /// use bitbelay_tests::probing::Test;
+    /// use bitbelay_tests::probing::length;
+    /// use bitbelay_tests::probing::length::Scheme;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::ProbeLength(length::Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Scheme::Linear,
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     Bucketing::Modulo,
+    ///     0.75,
+    ///     0.05,
+    ///     0.01,
+    /// )?);
+    ///
+    /// assert!(matches!(test.into_probe_length_test(), Some(_)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn into_probe_length_test(self) -> Option<length::Test<'a, H>> {
+        match self {
+            Test::ProbeLength(test) => Some(test),
+        }
+    }
+
+    /// Generates a report section for the [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    /// use bitbelay_tests::probing::Test;
+    /// use bitbelay_tests::probing::length;
+    /// use bitbelay_tests::probing::length::Scheme;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::ProbeLength(length::Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Scheme::Linear,
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     Bucketing::Modulo,
+    ///     0.75,
+    ///     0.05,
+    ///     0.01,
+    /// )?);
+    ///
+    /// let results = test.report_section();
+    /// // Include the section in a report.
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn report_section(&self) -> section::Test {
+        match self {
+            Test::ProbeLength(test) => test.report_section(),
+        }
+    }
+}
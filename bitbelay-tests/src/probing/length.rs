@@ -0,0 +1,1278 @@
+//! Probe sequence length test.
+
+use std::hash::BuildHasher;
+use std::num::NonZeroUsize;
+
+use bitbelay_providers::Provider;
+use bitbelay_report::section;
+use bitbelay_report::section::test;
+use bitbelay_report::section::test::Module;
+use bitbelay_report::section::test::module;
+use colored::Colorize;
+use rand::Rng as _;
+use rand::SeedableRng as _;
+use rand::rngs::StdRng;
+use statrs::distribution::ContinuousCDF as _;
+use statrs::distribution::Normal;
+
+use crate::bucketing::Bucketing;
+
+/// The number of displacements shown individually in the distribution table
+/// within the report (larger displacements are grouped together).
+const REPORTED_DISPLACEMENTS: usize = 16;
+
+/// The seed of the random number generator that provides the hashes for the
+/// baseline tables.
+///
+/// Seeding the baseline makes it (and, thus, the verdict for a given hash
+/// function and provider) reproducible across runs.
+const BASELINE_SEED: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// An error related to a [`Test`].
+#[derive(Debug)]
+pub enum Error {
+    /// The number of buckets is not a power of two.
+    InvalidBuckets(NonZeroUsize),
+
+    /// The load factor is not within the range (0, 1).
+    InvalidLoadFactor(f64),
+
+    /// The threshold of statistical significance is not within the range (0,
+    /// 1).
+    InvalidSignificance(f64),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidBuckets(buckets) => write!(
+                f,
+                "invalid number of buckets: {buckets} (must be a power of two)"
+            ),
+            Error::InvalidLoadFactor(load_factor) => write!(
+                f,
+                "invalid load factor: {load_factor} (must be within the range (0, 1))"
+            ),
+            Error::InvalidSignificance(significance) => write!(
+                f,
+                "invalid significance: {significance} (must be within the range (0, 1))"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A [`Result`](std::result::Result) with an [`Error`].
+type Result<T> = std::result::Result<T, Error>;
+
+/// An open addressing scheme used to resolve collisions.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum Scheme {
+    /// Probes each subsequent slot in turn.
+    #[cfg_attr(feature = "clap", clap(name = "linear"))]
+    Linear,
+
+    /// Probes slots at triangular offsets from the home slot (i.e., 1, 3, 6,
+    /// 10, ...), which visits every slot in a power-of-two table.
+    #[cfg_attr(feature = "clap", clap(name = "quadratic"))]
+    Quadratic,
+
+    /// Probes each subsequent slot in turn, displacing any resident that is
+    /// closer to its home slot than the item being inserted.
+    #[cfg_attr(feature = "clap", clap(name = "robin-hood"))]
+    RobinHood,
+}
+
+impl Scheme {
+    /// Gets the expected number of probes for a successful search within a
+    /// table at the provided load factor under an ideal, random hash function
+    /// (as the table size grows large).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_tests::probing::length::Scheme;
+    ///
+    /// assert_eq!(Scheme::Linear.expected_probes(0.5), 1.5);
+    /// assert_eq!(Scheme::RobinHood.expected_probes(0.5), 1.5);
+    /// assert!((Scheme::Quadratic.expected_probes(0.5) - 1.443147).abs() < 1e-6);
+    /// ```
+    pub fn expected_probes(&self, load_factor: f64) -> f64 {
+        match self {
+            // NOTE: Robin Hood hashing only rearranges which items are
+            // displaced, so the total (and, thus, mean) displacement is the
+            // same as linear probing.
+            Scheme::Linear | Scheme::RobinHood => 0.5 * (1.0 + 1.0 / (1.0 - load_factor)),
+            Scheme::Quadratic => 1.0 - (1.0 - load_factor).ln() - load_factor / 2.0,
+        }
+    }
+
+    /// Gets the offset from the home slot of the `probe`th slot visited.
+    fn offset(&self, probe: usize) -> usize {
+        match self {
+            Scheme::Linear | Scheme::RobinHood => probe,
+            Scheme::Quadratic => probe * (probe + 1) / 2,
+        }
+    }
+}
+
+impl std::fmt::Display for Scheme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Scheme::Linear => write!(f, "linear"),
+            Scheme::Quadratic => write!(f, "quadratic"),
+            Scheme::RobinHood => write!(f, "robin-hood"),
+        }
+    }
+}
+
+/// A simulated open addressing table.
+///
+/// Each slot holds the displacement (the number of probes beyond the first)
+/// of the item stored within it.
+struct Table {
+    /// The scheme used to resolve collisions.
+    scheme: Scheme,
+
+    /// The slots within the table.
+    slots: Vec<Option<usize>>,
+}
+
+impl Table {
+    /// Creates a new, empty [`Table`].
+    fn new(scheme: Scheme, buckets: NonZeroUsize) -> Self {
+        Self {
+            scheme,
+            slots: vec![None; buckets.get()],
+        }
+    }
+
+    /// Inserts an item with the provided home slot into the [`Table`].
+    ///
+    /// The table must have at least one empty slot.
+    fn insert(&mut self, home: usize) {
+        let mask = self.slots.len() - 1;
+        let mut home = home;
+        let mut displacement = 0;
+
+        loop {
+            let slot = (home + self.scheme.offset(displacement)) & mask;
+
+            match self.slots[slot] {
+                None => {
+                    self.slots[slot] = Some(displacement);
+                    return;
+                }
+                Some(resident) if self.scheme == Scheme::RobinHood && resident < displacement => {
+                    // The resident is richer than the item being inserted, so
+                    // they swap places and the resident continues probing.
+                    self.slots[slot] = Some(displacement);
+                    home = (slot.wrapping_sub(resident)) & mask;
+                    displacement = resident + 1;
+                    continue;
+                }
+                Some(_) => {}
+            }
+
+            displacement += 1;
+        }
+    }
+
+    /// Gets the displacements of every item within the [`Table`].
+    fn displacements(&self) -> impl Iterator<Item = usize> + '_ {
+        self.slots.iter().flatten().copied()
+    }
+}
+
+/// The distribution of displacements across one or more simulated tables.
+#[derive(Clone, Debug, Default)]
+pub struct Distribution {
+    /// The number of items observed at each displacement.
+    histogram: Vec<usize>,
+
+    /// The mean number of probes within each table.
+    table_means: Vec<f64>,
+
+    /// The maximum displacement within each table.
+    table_maxima: Vec<f64>,
+}
+
+impl Distribution {
+    /// Gets the number of items observed at each displacement (where the index
+    /// is the displacement).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_tests::probing::length::Distribution;
+    ///
+    /// let distribution = Distribution::default();
+    /// assert!(distribution.histogram().is_empty());
+    /// ```
+    pub fn histogram(&self) -> &[usize] {
+        &self.histogram
+    }
+
+    /// Gets the total number of items within the [`Distribution`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_tests::probing::length::Distribution;
+    ///
+    /// let distribution = Distribution::default();
+    /// assert_eq!(distribution.items(), 0);
+    /// ```
+    pub fn items(&self) -> usize {
+        self.histogram.iter().sum()
+    }
+
+    /// Gets the mean number of probes for a successful search (one more than
+    /// the mean displacement) or [`None`] if there are no items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_tests::probing::length::Distribution;
+    ///
+    /// let distribution = Distribution::default();
+    /// assert_eq!(distribution.mean_probes(), None);
+    /// ```
+    pub fn mean_probes(&self) -> Option<f64> {
+        let items = self.items();
+
+        if items == 0 {
+            return None;
+        }
+
+        let displacement = self
+            .histogram
+            .iter()
+            .enumerate()
+            .map(|(displacement, count)| displacement * count)
+            .sum::<usize>();
+
+        Some(1.0 + displacement as f64 / items as f64)
+    }
+
+    /// Gets the maximum displacement of any item or [`None`] if there are no
+    /// items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_tests::probing::length::Distribution;
+    ///
+    /// let distribution = Distribution::default();
+    /// assert_eq!(distribution.max_displacement(), None);
+    /// ```
+    pub fn max_displacement(&self) -> Option<usize> {
+        self.histogram.iter().rposition(|count| *count > 0)
+    }
+
+    /// Gets the mean (across tables) of the maximum displacement within each
+    /// table or [`None`] if no tables have been recorded.
+    ///
+    /// The maximum displacement of any single table is noisy, so averaging
+    /// across tables gives a more stable point of comparison.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_tests::probing::length::Distribution;
+    ///
+    /// let distribution = Distribution::default();
+    /// assert_eq!(distribution.mean_max_displacement(), None);
+    /// ```
+    pub fn mean_max_displacement(&self) -> Option<f64> {
+        mean(&self.table_maxima)
+    }
+
+    /// Records the displacements of every item within a [`Table`].
+    fn record(&mut self, table: &Table) {
+        let mut items = 0;
+        let mut total = 0;
+        let mut max = 0;
+
+        for displacement in table.displacements() {
+            if self.histogram.len() <= displacement {
+                self.histogram.resize(displacement + 1, 0);
+            }
+
+            self.histogram[displacement] += 1;
+            items += 1;
+            total += displacement;
+            max = max.max(displacement);
+        }
+
+        self.table_means
+            .push(1.0 + total as f64 / items.max(1) as f64);
+        self.table_maxima.push(max as f64);
+    }
+
+    /// Gets the standard error of the mean number of probes across tables.
+    fn mean_probes_standard_error(&self) -> Option<f64> {
+        standard_error(&self.table_means)
+    }
+
+    /// Gets the standard error of the mean maximum displacement across tables.
+    fn mean_max_displacement_standard_error(&self) -> Option<f64> {
+        standard_error(&self.table_maxima)
+    }
+
+    /// Gets the fraction of items with the provided displacement.
+    fn fraction(&self, displacement: usize) -> f64 {
+        self.histogram
+            .get(displacement)
+            .copied()
+            .unwrap_or_default() as f64
+            / self.items().max(1) as f64
+    }
+
+    /// Gets the fraction of items with at least the provided displacement.
+    fn tail_fraction(&self, displacement: usize) -> f64 {
+        self.histogram.iter().skip(displacement).sum::<usize>() as f64 / self.items().max(1) as f64
+    }
+}
+
+/// Gets the mean of a set of values or [`None`] if there are no values.
+fn mean(values: &[f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+
+    Some(values.iter().sum::<f64>() / values.len() as f64)
+}
+
+/// Gets the standard error of the mean of a set of values or [`None`] if there
+/// are fewer than two values.
+fn standard_error(values: &[f64]) -> Option<f64> {
+    if values.len() < 2 {
+        return None;
+    }
+
+    // SAFETY: we just checked that there are values, so this will unwrap.
+    let mean = mean(values).unwrap();
+    let variance =
+        values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (values.len() - 1) as f64;
+
+    Some((variance / values.len() as f64).sqrt())
+}
+
+/// A probe sequence length test.
+///
+/// Keys from the provider are hashed and inserted into simulated open
+/// addressing tables until a target load factor is reached. Alongside every
+/// table, a baseline table is filled to the same load factor using hashes
+/// drawn from a seeded random number generator (i.e., an ideal, random hash).
+#[derive(Debug)]
+pub struct Test<'a, H: BuildHasher> {
+    /// The hash function builder.
+    build_hasher: &'a H,
+
+    /// The data provider.
+    provider: Box<dyn Provider>,
+
+    /// The scheme used to resolve collisions.
+    scheme: Scheme,
+
+    /// The number of buckets within each simulated table.
+    buckets: NonZeroUsize,
+
+    /// The strategy used to map each hash to its home slot.
+    bucketing: Bucketing,
+
+    /// The load factor to which each simulated table is filled.
+    load_factor: f64,
+
+    /// The maximum relative deviance from the baseline allowed for the test
+    /// to be considered successful.
+    max_deviance: f64,
+
+    /// The threshold of statistical significance for the one-sided z-test
+    /// that determines whether an observed value that exceeds the baseline by
+    /// more than the maximum deviance is considered a failure.
+    significance: f64,
+
+    /// The number of tables filled so far.
+    tables: usize,
+
+    /// The distribution of displacements using the hash function.
+    observed: Distribution,
+
+    /// The distribution of displacements using random hashes.
+    baseline: Distribution,
+
+    /// The random number generator that provides the hashes for the baseline
+    /// tables.
+    baseline_rng: StdRng,
+}
+
+impl<'a, H: BuildHasher> Test<'a, H> {
+    /// Attempts to create a new [`Test`].
+    ///
+    /// # Notes
+    ///
+    /// * If `buckets` is not a power of two, an [`Error::InvalidBuckets`] is
+    ///   returned.
+    /// * If `load_factor` is not within the range (0, 1), an
+    ///   [`Error::InvalidLoadFactor`] is returned.
+    /// * If `significance` is not within the range (0, 1), an
+    ///   [`Error::InvalidSignificance`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::BuildHasher as _;
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::Provider as _;
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    /// use bitbelay_tests::probing::length::Scheme;
+    /// use bitbelay_tests::probing::length::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let test = Test::try_new(
+    ///     &hasher,
+    ///     provider.clone(),
+    ///     Scheme::Linear,
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     Bucketing::LowBitMask,
+    ///     0.75,
+    ///     0.05,
+    ///     0.01,
+    /// )?;
+    ///
+    /// assert_eq!(test.build_hasher().hash_one("42"), hasher.hash_one("42"));
+    /// assert_eq!(test.provider().name(), provider.name());
+    /// assert_eq!(test.scheme(), Scheme::Linear);
+    /// assert_eq!(test.buckets().get(), 1024);
+    /// assert_eq!(test.bucketing(), Bucketing::LowBitMask);
+    /// assert_eq!(test.load_factor(), 0.75);
+    /// assert_eq!(test.max_deviance(), 0.05);
+    /// assert_eq!(test.significance(), 0.01);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_new(
+        build_hasher: &'a H,
+        provider: Box<dyn Provider>,
+        scheme: Scheme,
+        buckets: NonZeroUsize,
+        bucketing: Bucketing,
+        load_factor: f64,
+        max_deviance: f64,
+        significance: f64,
+    ) -> Result<Self> {
+        if !buckets.is_power_of_two() {
+            return Err(Error::InvalidBuckets(buckets));
+        }
+
+        if !(load_factor > 0.0 && load_factor < 1.0) {
+            return Err(Error::InvalidLoadFactor(load_factor));
+        }
+
+        if !(significance > 0.0 && significance < 1.0) {
+            return Err(Error::InvalidSignificance(significance));
+        }
+
+        Ok(Self {
+            build_hasher,
+            provider,
+            scheme,
+            buckets,
+            bucketing,
+            load_factor,
+            max_deviance,
+            significance,
+            tables: 0,
+            observed: Distribution::default(),
+            baseline: Distribution::default(),
+            baseline_rng: StdRng::seed_from_u64(BASELINE_SEED),
+        })
+    }
+
+    /// Gets the [`BuildHasher`] from the [`Test`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::BuildHasher as _;
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    /// use bitbelay_tests::probing::length::Scheme;
+    /// use bitbelay_tests::probing::length::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Scheme::Linear,
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     Bucketing::Modulo,
+    ///     0.75,
+    ///     0.05,
+    ///     0.01,
+    /// )?;
+    ///
+    /// assert_eq!(test.build_hasher().hash_one("42"), hasher.hash_one("42"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn build_hasher(&self) -> &H {
+        self.build_hasher
+    }
+
+    /// Gets the [`Provider`] from the [`Test`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::Provider as _;
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    /// use bitbelay_tests::probing::length::Scheme;
+    /// use bitbelay_tests::probing::length::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let test = Test::try_new(
+    ///     &hasher,
+    ///     provider.clone(),
+    ///     Scheme::Linear,
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     Bucketing::Modulo,
+    ///     0.75,
+    ///     0.05,
+    ///     0.01,
+    /// )?;
+    ///
+    /// assert_eq!(test.provider().name(), provider.name());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn provider(&self) -> &dyn Provider {
+        self.provider.as_ref()
+    }
+
+    /// Gets the collision resolution [`Scheme`] from the [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    /// use bitbelay_tests::probing::length::Scheme;
+    /// use bitbelay_tests::probing::length::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Scheme::RobinHood,
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     Bucketing::Modulo,
+    ///     0.75,
+    ///     0.05,
+    ///     0.01,
+    /// )?;
+    ///
+    /// assert_eq!(test.scheme(), Scheme::RobinHood);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn scheme(&self) -> Scheme {
+        self.scheme
+    }
+
+    /// Gets the number of buckets within each simulated table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    /// use bitbelay_tests::probing::length::Scheme;
+    /// use bitbelay_tests::probing::length::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Scheme::Linear,
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     Bucketing::Modulo,
+    ///     0.75,
+    ///     0.05,
+    ///     0.01,
+    /// )?;
+    ///
+    /// assert_eq!(test.buckets().get(), 1024);
+    ///
+    /// // Only powers of two are supported.
+    /// let result = Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Scheme::Linear,
+    ///     NonZeroUsize::try_from(1000).unwrap(),
+    ///     Bucketing::Modulo,
+    ///     0.75,
+    ///     0.05,
+    ///     0.01,
+    /// );
+    ///
+    /// assert!(result.is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn buckets(&self) -> NonZeroUsize {
+        self.buckets
+    }
+
+    /// Gets the bucketing strategy used to map each hash to its home slot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    /// use bitbelay_tests::probing::length::Scheme;
+    /// use bitbelay_tests::probing::length::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Scheme::Linear,
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     Bucketing::HighBitShift,
+    ///     0.75,
+    ///     0.05,
+    ///     0.01,
+    /// )?;
+    ///
+    /// assert_eq!(test.bucketing(), Bucketing::HighBitShift);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn bucketing(&self) -> Bucketing {
+        self.bucketing
+    }
+
+    /// Gets the load factor to which each simulated table is filled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    /// use bitbelay_tests::probing::length::Scheme;
+    /// use bitbelay_tests::probing::length::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Scheme::Linear,
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     Bucketing::Modulo,
+    ///     0.75,
+    ///     0.05,
+    ///     0.01,
+    /// )?;
+    ///
+    /// assert_eq!(test.load_factor(), 0.75);
+    ///
+    /// // Load factors must be strictly between zero and one.
+    /// let result = Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Scheme::Linear,
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     Bucketing::Modulo,
+    ///     1.0,
+    ///     0.05,
+    ///     0.01,
+    /// );
+    ///
+    /// assert!(result.is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn load_factor(&self) -> f64 {
+        self.load_factor
+    }
+
+    /// Gets the maximum relative deviance from the baseline allowed for the
+    /// [`Test`] to be considered successful.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    /// use bitbelay_tests::probing::length::Scheme;
+    /// use bitbelay_tests::probing::length::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Scheme::Linear,
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     Bucketing::Modulo,
+    ///     0.75,
+    ///     0.05,
+    ///     0.01,
+    /// )?;
+    ///
+    /// assert_eq!(test.max_deviance(), 0.05);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn max_deviance(&self) -> f64 {
+        self.max_deviance
+    }
+
+    /// Gets the threshold of statistical significance for the [`Test`].
+    /// [`Test`] to be considered successful.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    /// use bitbelay_tests::probing::length::Scheme;
+    /// use bitbelay_tests::probing::length::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Scheme::Linear,
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     Bucketing::Modulo,
+    ///     0.75,
+    ///     0.05,
+    ///     0.01,
+    /// )?;
+    ///
+    /// assert_eq!(test.significance(), 0.01);
+    ///
+    /// // The significance must be strictly between zero and one.
+    /// let result = Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Scheme::Linear,
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     Bucketing::Modulo,
+    ///     0.75,
+    ///     0.05,
+    ///     0.0,
+    /// );
+    ///
+    /// assert!(result.is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn significance(&self) -> f64 {
+        self.significance
+    }
+
+    /// Gets the number of tables filled so far.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    /// use bitbelay_tests::probing::length::Scheme;
+    /// use bitbelay_tests::probing::length::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Scheme::Linear,
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     Bucketing::Modulo,
+    ///     0.75,
+    ///     0.05,
+    ///     0.01,
+    /// )?;
+    ///
+    /// test.run_single_table();
+    /// assert_eq!(test.tables(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn tables(&self) -> usize {
+        self.tables
+    }
+
+    /// Gets the [`Distribution`] of displacements using the hash function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    /// use bitbelay_tests::probing::length::Scheme;
+    /// use bitbelay_tests::probing::length::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Scheme::Linear,
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     Bucketing::Modulo,
+    ///     0.75,
+    ///     0.05,
+    ///     0.01,
+    /// )?;
+    ///
+    /// test.run_single_table();
+    /// assert_eq!(test.observed().items(), 768);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn observed(&self) -> &Distribution {
+        &self.observed
+    }
+
+    /// Gets the [`Distribution`] of displacements using random hashes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    /// use bitbelay_tests::probing::length::Scheme;
+    /// use bitbelay_tests::probing::length::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Scheme::Linear,
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     Bucketing::Modulo,
+    ///     0.75,
+    ///     0.05,
+    ///     0.01,
+    /// )?;
+    ///
+    /// test.run_single_table();
+    /// assert_eq!(test.baseline().items(), 768);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn baseline(&self) -> &Distribution {
+        &self.baseline
+    }
+
+    /// Fills a single simulated table (along with a baseline table) to the
+    /// target load factor and records the resulting displacements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::bucketing::Bucketing;
+    /// use bitbelay_tests::probing::length::Scheme;
+    /// use bitbelay_tests::probing::length::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::try_new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     Scheme::Quadratic,
+    ///     NonZeroUsize::try_from(1024).unwrap(),
+    ///     Bucketing::Modulo,
+    ///     0.75,
+    ///     0.05,
+    ///     0.01,
+    /// )?;
+    ///
+    /// test.run_single_table();
+    ///
+    /// assert!(test.observed().mean_probes().unwrap() >= 1.0);
+    /// assert!(test.observed().max_displacement().is_some());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn run_single_table(&mut self) {
+        let items = self.items_per_table();
+
+        let mut observed = Table::new(self.scheme, self.buckets);
+        for data in self.provider.provide(items) {
+            let hash = self.build_hasher.hash_one(data);
+            observed.insert(self.bucketing.bucket(hash, self.buckets));
+        }
+
+        let mut baseline = Table::new(self.scheme, self.buckets);
+        for _ in 0..items {
+            let hash = self.baseline_rng.r#gen::<u64>();
+            baseline.insert(self.bucketing.bucket(hash, self.buckets));
+        }
+
+        self.observed.record(&observed);
+        self.baseline.record(&baseline);
+
+        self.tables += 1;
+    }
+
+    /// Gets the critical value of the one-sided z-test at the threshold of
+    /// statistical significance.
+    fn critical_z(&self) -> f64 {
+        // SAFETY: a standard normal distribution is always valid.
+        Normal::new(0.0, 1.0)
+            .unwrap()
+            .inverse_cdf(1.0 - self.significance)
+    }
+
+    /// Gets the number of items inserted into each simulated table.
+    fn items_per_table(&self) -> usize {
+        // NOTE: at least one slot is always left empty so that every
+        // insertion terminates.
+        ((self.buckets.get() as f64 * self.load_factor) as usize).min(self.buckets.get() - 1)
+    }
+
+    /// Generates a [`Module`] comparing an observed value (and its standard
+    /// error) against the same value under the baseline.
+    ///
+    /// Any provided `theoretical` value is reported alongside the observed and
+    /// baseline values, and any provided `context` is appended to the details
+    /// of the module.
+    fn comparison_module(
+        &self,
+        name: &str,
+        (observed, observed_error): (Option<f64>, Option<f64>),
+        (baseline, baseline_error): (Option<f64>, Option<f64>),
+        theoretical: Option<f64>,
+        context: Option<String>,
+    ) -> Module {
+        let (Some(observed), Some(baseline)) = (observed, baseline) else {
+            return Module::new(
+                module::Result::Inconclusive,
+                name,
+                None,
+                Some(String::from(
+                    "No tables have been filled yet, so no comparison could be made.",
+                )),
+            );
+        };
+
+        let limit = baseline * (1.0 + self.max_deviance);
+        let value = Some(match theoretical {
+            Some(theoretical) => format!(
+                "{:.2} (baseline {:.2}, theoretical {:.2})",
+                observed, baseline, theoretical
+            ),
+            None => format!("{:.2} (baseline {:.2})", observed, baseline),
+        });
+
+        // NOTE: without at least two tables, the standard error cannot be
+        // estimated, so any deviance beyond the limit is treated as
+        // significant.
+        let z = match (observed_error, baseline_error) {
+            (Some(observed_error), Some(baseline_error)) => {
+                let error = observed_error.hypot(baseline_error);
+
+                if error > 0.0 {
+                    (observed - baseline) / error
+                } else {
+                    f64::INFINITY
+                }
+            }
+            _ => f64::INFINITY,
+        };
+
+        let (result, summary) = if observed <= limit {
+            (
+                module::Result::Pass,
+                format!(
+                    "The observed value of {:.2} was within {:.0}% of (or better than) the value \
+                     of {:.2} observed for an ideal, random hash.",
+                    observed,
+                    self.max_deviance * 100.0,
+                    baseline
+                ),
+            )
+        } else if z <= self.critical_z() {
+            (
+                module::Result::Pass,
+                format!(
+                    "The observed value of {:.2} exceeded the value of {:.2} observed for an \
+                     ideal, random hash by more than {:.0}%, but the difference was not \
+                     statistically significant at the {} level (z = {:.2}).",
+                    observed,
+                    baseline,
+                    self.max_deviance * 100.0,
+                    self.significance,
+                    z
+                ),
+            )
+        } else {
+            (
+                module::Result::Fail,
+                format!(
+                    "The observed value of {:.2} exceeded the value of {:.2} observed for an \
+                     ideal, random hash by more than {:.0}%.",
+                    observed,
+                    baseline,
+                    self.max_deviance * 100.0
+                ),
+            )
+        };
+
+        let details = match context {
+            Some(context) => format!("{}\n\n{}", summary, context),
+            None => summary,
+        };
+
+        Module::new(result, name, value, Some(details))
+    }
+}
+
+impl<'a, H: BuildHasher> crate::r#trait::Test for Test<'a, H> {
+    fn title(&self) -> &'static str {
+        "Probe Sequence Length"
+    }
+
+    fn report_section(&self) -> section::Test {
+        let mut distribution = format!(
+            "For an ideal, random hash, the expected number of probes for a successful search at \
+             a load factor of {:.3} is {:.3} (as the table size grows large).\n\n{}\n\n  {:>12}  \
+             {:>9}  {:>9}",
+            self.load_factor,
+            self.scheme.expected_probes(self.load_factor),
+            "Displacement Distribution".italic(),
+            "Displacement",
+            "Observed",
+            "Baseline"
+        );
+
+        for displacement in 0..REPORTED_DISPLACEMENTS {
+            distribution.push_str(&format!(
+                "\n  {:>12}  {:>8.2}%  {:>8.2}%",
+                displacement,
+                self.observed.fraction(displacement) * 100.0,
+                self.baseline.fraction(displacement) * 100.0
+            ));
+        }
+
+        distribution.push_str(&format!(
+            "\n  {:>12}  {:>8.2}%  {:>8.2}%",
+            format!("{}+", REPORTED_DISPLACEMENTS),
+            self.observed.tail_fraction(REPORTED_DISPLACEMENTS) * 100.0,
+            self.baseline.tail_fraction(REPORTED_DISPLACEMENTS) * 100.0
+        ));
+
+        let mean = self.comparison_module(
+            "Mean Probe Length",
+            (
+                self.observed.mean_probes(),
+                self.observed.mean_probes_standard_error(),
+            ),
+            (
+                self.baseline.mean_probes(),
+                self.baseline.mean_probes_standard_error(),
+            ),
+            Some(self.scheme.expected_probes(self.load_factor)),
+            Some(distribution),
+        );
+
+        let max = self.comparison_module(
+            "Mean Maximum Displacement",
+            (
+                self.observed.mean_max_displacement(),
+                self.observed.mean_max_displacement_standard_error(),
+            ),
+            (
+                self.baseline.mean_max_displacement(),
+                self.baseline.mean_max_displacement_standard_error(),
+            ),
+            None,
+            self.observed
+                .max_displacement()
+                .zip(self.baseline.max_displacement())
+                .map(|(observed, baseline)| {
+                    format!(
+                        "Across all tables, the largest displacement was {} (versus {} for the \
+                         baseline).",
+                        observed, baseline
+                    )
+                }),
+        );
+
+        // SAFETY: all of the pieces of this [`Builder`] are hand-crafted to not
+        // fail, so all of the below will unwrap.
+        get_report_base(
+            self.provider.as_ref(),
+            self.scheme,
+            self.buckets,
+            self.bucketing,
+            self.load_factor,
+            self.significance,
+            self.tables,
+        )
+        .push_module(mean)
+        .push_module(max)
+        .try_build()
+        .unwrap()
+    }
+}
+
+/// Populates the boilerplate report information within a
+/// [`Test`](section::Test).
+pub fn get_report_base(
+    provider: &dyn Provider,
+    scheme: Scheme,
+    buckets: NonZeroUsize,
+    bucketing: Bucketing,
+    load_factor: f64,
+    significance: f64,
+    tables: usize,
+) -> section::test::Builder {
+    let overview = "The probe sequence length test measures how a hash function performs within \
+                    an open addressing hash table. Rather than testing the statistical uniformity \
+                    of the hashes directly, keys are inserted into simulated tables and the \
+                    number of probes needed to find each key is recorded. The resulting \
+                    distribution is compared against the distribution for an ideal, random hash \
+                    function.";
+
+    let relation = "Open addressing hash tables store every item within a single array of slots. \
+                    When the home slot for an item is already occupied, a probe sequence is \
+                    followed until an empty slot is found, and every lookup for that item must \
+                    retrace the same sequence. Hash functions that cluster items (even slightly) \
+                    cause probe sequences to run into each other, which increases the number of \
+                    probes for every lookup and, in the worst case, produces very long probe \
+                    sequences for a handful of unlucky items.";
+
+    let algorithm = format!(
+        "For a specified hash function, data provider, collision resolution scheme, bucketing \
+         strategy, number of buckets, and load factor:\n\n(1) A simulated table with the \
+         specified number of buckets is allocated.\n\n(2) Random inputs are generated using the \
+         specified data provider, hashed, mapped to a home slot using the bucketing strategy, and \
+         inserted into the table using the collision resolution scheme until the table reaches \
+         the specified load factor.\n\n(3) A baseline table is filled in the same way using \
+         hashes drawn from a seeded random number generator in place of the hash function (so the \
+         baseline is the same across runs).\n\n(4) The displacement (the number of probes beyond \
+         the first) of every item in both tables is recorded.\n\n(5) Steps (1) through (4) are \
+         repeated for the specified number of tables, and the mean probe length and the mean (per \
+         table) maximum displacement of the hash function are compared against those of the \
+         baseline. A value that exceeds the baseline by more than the maximum deviance is only \
+         considered a failure if the difference is also statistically significant (a one-sided \
+         z-test at the {} level using the variation between tables). The theoretical mean probe \
+         length is also reported (Knuth gives 1/2 (1 + 1/(1 - a)) for linear probing and Robin \
+         Hood hashing and 1 - ln(1 - a) - a/2 for quadratic probing, where a is the load factor).",
+        significance
+    );
+
+    let interpretation =
+        "Under this test design:\n\n* A mean probe length and maximum displacement that are close \
+         to (or better than) the baseline are **good**, as they indicate that the hash function \
+         performs as well within the simulated table as an ideal, random hash.\n\n* A mean probe \
+         length or maximum displacement that is much larger than the baseline is **bad**, as it \
+         indicates that the hash function clusters items within the table, making lookups slower.";
+
+    let sources = "* Knuth, D. E. The Art of Computer Programming, Volume 3: Sorting and \
+                   Searching, Section 6.4.\n* https://en.wikipedia.org/wiki/Open_addressing\n* \
+                   https://en.wikipedia.org/wiki/Hash_table#Robin_Hood_hashing";
+
+    test::Builder::default()
+        .title(format!(
+            "Probe Sequence Length / {} / {} probing / {} buckets / {} bucketing / {:.3} load \
+             factor / {} tables",
+            provider.name(),
+            scheme,
+            buckets,
+            bucketing,
+            load_factor,
+            tables
+        ))
+        .unwrap()
+        .description(format!(
+            "{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}",
+            "Overview".italic(),
+            overview,
+            "Relation to Hashing".italic(),
+            relation,
+            "Algorithm".italic(),
+            algorithm,
+            "Interpretation".italic(),
+            interpretation,
+            "Sources".italic(),
+            sources
+        ))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn robin_hood_preserves_total_displacement() {
+        let buckets = NonZeroUsize::try_from(64).unwrap();
+        let homes = (0..56).map(|i| (i * 37) % 11).collect::<Vec<_>>();
+
+        let mut linear = Table::new(Scheme::Linear, buckets);
+        let mut robin_hood = Table::new(Scheme::RobinHood, buckets);
+
+        for home in homes {
+            linear.insert(home);
+            robin_hood.insert(home);
+        }
+
+        assert_eq!(
+            linear.displacements().sum::<usize>(),
+            robin_hood.displacements().sum::<usize>()
+        );
+        assert!(robin_hood.displacements().max().unwrap() <= linear.displacements().max().unwrap());
+    }
+}
@@ -0,0 +1,107 @@
+//! Sanity tests.
+//!
+//! These tests perform basic correctness checks that should be run before any
+//! statistical test, as a hasher that fails them is broken regardless of how
+//! well distributed its outputs are.
+
+use std::hash::BuildHasher;
+
+use bitbelay_report::section;
+
+use crate::r#trait::Test as _;
+
+pub mod correctness;
+
+/// A type of sanity test.
+#[derive(Debug)]
+pub enum Test<'a, H: BuildHasher> {
+    /// Correctness test.
+    Correctness(correctness::Test<'a, H>),
+}
+
+impl<'a, H: BuildHasher> Test<'a, H> {
+    /// Gets a reference to a [`correctness::Test`] wrapped in [`Some`] if the
+    /// [`Test`] is a [`Test::Correctness`]. Else, returns [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::sanity::Test;
+    /// use bitbelay_tests::sanity::correctness;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::Correctness(correctness::Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    /// ));
+    ///
+    /// assert!(matches!(test.as_correctness_test(), Some(_)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn as_correctness_test(&self) -> Option<&correctness::Test<'a, H>> {
+        match self {
+            Test::Correctness(test) => Some(test),
+        }
+    }
+
+    /// Consumes the [`Test`] and returns a [`correctness::Test`] wrapped in
+    /// [`Some`] if the [`Test`] is a [`Test::Correctness`]. Else, returns
+    /// [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::sanity::Test;
+    /// use bitbelay_tests::sanity::correctness;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::Correctness(correctness::Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    /// ));
+    ///
+    /// assert!(matches!(test.into_correctness_test(), Some(_)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn into_correctness_test(self) -> Option<correctness::Test<'a, H>> {
+        match self {
+            Test::Correctness(test) => Some(test),
+        }
+    }
+
+    /// Generates a report section for the [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::sanity::Test;
+    /// use bitbelay_tests::sanity::correctness;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::Correctness(correctness::Test::new(
+    ///     &hasher,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    /// ));
+    ///
+    /// let results = test.report_section();
+    /// // Include the section in a report.
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn report_section(&self) -> section::Test {
+        match self {
+            Test::Correctness(test) => test.report_section(),
+        }
+    }
+}
@@ -0,0 +1,550 @@
+//! Correctness test.
+//!
+//! Unlike most tests within `bitbelay`, the checks within this test are not
+//! statistical: any single failure indicates that the hasher is broken (or, in
+//! the case of chunked writes, that it must be used with care).
+
+use std::hash::BuildHasher;
+use std::hash::Hasher as _;
+
+use bitbelay_providers::Provider;
+use bitbelay_report::section;
+use bitbelay_report::section::test;
+use bitbelay_report::section::test::Module;
+use bitbelay_report::section::test::module;
+use colored::Colorize;
+use rand::Rng as _;
+
+use crate::util::hex;
+
+/// The alignments (in bytes) at which each key is hashed.
+pub const ALIGNMENTS: usize = 8;
+
+/// The maximum number of points at which each key is split into chunks.
+pub const MAX_SPLITS: usize = 4;
+
+/// The outcome of a single check within a [`Test`].
+#[derive(Clone, Debug, Default)]
+pub struct Check {
+    /// The number of times the check was performed.
+    pub trials: usize,
+
+    /// The number of times the check failed.
+    pub failures: usize,
+
+    /// A description of the first failure (if any).
+    pub example: Option<String>,
+}
+
+impl Check {
+    /// Records the outcome of a single trial.
+    ///
+    /// The `example` closure is only called for the first failure.
+    fn record(&mut self, passed: bool, example: impl FnOnce() -> String) {
+        self.trials += 1;
+
+        if !passed {
+            self.failures += 1;
+
+            if self.example.is_none() {
+                self.example = Some(example());
+            }
+        }
+    }
+
+    /// Generates a [`Module`] for the check.
+    fn module(&self, name: &str, unit: &str, passed: String, failed: String) -> Module {
+        let value = Some(format!("{}/{} {}", self.failures, self.trials, unit));
+
+        match (&self.example, self.trials) {
+            (_, 0) => Module::new(
+                module::Result::Inconclusive,
+                name,
+                None,
+                Some(String::from(
+                    "The check was never performed (try increasing the number of keys).",
+                )),
+            ),
+            (None, _) => Module::new(module::Result::Pass, name, value, Some(passed)),
+            (Some(example), _) => Module::new(
+                module::Result::Fail,
+                name,
+                value,
+                Some(format!(
+                    "{}\n\n{}\n\n{}",
+                    failed,
+                    "Example".italic(),
+                    example
+                )),
+            ),
+        }
+    }
+}
+
+/// A correctness test.
+#[derive(Debug)]
+pub struct Test<'a, H: BuildHasher> {
+    /// The build hasher.
+    build_hasher: &'a H,
+
+    /// The data provider.
+    provider: Box<dyn Provider>,
+
+    /// The number of keys checked.
+    keys: usize,
+
+    /// Whether hashing the same input twice gives the same output.
+    determinism: Check,
+
+    /// Whether changing any input byte changes the output.
+    sensitivity: Check,
+
+    /// Whether hashing the same input at different alignments gives the same
+    /// output.
+    alignment: Check,
+
+    /// Whether writing the input in chunks gives the same output as writing it
+    /// all at once.
+    chunking: Check,
+}
+
+impl<'a, H: BuildHasher> Test<'a, H> {
+    /// Creates a new [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::sanity::correctness::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::new(&hasher, Box::new(AlphanumericProvider::new(10)));
+    ///
+    /// assert_eq!(test.keys(), 0);
+    /// ```
+    pub fn new(build_hasher: &'a H, provider: Box<dyn Provider>) -> Self {
+        Self {
+            build_hasher,
+            provider,
+            keys: 0,
+            determinism: Default::default(),
+            sensitivity: Default::default(),
+            alignment: Default::default(),
+            chunking: Default::default(),
+        }
+    }
+
+    /// Gets the build hasher for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::BuildHasher as _;
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::sanity::correctness::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let test = Test::new(&hasher, Box::new(AlphanumericProvider::new(10)));
+    ///
+    /// // Used as a surrogate to test that the [`BuildHasher`]s are the same.
+    /// assert_eq!(test.build_hasher().hash_one("42"), hasher.hash_one("42"));
+    /// ```
+    pub fn build_hasher(&self) -> &H {
+        self.build_hasher
+    }
+
+    /// Gets the data provider for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::Provider as _;
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::sanity::correctness::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let test = Test::new(&hasher, provider.clone());
+    ///
+    /// assert_eq!(test.provider().name(), provider.name());
+    /// ```
+    pub fn provider(&self) -> &dyn Provider {
+        self.provider.as_ref()
+    }
+
+    /// Gets the number of keys checked within this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::sanity::correctness::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(&hasher, Box::new(AlphanumericProvider::new(10)));
+    ///
+    /// test.single_iteration();
+    /// assert_eq!(test.keys(), 1);
+    /// ```
+    pub fn keys(&self) -> usize {
+        self.keys
+    }
+
+    /// Gets the determinism [`Check`] for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::sanity::correctness::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(&hasher, Box::new(AlphanumericProvider::new(10)));
+    ///
+    /// test.single_iteration();
+    /// assert_eq!(test.determinism().failures, 0);
+    /// ```
+    pub fn determinism(&self) -> &Check {
+        &self.determinism
+    }
+
+    /// Gets the input sensitivity [`Check`] for this [`Test`].
+    ///
+    /// A trial is performed for every byte of every key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::sanity::correctness::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(&hasher, Box::new(AlphanumericProvider::new(10)));
+    ///
+    /// test.single_iteration();
+    /// assert_eq!(test.sensitivity().trials, 10);
+    /// assert_eq!(test.sensitivity().failures, 0);
+    /// ```
+    pub fn sensitivity(&self) -> &Check {
+        &self.sensitivity
+    }
+
+    /// Gets the alignment independence [`Check`] for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::sanity::correctness::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(&hasher, Box::new(AlphanumericProvider::new(10)));
+    ///
+    /// test.single_iteration();
+    /// assert_eq!(test.alignment().failures, 0);
+    /// ```
+    pub fn alignment(&self) -> &Check {
+        &self.alignment
+    }
+
+    /// Gets the chunked write [`Check`] for this [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::sanity::correctness::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(&hasher, Box::new(AlphanumericProvider::new(10)));
+    ///
+    /// test.single_iteration();
+    /// assert_eq!(test.chunking().failures, 0);
+    /// ```
+    pub fn chunking(&self) -> &Check {
+        &self.chunking
+    }
+
+    /// Hashes `data` with a single call to
+    /// [`Hasher::write()`](std::hash::Hasher::write).
+    fn hash(&self, data: &[u8]) -> u64 {
+        let mut hasher = self.build_hasher.build_hasher();
+        hasher.write(data);
+        hasher.finish()
+    }
+
+    /// Checks a single key from the provider.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_tests::sanity::correctness::Test;
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut test = Test::new(&hasher, Box::new(AlphanumericProvider::new(10)));
+    ///
+    /// test.single_iteration();
+    /// assert_eq!(test.keys(), 1);
+    /// ```
+    pub fn single_iteration(&mut self) {
+        // SAFETY: we hardcode generating one value, so we know this pop must unwrap.
+        let key = self.provider.provide(1).pop().unwrap().to_vec();
+        let mut rng = rand::thread_rng();
+        let expected = self.hash(&key);
+
+        // (1) Determinism.
+        let again = self.hash(&key);
+        self.determinism.record(again == expected, || {
+            format!(
+                "Key 0x{} hashed to 0x{:016x} and then to 0x{:016x}.",
+                hex(&key),
+                expected,
+                again
+            )
+        });
+
+        // (2) Input sensitivity.
+        let mut modified = key.clone();
+        for i in 0..key.len() {
+            let mask = rng.gen_range(1..=u8::MAX);
+            modified[i] ^= mask;
+            let hash = self.hash(&modified);
+            modified[i] ^= mask;
+
+            self.sensitivity.record(hash != expected, || {
+                format!(
+                    "Key 0x{} hashed to 0x{:016x} both before and after byte {} was XORed with \
+                     0x{:02x}.",
+                    hex(&key),
+                    expected,
+                    i,
+                    mask
+                )
+            });
+        }
+
+        // (3) Alignment independence.
+        let mut buffer = vec![0u8; key.len() + ALIGNMENTS * 2];
+        let base = buffer.as_ptr().align_offset(ALIGNMENTS).min(ALIGNMENTS);
+        for offset in 0..ALIGNMENTS {
+            let start = base + offset;
+            buffer[start..start + key.len()].copy_from_slice(&key);
+            let hash = self.hash(&buffer[start..start + key.len()]);
+
+            self.alignment.record(hash == expected, || {
+                format!(
+                    "Key 0x{} hashed to 0x{:016x} when aligned to {} bytes but to 0x{:016x} when \
+                     offset by {} byte(s) from that alignment.",
+                    hex(&key),
+                    expected,
+                    ALIGNMENTS,
+                    hash,
+                    offset
+                )
+            });
+        }
+
+        // (4) Chunked writes.
+        let mut splits = (0..rng.gen_range(1..=MAX_SPLITS))
+            .map(|_| rng.gen_range(0..=key.len()))
+            .collect::<Vec<_>>();
+        splits.sort_unstable();
+
+        let mut hasher = self.build_hasher.build_hasher();
+        let mut previous = 0;
+        for split in splits.iter().copied().chain(std::iter::once(key.len())) {
+            hasher.write(&key[previous..split]);
+            previous = split;
+        }
+        let hash = hasher.finish();
+
+        self.chunking.record(hash == expected, || {
+            format!(
+                "Key 0x{} hashed to 0x{:016x} when written at once but to 0x{:016x} when written \
+                 in chunks split at {:?}.",
+                hex(&key),
+                expected,
+                hash,
+                splits
+            )
+        });
+
+        self.keys += 1;
+    }
+}
+
+impl<'a, H: BuildHasher> crate::r#trait::Test for Test<'a, H> {
+    fn title(&self) -> &'static str {
+        "Correctness"
+    }
+
+    fn report_section(&self) -> section::Test {
+        let determinism = self.determinism.module(
+            "Determinism",
+            "keys",
+            String::from("Every key hashed to the same output when hashed twice."),
+            String::from(
+                "At least one key hashed to different outputs when hashed twice with the same \
+                 `BuildHasher`. The hasher likely depends on state other than its input (e.g., \
+                 uninitialized memory, global state, or a time-based seed).",
+            ),
+        );
+
+        let sensitivity = self.sensitivity.module(
+            "Input Sensitivity",
+            "bytes",
+            String::from("Changing any single byte of any key changed the output."),
+            String::from(
+                "Changing at least one byte of a key did not change the output. The hasher likely \
+                 ignores part of its input (e.g., a tail that is not a multiple of the block \
+                 size).",
+            ),
+        );
+
+        let alignment = self.alignment.module(
+            "Alignment Independence",
+            "alignments",
+            format!(
+                "Every key hashed to the same output when placed at each of {} different memory \
+                 alignments.",
+                ALIGNMENTS
+            ),
+            String::from(
+                "At least one key hashed to different outputs depending on its alignment in \
+                 memory. The hasher likely reads beyond its input or reads memory based on its \
+                 address rather than its contents.",
+            ),
+        );
+
+        let chunking = self.chunking.module(
+            "Chunked Writes",
+            "keys",
+            String::from(
+                "Every key hashed to the same output when written in randomly sized chunks as \
+                 when written in a single call.",
+            ),
+            String::from(
+                "At least one key hashed to a different output when written in chunks than when \
+                 written in a single call. Note that `Hasher` does not strictly require this \
+                 property, but a hasher without it must be fed identically (with identical write \
+                 boundaries) everywhere the same value is hashed.",
+            ),
+        );
+
+        get_report_base(self.provider.as_ref(), self.keys)
+            .push_module(determinism)
+            .push_module(sensitivity)
+            .push_module(alignment)
+            .push_module(chunking)
+            .try_build()
+            .unwrap()
+    }
+}
+
+/// Populates the boilerplate report information within a
+/// [`Test`](section::Test).
+pub fn get_report_base(provider: &dyn Provider, keys: usize) -> section::test::Builder {
+    let overview = "The correctness test performs basic checks that every hasher should pass \
+                    before any statistical test is run. Unlike statistical tests, any single \
+                    failure is meaningful.";
+
+    let relation = "A hasher that is not deterministic cannot be used within a hash table at all, \
+                    as keys will not be found after they are inserted. A hasher that ignores part \
+                    of its input or depends on the memory alignment of its input will produce \
+                    collisions (or misses) that no statistical test is designed to explain. \
+                    Catching these problems early avoids wasting time on expensive runs for a \
+                    broken hasher.";
+
+    let algorithm = format!(
+        "For each key generated by the data provider:\n\n(1) *Determinism.* The key is hashed \
+         twice, and both outputs are compared.\n\n(2) *Input sensitivity.* Each byte of the key \
+         is XORed with a random, non-zero value in turn, and the output is checked to have \
+         changed.\n\n(3) *Alignment independence.* The key is copied into memory at each of {} \
+         consecutive byte offsets from an aligned address and hashed, and every output is \
+         compared with the original.\n\n(4) *Chunked writes.* The key is split at random points \
+         and written through multiple calls to `Hasher::write`, and the output is compared with \
+         the output of a single call.",
+        ALIGNMENTS
+    );
+
+    let interpretation = "Each check passes only if it never failed. When a check fails, an \
+                          example failure is provided so that it can be reproduced.";
+
+    test::Builder::default()
+        .title(format!("Correctness / {} / {} keys", provider.name(), keys))
+        .unwrap()
+        .description(format!(
+            "{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}",
+            "Overview".italic(),
+            overview,
+            "Relation to Hashing".italic(),
+            relation,
+            "Algorithm".italic(),
+            algorithm,
+            "Interpretation".italic(),
+            interpretation,
+        ))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::BuildHasherDefault;
+    use std::hash::Hasher;
+
+    use bitbelay_providers::ascii::AlphanumericProvider;
+
+    use super::*;
+
+    /// A hasher that ignores every byte after the first eight.
+    #[derive(Debug, Default)]
+    struct Truncating(u64, usize);
+
+    impl Hasher for Truncating {
+        fn finish(&self) -> u64 {
+            self.0.wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            for byte in bytes {
+                if self.1 < 8 {
+                    self.0 = self.0.rotate_left(8) ^ u64::from(*byte);
+                }
+
+                self.1 += 1;
+            }
+        }
+    }
+
+    #[test]
+    fn ignored_bytes_fail() {
+        let hasher = BuildHasherDefault::<Truncating>::default();
+        let mut test = Test::new(&hasher, Box::new(AlphanumericProvider::new(16)));
+
+        test.single_iteration();
+
+        assert_eq!(test.determinism().failures, 0);
+        assert_eq!(test.sensitivity().failures, 8);
+        assert_eq!(test.alignment().failures, 0);
+        assert_eq!(test.chunking().failures, 0);
+    }
+}
@@ -0,0 +1,123 @@
+//! Seed tests.
+//!
+//! Keyed hashers (such as the standard library's
+//! [`RandomState`](std::hash::RandomState)) are designed such that each
+//! instance produces outputs that are unrelated to those of every other
+//! instance. The tests in this module evaluate many instances of a hasher
+//! against one another rather than a single instance in isolation.
+
+use std::hash::BuildHasher;
+
+use bitbelay_report::section;
+
+use crate::r#trait::Test as _;
+
+pub mod independence;
+
+/// A type of seed test.
+#[derive(Debug)]
+pub enum Test<H: BuildHasher> {
+    /// Seed independence test.
+    Independence(independence::Test<H>),
+}
+
+impl<H: BuildHasher> Test<H> {
+    /// Gets a reference to an [`independence::Test`] wrapped in [`Some`] if
+    /// the [`Test`] is a [`Test::Independence`]. Else, returns [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::seed::Test;
+    /// use bitbelay_tests::seed::independence;
+    ///
+    /// let instances = (0..4).map(|_| RandomState::new()).collect::<Vec<_>>();
+    /// let test = Test::Independence(independence::Test::try_new(
+    ///     instances,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// )?);
+    ///
+    /// assert!(matches!(test.as_independence_test(), Some(_)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn as_independence_test(&self) -> Option<&independence::Test<H>> {
+        match self {
+            Test::Independence(test) => Some(test),
+        }
+    }
+
+    /// Consumes the [`Test`] and returns an [`independence::Test`] wrapped in
+    /// [`Some`] if the [`Test`] is a [`Test::Independence`]. Else, returns
+    /// [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::seed::Test;
+    /// use bitbelay_tests::seed::independence;
+    ///
+    /// let instances = (0..4).map(|_| RandomState::new()).collect::<Vec<_>>();
+    /// let test = Test::Independence(independence::Test::try_new(
+    ///     instances,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// )?);
+    ///
+    /// assert!(matches!(test.into_independence_test(), Some(_)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn into_independence_test(self) -> Option<independence::Test<H>> {
+        match self {
+            Test::Independence(test) => Some(test),
+        }
+    }
+
+    /// Generates a report section for the [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::seed::Test;
+    /// use bitbelay_tests::seed::independence;
+    ///
+    /// let instances = (0..4).map(|_| RandomState::new()).collect::<Vec<_>>();
+    /// let mut test = independence::Test::try_new(
+    ///     instances,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// )?;
+    ///
+    /// for _ in 0..100 {
+    ///     test.single_iteration();
+    /// }
+    ///
+    /// let test = Test::Independence(test);
+    /// let results = test.report_section();
+    /// // Include the section in a report.
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn report_section(&self) -> section::Test {
+        match self {
+            Test::Independence(test) => test.report_section(),
+        }
+    }
+}
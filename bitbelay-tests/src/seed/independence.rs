@@ -0,0 +1,694 @@
+//! Seed independence test.
+
+use std::hash::BuildHasher;
+
+use bitbelay_providers::Provider;
+use bitbelay_report::section;
+use bitbelay_report::section::test;
+use bitbelay_report::section::test::Module;
+use bitbelay_report::section::test::module;
+use bitbelay_statistics::correction::Procedure;
+use colored::Colorize;
+use statrs::distribution::ContinuousCDF as _;
+use statrs::distribution::Normal;
+
+/// The minimum number of instances needed to run the test.
+pub const MIN_INSTANCES: usize = 2;
+
+/// The number of pairs listed within the details of a report module.
+const REPORTED_PAIRS: usize = 10;
+
+/// An error related to a [`Test`].
+#[derive(Debug)]
+pub enum Error {
+    /// Fewer than [`MIN_INSTANCES`] instances were provided.
+    TooFewInstances(usize),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::TooFewInstances(instances) => write!(
+                f,
+                "too few instances: {instances} (at least {MIN_INSTANCES} are required)"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A [`Result`](std::result::Result) with an [`Error`].
+type Result<T> = std::result::Result<T, Error>;
+
+/// The comparison of the outputs of two instances within a [`Test`].
+#[derive(Clone, Debug)]
+pub struct Pair {
+    /// The index of the first instance.
+    pub first: usize,
+
+    /// The index of the second instance.
+    pub second: usize,
+
+    /// The number of keys for which both instances produced identical
+    /// outputs.
+    pub identical: usize,
+
+    /// The number of keys for which the difference between the outputs of
+    /// the two instances (either by XOR or by subtraction) was the same as
+    /// that of an earlier key.
+    pub repeated_differences: usize,
+
+    /// The fraction of output bits that agreed between the two instances.
+    pub agreement: f64,
+
+    /// The (two-sided) p-value of observing a bit agreement at least as
+    /// extreme as the one observed if the instances were independent.
+    pub p_value: f64,
+
+    /// The p-value of the bit agreement after adjusting for the number of
+    /// pairs tested.
+    pub adjusted_p_value: f64,
+}
+
+/// A seed independence test.
+///
+/// The same keys are hashed by many instances of a hasher, and the outputs
+/// of every pair of instances are compared against one another.
+#[derive(Debug)]
+pub struct Test<H: BuildHasher> {
+    /// The hasher instances.
+    instances: Vec<H>,
+
+    /// The data provider.
+    provider: Box<dyn Provider>,
+
+    /// The hashes computed by each instance (indexed first by instance and
+    /// then by key).
+    hashes: Vec<Vec<u64>>,
+
+    /// The threshold of statistical signficance to use.
+    threshold: f64,
+
+    /// The procedure used to correct for the number of pairs tested.
+    correction: Procedure,
+}
+
+impl<H: BuildHasher> Test<H> {
+    /// Attempts to create a new [`Test`].
+    ///
+    /// # Notes
+    ///
+    /// * If fewer than [`MIN_INSTANCES`] instances are provided, an
+    ///   [`Error::TooFewInstances`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::Provider as _;
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::seed::independence::Test;
+    ///
+    /// let instances = (0..4).map(|_| RandomState::new()).collect::<Vec<_>>();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let test = Test::try_new(instances, provider.clone(), 0.05, Procedure::Holm)?;
+    ///
+    /// assert_eq!(test.instances().len(), 4);
+    /// assert_eq!(test.provider().name(), provider.name());
+    ///
+    /// // At least two instances are needed.
+    /// assert!(Test::try_new(vec![RandomState::new()], provider, 0.05, Procedure::Holm).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_new(
+        instances: Vec<H>,
+        provider: Box<dyn Provider>,
+        threshold: f64,
+        correction: Procedure,
+    ) -> Result<Self> {
+        if instances.len() < MIN_INSTANCES {
+            return Err(Error::TooFewInstances(instances.len()));
+        }
+
+        let hashes = vec![Vec::new(); instances.len()];
+
+        Ok(Self {
+            instances,
+            provider,
+            hashes,
+            threshold,
+            correction,
+        })
+    }
+
+    /// Gets the hasher instances from the [`Test`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::seed::independence::Test;
+    ///
+    /// let instances = (0..4).map(|_| RandomState::new()).collect::<Vec<_>>();
+    /// let test = Test::try_new(
+    ///     instances,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// )?;
+    ///
+    /// assert_eq!(test.instances().len(), 4);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn instances(&self) -> &[H] {
+        &self.instances
+    }
+
+    /// Gets the [`Provider`] from the [`Test`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::Provider as _;
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::seed::independence::Test;
+    ///
+    /// let instances = (0..4).map(|_| RandomState::new()).collect::<Vec<_>>();
+    /// let provider = Box::new(AlphanumericProvider::new(10));
+    /// let test = Test::try_new(instances, provider.clone(), 0.05, Procedure::Holm)?;
+    ///
+    /// assert_eq!(test.provider().name(), provider.name());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn provider(&self) -> &dyn Provider {
+        self.provider.as_ref()
+    }
+
+    /// Gets the threshold from the [`Test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::seed::independence::Test;
+    ///
+    /// let instances = (0..4).map(|_| RandomState::new()).collect::<Vec<_>>();
+    /// let test = Test::try_new(
+    ///     instances,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// )?;
+    ///
+    /// assert_eq!(test.threshold(), 0.05);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Gets the procedure used to correct for the number of pairs tested.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::seed::independence::Test;
+    ///
+    /// let instances = (0..4).map(|_| RandomState::new()).collect::<Vec<_>>();
+    /// let test = Test::try_new(
+    ///     instances,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     0.05,
+    ///     Procedure::Bonferroni,
+    /// )?;
+    ///
+    /// assert_eq!(test.correction(), Procedure::Bonferroni);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn correction(&self) -> Procedure {
+        self.correction
+    }
+
+    /// Gets the number of keys hashed by every instance so far.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::seed::independence::Test;
+    ///
+    /// let instances = (0..4).map(|_| RandomState::new()).collect::<Vec<_>>();
+    /// let mut test = Test::try_new(
+    ///     instances,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// )?;
+    ///
+    /// test.single_iteration();
+    /// assert_eq!(test.keys(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn keys(&self) -> usize {
+        // SAFETY: there are always at least [`MIN_INSTANCES`] instances, so
+        // this will always unwrap.
+        self.hashes.first().unwrap().len()
+    }
+
+    /// Performs a single iteration of the test (hashing a single key with
+    /// every instance).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::seed::independence::Test;
+    ///
+    /// let instances = (0..4).map(|_| RandomState::new()).collect::<Vec<_>>();
+    /// let mut test = Test::try_new(
+    ///     instances,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// )?;
+    ///
+    /// test.single_iteration();
+    /// test.single_iteration();
+    ///
+    /// assert_eq!(test.keys(), 2);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn single_iteration(&mut self) {
+        let data = *self.provider.provide(1).first().unwrap();
+
+        for (instance, hashes) in self.instances.iter().zip(self.hashes.iter_mut()) {
+            hashes.push(instance.hash_one(data));
+        }
+    }
+
+    /// Compares the outputs of every pair of instances.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::RandomState;
+    ///
+    /// use bitbelay_providers::ascii::AlphanumericProvider;
+    /// use bitbelay_statistics::correction::Procedure;
+    /// use bitbelay_tests::seed::independence::Test;
+    ///
+    /// let instances = (0..4).map(|_| RandomState::new()).collect::<Vec<_>>();
+    /// let mut test = Test::try_new(
+    ///     instances,
+    ///     Box::new(AlphanumericProvider::new(10)),
+    ///     0.05,
+    ///     Procedure::Holm,
+    /// )?;
+    ///
+    /// for _ in 0..100 {
+    ///     test.single_iteration();
+    /// }
+    ///
+    /// let pairs = test.pairs();
+    /// assert_eq!(pairs.len(), 6);
+    /// assert!(pairs.iter().all(|pair| pair.identical == 0));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn pairs(&self) -> Vec<Pair> {
+        // SAFETY: a standard normal distribution is always valid, so this will
+        // always unwrap.
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        let mut pairs = Vec::new();
+
+        for first in 0..self.hashes.len() {
+            for second in (first + 1)..self.hashes.len() {
+                let a = &self.hashes[first];
+                let b = &self.hashes[second];
+
+                let identical = a.iter().zip(b).filter(|(a, b)| a == b).count();
+                let repeated_differences = repeated(a.iter().zip(b).map(|(a, b)| a ^ b))
+                    .max(repeated(a.iter().zip(b).map(|(a, b)| a.wrapping_sub(*b))));
+
+                let bits = (a.len() * u64::BITS as usize) as f64;
+                let agreeing = a
+                    .iter()
+                    .zip(b)
+                    .map(|(a, b)| (!(a ^ b)).count_ones() as usize)
+                    .sum::<usize>() as f64;
+
+                // NOTE: under independence, each bit agrees with probability
+                // 1/2, so the number of agreeing bits is binomial and is
+                // well-approximated by a normal distribution.
+                let (agreement, p_value) = if bits > 0.0 {
+                    let z = (agreeing - bits / 2.0) / (bits / 4.0).sqrt();
+                    (agreeing / bits, 2.0 * (1.0 - normal.cdf(z.abs())))
+                } else {
+                    (0.5, 1.0)
+                };
+
+                pairs.push(Pair {
+                    first,
+                    second,
+                    identical,
+                    repeated_differences,
+                    agreement,
+                    p_value,
+                    // NOTE: this is filled in below once every pair has been
+                    // compared.
+                    adjusted_p_value: p_value,
+                });
+            }
+        }
+
+        let adjusted = self
+            .correction
+            .adjust(&pairs.iter().map(|pair| pair.p_value).collect::<Vec<_>>());
+
+        for (pair, adjusted) in pairs.iter_mut().zip(adjusted) {
+            pair.adjusted_p_value = adjusted;
+        }
+
+        pairs
+    }
+}
+
+/// Counts the number of values that repeat an earlier value.
+fn repeated(values: impl Iterator<Item = u64>) -> usize {
+    let mut values = values.collect::<Vec<_>>();
+    let total = values.len();
+
+    values.sort_unstable();
+    values.dedup();
+
+    total - values.len()
+}
+
+/// Lists the provided pairs (up to [`REPORTED_PAIRS`]) within a string.
+fn list_pairs<'a>(
+    pairs: impl Iterator<Item = &'a Pair>,
+    describe: impl Fn(&Pair) -> String,
+) -> String {
+    pairs
+        .take(REPORTED_PAIRS)
+        .map(|pair| {
+            format!(
+                "\n* Instances {} and {} {}.",
+                pair.first,
+                pair.second,
+                describe(pair)
+            )
+        })
+        .collect()
+}
+
+impl<H: BuildHasher> crate::r#trait::Test for Test<H> {
+    fn title(&self) -> &'static str {
+        "Seed Independence"
+    }
+
+    fn report_section(&self) -> section::Test {
+        let mut pairs = self.pairs();
+        let total = pairs.len();
+
+        // Identical outputs.
+        let identical = pairs.iter().filter(|p| p.identical > 0).collect::<Vec<_>>();
+        let identical = if identical.is_empty() {
+            Module::new(
+                module::Result::Pass,
+                "Identical Outputs",
+                Some(format!("0/{} pairs", total)),
+                Some(String::from(
+                    "No pair of instances produced the same output for any key.",
+                )),
+            )
+        } else {
+            Module::new(
+                module::Result::Fail,
+                "Identical Outputs",
+                Some(format!("{}/{} pairs", identical.len(), total)),
+                Some(format!(
+                    "At least one pair of instances produced the same output for the same key \
+                     (which is vanishingly unlikely for independent 64-bit outputs).\n{}",
+                    list_pairs(identical.into_iter(), |pair| format!(
+                        "produced identical outputs for {} of {} keys",
+                        pair.identical,
+                        self.keys()
+                    ))
+                )),
+            )
+        };
+
+        // Related outputs.
+        let related = pairs
+            .iter()
+            .filter(|p| p.repeated_differences > 0)
+            .collect::<Vec<_>>();
+        let related = if related.is_empty() {
+            Module::new(
+                module::Result::Pass,
+                "Related Outputs",
+                Some(format!("0/{} pairs", total)),
+                Some(String::from(
+                    "No pair of instances produced outputs that differed by the same amount (by \
+                     XOR or by subtraction) for more than one key.",
+                )),
+            )
+        } else {
+            Module::new(
+                module::Result::Fail,
+                "Related Outputs",
+                Some(format!("{}/{} pairs", related.len(), total)),
+                Some(format!(
+                    "At least one pair of instances produced outputs that differed by the same \
+                     amount (by XOR or by subtraction) for more than one key, which indicates \
+                     that the outputs of those instances are nearly identical.\n{}",
+                    list_pairs(related.into_iter(), |pair| format!(
+                        "repeated a difference for {} of {} keys",
+                        pair.repeated_differences,
+                        self.keys()
+                    ))
+                )),
+            )
+        };
+
+        // Bit agreement.
+        pairs.sort_by(|a, b| a.p_value.total_cmp(&b.p_value));
+
+        let agreement = match pairs.first() {
+            None => Module::new(module::Result::Inconclusive, "Bit Agreement", None, None),
+            Some(_) if self.keys() == 0 => Module::new(
+                module::Result::Inconclusive,
+                "Bit Agreement",
+                None,
+                Some(String::from("No keys have been hashed yet.")),
+            ),
+            Some(worst) => {
+                let failed = pairs
+                    .iter()
+                    .filter(|p| p.adjusted_p_value <= self.threshold)
+                    .count();
+                let result = if failed == 0 {
+                    module::Result::Pass
+                } else {
+                    module::Result::Fail
+                };
+
+                Module::new(
+                    result,
+                    "Bit Agreement",
+                    Some(format!("{:.4}", worst.adjusted_p_value)),
+                    Some(format!(
+                        "{} of {} pairs of instances had a bit agreement that differed \
+                         significantly from 50% at a threshold of {:.2} (after applying the {} \
+                         across all pairs).\n\n{}\n{}",
+                        failed,
+                        total,
+                        self.threshold,
+                        self.correction.name(),
+                        "Most Extreme Pairs".italic(),
+                        list_pairs(pairs.iter(), |pair| format!(
+                            "agreed on {:.2}% of output bits (p = {:.4}, adjusted p = {:.4})",
+                            pair.agreement * 100.0,
+                            pair.p_value,
+                            pair.adjusted_p_value
+                        ))
+                    )),
+                )
+            }
+        };
+
+        // SAFETY: all of the pieces of this [`Builder`] are hand-crafted to not
+        // fail, so all of the below will unwrap.
+        get_report_base(
+            self.provider.as_ref(),
+            self.instances.len(),
+            self.keys(),
+            self.correction,
+        )
+        .push_module(identical)
+        .push_module(related)
+        .push_module(agreement)
+        .try_build()
+        .unwrap()
+    }
+}
+
+/// Populates the boilerplate report information within a
+/// [`Test`](section::Test).
+pub fn get_report_base(
+    provider: &dyn Provider,
+    instances: usize,
+    keys: usize,
+    correction: Procedure,
+) -> section::test::Builder {
+    let overview = "The seed independence test evaluates whether different instances of a keyed \
+                    hasher produce unrelated outputs. Many instances of the hasher are created, \
+                    the same keys are hashed by every instance, and the outputs of every pair of \
+                    instances are compared against one another.";
+
+    let relation = "Keyed hashers (such as the Rust standard library's `RandomState`) choose a \
+                    random seed for each instance so that an attacker cannot predict which keys \
+                    will collide within a particular hash table. This protection only holds if \
+                    the outputs of different instances are truly unrelated: if two instances \
+                    produce identical (or nearly identical) outputs, then keys that collide \
+                    within one table will collide within the other, and the seed provides little \
+                    protection. Weak seeding (e.g., a seed drawn from a low-entropy source or a \
+                    seed that is only mixed into the output at the very end) commonly causes this.";
+
+    let algorithm = format!(
+        "For a specified hasher factory, data provider, number of instances, and number of \
+         keys:\n\n(1) The specified number of hasher instances are created using the \
+         factory.\n\n(2) Random keys are generated using the specified data provider, and each \
+         key is hashed by every instance.\n\n(3) For every pair of instances:\n\n  * The number \
+         of keys for which both instances produced identical outputs is counted.\n  * The \
+         difference between the outputs of both instances (by XOR and by subtraction) is computed \
+         for every key, and the number of repeated differences is counted. Independent outputs \
+         almost never repeat a difference, whereas outputs that only differ by a constant always \
+         do.\n  * The fraction of output bits that agree between both instances is computed and \
+         compared against the 50% expected for independent outputs using a two-sided \
+         z-test.\n\n(4) The bit agreement p-values are adjusted for the number of pairs using the \
+         {} (which controls the {}) and compared against the threshold of statistical \
+         significance.",
+        correction.name(),
+        correction.controls()
+    );
+
+    let interpretation =
+        "Under this test design:\n\n* No identical outputs, no repeated differences, and bit \
+         agreements that are consistent with 50% are **good**, as they indicate that each \
+         instance behaves like an independently chosen hash function.\n\n* Any identical outputs \
+         or repeated differences are **bad**, as they indicate that some instances are identical \
+         or nearly so. A significant bit agreement is also **bad**, as it indicates that the \
+         outputs of some instances are correlated.";
+
+    let sources = format!(
+        "* https://doc.rust-lang.org/std/collections/hash_map/struct.RandomState.html\n* \
+         https://en.wikipedia.org/wiki/Universal_hashing\n* {}",
+        correction.source()
+    );
+
+    test::Builder::default()
+        .title(format!(
+            "Seed Independence / {} / {} instances / {} keys",
+            provider.name(),
+            instances,
+            keys
+        ))
+        .unwrap()
+        .description(format!(
+            "{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}",
+            "Overview".italic(),
+            overview,
+            "Relation to Hashing".italic(),
+            relation,
+            "Algorithm".italic(),
+            algorithm,
+            "Interpretation".italic(),
+            interpretation,
+            "Sources".italic(),
+            sources
+        ))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::BuildHasherDefault;
+    use std::hash::DefaultHasher;
+    use std::hash::RandomState;
+
+    use bitbelay_providers::ascii::AlphanumericProvider;
+
+    use super::*;
+
+    #[test]
+    fn identical_instances_are_flagged() {
+        let instances = vec![BuildHasherDefault::<DefaultHasher>::default(); 4];
+        let mut test = Test::try_new(
+            instances,
+            Box::new(AlphanumericProvider::new(10)),
+            0.05,
+            Procedure::Holm,
+        )
+        .unwrap();
+
+        for _ in 0..100 {
+            test.single_iteration();
+        }
+
+        assert!(test.pairs().iter().all(|pair| pair.identical == 100));
+    }
+
+    #[test]
+    fn random_instances_are_independent() {
+        let instances = (0..8).map(|_| RandomState::new()).collect::<Vec<_>>();
+        let mut test = Test::try_new(
+            instances,
+            Box::new(AlphanumericProvider::new(10)),
+            0.05,
+            Procedure::Holm,
+        )
+        .unwrap();
+
+        for _ in 0..1000 {
+            test.single_iteration();
+        }
+
+        assert!(test.pairs().iter().all(|pair| pair.identical == 0));
+        assert!(
+            test.pairs()
+                .iter()
+                .all(|pair| pair.repeated_differences == 0)
+        );
+    }
+}
@@ -0,0 +1,351 @@
+//! Hashers that can be explicitly seeded.
+//!
+//! [`BuildHasher`] provides no way to construct a hasher from a given seed, so
+//! tests that evaluate how changes to the seed propagate to the output (e.g.,
+//! the [seed avalanche test](crate::avalanche::seed)) require hashers to
+//! implement [`SeededBuildHasher`]. Adapters are provided for common hashers.
+
+use std::hash::BuildHasher;
+use std::hash::BuildHasherDefault;
+use std::hash::Hasher;
+
+/// A [`BuildHasher`] that can be constructed from an explicit seed.
+pub trait SeededBuildHasher: BuildHasher + Sized {
+    /// The number of bytes within the seed.
+    const SEED_BYTES: usize;
+
+    /// Creates a new [`SeededBuildHasher`] from a seed of exactly
+    /// [`SEED_BYTES`](SeededBuildHasher::SEED_BYTES) bytes.
+    ///
+    /// # Panics
+    ///
+    /// Implementations may panic if `bytes` is not exactly
+    /// [`SEED_BYTES`](SeededBuildHasher::SEED_BYTES) bytes long.
+    fn from_seed_bytes(bytes: &[u8]) -> Self;
+
+    /// Creates a new [`SeededBuildHasher`] from a [`u64`] seed.
+    ///
+    /// By default, the little-endian bytes of `seed` are placed at the start of
+    /// the seed and any remaining bytes are set to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasher as _;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::SeededBuildHasher as _;
+    ///
+    /// type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// assert_eq!(
+    ///     Hasher::from_seed(42).hash_one("42"),
+    ///     Hasher::from_seed(42).hash_one("42")
+    /// );
+    /// assert_ne!(
+    ///     Hasher::from_seed(42).hash_one("42"),
+    ///     Hasher::from_seed(43).hash_one("42")
+    /// );
+    /// ```
+    fn from_seed(seed: u64) -> Self {
+        let mut bytes = vec![0u8; Self::SEED_BYTES];
+        let seed = seed.to_le_bytes();
+        let len = bytes.len().min(seed.len());
+        bytes[..len].copy_from_slice(&seed[..len]);
+        Self::from_seed_bytes(&bytes)
+    }
+}
+
+/// A type-erased means of constructing a [`SeededBuildHasher`].
+///
+/// A [`Seeder`] allows seeded construction to be passed around in contexts
+/// where a hasher is only known to implement [`BuildHasher`].
+pub struct Seeder<H: BuildHasher> {
+    /// The number of bytes within the seed.
+    seed_bytes: usize,
+
+    /// Constructs a hasher from a seed.
+    from_seed_bytes: fn(&[u8]) -> H,
+}
+
+impl<H: SeededBuildHasher> Default for Seeder<H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<H: SeededBuildHasher> Seeder<H> {
+    /// Creates a new [`Seeder`] for a [`SeededBuildHasher`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    ///
+    /// let seeder = Seeder::<SeedPrefixed<BuildHasherDefault<DefaultHasher>>>::new();
+    /// assert_eq!(seeder.seed_bytes(), 8);
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            seed_bytes: H::SEED_BYTES,
+            from_seed_bytes: H::from_seed_bytes,
+        }
+    }
+}
+
+impl<H: BuildHasher> Seeder<H> {
+    /// Gets the number of bytes within the seed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    ///
+    /// let seeder = Seeder::<SeedPrefixed<BuildHasherDefault<DefaultHasher>>>::new();
+    /// assert_eq!(seeder.seed_bytes(), 8);
+    /// ```
+    pub fn seed_bytes(&self) -> usize {
+        self.seed_bytes
+    }
+
+    /// Builds a hasher from a seed of exactly
+    /// [`seed_bytes()`](Seeder::seed_bytes) bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasher as _;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    ///
+    /// let seeder = Seeder::<SeedPrefixed<BuildHasherDefault<DefaultHasher>>>::new();
+    /// let hasher = seeder.build(&[0u8; 8]);
+    ///
+    /// assert_eq!(
+    ///     hasher.hash_one("42"),
+    ///     seeder.build(&[0u8; 8]).hash_one("42")
+    /// );
+    /// ```
+    pub fn build(&self, bytes: &[u8]) -> H {
+        (self.from_seed_bytes)(bytes)
+    }
+
+    /// Builds a hasher from a randomly generated seed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    /// use bitbelay_tests::seeded::Seeder;
+    ///
+    /// let seeder = Seeder::<SeedPrefixed<BuildHasherDefault<DefaultHasher>>>::new();
+    /// let hasher = seeder.random();
+    /// ```
+    pub fn random(&self) -> H {
+        let bytes = (0..self.seed_bytes)
+            .map(|_| rand::random::<u8>())
+            .collect::<Vec<_>>();
+        self.build(&bytes)
+    }
+}
+
+impl<H: BuildHasher> Clone for Seeder<H> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<H: BuildHasher> Copy for Seeder<H> {}
+
+impl<H: BuildHasher> std::fmt::Debug for Seeder<H> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Seeder")
+            .field("hasher", &std::any::type_name::<H>())
+            .field("seed_bytes", &self.seed_bytes)
+            .finish()
+    }
+}
+
+/// An adapter that seeds any [`BuildHasher`] by writing a [`u64`] seed into
+/// each hasher before any other data.
+///
+/// This is the simplest possible way to seed a hasher that provides no seeding
+/// of its own. Whether or not it is a _good_ way depends entirely on how well
+/// the underlying hasher mixes its initial input—which is exactly what the
+/// [seed avalanche test](crate::avalanche::seed) measures.
+///
+/// Only a [`SeedPrefixed`] wrapping a [`BuildHasherDefault`] implements
+/// [`SeededBuildHasher`]: the hashers it builds are deterministic, so the same
+/// seed always yields the same hasher. Build hashers with randomly keyed
+/// defaults (such as [`std::hash::RandomState`]) would otherwise produce a
+/// different hasher for every call with the same seed.
+///
+/// ```compile_fail
+/// use std::hash::RandomState;
+///
+/// use bitbelay_tests::seeded::SeedPrefixed;
+/// use bitbelay_tests::seeded::SeededBuildHasher as _;
+///
+/// SeedPrefixed::<RandomState>::from_seed(42);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SeedPrefixed<B: BuildHasher> {
+    /// The inner build hasher.
+    inner: B,
+
+    /// The seed.
+    seed: u64,
+}
+
+impl<B: BuildHasher> SeedPrefixed<B> {
+    /// Creates a new [`SeedPrefixed`] from an inner [`BuildHasher`] and a
+    /// seed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    ///
+    /// let hasher = SeedPrefixed::new(BuildHasherDefault::<DefaultHasher>::default(), 42);
+    /// assert_eq!(hasher.seed(), 42);
+    /// ```
+    pub fn new(inner: B, seed: u64) -> Self {
+        Self { inner, seed }
+    }
+
+    /// Gets the inner [`BuildHasher`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasher as _;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    ///
+    /// let inner = BuildHasherDefault::<DefaultHasher>::default();
+    /// let hasher = SeedPrefixed::new(inner.clone(), 42);
+    ///
+    /// assert_eq!(hasher.inner().hash_one("42"), inner.hash_one("42"));
+    /// ```
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// Gets the seed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// use bitbelay_tests::seeded::SeedPrefixed;
+    ///
+    /// let hasher = SeedPrefixed::new(BuildHasherDefault::<DefaultHasher>::default(), 42);
+    /// assert_eq!(hasher.seed(), 42);
+    /// ```
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+}
+
+impl<B: BuildHasher> BuildHasher for SeedPrefixed<B> {
+    type Hasher = B::Hasher;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = self.inner.build_hasher();
+        hasher.write_u64(self.seed);
+        hasher
+    }
+}
+
+impl<H: Hasher + Default> SeededBuildHasher for SeedPrefixed<BuildHasherDefault<H>> {
+    const SEED_BYTES: usize = 8;
+
+    fn from_seed_bytes(bytes: &[u8]) -> Self {
+        assert_eq!(bytes.len(), Self::SEED_BYTES);
+
+        // SAFETY: the seed was checked to be exactly eight bytes above.
+        let seed = u64::from_le_bytes(bytes.try_into().unwrap());
+        Self::new(BuildHasherDefault::default(), seed)
+    }
+}
+
+#[cfg(feature = "ahash")]
+impl SeededBuildHasher for ahash::RandomState {
+    const SEED_BYTES: usize = 32;
+
+    fn from_seed_bytes(bytes: &[u8]) -> Self {
+        assert_eq!(bytes.len(), Self::SEED_BYTES);
+
+        let mut keys = bytes
+            .chunks_exact(8)
+            // SAFETY: each chunk is exactly eight bytes.
+            .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()));
+
+        // SAFETY: the seed is exactly four chunks of eight bytes.
+        ahash::RandomState::with_seeds(
+            keys.next().unwrap(),
+            keys.next().unwrap(),
+            keys.next().unwrap(),
+            keys.next().unwrap(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+
+    use super::*;
+
+    type Hasher = SeedPrefixed<BuildHasherDefault<DefaultHasher>>;
+
+    #[test]
+    fn the_same_seed_bytes_build_the_same_hasher() {
+        let bytes = [0x42, 0x00, 0xFF, 0x13, 0x37, 0x00, 0x01, 0x02];
+
+        assert_eq!(
+            Hasher::from_seed_bytes(&bytes).hash_one("42"),
+            Hasher::from_seed_bytes(&bytes).hash_one("42")
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn seed_bytes_of_the_wrong_length_panic() {
+        Hasher::from_seed_bytes(&[0u8; 4]);
+    }
+
+    #[cfg(feature = "ahash")]
+    #[test]
+    fn the_same_seed_bytes_build_the_same_ahash_hasher() {
+        let bytes = (0..32).collect::<Vec<u8>>();
+
+        assert_eq!(
+            ahash::RandomState::from_seed_bytes(&bytes).hash_one("42"),
+            ahash::RandomState::from_seed_bytes(&bytes).hash_one("42")
+        );
+    }
+}
@@ -0,0 +1,108 @@
+//! Tolerances that determine whether a test passes or fails.
+//!
+//! A fixed tolerance (for instance, "every bit must be flipped 50% ± 1% of the
+//! time") means something very different depending on how many samples were
+//! drawn: with ten thousand samples, a perfect hash function regularly strays
+//! outside of such a bound purely due to chance, whereas with a billion
+//! samples, a badly biased hash function comfortably stays within it. A
+//! [`Tolerance::SampleSize`] tolerance instead derives the bound from the
+//! number of samples such that a perfect hash function only exceeds it with a
+//! chosen probability, so the meaning of a passing result does not depend on
+//! how long the test was run.
+//!
+//! When a test makes many comparisons at once (such as one per output bit),
+//! the chosen threshold of statistical significance is split evenly among them
+//! (a [Bonferroni correction]) so that it bounds the probability of a perfect
+//! hash function failing _any_ of them.
+//!
+//! [Bonferroni correction]: https://en.wikipedia.org/wiki/Bonferroni_correction
+
+use bitbelay_statistics::interval;
+
+/// A tolerance that determines whether a test passes or fails.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Tolerance {
+    /// A fixed tolerance that does not depend on the number of samples.
+    Fixed(f64),
+
+    /// A tolerance derived from the number of samples such that a perfect hash
+    /// function exceeds it with a probability of at most the contained
+    /// threshold of statistical significance.
+    SampleSize(f64),
+}
+
+impl Tolerance {
+    /// Gets the maximum deviance of an observed proportion from `probability`
+    /// allowed by this [`Tolerance`] when `trials` trials are split across
+    /// `comparisons` comparisons.
+    ///
+    /// For a [`Tolerance::SampleSize`], this is half of the width of the
+    /// [binomial acceptance interval](interval::binomial()). If there are no
+    /// trials or comparisons, then no result is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_tests::tolerance::Tolerance;
+    ///
+    /// assert_eq!(
+    ///     Tolerance::Fixed(0.01).proportion(10_000, 0.5, 64),
+    ///     Some(0.01)
+    /// );
+    ///
+    /// // More samples yield a tighter tolerance.
+    /// let tolerance = Tolerance::SampleSize(0.05);
+    /// let few = tolerance.proportion(10_000, 0.5, 64).unwrap();
+    /// let many = tolerance.proportion(1_000_000_000, 0.5, 64).unwrap();
+    /// assert!(many < few);
+    /// ```
+    pub fn proportion(&self, trials: u64, probability: f64, comparisons: usize) -> Option<f64> {
+        match self {
+            Tolerance::Fixed(tolerance) => Some(*tolerance),
+            Tolerance::SampleSize(significance) => {
+                if comparisons == 0 {
+                    return None;
+                }
+
+                interval::binomial(trials, probability, significance / comparisons as f64)
+                    .map(|interval| interval.half_width())
+            }
+        }
+    }
+
+    /// Gets the maximum absolute Pearson correlation coefficient between two
+    /// uncorrelated variables allowed by this [`Tolerance`] when each of
+    /// `comparisons` coefficients is computed over `pairs` samples.
+    ///
+    /// For a [`Tolerance::SampleSize`], this is the upper bound of the [Fisher
+    /// transformation confidence interval](interval::fisher_z()) around zero.
+    /// If there are not enough pairs or no comparisons, then no result is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitbelay_tests::tolerance::Tolerance;
+    ///
+    /// assert_eq!(Tolerance::Fixed(0.05).correlation(10_000, 2016), Some(0.05));
+    ///
+    /// // More samples yield a tighter tolerance.
+    /// let tolerance = Tolerance::SampleSize(0.05);
+    /// let few = tolerance.correlation(10_000, 2016).unwrap();
+    /// let many = tolerance.correlation(10_000_000, 2016).unwrap();
+    /// assert!(many < few);
+    /// ```
+    pub fn correlation(&self, pairs: usize, comparisons: usize) -> Option<f64> {
+        match self {
+            Tolerance::Fixed(tolerance) => Some(*tolerance),
+            Tolerance::SampleSize(significance) => {
+                if comparisons == 0 {
+                    return None;
+                }
+
+                interval::fisher_z(0.0, pairs, significance / comparisons as f64)
+                    .map(|interval| interval.upper)
+            }
+        }
+    }
+}
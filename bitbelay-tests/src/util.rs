@@ -0,0 +1,66 @@
+//! Utilities shared across tests.
+
+use bitbelay_report::section::test::Module;
+use bitbelay_report::section::test::module;
+
+/// Formats bytes as a hexadecimal string.
+pub(crate) fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Generates a [`Module`] that compares a p-value against the `threshold` of
+/// statistical significance.
+///
+/// If the p-value was not able to be computed, the module is inconclusive and
+/// its details are `inconclusive`. Any provided `context` is appended to the
+/// details of the module.
+pub(crate) fn p_value_module(
+    name: &str,
+    p_value: Option<f64>,
+    threshold: f64,
+    hypothesis: &str,
+    inconclusive: &str,
+    context: Option<String>,
+) -> Module {
+    let (result, value, details) = match p_value {
+        Some(p_value) if p_value > threshold => (
+            module::Result::Pass,
+            Some(format!("{:.2}", p_value)),
+            format!(
+                "The resulting p-value of {:.2} was greater than (and, thus, failed to reach) the \
+                 predetermined threshold of statistical significance set at {:.2}. As such, the \
+                 null hypothesis that {} **cannot** be rejected.",
+                p_value, threshold, hypothesis
+            ),
+        ),
+        Some(p_value) => (
+            module::Result::Fail,
+            Some(format!("{:.2}", p_value)),
+            format!(
+                "The resulting p-value of {:.2} was less than (and, thus, reached) the \
+                 predetermined threshold of statistical significance set at {:.2}. As such, the \
+                 null hypothesis that {} **is** rejected.",
+                p_value, threshold, hypothesis
+            ),
+        ),
+        None => (module::Result::Inconclusive, None, inconclusive.to_string()),
+    };
+
+    let details = match context {
+        Some(context) => format!("{}\n\n{}", details, context),
+        None => details,
+    };
+
+    Module::new(result, name, value, Some(details))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_pads_every_byte() {
+        assert_eq!(hex(&[0x00, 0x0F, 0xAB]), "000fab");
+        assert_eq!(hex(&[]), "");
+    }
+}
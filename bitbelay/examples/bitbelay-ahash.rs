@@ -5,8 +5,8 @@
 
 type Hasher = ahash::RandomState;
 
-use bitbelay::cli::wrapper;
+use bitbelay::cli::wrapper_seeded;
 
 pub fn main() -> anyhow::Result<()> {
-    wrapper(Hasher::default())
+    wrapper_seeded::<Hasher>()
 }
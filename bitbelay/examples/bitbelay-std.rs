@@ -6,5 +6,5 @@
 use std::hash::RandomState;
 
 pub fn main() -> anyhow::Result<()> {
-    bitbelay::cli::wrapper(RandomState::default())
+    bitbelay::cli::wrapper_with_factory(RandomState::new)
 }
@@ -1,10 +1,9 @@
 #[cfg(not(feature = "hash-ahash"))]
-use std::hash::RandomState as Hasher;
-#[cfg(feature = "hash-ahash")]
-type Hasher = ahash::RandomState;
-
-use bitbelay::cli::wrapper;
+pub fn main() -> anyhow::Result<()> {
+    bitbelay::cli::wrapper_with_factory(std::hash::RandomState::new)
+}
 
+#[cfg(feature = "hash-ahash")]
 pub fn main() -> anyhow::Result<()> {
-    wrapper(Hasher::default())
+    bitbelay::cli::wrapper_seeded::<ahash::RandomState>()
 }